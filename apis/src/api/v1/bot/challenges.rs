@@ -3,7 +3,7 @@ use crate::{
         auth::Auth,
         messages::send::{send_challenge_creation_message, send_challenge_messages},
     },
-    notifications::{notify, time_control_label, Event},
+    notifications::{notify, notify_followers_game_started, time_control_label, Event},
     responses::{ChallengeResponse, GameResponse},
     websocket::WsHub,
 };
@@ -27,6 +27,7 @@ use shared_types::{
     ChallengeDetails,
     ChallengeId,
     ChallengeVisibility,
+    ClockKind,
     CorrespondenceMode,
     GameSpeed,
     TimeMode,
@@ -48,6 +49,9 @@ pub struct BotChallengeRequest {
     pub opponent: Option<String>,
     pub color_choice: ColorChoice,
     pub time_control: BotTimeControl,
+    /// Real-time only, defaults to a Fischer increment
+    #[serde(default)]
+    pub clock_kind: ClockKind,
     pub rated: bool,
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
@@ -125,6 +129,13 @@ impl BotChallengeRequest {
             }
         };
 
+        if !self.clock_kind.supports(time_mode, time_increment) {
+            return Err(anyhow::anyhow!(
+                "{} needs a real-time clock with an increment",
+                self.clock_kind.label()
+            ));
+        }
+
         // Convert to ChallengeDetails
         Ok(ChallengeDetails {
             rated: self.rated,
@@ -135,6 +146,7 @@ impl BotChallengeRequest {
             time_mode,
             time_base,
             time_increment,
+            clock_kind: self.clock_kind,
             band_upper: self.band_upper,
             band_lower: self.band_lower,
         })
@@ -293,6 +305,9 @@ async fn accept_challenge(
         time_control: time_control_label(speed, game.time_base, game.time_increment),
         speed,
     });
+    if let Err(e) = notify_followers_game_started(&game, &mut conn).await {
+        log::error!("notify followers {}: {e}", game.nanoid);
+    }
 
     let response = GameResponse::from_model(&game, &mut conn).await?;
     Ok(response)
@@ -1,4 +1,5 @@
 pub mod challenges;
 pub mod games;
 pub mod play;
+pub mod rematch;
 pub mod users;
@@ -13,7 +13,7 @@ use actix_web::{
 use anyhow::{anyhow, Result};
 use db_lib::{
     get_conn,
-    models::{Game, User},
+    models::{Game, TimeSpent, User},
     DbPool,
 };
 use diesel_async::AsyncConnection;
@@ -109,7 +109,7 @@ async fn play_move(
                     );
                     return Err(err.into());
                 }
-                let updated_game = game.update_gamestate(&state, 0_f64, tc).await?;
+                let updated_game = game.update_gamestate(&state, TimeSpent::Measured(0.0), tc).await?;
                 send_turn_messages(hub.clone(), &updated_game, &bot, &pool, played_turn.clone())
                     .await?;
 
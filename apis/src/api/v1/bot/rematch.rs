@@ -0,0 +1,111 @@
+use crate::{
+    api::v1::{auth::Auth, messages::send::send_messages_batch},
+    common::RematchAction,
+    websocket::{server_handlers::game::rematch_handler::RematchHandler, WebsocketData, WsHub},
+};
+use actix_web::{
+    get,
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use anyhow::{anyhow, Result};
+use db_lib::{
+    get_conn,
+    models::{Game, Rematch, User},
+    DbPool,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shared_types::GameId;
+use std::sync::Arc;
+
+#[derive(Serialize, Deserialize)]
+struct RematchRequest {
+    game_id: GameId,
+    action: String,
+}
+
+#[post("/api/v1/bot/games/rematch")]
+pub async fn api_rematch(
+    Json(req): Json<RematchRequest>,
+    Auth(bot): Auth,
+    pool: Data<DbPool>,
+    hub: Data<Arc<WsHub>>,
+    data: Data<WebsocketData>,
+) -> HttpResponse {
+    match handle_rematch(req, bot.clone(), pool, hub, data).await {
+        Ok(game) => HttpResponse::Ok().json(json!({
+          "success": true,
+          "data": {
+            "bot": bot.email,
+            "bot_username": bot.username,
+            "game_id": game.nanoid,
+          }
+        })),
+        Err(e) => HttpResponse::Ok().json(json!({
+          "success": false,
+          "data": {
+            "error": e.to_string(),
+          }
+        })),
+    }
+}
+
+async fn handle_rematch(
+    req: RematchRequest,
+    bot: User,
+    pool: Data<DbPool>,
+    hub: Data<Arc<WsHub>>,
+    data: Data<WebsocketData>,
+) -> Result<Game> {
+    let action = match req.action.as_str() {
+        "offer" => RematchAction::Offer,
+        "accept" => RematchAction::Accept,
+        "decline" => RematchAction::Decline,
+        _ => return Err(anyhow!("Invalid rematch action: {}", req.action)),
+    };
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&req.game_id, &mut conn).await?;
+    if !game.user_is_player(bot.id) {
+        return Err(anyhow!("Not your game"));
+    }
+    let messages = RematchHandler::new(
+        action,
+        &game,
+        &bot.username,
+        bot.id,
+        data.into_inner(),
+        &pool,
+    )
+    .handle()
+    .await?;
+    send_messages_batch(hub.as_ref(), messages).await;
+    Ok(game)
+}
+
+#[get("/api/v1/bot/games/rematches")]
+pub async fn api_get_rematch_offers(Auth(bot): Auth, pool: Data<DbPool>) -> HttpResponse {
+    match get_rematch_offers(&bot, pool).await {
+        Ok(games) => HttpResponse::Ok().json(json!({
+          "success": true,
+          "data": {
+            "bot": bot.email,
+            "bot_username": bot.username,
+            "games": games,
+          }
+        })),
+        Err(e) => HttpResponse::Ok().json(json!({
+          "success": false,
+          "data": {
+            "error": e.to_string(),
+          }
+        })),
+    }
+}
+
+async fn get_rematch_offers(bot: &User, pool: Data<DbPool>) -> Result<Vec<String>> {
+    let mut conn = get_conn(&pool).await?;
+    let games = Rematch::offered_to(bot.id, &mut conn).await?;
+    Ok(games.into_iter().map(|game| game.nanoid).collect())
+}
@@ -36,16 +36,20 @@ use crate::{
         rules::Rules,
         rules_summary::RulesSummary,
         strategy::Strategy,
+        study::{StudyEmbed, StudyView},
         top_players::TopPlayers,
         tournament::Tournament,
         tournament_create::TournamentCreate,
+        tournament_series::TournamentSeries,
         tournaments::{HostingTournaments, JoinedTournaments, Tournaments, TournamentsByStatus},
         tutorial::Tutorial,
+        tv::TvChannelView,
     },
     providers::{
         challenges::provide_challenges,
         chat::provide_chat,
         games::provide_games,
+        live_analysis::provide_live_analysis,
         online_users::provide_users,
         provide_alerts,
         provide_api_requests,
@@ -97,6 +101,7 @@ pub fn App() -> impl IntoView {
     provide_referer();
     provide_server_updates();
     provide_schedules();
+    provide_live_analysis();
     provide_notifications();
     provide_sounds();
     provide_refocus();
@@ -230,6 +235,7 @@ pub fn App() -> impl IntoView {
                             view=|| view! { <Notifications /> }
                         />
                         <Route path=path!("/tournament/:nanoid") view=|| view! { <Tournament /> } />
+                        <Route path=path!("/series/:nanoid") view=|| view! { <TournamentSeries /> } />
                         <ProtectedRoute
                             condition=is_logged_in
                             path=path!("/tournaments/create")
@@ -294,11 +300,15 @@ pub fn App() -> impl IntoView {
                         <Route path=path!("/donate") view=|| view! { <Donate /> } />
                         <Route path=path!("/faq") view=|| view! { <Faq /> } />
                         <Route path=path!("/puzzles") view=|| view! { <Puzzles /> } />
+                        <Route path=path!("/puzzles/:nanoid") view=|| view! { <Puzzles /> } />
                         <Route path=path!("/rules") view=|| view! { <Rules /> } />
                         <Route path=path!("/strategy") view=|| view! { <Strategy /> } />
                         <Route path=path!("/resources") view=|| view! { <Resources /> } />
                         <Route path=path!("/tutorial") view=|| view! { <Tutorial /> } />
                         <Route path=path!("/rules_summary") view=|| view! { <RulesSummary /> } />
+                        <Route path=path!("/study/:nanoid") view=|| view! { <StudyView /> } />
+                        <Route path=path!("/tv") view=|| view! { <TvChannelView /> } />
+                        <Route path=path!("/tv/:channel") view=|| view! { <TvChannelView /> } />
                         <Route path=path!("/game/:nanoid") view=|| view! { <Play /> } />
                         <ProtectedRoute
                             condition=is_admin
@@ -319,6 +329,7 @@ pub fn App() -> impl IntoView {
                             view=|| view! { <AdminPushMetrics /> }
                         />
                     </ParentRoute>
+                    <Route path=path!("/embed/study/:nanoid") view=|| view! { <StudyEmbed /> } />
                 </Routes>
             </Router>
             <DirectChallengeModal state=direct_challenge />
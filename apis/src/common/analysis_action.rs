@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// A change on a live analysis board. Trees travel as `AnalysisTree::to_saved` JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnalysisSync {
+    /// The whole tree, after a new variation or annotation.
+    Tree(String),
+    /// Only the selected node changed.
+    Node(i32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnalysisAction {
+    /// Opens a session on the sender's tree, closing any other one this socket hosts.
+    Host(String),
+    Join(String),
+    Leave(String),
+    Sync {
+        session_id: String,
+        sync: AnalysisSync,
+    },
+    /// Lets every participant move instead of only the owner.
+    OpenMoves {
+        session_id: String,
+        open: bool,
+    },
+    Chat {
+        session_id: String,
+        body: String,
+    },
+}
@@ -52,7 +52,10 @@ pub fn challenge_displayed_player(
 ) -> (&UserResponse, u64) {
     if role == ChallengeViewerRole::Challenger {
         if let Some(opponent) = challenge.opponent.as_ref() {
-            return (opponent, opponent.rating_for_speed(&challenge.speed));
+            return (
+                opponent,
+                opponent.rating_for_speed(&challenge.speed, challenge.variant_class()),
+            );
         }
     }
 
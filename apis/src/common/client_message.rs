@@ -1,7 +1,9 @@
 use super::{
+    analysis_action::AnalysisAction,
     challenge_action::ChallengeAction,
     game_action::GameAction,
     ScheduleAction,
+    SeekAction,
     TournamentAction,
 };
 use serde::{Deserialize, Serialize};
@@ -25,6 +27,7 @@ pub struct SubscriptionAttempt {
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ClientRequest {
+    Analysis(AnalysisAction),
     Chat(ChatSendRequest),
     ChatSubscribe(SubscriptionAttempt),
     ChatUnsubscribe(ConversationKey),
@@ -35,6 +38,7 @@ pub enum ClientRequest {
     Pong(u64),
     Resync,
     Schedule(ScheduleAction),
+    Seek(SeekAction),
     Tournament(TournamentAction),
     // leptos-use idle or window unfocused will send
     Away, // Online and Offline are not needed because they will be handled by the WS connection
@@ -1,3 +1,4 @@
+use super::RematchAction;
 use hive_lib::{GameControl, Turn};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -8,6 +9,9 @@ pub enum GameAction {
     CheckTime,
     Control(GameControl),
     Join,
+    /// Queues a move for when the opponent has played, `None` cancels it
+    Premove(Option<Turn>),
+    Rematch(RematchAction),
     Turn(Turn),
     Unwatch,
 }
@@ -18,6 +22,9 @@ impl fmt::Display for GameAction {
             GameAction::CheckTime => write!(f, "CheckTime"),
             GameAction::Control(ref gc) => write!(f, "{gc}"),
             GameAction::Join => write!(f, "Join"),
+            GameAction::Premove(Some(ref turn)) => write!(f, "Premove {turn}"),
+            GameAction::Premove(None) => write!(f, "PremoveCancel"),
+            GameAction::Rematch(ref action) => write!(f, "{action}"),
             GameAction::Start => write!(f, "Start"),
             GameAction::Turn(ref turn) => write!(f, "{turn}"),
             GameAction::Unwatch => write!(f, "Unwatch"),
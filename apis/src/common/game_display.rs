@@ -4,7 +4,7 @@ use crate::{
 };
 use hive_lib::{Color, GameResult, GameStatus};
 use leptos_i18n::I18nContext;
-use shared_types::{Conclusion, PrettyString, TimeInfo, TimeMode, TournamentGameResult};
+use shared_types::{ClockKind, Conclusion, PrettyString, TimeInfo, TimeMode, TournamentGameResult};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TournamentLink {
@@ -17,6 +17,7 @@ pub fn untimed_time_info() -> TimeInfo {
         mode: TimeMode::Untimed,
         base: None,
         increment: None,
+        clock: ClockKind::Fischer,
     }
 }
 
@@ -25,6 +26,7 @@ pub fn game_time_info(game: &GameResponse) -> TimeInfo {
         mode: game.time_mode,
         base: game.time_base,
         increment: game.time_increment,
+        clock: game.clock_kind,
     }
 }
 
@@ -1,3 +1,4 @@
+use super::{PremoveStatus, RematchStatus};
 use hive_lib::{GameControl, Turn};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -12,6 +13,8 @@ pub enum GameReaction {
     New,
     TimedOut,
     Tv,
+    Rematch(RematchStatus),
+    Premove(PremoveStatus),
 }
 
 impl fmt::Display for GameReaction {
@@ -25,6 +28,8 @@ impl fmt::Display for GameReaction {
             GameReaction::Ready => write!(f, "Ready"),
             GameReaction::TimedOut => write!(f, "TimedOut"),
             GameReaction::Tv => write!(f, "Tv"),
+            GameReaction::Rematch(_) => write!(f, "Rematch"),
+            GameReaction::Premove(_) => write!(f, "Premove"),
         }
     }
 }
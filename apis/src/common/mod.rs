@@ -1,3 +1,4 @@
+mod analysis_action;
 mod challenge_action;
 mod challenge_viewer;
 mod client_message;
@@ -10,14 +11,19 @@ mod move_info;
 mod overlay_paint;
 mod piece_paint;
 mod piece_type;
+mod premove;
 mod rating_change_info;
+mod rematch;
 mod schedule_action;
+mod seek_action;
 mod server_result;
 mod svg_pos;
 mod time_signals;
 mod tournament_action;
+mod tv_channel;
 mod ui_utils;
 mod user_action;
+pub use analysis_action::{AnalysisAction, AnalysisSync};
 pub use challenge_action::ChallengeAction;
 pub use challenge_viewer::{
     challenge_action_flags,
@@ -44,16 +50,22 @@ pub use move_info::MoveInfo;
 pub use overlay_paint::OverlayPaint;
 pub use piece_paint::{resolve_piece_paint, BugHref, DotsHref, PiecePaint, ShadowHref, TileHref};
 pub use piece_type::PieceType;
+pub use premove::PremoveStatus;
 pub use rating_change_info::RatingChangeInfo;
+pub use rematch::{RematchAction, RematchStatus};
 pub use schedule_action::ScheduleAction;
+pub use seek_action::SeekAction;
 pub use server_result::{
+    AnalysisUpdate,
     ChallengeUpdate,
     ChatSendError,
     ExternalServerError,
     GameActionResponse,
     GameUpdate,
+    LiveAnalysisSnapshot,
     LobbySnapshot,
     ScheduleUpdate,
+    SeekUpdate,
     ServerMessage,
     ServerResult,
     SubscriptionError,
@@ -65,5 +77,6 @@ pub use server_result::{
 pub use svg_pos::{position_from_svg, SvgPos};
 pub use time_signals::{TimeParams, TimeParamsStoreFields};
 pub use tournament_action::{TournamentAction, TournamentResponseDepth};
+pub use tv_channel::TvChannel;
 pub use ui_utils::{render_text_prop, with_class};
 pub use user_action::UserAction;
@@ -0,0 +1,11 @@
+use hive_lib::Turn;
+use serde::{Deserialize, Serialize};
+
+/// What became of a player's premove. Only the player who queued it hears
+/// about it; a premove that was played shows up as a regular turn instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PremoveStatus {
+    Queued(Turn),
+    Cancelled,
+    Discarded(Turn),
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use shared_types::GameId;
+use std::fmt;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RematchAction {
+    Offer, // Accepts instead when the opponent already offered
+    Accept,
+    Decline, // Withdraws the offer when sent by whoever made it
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RematchStatus {
+    Offered { by: Uuid },
+    Declined { by: Uuid },
+    Started(GameId),
+}
+
+impl fmt::Display for RematchAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RematchAction::Offer => write!(f, "RematchOffer"),
+            RematchAction::Accept => write!(f, "RematchAccept"),
+            RematchAction::Decline => write!(f, "RematchDecline"),
+        }
+    }
+}
@@ -9,6 +9,8 @@ pub enum ScheduleAction {
     Propose(DateTime<Utc>, GameId),
     Accept(Uuid),
     Cancel(Uuid),
+    /// Asks for start times inside both players' availability windows.
+    Suggest(GameId),
     TournamentPublic(TournamentId),
     TournamentOwn(TournamentId),
 }
@@ -19,6 +21,7 @@ impl fmt::Display for ScheduleAction {
             Self::Propose(date, game_id) => write!(f, "Propose({date}, {game_id})"),
             Self::Accept(game_id) => write!(f, "Accept({game_id})"),
             Self::Cancel(game_id) => write!(f, "Cancel({game_id})"),
+            Self::Suggest(game_id) => write!(f, "Suggest({game_id})"),
             Self::TournamentPublic(id) => write!(f, "TournamentPublic({id})"),
             Self::TournamentOwn(id) => write!(f, "TournamentOwn({id})"),
         }
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use shared_types::SeekDetails;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeekAction {
+    Join(SeekDetails), // Replaces any seek the user already has
+    Cancel,
+}
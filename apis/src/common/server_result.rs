@@ -1,4 +1,8 @@
-use super::{client_message::SubscriptionAttempt, game_reaction::GameReaction};
+use super::{
+    analysis_action::AnalysisSync,
+    client_message::SubscriptionAttempt,
+    game_reaction::GameReaction,
+};
 use crate::responses::{
     ChallengeResponse,
     GameResponse,
@@ -6,8 +10,17 @@ use crate::responses::{
     ScheduleResponse,
     UserResponse,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use shared_types::{ChallengeId, ChatMessageContainer, ConversationKey, GameId, TournamentId};
+use shared_types::{
+    ChallengeId,
+    ChatMessageContainer,
+    ConversationKey,
+    GameId,
+    SeekDetails,
+    TournamentId,
+    TournamentJoinError,
+};
 use std::{collections::HashMap, fmt, time::Duration};
 use uuid::Uuid;
 
@@ -31,6 +44,10 @@ pub enum ExternalServerError {
         attempt: SubscriptionAttempt,
         error: SubscriptionError,
     },
+    TournamentJoin {
+        tournament_id: TournamentId,
+        error: TournamentJoinError,
+    },
     Request {
         reason: String,
     },
@@ -90,6 +107,9 @@ impl fmt::Display for ExternalServerError {
             Self::Unauthorized { reason } | Self::Request { reason } => reason,
             Self::ChatSend { error, .. } => error.reason(),
             Self::ChatSubscribe { error, .. } => error.reason(),
+            Self::TournamentJoin { error, .. } => {
+                return write!(f, "WebSocket request failed: {error}");
+            }
         };
         write!(f, "WebSocket request failed: {reason}")
     }
@@ -97,6 +117,7 @@ impl fmt::Display for ExternalServerError {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ServerMessage {
+    Analysis(AnalysisUpdate),
     Challenge(ChallengeUpdate),
     Chat(ChatMessageContainer),
     ChatRead {
@@ -116,6 +137,7 @@ pub enum ServerMessage {
         value: f64,
     },
     Schedule(ScheduleUpdate),
+    Seek(SeekUpdate),
     Tournament(TournamentUpdate),
     UserSettings(UserSettingsUpdate),
     UserStatus(UserUpdate),
@@ -131,6 +153,7 @@ pub struct LobbySnapshot {
     pub challenges: Vec<ChallengeResponse>,
     pub tv_games: Vec<GameResponse>,
     pub online_users: Vec<UserResponse>,
+    pub seek: Option<SeekDetails>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -143,9 +166,14 @@ pub enum TournamentUpdate {
     Invited(TournamentId),
     Joined(TournamentId),
     Left(TournamentId),
+    Paused(TournamentId),
+    /// Sent to a waitlisted player who got a seat after someone left.
+    Promoted(TournamentId),
+    Resumed(TournamentId),
     StateChanged(TournamentId),
     Started(TournamentId),
     Uninvited(TournamentId),
+    Waitlisted(TournamentId),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -187,6 +215,58 @@ pub enum ChallengeUpdate {
     Direct(ChallengeResponse),  // Player got directly invited to a game
 }
 
+/// A live analysis session as a participant sees it on joining.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveAnalysisSnapshot {
+    pub session_id: String,
+    pub owner_id: Uuid,
+    pub owner: String,
+    pub open_moves: bool,
+    pub tree: String,
+    /// Set when the owner moved around after the last full tree.
+    pub node: Option<i32>,
+    pub participants: usize,
+}
+
+/// Live analysis traffic, sent to the sockets in a session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AnalysisUpdate {
+    /// Sent to a socket that hosted or joined.
+    Joined(LiveAnalysisSnapshot),
+    Synced {
+        session_id: String,
+        sync: AnalysisSync,
+    },
+    OpenMoves {
+        session_id: String,
+        open: bool,
+    },
+    Participants {
+        session_id: String,
+        count: usize,
+    },
+    Chat {
+        session_id: String,
+        username: String,
+        body: String,
+    },
+    /// Sent back to a socket whose action the session turned down.
+    Refused {
+        session_id: String,
+        reason: String,
+    },
+    /// The owner left, or the session never existed.
+    Closed(String),
+}
+
+/// Quick-pairing state, sent to every socket of the seeking user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SeekUpdate {
+    Seeking(SeekDetails),
+    /// Paired, cancelled, or the socket that was seeking went away.
+    Stopped,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserUpdate {
     pub status: UserStatus,
@@ -206,6 +286,7 @@ pub enum ScheduleUpdate {
     Proposed(ScheduleResponse),
     Accepted(ScheduleResponse),
     Deleted(ScheduleResponse),
+    Suggestions(GameId, Vec<DateTime<Utc>>),
     TournamentSchedules(HashMap<GameId, HashMap<Uuid, ScheduleResponse>>),
     OwnTournamentSchedules(HashMap<GameId, HashMap<Uuid, ScheduleResponse>>),
 }
@@ -1,6 +1,6 @@
 use reactive_stores::Store;
 use serde::{Deserialize, Serialize};
-use shared_types::{CorrespondenceMode, TimeMode};
+use shared_types::{ClockKind, CorrespondenceMode, TimeMode};
 
 #[derive(Debug, Clone, Store, Serialize, Deserialize, PartialEq)]
 pub struct TimeParams {
@@ -9,6 +9,8 @@ pub struct TimeParams {
     pub corr_days: i32,
     pub step_sec: i32,
     pub step_min: i32,
+    #[serde(default)]
+    pub clock_kind: ClockKind,
 }
 
 impl Default for TimeParams {
@@ -64,6 +66,14 @@ impl TimeParams {
             },
         }
     }
+    /// The picked clock, falling back to Fischer where it can't apply.
+    pub fn clock_kind(&self) -> ClockKind {
+        if self.clock_kind.supports(self.time_mode, self.increment()) {
+            self.clock_kind
+        } else {
+            ClockKind::Fischer
+        }
+    }
     pub fn new() -> Self {
         let time_mode = TimeMode::RealTime;
         let corr_mode = CorrespondenceMode::DaysPerMove;
@@ -76,6 +86,7 @@ impl TimeParams {
             corr_days,
             step_sec,
             step_min,
+            clock_kind: ClockKind::Fischer,
         }
     }
 }
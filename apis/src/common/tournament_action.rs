@@ -1,5 +1,11 @@
 use serde::{Deserialize, Serialize};
-use shared_types::{GameId, TournamentDetails, TournamentGameResult, TournamentId};
+use shared_types::{
+    GameId,
+    TournamentDetails,
+    TournamentGameResult,
+    TournamentId,
+    TournamentSeriesId,
+};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -11,8 +17,9 @@ pub enum TournamentResponseDepth {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TournamentAction {
     Abandon(TournamentId),
-    // TODO: AddToSeries(TournamentId),
+    AddToSeries(TournamentId, TournamentSeriesId),
     AdjudicateResult(GameId, TournamentGameResult),
+    CancelBye(TournamentId, i32),
     DoubleForfeitUnstartedGames(TournamentId),
     ResetAdjudicatedGames(TournamentId),
     Create(Box<TournamentDetails>),
@@ -26,6 +33,11 @@ pub enum TournamentAction {
     Join(TournamentId),
     Kick(TournamentId, Uuid),
     Leave(TournamentId),
-    // TODO: RemoveFromSeries(TournamentId),
+    Pause(TournamentId),
+    RemoveFromSeries(TournamentId),
+    /// Half-point bye for a Swiss round that has not been paired yet.
+    RequestBye(TournamentId, i32),
+    Resume(TournamentId),
     Start(TournamentId),
+    Withdraw(TournamentId),
 }
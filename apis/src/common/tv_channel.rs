@@ -0,0 +1,114 @@
+use crate::responses::GameResponse;
+use shared_types::{GameSpeed, TournamentId};
+use std::{collections::HashSet, fmt, str::FromStr};
+use uuid::Uuid;
+
+const TOURNAMENT_PREFIX: &str = "tournament-";
+
+/// A `/tv/{channel}` feed: a filter over the live games the lobby already
+/// receives, best rated first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TvChannel {
+    #[default]
+    Top,
+    Speed(GameSpeed),
+    Tournament(TournamentId),
+    Following,
+}
+
+impl TvChannel {
+    pub fn includes(&self, game: &GameResponse, following: &HashSet<Uuid>) -> bool {
+        match self {
+            TvChannel::Top => true,
+            TvChannel::Speed(speed) => game.speed == *speed,
+            TvChannel::Tournament(tournament_id) => game
+                .tournament
+                .as_ref()
+                .is_some_and(|tournament| tournament.tournament_id == *tournament_id),
+            TvChannel::Following => {
+                following.contains(&game.white_player.uid)
+                    || following.contains(&game.black_player.uid)
+            }
+        }
+    }
+
+    /// The channel's games ordered by the players' combined rating, so the
+    /// first one is what the channel features.
+    pub fn games<'a>(
+        &self,
+        live: impl IntoIterator<Item = &'a GameResponse>,
+        following: &HashSet<Uuid>,
+    ) -> Vec<GameResponse> {
+        let mut games = live
+            .into_iter()
+            .filter(|game| !game.finished && self.includes(game, following))
+            .cloned()
+            .collect::<Vec<_>>();
+        games.sort_by(|a, b| {
+            combined_rating(b)
+                .total_cmp(&combined_rating(a))
+                .then_with(|| a.game_id.0.cmp(&b.game_id.0))
+        });
+        games
+    }
+}
+
+fn combined_rating(game: &GameResponse) -> f64 {
+    game.white_rating.unwrap_or_default() + game.black_rating.unwrap_or_default()
+}
+
+impl fmt::Display for TvChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TvChannel::Top => write!(f, "top"),
+            TvChannel::Speed(speed) => write!(f, "{}", speed.to_string().to_lowercase()),
+            TvChannel::Tournament(tournament_id) => write!(f, "{TOURNAMENT_PREFIX}{tournament_id}"),
+            TvChannel::Following => write!(f, "following"),
+        }
+    }
+}
+
+impl FromStr for TvChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(tournament_id) = s.strip_prefix(TOURNAMENT_PREFIX) {
+            return Ok(TvChannel::Tournament(TournamentId(
+                tournament_id.to_string(),
+            )));
+        }
+        match s {
+            "top" => Ok(TvChannel::Top),
+            "following" => Ok(TvChannel::Following),
+            _ => GameSpeed::real_time_speeds()
+                .into_iter()
+                .find(|speed| speed.to_string().to_lowercase() == s)
+                .map(TvChannel::Speed)
+                .ok_or_else(|| format!("Unknown TV channel: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_round_trip_through_their_path() {
+        for channel in [
+            TvChannel::Top,
+            TvChannel::Speed(GameSpeed::Blitz),
+            TvChannel::Tournament(TournamentId("a-b_c".to_string())),
+            TvChannel::Following,
+        ] {
+            assert_eq!(channel.to_string().parse::<TvChannel>(), Ok(channel));
+        }
+    }
+
+    #[test]
+    fn only_real_time_speeds_have_channels() {
+        assert!("correspondence".parse::<TvChannel>().is_err());
+        assert!("Blitz".parse::<TvChannel>().is_err());
+        assert_eq!("bullet".parse(), Ok(TvChannel::Speed(GameSpeed::Bullet)));
+    }
+}
@@ -57,6 +57,7 @@ pub fn ChallengeDetails(
         time_mode,
         time_base,
         time_increment,
+        clock_kind,
         ..
     } = challenge.get_value();
     let challenge_id = StoredValue::new(challenge_id);
@@ -64,6 +65,7 @@ pub fn ChallengeDetails(
         mode: time_mode,
         base: time_base,
         increment: time_increment,
+        clock: clock_kind,
     };
     let color_icon = match color_choice {
         ColorChoice::Random => icondata_bs::BsHexagonHalf,
@@ -1,6 +1,6 @@
-use hive_lib::{Color, GameResult, GameStatus};
 use leptos::prelude::*;
 use leptos_icons::*;
+use shared_types::MoveAnnotation;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{js_sys::Array, Blob, Url};
 
@@ -23,27 +23,14 @@ pub fn DownloadPgn(
         }
     };
     let download = move |_| {
-        let file = if let Some(game) = game {
-            Some(game.with_value(blob_and_filename))
+        if let Some(game) = game {
+            game.with_value(|game| download_pgn(game, &[]));
         } else {
-            game_response.with_untracked(|game| game.as_ref().map(blob_and_filename))
-        };
-        if let Some((blob, filename)) = file {
-            // Create an object URL for the blob
-            let url = Url::create_object_url_with_blob(&blob).unwrap();
-            // Create a download link
-            let a = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .create_element("a")
-                .unwrap()
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("This element is not an HtmlElement");
-            a.set_attribute("href", &url).unwrap();
-            a.set_attribute("download", &filename).unwrap();
-            a.click();
-            let _ = Url::revoke_object_url(&url);
+            game_response.with_untracked(|game| {
+                if let Some(game) = game {
+                    download_pgn(game, &[]);
+                }
+            });
         }
     };
 
@@ -56,45 +43,28 @@ pub fn DownloadPgn(
     }
 }
 
-fn blob_and_filename(game: &GameResponse) -> (Blob, String) {
-    let date = game.created_at.format("%d-%b-%Y_%H:%M:%S").to_string();
-    let game_result = match &game.game_status {
-        GameStatus::Finished(result) => match result {
-            GameResult::Draw => "Draw".to_owned(),
-            GameResult::Unknown => "Unkown".to_owned(),
-            GameResult::Winner(Color::White) => "WhiteWins".to_owned(),
-            GameResult::Winner(Color::Black) => "BlackWins".to_owned(),
-        },
-        _ => game.game_status.to_string(),
-    };
-    let mut file: Vec<String> = Vec::new();
-    let header = format!(
-        "[GameType \"{}\"]\n\
-         [Date \"{}\"]\n\
-         [Site \"hivegame.com\"]\n\
-         [White \"{}\"]\n\
-         [Black \"{}\"]\n\
-         [Result \"{}\"]\n\n",
-        game.game_type, date, game.white_player.username, game.black_player.username, game_result
-    );
-    file.push(header);
-    let mut history = game
-        .history
-        .iter()
-        .enumerate()
-        .map(|(i, (mv, dest))| format!("{}. {} {}\n", i + 1, mv, dest))
-        .collect::<Vec<String>>();
-    file.append(&mut history);
-    if game.finished {
-        file.push(format!("\n{game_result}\n"));
-    }
-    let file = file.into_iter().map(JsValue::from).collect::<Array>();
+/// Saves the game as a `.pgn` file, with `annotations` written after their moves.
+pub fn download_pgn(game: &GameResponse, annotations: &[MoveAnnotation]) {
+    let file = Array::of1(&JsValue::from(game.annotated_pgn(&[], annotations)));
+    let blob = Blob::new_with_u8_array_sequence(&file).unwrap();
     let date = game.created_at.format("%+").to_string();
-    (
-        Blob::new_with_u8_array_sequence(&file).unwrap(),
-        format!(
-            "{}_{}_vs_{}.pgn",
-            date, game.white_player.username, game.black_player.username
-        ),
-    )
+    let filename = format!(
+        "{}_{}_vs_{}.pgn",
+        date, game.white_player.username, game.black_player.username
+    );
+    // Create an object URL for the blob
+    let url = Url::create_object_url_with_blob(&blob).unwrap();
+    // Create a download link
+    let a = web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .create_element("a")
+        .unwrap()
+        .dyn_into::<web_sys::HtmlElement>()
+        .expect("This element is not an HtmlElement");
+    a.set_attribute("href", &url).unwrap();
+    a.set_attribute("download", &filename).unwrap();
+    a.click();
+    let _ = Url::revoke_object_url(&url);
 }
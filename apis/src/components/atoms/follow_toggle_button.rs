@@ -0,0 +1,62 @@
+use crate::{
+    functions::follows::{get_is_following, SetUserFollowed},
+    i18n::*,
+};
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[component]
+pub fn FollowToggleButton(user_id: Uuid) -> impl IntoView {
+    let i18n = use_i18n();
+    let action = ServerAction::<SetUserFollowed>::new();
+    let initial = LocalResource::new(move || get_is_following(user_id));
+    // The last toggle wins over the initial lookup
+    let following = Signal::derive(move || match action.value().get() {
+        Some(Ok(followed)) => followed,
+        _ => initial
+            .get()
+            .and_then(|result| result.ok())
+            .unwrap_or_default(),
+    });
+    let error = move || {
+        action
+            .value()
+            .get()
+            .and_then(|result| result.err())
+            .map(|err| err.to_string())
+    };
+    let pending = action.pending();
+    let on_click = move |_| {
+        if !pending.get_untracked() {
+            action.dispatch(SetUserFollowed {
+                followed_id: user_id,
+                followed: !following.get_untracked(),
+            });
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            on:click=on_click
+            disabled=pending
+            aria-busy=move || pending.get().to_string()
+            aria-pressed=move || following.get().to_string()
+            class=move || {
+                format!(
+                    "ui-button ui-button-sm {}",
+                    if following.get() { "ui-button-secondary" } else { "ui-button-primary" },
+                )
+            }
+        >
+            {move || {
+                if following.get() {
+                    t_string!(i18n, profile.unfollow)
+                } else {
+                    t_string!(i18n, profile.follow)
+                }
+            }}
+        </button>
+        {move || error().map(|err| view! { <span class="ui-field-error">{err}</span> })}
+    }
+}
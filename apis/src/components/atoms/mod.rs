@@ -7,6 +7,7 @@ pub mod create_challenge_button;
 pub mod date_time_picker;
 pub mod direct_challenge_button;
 pub mod download_pgn;
+pub mod follow_toggle_button;
 pub mod game_type;
 pub mod gc_button;
 pub mod history_nav_button;
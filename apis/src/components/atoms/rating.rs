@@ -2,17 +2,16 @@ use crate::responses::RatingResponse;
 use icondata_core;
 use leptos::prelude::*;
 use leptos_icons::*;
-use shared_types::{Certainty, GameSpeed};
+use shared_types::GameSpeed;
 
 #[component]
 pub fn Rating(rating: RatingResponse) -> impl IntoView {
-    let certainty_str = match rating.certainty {
-        Certainty::Clueless => "?",
-        _ => "",
-    };
+    let provisional = rating.certainty.is_provisional();
     view! {
         {rating.rating}
-        {certainty_str}
+        <Show when=move || provisional>
+            <span title="Provisional rating">"?"</span>
+        </Show>
     }
 }
 
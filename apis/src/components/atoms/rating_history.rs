@@ -8,7 +8,7 @@ use leptos::prelude::*;
 use leptos_chartistry::*;
 use leptos_meta::Style;
 use leptos_use::use_window_size;
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 use uuid::Uuid;
 
 fn build_x_ticks(data: ReadSignal<Vec<RatingHistoryResponse>>) -> TickLabels<DateTime<Utc>> {
@@ -31,9 +31,9 @@ fn build_x_ticks(data: ReadSignal<Vec<RatingHistoryResponse>>) -> TickLabels<Dat
 }
 
 #[component]
-pub fn RatingGraph(user_id: Uuid, game_speed: GameSpeed) -> impl IntoView {
+pub fn RatingGraph(user_id: Uuid, game_speed: GameSpeed, variant: VariantClass) -> impl IntoView {
     let vertical = expect_context::<OrientationSignal>().orientation_vertical;
-    let history = OnceResource::new(get_rating_history_resource(user_id, game_speed));
+    let history = OnceResource::new(get_rating_history_resource(user_id, game_speed, variant));
     let window_size = use_window_size();
     let padding_right =
         Signal::derive(move || window_size.width.get() * if vertical.get() { 0.01 } else { 0.06 });
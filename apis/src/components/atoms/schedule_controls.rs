@@ -1,7 +1,7 @@
 use crate::{
     common::{with_class, ScheduleAction},
     components::atoms::date_time_picker::DateTimePicker,
-    providers::ApiRequestsProvider,
+    providers::{schedules::SchedulesContext, ApiRequestsProvider},
     responses::ScheduleResponse,
 };
 use chrono::{DateTime, Duration, Local, Utc};
@@ -69,6 +69,21 @@ pub fn GameDateControls(player_id: Uuid, schedule: ScheduleResponse) -> impl Int
 pub fn ProposeDateControls(game_id: GameId) -> impl IntoView {
     let selected_time = RwSignal::new(Utc::now() + Duration::minutes(10));
     let api = expect_context::<ApiRequestsProvider>().0;
+    let ctx = expect_context::<SchedulesContext>();
+    let suggest_id = game_id.clone();
+    Effect::new(move |_| {
+        api.get_untracked()
+            .schedule_action(ScheduleAction::Suggest(suggest_id.clone()));
+    });
+    let suggestions_id = game_id.clone();
+    let suggestions = Signal::derive(move || {
+        ctx.suggestions.with(|suggestions| {
+            suggestions
+                .get(&suggestions_id)
+                .map(|slots| slots.iter().take(3).copied().collect::<Vec<_>>())
+                .unwrap_or_default()
+        })
+    });
     let propose = Callback::from(move |date| {
         let api = api.get();
         api.schedule_action(ScheduleAction::Propose(date, game_id.clone()));
@@ -77,6 +92,19 @@ pub fn ProposeDateControls(game_id: GameId) -> impl IntoView {
         selected_time.set(utc);
     });
     view! {
+        <Show when=move || suggestions.with(|slots| !slots.is_empty())>
+            <div class="flex flex-wrap gap-2 items-center px-2">
+                <span class="text-sm">"Both free:"</span>
+                <For each=move || suggestions.get() key=|slot| *slot let:slot>
+                    <button
+                        class="ui-button ui-button-secondary ui-button-sm"
+                        on:click=move |_| propose.run((slot,))
+                    >
+                        {slot.with_timezone(&Local).format("%a %m-%d %H:%M").to_string()}
+                    </button>
+                </For>
+            </div>
+        </Show>
         <div class="flex flex-col gap-2 p-2 sm:flex-row sm:justify-between sm:items-center">
             <DateTimePicker
                 text=""
@@ -0,0 +1,172 @@
+use crate::functions::schedules::{get_availability, SetAvailability};
+use chrono::{NaiveTime, Timelike};
+use leptos::prelude::*;
+use shared_types::{AvailabilityWindow, MINUTES_PER_DAY};
+use web_sys::js_sys::{Array, Intl::DateTimeFormat, Object, Reflect};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// The browser's IANA time zone, e.g. "Europe/Berlin".
+fn browser_time_zone() -> Option<String> {
+    let options = DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+    Reflect::get(&options, &"timeZone".into()).ok()?.as_string()
+}
+
+/// "00:00" as an end time means the end of the day.
+fn parse_minute(value: &str, is_end: bool) -> Option<i32> {
+    let time = NaiveTime::parse_from_str(value, "%H:%M").ok()?;
+    let minute = (time.hour() * 60 + time.minute()) as i32;
+    Some(if is_end && minute == 0 {
+        MINUTES_PER_DAY
+    } else {
+        minute
+    })
+}
+
+fn format_minute(minute: i32) -> String {
+    format!("{:02}:{:02}", minute / 60 % 24, minute % 60)
+}
+
+/// Weekly windows the scheduling assistant uses to suggest and propose game times.
+#[component]
+pub fn AvailabilityEditor() -> impl IntoView {
+    let windows = RwSignal::new(Vec::<AvailabilityWindow>::new());
+    let dirty = RwSignal::new(false);
+    let stored = LocalResource::new(move || async move { get_availability().await });
+    Effect::new(move |_| {
+        if let Some(Ok(stored)) = stored.get() {
+            windows.set(stored);
+        }
+    });
+    let save = ServerAction::<SetAvailability>::new();
+    let save_value = save.value();
+    Effect::watch(
+        save.version(),
+        move |_, _, _| {
+            if let Some(Ok(saved)) = save_value.get_untracked() {
+                windows.set(saved);
+                dirty.set(false);
+            }
+        },
+        false,
+    );
+
+    let time_zone = RwSignal::new(String::from("UTC"));
+    Effect::new(move |_| {
+        if let Some(zone) = browser_time_zone() {
+            time_zone.set(zone);
+        }
+    });
+    let weekday = RwSignal::new(0);
+    let start = RwSignal::new(String::from("18:00"));
+    let end = RwSignal::new(String::from("22:00"));
+    let new_window = move || {
+        let window = AvailabilityWindow {
+            weekday: weekday.get(),
+            start_minute: parse_minute(&start.get(), false)?,
+            end_minute: parse_minute(&end.get(), true)?,
+            time_zone: time_zone.get(),
+        };
+        window.is_valid().then_some(window)
+    };
+    let add = move |_| {
+        if let Some(window) = new_window() {
+            windows.update(|windows| {
+                windows.push(window);
+                windows.sort_by_key(|window| (window.weekday, window.start_minute));
+                windows.dedup();
+            });
+            dirty.set(true);
+        }
+    };
+    let remove = move |window: AvailabilityWindow| {
+        windows.update(|windows| windows.retain(|other| *other != window));
+        dirty.set(true);
+    };
+
+    view! {
+        <details class="w-full min-w-0 h-fit ui-panel">
+            <summary class="ui-panel-summary">"My Availability"</summary>
+            <div class="space-y-2 ui-panel-body">
+                <p class="text-sm">
+                    "When you and your opponent share a free slot, a game time is proposed for you. Times are in "
+                    {time_zone} "."
+                </p>
+                <For each=move || windows.get() key=|window| window.clone() let:window>
+                    <div class="flex gap-2 justify-between items-center ui-card-row">
+                        <span>
+                            {format!(
+                                "{} {}–{}",
+                                WEEKDAYS[window.weekday as usize],
+                                format_minute(window.start_minute),
+                                format_minute(window.end_minute),
+                            )}
+                            {
+                                let zone = window.time_zone.clone();
+                                move || {
+                                    (zone != time_zone.get()).then(|| format!(" ({zone})"))
+                                }
+                            }
+                        </span>
+                        <button
+                            class="ui-button ui-button-danger ui-button-sm"
+                            on:click=move |_| remove(window.clone())
+                        >
+                            "Remove"
+                        </button>
+                    </div>
+                </For>
+                <div class="flex flex-wrap gap-2 items-center">
+                    <select
+                        class="ui-field-select"
+                        prop:value=move || weekday.get().to_string()
+                        on:change=move |ev| {
+                            if let Ok(day) = event_target_value(&ev).parse() {
+                                weekday.set(day);
+                            }
+                        }
+                    >
+                        {WEEKDAYS
+                            .iter()
+                            .enumerate()
+                            .map(|(day, name)| view! { <option value=day.to_string()>{*name}</option> })
+                            .collect_view()}
+                    </select>
+                    <input
+                        class="ui-input"
+                        type="time"
+                        prop:value=start
+                        on:change=move |ev| start.set(event_target_value(&ev))
+                    />
+                    <input
+                        class="ui-input"
+                        type="time"
+                        prop:value=end
+                        on:change=move |ev| end.set(event_target_value(&ev))
+                    />
+                    <button
+                        class="ui-button ui-button-secondary ui-button-sm"
+                        prop:disabled=move || new_window().is_none()
+                        on:click=add
+                    >
+                        "Add"
+                    </button>
+                    <button
+                        class="ui-button ui-button-primary ui-button-sm"
+                        prop:disabled=move || !dirty.get()
+                        on:click=move |_| {
+                            save.dispatch(SetAvailability {
+                                windows: windows.get_untracked(),
+                            });
+                        }
+                    >
+                        "Save"
+                    </button>
+                </div>
+                <Show when=move || save_value.with(|value| matches!(value, Some(Err(_))))>
+                    <p class="text-sm text-ladybug-red">"Could not save your availability"</p>
+                </Show>
+            </div>
+        </details>
+    }
+}
@@ -42,6 +42,7 @@ pub fn ChallengeRow(
         time_mode,
         time_base,
         time_increment,
+        clock_kind,
         ..
     } = challenge;
     let i18n = use_i18n();
@@ -201,6 +202,7 @@ pub fn ChallengeRow(
         mode: time_mode,
         base: time_base,
         increment: time_increment,
+        clock: clock_kind,
     };
     view! {
         <tr class="cursor-pointer ui-dense-table-row">
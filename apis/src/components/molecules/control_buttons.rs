@@ -1,12 +1,13 @@
 use crate::{
-    common::ChallengeAction,
+    common::{ChallengeAction, RematchAction, RematchStatus},
     components::atoms::gc_button::{AcceptDenyGc, ConfirmButton},
+    functions::games::get::get_rematch_status,
     providers::{
-        challenges::ChallengeStateSignal,
         game_state::{GameStateStore, GameStateStoreFields},
         ApiRequestsProvider,
         AuthContext,
         AuthIdentity,
+        UpdateNotifier,
     },
 };
 use hive_lib::{ColorChoice, GameControl};
@@ -89,6 +90,7 @@ pub fn ControlButtons() -> impl IntoView {
                 time_mode: game.time_mode,
                 time_base: game.time_base,
                 time_increment: game.time_increment,
+                clock_kind: game.clock_kind,
                 band_upper: None,
                 band_lower: None,
             })
@@ -102,104 +104,78 @@ pub fn ControlButtons() -> impl IntoView {
         navigate("/", Default::default());
     };
 
-    let challenge_state = expect_context::<ChallengeStateSignal>();
-    let rematch_present = Memo::new(move |_| {
-        game_response.with(|game_response| {
-            let game_response = game_response.as_ref()?;
-            let game_type = game_response.game_type.to_string();
-            challenge_state.signal.with(|state| {
-                state
-                    .challenges
-                    .values()
-                    .find(|challenge| {
-                        challenge.visibility == ChallengeVisibility::Direct
-                            && challenge.opponent.as_ref().is_some_and(|opponent| {
-                                opponent.uid == game_response.black_player.uid
-                                    || opponent.uid == game_response.white_player.uid
-                            })
-                            && (challenge.challenger.uid == game_response.black_player.uid
-                                || challenge.challenger.uid == game_response.white_player.uid)
-                            && challenge.game_type == game_type
-                            && challenge.time_mode == game_response.time_mode
-                            && challenge.time_base == game_response.time_base
-                            && challenge.time_increment == game_response.time_increment
-                    })
-                    .map(|challenge| (challenge.challenge_id.clone(), challenge.challenger.uid))
-            })
-        })
+    let update_notifier = expect_context::<UpdateNotifier>();
+    let game_id = game_state.game_id();
+    let stored_rematch = LocalResource::new(move || {
+        let game_id = game_id.get();
+        let finished = is_finished.get();
+        async move {
+            match game_id {
+                Some(game_id) if finished => get_rematch_status(game_id.clone())
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|status| (game_id, status)),
+                _ => None,
+            }
+        }
     });
-
-    let sent_challenge = move || {
-        rematch_present.with(|challenge| {
-            challenge
-                .as_ref()
-                .is_some_and(|(_, challenger_id)| *challenger_id == user_id())
-        })
+    Effect::new(move |_| {
+        if let Some(Some((game_id, status))) = stored_rematch.get() {
+            update_notifier.rematches.update(|rematches| {
+                rematches.entry(game_id).or_insert(status);
+            });
+        }
+    });
+    let rematch_status = Memo::new(move |_| {
+        let game_id = game_id.get()?;
+        update_notifier
+            .rematches
+            .with(|rematches| rematches.get(&game_id).cloned())
+    });
+    let offer_from = move || match rematch_status.get() {
+        Some(RematchStatus::Offered { by }) => Some(by),
+        _ => None,
     };
+    let offered_by_me = move || offer_from().is_some_and(|by| by == user_id());
+    let offered_to_me = move || offer_from().is_some_and(|by| by != user_id());
 
     let rematch_button_tone = move || {
-        rematch_present.with(|challenge| {
-            if challenge
-                .as_ref()
-                .is_some_and(|(_, challenger_id)| *challenger_id != user_id())
-            {
-                "ui-button-success"
-            } else {
-                "ui-button-primary"
-            }
-        })
+        if offered_to_me() {
+            "ui-button-success"
+        } else {
+            "ui-button-primary"
+        }
     };
 
     let rematch_text = move || {
-        rematch_present.with(|challenge| {
-            if let Some((_, challenger_id)) = challenge {
-                if *challenger_id == user_id() {
-                    "Sent"
-                } else {
-                    "Accept"
-                }
-            } else {
-                "Rematch"
-            }
-        })
+        if let Some(RematchStatus::Started(_)) = rematch_status.get() {
+            "Go to rematch"
+        } else if offered_by_me() {
+            "Sent"
+        } else if offered_to_me() {
+            "Accept"
+        } else {
+            "Rematch"
+        }
     };
 
+    let send_rematch = move |action: RematchAction| {
+        if let Some(game_id) = game_id.get_untracked() {
+            api.get().rematch(game_id, action);
+        }
+    };
     let rematch = move |_| {
-        if let Some(challenge_id) =
-            rematch_present.with_untracked(|challenge| challenge.as_ref().map(|(id, _)| id.clone()))
-        {
-            let api = api.get();
-            api.challenge_accept(challenge_id);
-        } else if let Some(user_id) = identity.get_untracked().and_then(AuthIdentity::user_id) {
-            if let Some(details) = game_response.with_untracked(|game| {
-                let game = game.as_ref()?;
-                // TODO: color and opponent
-                let (color_choice, opponent) = if user_id == game.black_player.uid {
-                    (ColorChoice::White, Some(game.white_player.username.clone()))
-                } else if user_id == game.white_player.uid {
-                    (ColorChoice::Black, Some(game.black_player.username.clone()))
-                } else {
-                    unreachable!();
-                };
-                Some(ChallengeDetails {
-                    rated: game.rated,
-                    game_type: game.game_type,
-                    visibility: ChallengeVisibility::Direct,
-                    opponent,
-                    color_choice,
-                    time_mode: game.time_mode,
-                    time_base: game.time_base,
-                    time_increment: game.time_increment,
-                    band_upper: None,
-                    band_lower: None,
-                })
-            }) {
-                let challenge_action = ChallengeAction::Create(details);
-                let api = api.get();
-                api.challenge(challenge_action);
-            }
+        if let Some(RematchStatus::Started(new_game_id)) = rematch_status.get_untracked() {
+            let navigate = use_navigate();
+            navigate(&format!("/game/{new_game_id}"), Default::default());
+        } else if offered_to_me() {
+            send_rematch(RematchAction::Accept);
+        } else {
+            send_rematch(RematchAction::Offer);
         }
     };
+    let decline_rematch = move |_| send_rematch(RematchAction::Decline);
     move || {
         if is_finished() {
             if not_tournament() {
@@ -209,11 +185,19 @@ pub fn ControlButtons() -> impl IntoView {
                             format!("{} {}", FINISHED_GAME_BUTTON_CLASS, rematch_button_tone())
                         }
 
-                        prop:disabled=sent_challenge
+                        prop:disabled=offered_by_me
                         on:click=rematch
                     >
                         {rematch_text}
                     </button>
+                    <Show when=move || offered_by_me() || offered_to_me()>
+                        <button
+                            class=format!("{FINISHED_GAME_BUTTON_CLASS} ui-button-danger")
+                            on:click=decline_rematch
+                        >
+                            {move || if offered_by_me() { "Cancel" } else { "Decline" }}
+                        </button>
+                    </Show>
                     <button
                         class=format!("{FINISHED_GAME_BUTTON_CLASS} ui-button-primary")
                         on:click=new_opponent
@@ -0,0 +1,82 @@
+use crate::{
+    common::{UserAction, UserStatus},
+    components::molecules::user_row::UserRow,
+    functions::follows::get_following,
+    i18n::*,
+    providers::{online_users::OnlineUsersSignal, AuthContext},
+};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Players the viewer follows, online ones first, with the real-time games
+/// they're in and shortcuts to challenge or message them.
+#[component]
+pub fn FriendsPanel() -> impl IntoView {
+    let i18n = use_i18n();
+    let user = expect_context::<AuthContext>().user;
+    let online_users = expect_context::<OnlineUsersSignal>();
+    let logged_in = move || user.with(|account| account.is_some());
+    let following = LocalResource::new(move || {
+        let logged_in = logged_in();
+        async move {
+            if logged_in {
+                get_following().await.unwrap_or_default()
+            } else {
+                Vec::new()
+            }
+        }
+    });
+    let sorted = move || {
+        let mut followed = following.get().unwrap_or_default();
+        online_users.signal.with(|online| {
+            followed.sort_by_key(|entry| {
+                online.username_status.get(&entry.user.username) != Some(&UserStatus::Online)
+            });
+        });
+        followed
+    };
+
+    view! {
+        <Show when=logged_in>
+            <div class="p-2 my-2 w-full min-w-0 rounded border shrink-0 border-black/5 dark:border-white/10">
+                <div class="mb-1 text-sm font-bold">{t!(i18n, home.following.title)}</div>
+                <Show
+                    when=move || following.with(|list| list.as_ref().is_some_and(|list| !list.is_empty()))
+                    fallback=move || {
+                        view! { <p class="text-xs">{t!(i18n, home.following.empty)}</p> }
+                    }
+                >
+                    <For
+                        each=sorted
+                        key=|entry| (entry.user.uid, entry.live_games.len())
+                        let:entry
+                    >
+                        <UserRow
+                            user=entry.user
+                            actions=vec![UserAction::Challenge, UserAction::Message]
+                        />
+                        <div class="flex flex-col pl-6 text-xs">
+                            {entry
+                                .live_games
+                                .into_iter()
+                                .map(|game| {
+                                    let opponent = game.opponent;
+                                    view! {
+                                        <A href=format!("/game/{}", game.game_id)>
+                                            {game.speed.to_string()} " "
+                                            {move || {
+                                                t_string!(
+                                                    i18n, home.following.playing, opponent = opponent.clone()
+                                                )
+                                            }}
+                                        </A>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    </For>
+                </Show>
+            </div>
+        </Show>
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     i18n::*,
     providers::game_state::{GameStateStore, GameStateStoreFields},
 };
+use hive_lib::OpeningBook;
 use leptos::prelude::*;
 
 #[component]
@@ -63,6 +64,15 @@ pub fn GameInfo(
             .map(|link| format!("in {}", link.name))
             .unwrap_or_default()
     });
+    let opening_name = Memo::new(move |_| {
+        game_response.with(|game_response| {
+            game_response.as_ref().and_then(|game| {
+                OpeningBook::for_game_type(game.game_type)
+                    .classify(&game.hashes)
+                    .map(|opening| opening.name.clone())
+            })
+        })
+    });
     let result_text = Memo::new(move |_| {
         game_response.with(|game_response| {
             game_response
@@ -138,6 +148,11 @@ pub fn GameInfo(
                     <Show when=move || { compact && is_tournament() && result_text().is_some() }>
                         <div class=result_class>{result_text}</div>
                     </Show>
+                    <Show when=move || !compact && opening_name().is_some()>
+                        <div class="text-sm text-gray-600 truncate dark:text-gray-300">
+                            {opening_name}
+                        </div>
+                    </Show>
                 </div>
             </div>
         </Show>
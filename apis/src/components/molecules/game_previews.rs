@@ -10,7 +10,7 @@ use crate::{
 };
 use hive_lib::{Color, GameStatus};
 use leptos::prelude::*;
-use shared_types::{Conclusion, GameSpeed, PrettyString, TimeInfo};
+use shared_types::{Conclusion, GameSpeed, PrettyString, TimeInfo, VariantClass};
 
 #[component]
 pub fn GamePreviews(
@@ -21,7 +21,8 @@ pub fn GamePreviews(
     let unfinished_ratings_view = move |wp: StoredValue<UserResponse>,
                                         bp: StoredValue<UserResponse>,
                                         base: Option<i32>,
-                                        inc: Option<i32>| {
+                                        inc: Option<i32>,
+                                        variant: VariantClass| {
         let (white_username, white_rating) = wp.with_value(|u| {
             let username = if u.deleted {
                 t_string!(i18n, profile.deleted_user).to_string()
@@ -30,10 +31,7 @@ pub fn GamePreviews(
             };
             (
                 username,
-                u.ratings
-                    .get(&GameSpeed::from_base_increment(base, inc))
-                    .expect("Has a rating")
-                    .rating,
+                u.rating_for_speed(&GameSpeed::from_base_increment(base, inc), variant),
             )
         });
         let (black_username, black_rating) = bp.with_value(|u| {
@@ -44,10 +42,7 @@ pub fn GamePreviews(
             };
             (
                 username,
-                u.ratings
-                    .get(&GameSpeed::from_base_increment(base, inc))
-                    .expect("Has a rating")
-                    .rating,
+                u.rating_for_speed(&GameSpeed::from_base_increment(base, inc), variant),
             )
         });
         view! {
@@ -93,6 +88,7 @@ pub fn GamePreviews(
                     let board = game.create_state().board;
                     let base = game.time_base;
                     let inc = game.time_increment;
+                    let variant = VariantClass::from(game.game_type);
                     let finished = move || game.finished;
                     let rated = game.rated;
                     let game_id = game.game_id.clone();
@@ -100,6 +96,7 @@ pub fn GamePreviews(
                         mode: game.time_mode,
                         base,
                         increment: inc,
+                        clock: game.clock_kind,
                     });
                     let ratings = StoredValue::new(RatingChangeInfo::from_game_response(&game));
                     let gs = StoredValue::new(game.game_status.clone());
@@ -133,6 +130,7 @@ pub fn GamePreviews(
                                         black_player,
                                         base,
                                         inc,
+                                        variant,
                                     )
                                 >
 
@@ -16,7 +16,7 @@ use crate::{
     responses::GameResponse,
 };
 use chrono::Utc;
-use hive_lib::{Color, GameResult, GameStatus};
+use hive_lib::{Color, GameResult, GameStatus, OpeningBook};
 use leptos::prelude::*;
 use leptos_icons::*;
 use shared_types::{GameStart, PrettyString, TimeInfo, TournamentId};
@@ -28,6 +28,9 @@ pub fn GameRow(game: GameResponse) -> impl IntoView {
     let black_rating = game.black_rating();
     let ratings = StoredValue::new(RatingChangeInfo::from_game_response(&game));
     let board = game.create_state().board;
+    let opening = OpeningBook::for_game_type(game.game_type)
+        .classify(&game.hashes)
+        .map(|opening| opening.name.clone());
     let game_stored = StoredValue::new(game.clone());
     let game_status = StoredValue::new(game.game_status);
     let turn = game.turn;
@@ -45,6 +48,7 @@ pub fn GameRow(game: GameResponse) -> impl IntoView {
         mode: game.time_mode,
         base: game.time_base,
         increment: game.time_increment,
+        clock: game.clock_kind,
     };
     let rated_string = move || match game.rated {
         true => t_string!(i18n, game.rated),
@@ -175,6 +179,14 @@ pub fn GameRow(game: GameResponse) -> impl IntoView {
                         </div>
                     </Show>
                     <p class="mb-2 font-semibold text-center">{status_string}</p>
+                    {opening
+                        .map(|name| {
+                            view! {
+                                <p class="mb-1 text-xs text-center opacity-75 truncate" title=name.clone()>
+                                    {name}
+                                </p>
+                            }
+                        })}
 
                     <div class="flex overflow-hidden gap-1 justify-between items-center mb-2">
                         <div class="flex overflow-hidden flex-col items-center min-w-0 w-[45%]">
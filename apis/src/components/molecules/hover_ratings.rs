@@ -2,18 +2,26 @@ use crate::{
     components::{atoms::rating::RatingWithIcon, molecules::dropdown_panel::DropdownPanel},
     responses::UserResponse,
 };
-use leptos::{either::Either, html, prelude::*};
-use shared_types::GameSpeed;
+use leptos::{html, prelude::*};
+use shared_types::{GameSpeed, VariantClass};
 
 #[component]
 pub fn HoverRating(user: UserResponse, anchor_ref: NodeRef<html::A>) -> impl IntoView {
-    let ratings = GameSpeed::all_rated_games()
-        .iter()
-        .map(|speed| {
-            if let Some(rating) = user.ratings.get(speed) {
-                Either::Left(view! { <RatingWithIcon rating=StoredValue::new(rating.clone()) /> })
-            } else {
-                Either::Right("")
+    let ratings = VariantClass::all()
+        .into_iter()
+        .map(|variant| {
+            let rows = GameSpeed::all_rated_games()
+                .iter()
+                .filter_map(|speed| user.rating(speed, variant).cloned())
+                .map(|rating| view! { <RatingWithIcon rating=StoredValue::new(rating) /> })
+                .collect_view();
+            view! {
+                <div class="flex flex-col gap-0.5">
+                    <div class="text-xs font-semibold text-gray-500 dark:text-gray-400">
+                        {variant.to_string()}
+                    </div>
+                    {rows}
+                </div>
             }
         })
         .collect_view();
@@ -36,7 +44,7 @@ pub fn HoverRating(user: UserResponse, anchor_ref: NodeRef<html::A>) -> impl Int
             class="fixed z-50 p-2 pointer-events-none left-[var(--popup-x)] top-[var(--popup-y)]"
             style=position_vars
         >
-            {ratings}
+            <div class="flex gap-3">{ratings}</div>
         </DropdownPanel>
     }
 }
@@ -46,19 +46,7 @@ pub fn LiveTimer(side: Signal<Color>, #[prop(optional)] compact: bool) -> impl I
     let timer = expect_context::<TimerSignal>().signal;
     let tick_rate = Duration::from_millis(100);
     let Pausable { pause, resume, .. } = use_interval_fn_with_options(
-        move || {
-            timer.update(|t| {
-                if t.turn.is_multiple_of(2) {
-                    t.white_time_left = t
-                        .white_time_left
-                        .map(|t| t.checked_sub(tick_rate).unwrap_or_default());
-                } else {
-                    t.black_time_left = t
-                        .black_time_left
-                        .map(|t| t.checked_sub(tick_rate).unwrap_or_default());
-                };
-            })
-        },
+        move || timer.update(|t| t.tick(tick_rate)),
         100,
         UseIntervalFnOptions::default().immediate(false),
     );
@@ -158,10 +146,21 @@ pub fn LiveTimer(side: Signal<Color>, #[prop(optional)] compact: bool) -> impl I
             {move || {
                 timer
                     .with(|t| {
-                        let time_left = t.time_left(side());
+                        let (time_left, _) = t.display_time(side());
                         t.time_mode.time_remaining(time_left)
                     })
             }}
+            {move || {
+                timer
+                    .with(|t| {
+                        let on_move = (side() == Color::White) == t.turn.is_multiple_of(2);
+                        let delay = (on_move && !t.delay_left.is_zero())
+                            .then(|| format!("+{}", t.delay_left.as_secs_f32().ceil()));
+                        let periods = t.display_time(side()).1.map(|periods| format!("×{periods}"));
+                        delay.or(periods)
+                    })
+                    .map(|extra| view! { <span class="pl-1 text-sm opacity-70">{extra}</span> })
+            }}
 
         </div>
     }
@@ -2,6 +2,7 @@ pub mod alert;
 pub mod analysis_and_download;
 pub mod annotation_toolbar;
 pub mod annotations_layer;
+pub mod availability_editor;
 pub mod banner;
 pub mod board_pieces;
 pub mod challenge_buttons_trio;
@@ -10,6 +11,7 @@ pub mod chat_and_controls;
 pub mod control_buttons;
 pub mod dropdown_panel;
 pub mod empty_state;
+pub mod friends_panel;
 pub mod game_info;
 pub mod game_previews;
 pub mod game_row;
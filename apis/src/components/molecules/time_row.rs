@@ -24,11 +24,15 @@ pub fn TimeRow(
         let time_info = time_info();
         match time_info.mode {
             TimeMode::Untimed => EitherOf3::A("No time limit".to_owned()),
-            TimeMode::RealTime => EitherOf3::A(format!(
-                "{} + {}",
-                time_info.base.expect("Time exists") / 60,
-                time_info.increment.expect("Increment exists"),
-            )),
+            TimeMode::RealTime => {
+                let base = time_info.base.expect("Time exists") / 60;
+                let increment = time_info.increment.expect("Increment exists");
+                EitherOf3::A(if time_info.clock.is_fischer() {
+                    format!("{base} + {increment}")
+                } else {
+                    time_info.clock.time_control(base, increment)
+                })
+            }
 
             TimeMode::Correspondence => {
                 if let Some(base) = time_info.base {
@@ -31,6 +31,7 @@ pub fn TournamentInvitationNotification(tournament: TournamentAbstractResponse)
         mode: tournament.time_mode,
         base: tournament.time_base,
         increment: tournament.time_increment,
+        clock: tournament.clock_kind,
     };
 
     view! {
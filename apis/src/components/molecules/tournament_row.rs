@@ -40,6 +40,7 @@ pub fn TournamentRow(tournament: TournamentAbstractResponse) -> impl IntoView {
         mode: tournament.time_mode,
         base: tournament.time_base,
         increment: tournament.time_increment,
+        clock: tournament.clock_kind,
     };
     let total_games = tournament.games_total;
     let finished_games = Signal::derive(move || tournament.games_played);
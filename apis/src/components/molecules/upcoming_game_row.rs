@@ -48,6 +48,7 @@ pub fn UpcomingGameRow(
         mode: game.time_mode,
         base: game.time_base,
         increment: game.time_increment,
+        clock: game.clock_kind,
     };
 
     let show_button = move || {
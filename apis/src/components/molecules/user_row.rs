@@ -19,18 +19,23 @@ use leptos::{
     either::{Either, EitherOf5},
     prelude::*,
 };
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 
 #[component]
 pub fn UserRow(
     user: UserResponse,
     actions: Vec<UserAction>,
     #[prop(optional)] game_speed: Option<StoredValue<GameSpeed>>,
+    #[prop(optional)] variant: Option<VariantClass>,
 ) -> impl IntoView {
     let username = StoredValue::new(user.username.clone());
     let user_id = StoredValue::new(user.uid);
     let rating = StoredValue::new(if let Some(speed) = game_speed {
-        user.ratings.get(&speed.get_value()).cloned()
+        user.rating(
+            &speed.get_value(),
+            variant.unwrap_or(VariantClass::Expansions),
+        )
+        .cloned()
     } else {
         None
     });
@@ -1,6 +1,6 @@
 use hive_lib::Color;
 use leptos::{either::Either, prelude::*};
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 
 use crate::{
     components::{
@@ -27,11 +27,14 @@ pub fn UserWithRating(
         Color::White => game_response.with(|g| g.as_ref().map(|g| g.white_player.clone())),
         Color::Black => game_response.with(|g| g.as_ref().map(|g| g.black_player.clone())),
     });
-    let speed = Memo::new(move |_| {
+    let rating_key = Memo::new(move |_| {
         game_response.with(|g| {
-            g.as_ref().map(|resp| match resp.speed {
-                GameSpeed::Untimed => GameSpeed::Correspondence,
-                _ => resp.speed,
+            g.as_ref().map(|resp| {
+                let speed = match resp.speed {
+                    GameSpeed::Untimed => GameSpeed::Correspondence,
+                    _ => resp.speed,
+                };
+                (speed, VariantClass::from(resp.game_type))
             })
         })
     });
@@ -44,8 +47,8 @@ pub fn UserWithRating(
     let rating = move || {
         player.with(|p| {
             p.as_ref().and_then(|player| {
-                speed().map(|speed| {
-                    Either::Left(view! { <Rating rating=player.ratings.get(&speed).expect("Valid rating from speed").clone() /> })
+                rating_key().map(|(speed, variant)| {
+                    Either::Left(view! { <Rating rating=player.rating(&speed, variant).expect("Valid rating from speed").clone() /> })
                 })
             }).unwrap_or_else(|| Either::Right(view! { "" }))
         })
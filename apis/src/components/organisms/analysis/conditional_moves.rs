@@ -0,0 +1,116 @@
+use crate::{
+    functions::games::conditional_moves::{get_conditional_moves, SetConditionalMoves},
+    providers::{
+        analysis::{AnalysisSignal, AnalysisTree},
+        game_state::{GameStateStore, GameStateStoreFields},
+        AuthContext,
+    },
+};
+use leptos::prelude::*;
+use shared_types::TimeMode;
+
+const BUTTON_CLASS: &str = "ui-button ui-button-sm h-9 flex-1 px-3 text-xs";
+
+/// Lets the player who is waiting in a correspondence game store the
+/// variations explored from the current position as conditional moves.
+#[component]
+pub fn ConditionalMoves() -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let analysis = expect_context::<AnalysisSignal>().tree;
+    let user = expect_context::<AuthContext>().user;
+    let game_response = game_state.game_response();
+    let waiting_game = Memo::new(move |_| {
+        let user_id = user.with(|account| account.as_ref().map(|account| account.user.uid))?;
+        game_response.with(|game| {
+            let game = game.as_ref()?;
+            let is_player = [game.white_player.uid, game.black_player.uid].contains(&user_id);
+            (is_player
+                && !game.finished
+                && game.time_mode == TimeMode::Correspondence
+                && game.current_player_id != user_id)
+                .then(|| (game.game_id.clone(), AnalysisTree::game_node_id(game)))
+        })
+    });
+
+    let save = ServerAction::<SetConditionalMoves>::new();
+    let stored = LocalResource::new(move || {
+        save.version().get();
+        let game = waiting_game.get();
+        async move {
+            match game {
+                Some((game_id, _)) => get_conditional_moves(game_id).await.unwrap_or_default(),
+                None => Vec::new(),
+            }
+        }
+    });
+    let error = move || {
+        save.value()
+            .get()
+            .and_then(|result| result.err())
+            .map(|err| err.to_string())
+    };
+    // Lines ending on the opponent's move have nothing to reply with
+    let explored = move || {
+        let (_, node_id) = waiting_game.get()?;
+        let lines = analysis.with(|tree| tree.lines_from(node_id));
+        Some(
+            lines
+                .into_iter()
+                .map(|mut line| {
+                    line.truncate(line.len() / 2 * 2);
+                    line
+                })
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>(),
+        )
+    };
+    let store_explored = move |_| {
+        if let (Some((game_id, _)), Some(lines)) = (waiting_game.get_untracked(), explored()) {
+            save.dispatch(SetConditionalMoves { game_id, lines });
+        }
+    };
+    let clear = move |_| {
+        if let Some((game_id, _)) = waiting_game.get_untracked() {
+            save.dispatch(SetConditionalMoves {
+                game_id,
+                lines: Vec::new(),
+            });
+        }
+    };
+    let stored_count = move || stored.get().map_or(0, |lines| lines.len());
+    let explored_count = move || explored().map_or(0, |lines| lines.len());
+
+    view! {
+        <Show when=move || waiting_game.with(Option::is_some)>
+            <div class="flex flex-col gap-2 p-2 mt-2 text-xs rounded border border-black/5 dark:border-white/10">
+                <div class="font-bold">"Conditional moves"</div>
+                <p>
+                    {move || match stored_count() {
+                        0 => "None stored. Explore replies to your opponent's next move, then store them.".to_string(),
+                        1 => "1 line stored.".to_string(),
+                        n => format!("{n} lines stored."),
+                    }}
+                </p>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class=format!("{BUTTON_CLASS} ui-button-primary")
+                        prop:disabled=move || explored_count() == 0 || save.pending().get()
+                        on:click=store_explored
+                    >
+                        {move || format!("Store {} explored", explored_count())}
+                    </button>
+                    <button
+                        type="button"
+                        class=format!("{BUTTON_CLASS} ui-button-secondary")
+                        prop:disabled=move || stored_count() == 0 || save.pending().get()
+                        on:click=clear
+                    >
+                        "Clear"
+                    </button>
+                </div>
+                {move || error().map(|err| view! { <p class="text-ladybug-red">{err}</p> })}
+            </div>
+        </Show>
+    }
+}
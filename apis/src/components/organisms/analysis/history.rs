@@ -4,7 +4,9 @@ use crate::{
             atoms::{CollapsibleMove, HistoryMove},
             AnalysisHistoryControls,
             DownloadTree,
+            LiveSessionControls,
             LoadTree,
+            StudyControls,
         },
         reserve::{Alignment, Reserve},
     },
@@ -256,6 +258,8 @@ pub fn History(
                 </Show>
                 <LoadTree />
             </div>
+            <StudyControls />
+            <LiveSessionControls />
             <div class="grid gap-2 w-full grid-cols-[repeat(auto-fit,minmax(7rem,1fr))]">
                 <button on:click=move |_| promote_variation(true) class=action_button_class()>
                     "Make main line"
@@ -0,0 +1,162 @@
+use crate::{
+    common::AnalysisAction,
+    providers::{
+        analysis::{AnalysisSignal, AnalysisTree},
+        live_analysis::LiveAnalysisContext,
+        ApiRequestsProvider,
+        AuthContext,
+    },
+};
+use leptos::{ev::SubmitEvent, prelude::*};
+
+const BUTTON_CLASS: &str = "ui-button ui-button-secondary ui-button-sm h-9 flex-1 px-3 text-xs";
+
+/// Hosts, shares and chats in a live analysis session. The syncing itself happens in
+/// `use_live_analysis_sync`, so it keeps going while this panel is hidden.
+#[component]
+pub fn LiveSessionControls() -> impl IntoView {
+    let live = expect_context::<LiveAnalysisContext>();
+    let analysis = expect_context::<AnalysisSignal>().tree;
+    let api = expect_context::<ApiRequestsProvider>().0;
+    let user = expect_context::<AuthContext>().user;
+    let user_id = move || user.with(|account| account.as_ref().map(|account| account.user.uid));
+    let is_owner = move || {
+        live.session.with(|session| {
+            session
+                .as_ref()
+                .is_some_and(|session| Some(session.owner_id) == user_id())
+        })
+    };
+    let session_id = move || {
+        live.session
+            .with(|session| session.as_ref().map(|session| session.session_id.clone()))
+    };
+    let origin = RwSignal::new(String::new());
+    Effect::new(move |_| origin.set(window().location().origin().unwrap_or_default()));
+    let share_link = move || {
+        session_id()
+            .map(|id| format!("{}/analysis?live={id}", origin.get()))
+            .unwrap_or_default()
+    };
+    let message = RwSignal::new(String::new());
+
+    let host = move |_| {
+        let tree = analysis.with_untracked(AnalysisTree::to_saved);
+        api.get_untracked().analysis(AnalysisAction::Host(tree));
+    };
+    let leave = move |_| {
+        if let Some(session_id) = session_id() {
+            api.get_untracked()
+                .analysis(AnalysisAction::Leave(session_id));
+        }
+        live.clear(None);
+    };
+    let toggle_open_moves = move |ev| {
+        if let Some(session_id) = session_id() {
+            api.get_untracked().analysis(AnalysisAction::OpenMoves {
+                session_id,
+                open: event_target_checked(&ev),
+            });
+        }
+    };
+    let send_chat = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let body = message.get_untracked();
+        if body.trim().is_empty() {
+            return;
+        }
+        if let Some(session_id) = session_id() {
+            api.get_untracked()
+                .analysis(AnalysisAction::Chat { session_id, body });
+            message.set(String::new());
+        }
+    };
+
+    view! {
+        <div class="flex flex-col gap-2 w-full">
+            {move || {
+                live.notice.get().map(|notice| view! { <p class="text-xs text-ladybug-red">{notice}</p> })
+            }}
+            <Show
+                when=move || live.session.with(Option::is_some)
+                fallback=move || {
+                    view! {
+                        <Show when=move || user_id().is_some()>
+                            <button type="button" class=BUTTON_CLASS on:click=host>
+                                "Start live session"
+                            </button>
+                        </Show>
+                    }
+                }
+            >
+                <div class="flex gap-2 justify-between items-center text-xs">
+                    <span class="truncate">
+                        {move || {
+                            live.session
+                                .with(|session| {
+                                    session
+                                        .as_ref()
+                                        .map(|session| {
+                                            format!(
+                                                "Live with {} · {} here",
+                                                session.owner,
+                                                session.participants,
+                                            )
+                                        })
+                                })
+                        }}
+                    </span>
+                    <button type="button" class="ui-button ui-button-secondary ui-button-sm" on:click=leave>
+                        {move || if is_owner() { "End" } else { "Leave" }}
+                    </button>
+                </div>
+                <Show when=is_owner>
+                    <input
+                        type="text"
+                        class="ui-field-input h-9 text-xs"
+                        readonly
+                        prop:value=share_link
+                    />
+                    <label class="flex gap-2 items-center text-xs">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || {
+                                live.session
+                                    .with(|session| {
+                                        session.as_ref().is_some_and(|session| session.open_moves)
+                                    })
+                            }
+                            on:change=toggle_open_moves
+                        />
+                        "Everyone can move"
+                    </label>
+                </Show>
+                <ul class="overflow-y-auto p-1 max-h-40 text-xs rounded border border-black/5 dark:border-white/10">
+                    <For
+                        each=move || live.chat.get().into_iter().enumerate()
+                        key=|(index, _)| *index
+                        let((_, line))
+                    >
+                        <li>
+                            <span class="font-semibold">{line.username}</span>
+                            ": "
+                            {line.body}
+                        </li>
+                    </For>
+                </ul>
+                <Show when=move || user_id().is_some()>
+                    <form class="flex gap-1" on:submit=send_chat>
+                        <input
+                            type="text"
+                            class="ui-field-input h-9 text-xs"
+                            placeholder="Message"
+                            maxlength="1000"
+                            prop:value=message
+                            on:input=move |ev| message.set(event_target_value(&ev))
+                        />
+                    </form>
+                </Show>
+            </Show>
+        </div>
+    }
+}
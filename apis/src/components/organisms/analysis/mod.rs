@@ -1,16 +1,22 @@
 mod atoms;
+mod conditional_moves;
 mod game_details;
 mod history;
+mod live_session;
 mod opening_explorer;
 mod save_and_load;
 mod sidebar;
+mod study_controls;
 mod variation_list;
 
 pub use crate::hooks::history_nav::AnalysisHistoryNavigation as HistoryNavigation;
 pub use atoms::{AnalysisHistoryControls, HistoryButton, UndoButton};
+pub use conditional_moves::ConditionalMoves;
 pub use game_details::GameDetailsPanel;
 pub use history::History;
+pub use live_session::LiveSessionControls;
 pub use opening_explorer::{reset_analysis_preview, AnalysisPreviewSnapshot, OpeningExplorer};
 pub use save_and_load::{DownloadTree, LoadTree};
 pub use sidebar::{AnalysisMobileHistoryControls, AnalysisMobileTabs, AnalysisSidebar};
+pub use study_controls::StudyControls;
 pub use variation_list::VariationList;
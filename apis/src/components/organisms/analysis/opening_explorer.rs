@@ -6,16 +6,19 @@ use crate::{
         analysis::AnalysisSignal,
         game_state::{GameStateStore, GameStateStoreFields},
         ApiRequestsProvider,
+        AuthContext,
     },
     responses::ExplorerResponse,
 };
-use hive_lib::{GameStatus, GameType, Piece, Position, State};
+use chrono::{DateTime, Months, NaiveDate, Utc};
+use hive_lib::{Color, GameStatus, GameType, OpeningBook, Piece, Position, State};
 use leptos::{prelude::*, reactive::effect::batch};
 use leptos_icons::*;
 use shared_types::{
     Conclusion,
     ExplorerFilters,
     ExplorerMove,
+    ExplorerPlayer,
     GameProgress,
     GameSpeed,
     GamesQueryOptions,
@@ -58,6 +61,21 @@ fn local_moves(state: &State) -> HashMap<u64, (Piece, Position)> {
     map
 }
 
+/// Lower bounds of the rating choices; multiples of `RATING_BAND` so the aggregates apply.
+const RATING_CHOICES: [i32; 8] = [1000, 1200, 1400, 1600, 1800, 2000, 2200, 2400];
+
+/// The first instant of a `<input type="month">` value ("2026-03").
+fn month_start(input: &str) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(&format!("{}-01", input.trim()), "%Y-%m-%d").ok()?;
+    date.and_hms_opt(0, 0, 0)
+        .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+}
+
+fn month_input(date: Option<DateTime<Utc>>) -> String {
+    date.map(|d| d.format("%Y-%m").to_string())
+        .unwrap_or_default()
+}
+
 fn pct(part: i64, total: i64) -> f64 {
     if total <= 0 {
         0.0
@@ -67,13 +85,18 @@ fn pct(part: i64, total: i64) -> f64 {
 }
 
 /// The play / preview / reset handlers shared by every suggestion row, plus the (fixed for this
-/// position) side-to-move used to compute the mover's score. All `Copy`, so rows capture freely.
+/// position) side-to-move used to compute the mover's score. With a player filter the score is
+/// the player's own instead. `book` and `next_ply` name the openings the moves lead into.
+/// All `Copy`, so rows capture freely.
 #[derive(Clone, Copy)]
 struct RowHandlers {
     play: Callback<(Piece, Position)>,
     preview: Callback<(Piece, Position)>,
     reset: Callback<()>,
     white_to_move: bool,
+    personal: bool,
+    book: &'static OpeningBook,
+    next_ply: usize,
 }
 
 #[derive(Clone)]
@@ -214,16 +237,28 @@ pub fn OpeningExplorer(
         resource.get().map(|result| match result {
             Err(_) => view! { <div class="p-2">"Failed to load opening data."</div> }.into_any(),
             Ok(response) => {
-                let (local, white_to_move) = game_state
-                    .state()
-                    .with_untracked(|state| (local_moves(state), state.turn % 2 == 0));
+                let (local, white_to_move, next_ply, opening) =
+                    game_state.state().with_untracked(|state| {
+                        let opening = OpeningBook::for_game_type(state.game_type)
+                            .classify(&state.hashes)
+                            .map(|opening| opening.name.clone());
+                        (
+                            local_moves(state),
+                            state.turn % 2 == 0,
+                            state.hashes.len(),
+                            opening,
+                        )
+                    });
                 let handlers = RowHandlers {
                     play: play_move,
                     preview: preview_move,
                     reset: reset_preview,
                     white_to_move,
+                    personal: filters.with_untracked(|f| f.player.is_some()),
+                    book: OpeningBook::for_game_type(game_type),
+                    next_ply,
                 };
-                render_response(response, handlers, &local, search_href()).into_any()
+                render_response(response, handlers, &local, search_href(), opening).into_any()
             }
         })
     };
@@ -250,14 +285,26 @@ fn render_response(
     handlers: RowHandlers,
     local: &HashMap<u64, (Piece, Position)>,
     search_href: Option<String>,
+    opening: Option<String>,
 ) -> impl IntoView {
     let header = response.position_total;
+    let opening = opening.map(|name| {
+        view! { <div class="px-2 text-xs font-semibold truncate" title=name.clone()>{name}</div> }
+    });
     let total = header.total;
     let games_label = if total == 1 {
         "1 game".to_string()
     } else {
         format!("{total} games")
     };
+    let personal = handlers.personal.then(|| {
+        let (wins, losses) = (header.player_wins, header.player_losses);
+        view! {
+            <div class="px-2 text-xs text-gray-500 dark:text-gray-400">
+                {format!("Player: {wins} won · {} drawn · {losses} lost", header.draws)}
+            </div>
+        }
+    });
     let has_top = !response.top_games.is_empty();
     let has_recent = !response.recent_games.is_empty();
     let rows = response
@@ -287,7 +334,9 @@ fn render_response(
                     <tr class="text-left text-gray-500 dark:text-gray-400">
                         <th class="py-1 px-1 w-1/3 font-normal">"Move"</th>
                         <th class="py-1 px-1 w-1/5 font-normal text-right">"Games"</th>
-                        <th class="py-1 px-1 w-1/5 font-normal text-right">"Score"</th>
+                        <th class="py-1 px-1 w-1/5 font-normal text-right">
+                            {if handlers.personal { "Player" } else { "Score" }}
+                        </th>
                         <th class="py-1 px-1 font-normal">"W / D / B"</th>
                     </tr>
                 </thead>
@@ -337,6 +386,8 @@ fn render_response(
                     total=header.total
                 />
             </div>
+            {opening}
+            {personal}
             {table}
             {search_link}
             {top_header}
@@ -424,10 +475,20 @@ fn move_row(
     };
     let total = m.total;
     let (white, draws, black) = (m.white_wins, m.draws, m.black_wins);
-    let mover_wins = if handlers.white_to_move { white } else { black };
-    let score = pct(mover_wins, total); // win share; draws shown separately in the bar
-                                        // Prefer the locally-derived piece (matches the move that gets played); fall back to the
-                                        // server's representative label when we can't map the suggestion onto a local move.
+    let wins = if handlers.personal {
+        m.player_wins
+    } else if handlers.white_to_move {
+        white
+    } else {
+        black
+    };
+    let score = pct(wins, total); // win share; draws shown separately in the bar
+                                  // Prefer the locally-derived piece (matches the move that gets played); fall back to the
+                                  // server's representative label when we can't map the suggestion onto a local move.
+    let opening = handlers
+        .book
+        .lookup(handlers.next_ply, m.next_hash as u64)
+        .map(|opening| (opening.code.clone(), opening.name.clone()));
     let icon_piece = local_move
         .map(|(piece, _)| piece)
         .or_else(|| Piece::from_str(&m.piece).ok());
@@ -450,6 +511,17 @@ fn move_row(
                 <div class="flex gap-1 items-center min-w-0">
                     {icon_piece.map(|piece| view! { <BugTile piece /> })}
                     <span class="font-mono truncate">{label}</span>
+                    {opening
+                        .map(|(code, name)| {
+                            view! {
+                                <span
+                                    class="text-gray-500 shrink-0 dark:text-gray-400"
+                                    title=name
+                                >
+                                    {code}
+                                </span>
+                            }
+                        })}
                 </div>
             </td>
             <td class="py-1.5 px-1 tabular-nums text-right">{total.to_string()}</td>
@@ -487,6 +559,26 @@ fn FilterControls(filters: RwSignal<ExplorerFilters>) -> impl IntoView {
             }
         });
     };
+    // "Me" fills in the logged in player; any other username can be typed in for preparation.
+    let auth_context = expect_context::<AuthContext>();
+    let my_username = Signal::derive(move || {
+        auth_context
+            .user
+            .with(|a| a.as_ref().map(|account| account.user.username.clone()))
+    });
+    let set_player = move |username: String| {
+        let username = username.trim().to_string();
+        filters.update(|f| {
+            f.player = if username.is_empty() {
+                None
+            } else {
+                Some(ExplorerPlayer {
+                    username,
+                    color: f.player.as_ref().and_then(|player| player.color),
+                })
+            };
+        });
+    };
     // Show Untimed only when not restricted to rated games (rated games can't be untimed).
     let show_untimed = Signal::derive(move || filters.with(|f| f.rated != Some(true)));
     let untimed_pill_class = speed_pill_class(GameSpeed::Untimed);
@@ -543,6 +635,154 @@ fn FilterControls(filters: RwSignal<ExplorerFilters>) -> impl IntoView {
                     </select>
                 </div>
             </div>
+            <div class="grid grid-cols-2 gap-2">
+                <div class="space-y-1">
+                    <label class="ui-field-label">"Player"</label>
+                    <div class="flex gap-1">
+                        <input
+                            class="min-w-0 ui-field-input"
+                            type="text"
+                            placeholder="Everyone"
+                            prop:value=move || {
+                                filters
+                                    .with(|f| {
+                                        f.player
+                                            .as_ref()
+                                            .map(|player| player.username.clone())
+                                            .unwrap_or_default()
+                                    })
+                            }
+                            on:change=move |ev| set_player(event_target_value(&ev))
+                        />
+                        <Show when=move || my_username.with(Option::is_some)>
+                            <button
+                                class="ui-button ui-button-secondary ui-button-sm"
+                                on:click=move |_| set_player(my_username.get().unwrap_or_default())
+                            >
+                                "Me"
+                            </button>
+                        </Show>
+                    </div>
+                </div>
+                <div class="space-y-1">
+                    <label class="ui-field-label">"As"</label>
+                    <select
+                        class="ui-field-select"
+                        prop:disabled=move || filters.with(|f| f.player.is_none())
+                        prop:value=Signal::derive(move || {
+                            match filters.with(|f| f.player.as_ref().and_then(|player| player.color))
+                            {
+                                Some(Color::White) => "white".to_string(),
+                                Some(Color::Black) => "black".to_string(),
+                                None => "any".to_string(),
+                            }
+                        })
+                        on:change=move |ev| {
+                            let v = event_target_value(&ev);
+                            filters
+                                .update(|f| {
+                                    if let Some(player) = f.player.as_mut() {
+                                        player.color = match v.as_str() {
+                                            "white" => Some(Color::White),
+                                            "black" => Some(Color::Black),
+                                            _ => None,
+                                        };
+                                    }
+                                });
+                        }
+                    >
+                        <option value="any">"Either colour"</option>
+                        <option value="white">"White"</option>
+                        <option value="black">"Black"</option>
+                    </select>
+                </div>
+            </div>
+            <div class="grid grid-cols-2 gap-2">
+                <div class="space-y-1">
+                    <label class="ui-field-label">"Rating"</label>
+                    <div class="flex gap-1">
+                        <select
+                            class="min-w-0 ui-field-select"
+                            prop:value=Signal::derive(move || {
+                                filters
+                                    .with(|f| f.rating_min.map(|r| r.to_string()).unwrap_or_default())
+                            })
+                            on:change=move |ev| {
+                                let v = event_target_value(&ev);
+                                filters.update(|f| f.rating_min = v.parse().ok());
+                            }
+                        >
+                            <option value="">"Any"</option>
+                            {RATING_CHOICES
+                                .into_iter()
+                                .map(|r| view! { <option value=r.to_string()>{format!("{r}+")}</option> })
+                                .collect_view()}
+                        </select>
+                        <select
+                            class="min-w-0 ui-field-select"
+                            prop:value=Signal::derive(move || {
+                                filters
+                                    .with(|f| f.rating_max.map(|r| r.to_string()).unwrap_or_default())
+                            })
+                            on:change=move |ev| {
+                                let v = event_target_value(&ev);
+                                filters.update(|f| f.rating_max = v.parse().ok());
+                            }
+                        >
+                            <option value="">"Any"</option>
+                            {RATING_CHOICES
+                                .into_iter()
+                                .map(|r| {
+                                    view! {
+                                        <option value=r.to_string()>
+                                            {format!("< {r}")}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </div>
+                </div>
+                <div class="space-y-1">
+                    <label class="ui-field-label">"Played"</label>
+                    <div class="flex gap-1">
+                        <input
+                            class="min-w-0 ui-field-input"
+                            type="month"
+                            title="From"
+                            prop:value=Signal::derive(move || {
+                                filters.with(|f| month_input(f.date_start))
+                            })
+                            on:change=move |ev| {
+                                let v = event_target_value(&ev);
+                                filters.update(|f| f.date_start = month_start(&v));
+                            }
+                        />
+                        // Inclusive for the user; the filter ends where the next month starts.
+                        <input
+                            class="min-w-0 ui-field-input"
+                            type="month"
+                            title="Until"
+                            prop:value=Signal::derive(move || {
+                                filters
+                                    .with(|f| {
+                                        month_input(
+                                            f.date_end.and_then(|d| d.checked_sub_months(Months::new(1))),
+                                        )
+                                    })
+                            })
+                            on:change=move |ev| {
+                                let v = event_target_value(&ev);
+                                filters
+                                    .update(|f| {
+                                        f.date_end = month_start(&v)
+                                            .and_then(|d| d.checked_add_months(Months::new(1)));
+                                    });
+                            }
+                        />
+                    </div>
+                </div>
+            </div>
             <div class="flex flex-wrap gap-2">
                 {[
                     GameSpeed::Bullet,
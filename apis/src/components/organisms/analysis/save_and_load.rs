@@ -22,16 +22,7 @@ pub fn DownloadTree() -> impl IntoView {
     let analysis = expect_context::<AnalysisSignal>().tree;
 
     let download = move |_| {
-        let tree_json = analysis.with_untracked(|a| {
-            let out = AnalysisTree {
-                current_node: a.current_node.clone(),
-                tree: a.tree.clone(),
-                hashes: a.hashes.clone(),
-                game_type: a.game_type,
-                annotations: a.annotations.clone(),
-            };
-            serde_json::to_string(&out).unwrap()
-        });
+        let tree_json = analysis.with_untracked(AnalysisTree::to_saved);
 
         let (blob, filename) = blob_and_filename(tree_json);
         let url = Url::create_object_url_with_blob(&blob).unwrap();
@@ -91,18 +82,16 @@ pub fn LoadTree() -> impl IntoView {
     let from_json = move |string: JsValue| {
         string
             .as_string()
-            .and_then(|string| serde_json::from_str::<AnalysisTree>(&string).ok())
-            .map(|mut tree| {
-                tree.ensure_start_node();
-                let current_node_id = tree.current_node_id();
+            .and_then(|string| {
                 batch(|| {
-                    game_state.full_reset();
-                    if let Some(node_id) = current_node_id {
-                        tree.update_node(node_id, Some(game_state));
-                    }
+                    let tree = AnalysisTree::from_saved(&string, game_state)?;
+                    let has_current_node = tree.current_node_id().is_some();
                     analysis.tree.set(tree);
-                });
-                if current_node_id.is_some() {
+                    Some(has_current_node)
+                })
+            })
+            .map(|has_current_node| {
+                if has_current_node {
                     analysis.sync_reserve_from_game_state(game_state);
                 }
             })
@@ -1,6 +1,12 @@
 use crate::{
     components::organisms::{
-        analysis::{AnalysisHistoryControls, AnalysisPreviewSnapshot, History, OpeningExplorer},
+        analysis::{
+            AnalysisHistoryControls,
+            AnalysisPreviewSnapshot,
+            ConditionalMoves,
+            History,
+            OpeningExplorer,
+        },
         reserve::{Alignment, Reserve},
     },
     hiveground::HivegroundInteraction,
@@ -58,6 +64,7 @@ pub fn AnalysisSidebar(
             <div class="overflow-y-auto flex-grow p-3 min-h-0">
                 <Show when=move || tab() == AnalysisTab::History>
                     <History interaction history_state />
+                    <ConditionalMoves />
                 </Show>
                 <Show when=move || tab() == AnalysisTab::Explorer>
                     <div class="flex flex-col gap-3 min-h-0">
@@ -134,6 +141,7 @@ pub fn AnalysisMobileTabs(
             <div class="p-3 min-h-0">
                 <Show when=move || tab() == AnalysisTab::History>
                     <History mobile=true hide_controls=true interaction history_state />
+                    <ConditionalMoves />
                 </Show>
                 <Show when=move || tab() == AnalysisTab::Explorer>
                     <OpeningExplorer preview_snapshot />
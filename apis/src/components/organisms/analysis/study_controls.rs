@@ -0,0 +1,177 @@
+use crate::{
+    functions::studies::{get_study, CreateStudy, SaveStudyChapter},
+    providers::{analysis::AnalysisSignal, AuthContext},
+};
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use shared_types::StudyVisibility;
+
+const BUTTON_CLASS: &str = "ui-button ui-button-secondary ui-button-sm h-9 flex-1 px-3 text-xs";
+const INPUT_CLASS: &str = "ui-field-input h-9 text-xs";
+
+/// Saves the analysis into a study: a new one, or the chapter opened from `?study=`.
+#[component]
+pub fn StudyControls() -> impl IntoView {
+    let analysis = expect_context::<AnalysisSignal>().tree;
+    let user = expect_context::<AuthContext>().user;
+    let queries = use_query_map();
+    let study_id = Memo::new(move |_| queries.get().get("study"));
+    let chapter = Memo::new(move |_| {
+        queries
+            .get()
+            .get("chapter")
+            .and_then(|s| s.parse::<i32>().ok())
+            .unwrap_or_default()
+    });
+    let study = LocalResource::new(move || {
+        let study_id = study_id.get();
+        async move {
+            match study_id {
+                Some(nanoid) => get_study(nanoid).await.ok(),
+                None => None,
+            }
+        }
+    });
+    let user_id = move || user.with(|account| account.as_ref().map(|account| account.user.uid));
+    let owned = move || {
+        study
+            .get()
+            .flatten()
+            .is_some_and(|study| Some(study.owner_id) == user_id())
+    };
+    let title = RwSignal::new(String::new());
+
+    let create = ServerAction::<CreateStudy>::new();
+    let save = ServerAction::<SaveStudyChapter>::new();
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(nanoid)) = create.value().get() {
+            navigate(&format!("/study/{nanoid}"), Default::default());
+        }
+    });
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let (Some(Ok(position)), Some(nanoid)) = (save.value().get(), study_id.get_untracked()) {
+            if position != chapter.get_untracked() {
+                navigate(
+                    &format!("/analysis?study={nanoid}&chapter={position}"),
+                    Default::default(),
+                );
+            }
+        }
+    });
+    let error = move || {
+        let create_error = create.value().get().and_then(|result| result.err());
+        let save_error = save.value().get().and_then(|result| result.err());
+        create_error.or(save_error).map(|err| err.to_string())
+    };
+    let pending = move || create.pending().get() || save.pending().get();
+
+    let create_study = move |_| {
+        let title = title.get_untracked();
+        create.dispatch(CreateStudy {
+            title: title.clone(),
+            visibility: StudyVisibility::Unlisted,
+            chapter_title: title,
+            tree: analysis.with_untracked(|tree| tree.to_saved()),
+        });
+    };
+    let save_chapter = move |_| {
+        let Some(nanoid) = study_id.get_untracked() else {
+            return;
+        };
+        let position = chapter.get_untracked();
+        let title = study
+            .get_untracked()
+            .flatten()
+            .and_then(|study| {
+                study
+                    .chapters
+                    .into_iter()
+                    .find(|saved| saved.position == position)
+                    .map(|saved| saved.title)
+            })
+            .unwrap_or_else(|| format!("Chapter {}", position + 1));
+        save.dispatch(SaveStudyChapter {
+            nanoid,
+            position: Some(position),
+            title,
+            tree: analysis.with_untracked(|tree| tree.to_saved()),
+        });
+    };
+    let add_chapter = move |_| {
+        if let Some(nanoid) = study_id.get_untracked() {
+            save.dispatch(SaveStudyChapter {
+                nanoid,
+                position: None,
+                title: title.get_untracked(),
+                tree: analysis.with_untracked(|tree| tree.to_saved()),
+            });
+        }
+    };
+    let title_input = move |placeholder: &'static str| {
+        view! {
+            <input
+                type="text"
+                class=INPUT_CLASS
+                placeholder=placeholder
+                maxlength="100"
+                prop:value=title
+                on:input=move |ev| title.set(event_target_value(&ev))
+            />
+        }
+    };
+
+    view! {
+        <Show when=move || user_id().is_some()>
+            <div class="flex flex-col gap-2 w-full">
+                {move || match study_id.get() {
+                    None => {
+                        view! {
+                            {title_input("Study title")}
+                            <button
+                                type="button"
+                                class=BUTTON_CLASS
+                                prop:disabled=move || title.with(|t| t.trim().is_empty()) || pending()
+                                on:click=create_study
+                            >
+                                "Save as study"
+                            </button>
+                        }
+                            .into_any()
+                    }
+                    Some(nanoid) => {
+                        view! {
+                            <a href=format!("/study/{nanoid}") class="text-xs ui-text-link">
+                                "Back to study"
+                            </a>
+                            <Show when=owned>
+                                <button
+                                    type="button"
+                                    class=BUTTON_CLASS
+                                    prop:disabled=pending
+                                    on:click=save_chapter
+                                >
+                                    "Save chapter"
+                                </button>
+                                {title_input("New chapter title")}
+                                <button
+                                    type="button"
+                                    class=BUTTON_CLASS
+                                    prop:disabled=move || {
+                                        title.with(|t| t.trim().is_empty()) || pending()
+                                    }
+                                    on:click=add_chapter
+                                >
+                                    "Add as new chapter"
+                                </button>
+                            </Show>
+                        }
+                            .into_any()
+                    }
+                }}
+                {move || error().map(|err| view! { <p class="text-xs text-ladybug-red">{err}</p> })}
+            </div>
+        </Show>
+    }
+}
@@ -94,7 +94,7 @@ pub fn DisplayTimer(placement: Placement, vertical: bool) -> impl IntoView {
     let is_button = move || {
         placement == Placement::Bottom
             && current_confirm() == MoveConfirm::Clock
-            && game_state.is_move_allowed(false)
+            && (game_state.is_move_allowed(false) || game_state.can_premove())
     };
 
     let button_class = move || {
@@ -117,7 +117,11 @@ pub fn DisplayTimer(placement: Placement, vertical: bool) -> impl IntoView {
     let api = expect_context::<ApiRequestsProvider>().0;
     let onclick = move |_| {
         if is_button() {
-            game_state.move_active(None, api());
+            if game_state.is_move_allowed(false) {
+                game_state.move_active(None, api());
+            } else {
+                game_state.queue_premove(api());
+            }
         }
     };
     let outer_container_class = move || {
@@ -0,0 +1,238 @@
+use crate::{
+    components::organisms::side_board::move_query_signal,
+    functions::games::analysis::{get_game_analysis, RequestGameAnalysis},
+    providers::{
+        game_state::{GameStateStore, GameStateStoreFields},
+        AuthContext,
+    },
+};
+use leptos::prelude::*;
+use leptos_use::use_interval_fn;
+use shared_types::{AnalysisReport, AnalysisStatus, PlyAnalysis, MATE_SCORE};
+
+/// How often a queued or running analysis is checked on, in ms.
+const POLL_MS: u64 = 5_000;
+/// Evaluations beyond this are drawn at the edge of the graph.
+const GRAPH_CLAMP: i32 = 600;
+/// Scores this close to `MATE_SCORE` are a win the engine played out, not an evaluation.
+const MATE_WINDOW: i32 = 100;
+const GRAPH_WIDTH: f64 = 300.0;
+const GRAPH_HEIGHT: f64 = 80.0;
+
+/// Engine analysis of a finished game: an evaluation graph and the moves it flagged.
+#[component]
+pub fn EngineAnalysis() -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let user = expect_context::<AuthContext>().user;
+    let game_response = game_state.game_response();
+    let game_id = Memo::new(move |_| {
+        game_response.with(|game| game.as_ref().map(|game| game.game_id.clone()))
+    });
+    let logged_in = move || user.with(|account| account.is_some());
+
+    let request = ServerAction::<RequestGameAnalysis>::new();
+    let poll = RwSignal::new(0_usize);
+    let report = LocalResource::new(move || {
+        request.version().get();
+        poll.get();
+        let game_id = game_id.get();
+        async move {
+            match game_id {
+                Some(game_id) => get_game_analysis(game_id).await.ok().flatten(),
+                None => None,
+            }
+        }
+    });
+    use_interval_fn(
+        move || {
+            let queued = report.with_untracked(|report| {
+                matches!(
+                    report,
+                    Some(Some(AnalysisReport {
+                        status: AnalysisStatus::Pending | AnalysisStatus::Running,
+                        ..
+                    }))
+                )
+            });
+            if queued {
+                poll.update(|poll| *poll += 1);
+            }
+        },
+        POLL_MS,
+    );
+    let request_analysis = move |_| {
+        if let Some(game_id) = game_id.get_untracked() {
+            request.dispatch(RequestGameAnalysis { game_id });
+        }
+    };
+    let error = move || {
+        request
+            .value()
+            .get()
+            .and_then(|result| result.err())
+            .map(|err| err.to_string())
+    };
+
+    view! {
+        <div class="flex flex-col gap-2 text-xs">
+            {move || {
+                let report = report.get().flatten();
+                match report.as_ref().map(|report| report.status) {
+                    None | Some(AnalysisStatus::Failed) => {
+                        let failed = report.and_then(|report| report.error);
+                        view! {
+                            <Show when=logged_in>
+                                <button
+                                    type="button"
+                                    class="w-full ui-button ui-button-secondary ui-button-sm"
+                                    prop:disabled=move || request.pending().get()
+                                    on:click=request_analysis
+                                >
+                                    "Request computer analysis"
+                                </button>
+                            </Show>
+                            {failed.map(|err| view! { <p class="text-ladybug-red">{err}</p> })}
+                        }
+                            .into_any()
+                    }
+                    Some(AnalysisStatus::Pending) => {
+                        view! { <p class="text-center">"Computer analysis queued…"</p> }.into_any()
+                    }
+                    Some(AnalysisStatus::Running) => {
+                        view! { <p class="text-center">"Computer analysis running…"</p> }
+                            .into_any()
+                    }
+                    Some(AnalysisStatus::Done) => {
+                        let AnalysisReport { engine, plies, .. } = report.unwrap_or_default();
+                        view! {
+                            <EvalGraph plies=plies.clone() />
+                            <FlaggedMoves plies />
+                            <p class="text-gray-500 dark:text-gray-400">
+                                {engine.trim_start_matches("id ").to_string()}
+                            </p>
+                        }
+                            .into_any()
+                    }
+                }
+            }}
+            {move || error().map(|err| view! { <p class="text-ladybug-red">{err}</p> })}
+        </div>
+    }
+}
+
+/// Jumps the board and the `move` query to the position after `ply`.
+fn show_ply(game_state: GameStateStore, set_move: SignalSetter<Option<usize>>, ply: usize) {
+    game_state.show_history_turn(ply);
+    set_move.set(Some(ply + 1));
+}
+
+/// An evaluation in neighbours around the queen, or the win the engine found.
+pub fn eval_title(eval: i32) -> String {
+    let plies = MATE_SCORE - eval.abs();
+    if plies > MATE_WINDOW {
+        return format!("{:+.1}", eval as f64 / 100.0);
+    }
+    match eval.signum() {
+        _ if plies == 0 => "game over".to_string(),
+        1 => format!("White wins in {plies}"),
+        _ => format!("Black wins in {plies}"),
+    }
+}
+
+fn graph_y(eval: i32) -> f64 {
+    let eval = eval.clamp(-GRAPH_CLAMP, GRAPH_CLAMP) as f64;
+    GRAPH_HEIGHT / 2.0 - eval / GRAPH_CLAMP as f64 * (GRAPH_HEIGHT / 2.0)
+}
+
+#[component]
+fn EvalGraph(plies: Vec<PlyAnalysis>) -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let (_, set_move) = move_query_signal();
+    let step = GRAPH_WIDTH / plies.len().max(1) as f64;
+    let points = plies
+        .iter()
+        .enumerate()
+        .map(|(i, ply)| format!("{:.1},{:.1}", (i as f64 + 0.5) * step, graph_y(ply.eval)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let markers = plies
+        .into_iter()
+        .enumerate()
+        .map(|(i, ply)| {
+            let fill = match ply.judgement {
+                Some(_) => "fill-ladybug-red",
+                None => "fill-transparent",
+            };
+            let title = eval_title(ply.eval);
+            let turn = ply.ply as usize;
+            view! {
+                <circle
+                    cx=format!("{:.1}", (i as f64 + 0.5) * step)
+                    cy=format!("{:.1}", graph_y(ply.eval))
+                    r="2.5"
+                    class=format!("cursor-pointer {fill} hover:fill-pillbug-teal")
+                    on:click=move |_| show_ply(game_state, set_move, turn)
+                >
+                    <title>{format!("{}. {title}", turn + 1)}</title>
+                </circle>
+            }
+        })
+        .collect_view();
+    view! {
+        <svg
+            viewBox=format!("0 0 {GRAPH_WIDTH} {GRAPH_HEIGHT}")
+            class="w-full bg-black rounded dark:bg-gray-900"
+        >
+            <rect
+                y=format!("{:.1}", GRAPH_HEIGHT / 2.0)
+                width=GRAPH_WIDTH.to_string()
+                height=format!("{:.1}", GRAPH_HEIGHT / 2.0)
+                class="fill-gray-300 dark:fill-gray-700"
+            />
+            <polyline
+                points=points
+                class="fill-none stroke-orange-twilight"
+                stroke-width="1.5"
+            />
+            {markers}
+        </svg>
+    }
+}
+
+#[component]
+fn FlaggedMoves(plies: Vec<PlyAnalysis>) -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let state = game_state.state();
+    let (_, set_move) = move_query_signal();
+    let flagged = plies
+        .into_iter()
+        .filter_map(|ply| ply.judgement.map(|judgement| (ply, judgement)))
+        .map(|(ply, judgement)| {
+            let turn = ply.ply as usize;
+            let played = state.with_untracked(|state| {
+                state
+                    .history
+                    .moves
+                    .get(turn)
+                    .map(|(piece, position)| format!("{piece} {position}"))
+                    .unwrap_or_default()
+            });
+            view! {
+                <li
+                    class="flex gap-2 justify-between p-1 rounded cursor-pointer hover:bg-pillbug-teal"
+                    title=judgement.to_string()
+                    on:click=move |_| show_ply(game_state, set_move, turn)
+                >
+                    <span>{format!("{}. {}{}", turn + 1, played.trim(), judgement.symbol())}</span>
+                    <span class="text-gray-500 dark:text-gray-400">
+                        {format!("best {}", ply.best_move)}
+                    </span>
+                </li>
+            }
+        })
+        .collect::<Vec<_>>();
+    if flagged.is_empty() {
+        return view! { <p class="text-center">"No mistakes found."</p> }.into_any();
+    }
+    view! { <ul class="flex flex-col">{flagged}</ul> }.into_any()
+}
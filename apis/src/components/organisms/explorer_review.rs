@@ -0,0 +1,174 @@
+use crate::{
+    components::organisms::{engine_analysis::eval_title, side_board::move_query_signal},
+    functions::opening_explorer::review_game,
+    providers::game_state::{BoardView, GameStateStore, GameStateStoreFields},
+};
+use leptos::prelude::*;
+use shared_types::{ExplorerFilters, PlyAnalysis, ReviewedPly};
+
+/// Steps through a finished game next to the opening explorer: how often each move was
+/// played, how it scored, where the game left book and, once the game was analysed, what
+/// the engine made of each move.
+#[component]
+pub fn ExplorerReview() -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let game_response = game_state.game_response();
+    let board_view = game_state.board_view();
+    let state = game_state.state();
+    let (_, set_move) = move_query_signal();
+    let game = Memo::new(move |_| {
+        game_response.with(|game| {
+            game.as_ref()
+                .map(|game| (game.game_id.clone(), game.game_type))
+        })
+    });
+    let current = Memo::new(move |_| match board_view.get() {
+        BoardView::Live => state.with(|state| state.turn.checked_sub(1)),
+        BoardView::History { turn } => turn,
+    });
+    let requested = RwSignal::new(false);
+    let rated_only = RwSignal::new(true);
+    let review = LocalResource::new(move || {
+        let requested = requested.get();
+        let rated_only = rated_only.get();
+        let game = game.get();
+        async move {
+            let (game_id, game_type) = game.filter(|_| requested)?;
+            let mut filters = ExplorerFilters::new(game_type);
+            if !rated_only {
+                filters.rated = None;
+                filters.speeds = Vec::new();
+            }
+            Some(
+                review_game(game_id, filters)
+                    .await
+                    .map_err(|err| err.to_string()),
+            )
+        }
+    });
+
+    let row = move |reviewed: ReviewedPly, novelty: bool| {
+        let ply = reviewed.ply as usize;
+        let percent = |share: Option<f64>| {
+            share
+                .map(|share| format!("{:.0}%", share * 100.0))
+                .unwrap_or_else(|| String::from("–"))
+        };
+        let played = percent(reviewed.popularity());
+        let score = percent(reviewed.score());
+        let engine = engine_cell(reviewed.analysis);
+        let class = move || {
+            let base = "grid grid-cols-5 gap-1 p-1 rounded cursor-pointer hover:bg-pillbug-teal";
+            if current.get() == Some(ply) {
+                format!("{base} bg-orange-twilight")
+            } else {
+                base.to_string()
+            }
+        };
+        view! {
+            <li
+                class=class
+                on:click=move |_| {
+                    game_state.show_history_turn(ply);
+                    set_move.set(Some(ply + 1));
+                }
+            >
+                <span class="col-span-2 truncate">
+                    {format!("{}. {} {}", ply + 1, reviewed.after.piece, reviewed.after.position)}
+                </span>
+                {if novelty {
+                    view! { <span class="col-span-2 font-semibold text-ladybug-red">"Novelty"</span> }
+                        .into_any()
+                } else {
+                    view! {
+                        <span title=format!("{} of {} games", reviewed.after.total, reviewed.before)>
+                            {played}
+                        </span>
+                        <span title="Score for the side that moved">{score}</span>
+                    }
+                        .into_any()
+                }}
+                {engine}
+            </li>
+        }
+    };
+
+    view! {
+        <div class="flex flex-col gap-2 text-xs">
+            <div class="flex gap-2 items-center">
+                <button
+                    type="button"
+                    class="flex-1 ui-button ui-button-secondary ui-button-sm"
+                    on:click=move |_| requested.update(|requested| *requested = !*requested)
+                >
+                    {move || if requested.get() { "Hide opening review" } else { "Review the opening" }}
+                </button>
+                <label class="flex gap-1 items-center">
+                    <input
+                        type="checkbox"
+                        prop:checked=rated_only
+                        on:change=move |ev| rated_only.set(event_target_checked(&ev))
+                    />
+                    "Rated only"
+                </label>
+            </div>
+            <Show when=move || requested.get()>
+                {move || match review.get().flatten() {
+                    None => view! { <p class="text-center">"Looking up the moves…"</p> }.into_any(),
+                    Some(Err(err)) => view! { <p class="text-ladybug-red">{err}</p> }.into_any(),
+                    Some(Ok(review)) => {
+                        let novelty = review.novelty;
+                        let in_book = review.plies.len();
+                        let rows = review
+                            .plies
+                            .into_iter()
+                            .map(|reviewed| {
+                                let is_novelty = Some(reviewed.ply) == novelty;
+                                row(reviewed, is_novelty)
+                            })
+                            .collect_view();
+                        view! {
+                            <div class="grid grid-cols-5 gap-1 px-1 text-gray-500 dark:text-gray-400">
+                                <span class="col-span-2">"Move"</span>
+                                <span>"Played"</span>
+                                <span>"Score"</span>
+                                <span>"Engine"</span>
+                            </div>
+                            <ul class="flex overflow-y-auto flex-col max-h-60">{rows}</ul>
+                            {novelty
+                                .is_none()
+                                .then(|| {
+                                    view! {
+                                        <p class="text-center">
+                                            {format!("Still in book after {in_book} moves")}
+                                        </p>
+                                    }
+                                })}
+                        }
+                            .into_any()
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
+
+/// The engine's move in that position, flagged with the played move's judgement.
+fn engine_cell(analysis: Option<PlyAnalysis>) -> impl IntoView {
+    let Some(analysis) = analysis else {
+        return view! { <span>"–"</span> }.into_any();
+    };
+    let title = format!(
+        "Played: {}, engine's move: {}",
+        eval_title(analysis.eval),
+        eval_title(analysis.best_eval)
+    );
+    let symbol = analysis.judgement.map(|judgement| judgement.symbol());
+    view! {
+        <span class="truncate" title=title>
+            {symbol.map(|symbol| view! { <span class="font-semibold text-ladybug-red">{symbol}</span> " " })}
+            {analysis.best_move}
+        </span>
+    }
+    .into_any()
+}
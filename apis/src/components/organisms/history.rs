@@ -1,7 +1,12 @@
 use crate::{
     components::{
         molecules::history_controls::HistoryControls,
-        organisms::side_board::move_query_signal,
+        organisms::{
+            engine_analysis::EngineAnalysis,
+            explorer_review::ExplorerReview,
+            move_annotations::MoveAnnotations,
+            side_board::move_query_signal,
+        },
     },
     hiveground::HivegroundInteraction,
     providers::game_state::{BoardView, GameStateStore, GameStateStoreFields},
@@ -177,6 +182,9 @@ pub fn History(interaction: HivegroundInteraction, history_state: Memo<State>) -
                             "Analyze here"
                         </div>
                     </a>
+                    <EngineAnalysis />
+                    <MoveAnnotations />
+                    <ExplorerReview />
                 </div>
             </Show>
             <div
@@ -9,10 +9,10 @@ use crate::{
 };
 use leptos::{either::Either, logging::log, prelude::*};
 use leptos_icons::Icon;
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 
 #[component]
-pub fn Leaderboard(speed: GameSpeed) -> impl IntoView {
+pub fn Leaderboard(speed: GameSpeed, #[prop(into)] variant: Signal<VariantClass>) -> impl IntoView {
     let speed = Signal::derive(move || speed);
     let auth_context = expect_context::<AuthContext>();
     let top_users = LocalResource::new({
@@ -21,7 +21,8 @@ pub fn Leaderboard(speed: GameSpeed) -> impl IntoView {
             let _viewer_id = auth_context
                 .user
                 .with(|account| account.as_ref().map(|account| account.id));
-            async move { get_top_users(speed(), 10).await }
+            let variant = variant.get();
+            async move { get_top_users(speed(), variant, 10).await }
         }
     });
     Effect::watch(
@@ -101,6 +102,7 @@ pub fn Leaderboard(speed: GameSpeed) -> impl IntoView {
                                                                     actions=vec![UserAction::Challenge]
                                                                     user
                                                                     game_speed=StoredValue::new(speed())
+                                                                    variant=variant.get_untracked()
                                                                 />
                                                             </div>
                                                         </div>
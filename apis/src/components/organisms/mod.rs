@@ -10,12 +10,16 @@ pub mod direct_challenge_modal;
 pub mod display_games;
 pub mod display_timer;
 pub mod dropdowns;
+pub mod engine_analysis;
+pub mod explorer_review;
 pub mod featured_video;
 pub mod games_filter;
 pub mod header;
 pub mod history;
 pub mod leaderboard;
 pub mod logout;
+pub mod move_annotations;
+pub mod opening_stats;
 pub mod preselect_toggle;
 pub mod preview_tiles;
 pub mod quickplay;
@@ -32,3 +36,4 @@ pub mod time_select;
 pub mod tournament_admin;
 pub mod tv;
 pub mod unstarted;
+pub mod user_studies;
@@ -0,0 +1,210 @@
+use crate::{
+    components::atoms::download_pgn::download_pgn,
+    functions::games::annotations::{
+        get_game_annotations,
+        SaveMoveAnnotation,
+        SetAnnotationsPublic,
+    },
+    providers::{
+        game_state::{BoardView, GameStateStore, GameStateStoreFields},
+        AuthContext,
+    },
+};
+use leptos::prelude::*;
+use shared_types::{GameAnnotations, MoveAnnotation, MoveNag, MAX_MOVE_COMMENT_LENGTH};
+
+/// Comments and move symbols on a finished game. Its players write their own, everyone
+/// reads the ones that were shared.
+#[component]
+pub fn MoveAnnotations() -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let user = expect_context::<AuthContext>().user;
+    let game_response = game_state.game_response();
+    let board_view = game_state.board_view();
+    let state = game_state.state();
+    let game_id = Memo::new(move |_| {
+        game_response.with(|game| game.as_ref().map(|game| game.game_id.clone()))
+    });
+    let user_id = move || user.with(|account| account.as_ref().map(|account| account.user.uid));
+    let is_player = move || {
+        let Some(user_id) = user_id() else {
+            return false;
+        };
+        game_response.with(|game| {
+            game.as_ref().is_some_and(|game| {
+                game.white_player.uid == user_id || game.black_player.uid == user_id
+            })
+        })
+    };
+    let ply = Memo::new(move |_| match board_view.get() {
+        BoardView::Live => state.with(|state| state.turn.checked_sub(1)),
+        BoardView::History { turn } => turn,
+    });
+
+    let save = ServerAction::<SaveMoveAnnotation>::new();
+    let share = ServerAction::<SetAnnotationsPublic>::new();
+    let annotations = LocalResource::new(move || {
+        save.version().get();
+        share.version().get();
+        let game_id = game_id.get();
+        async move {
+            match game_id {
+                Some(game_id) => get_game_annotations(game_id).await.unwrap_or_default(),
+                None => Vec::new(),
+            }
+        }
+    });
+    let loaded = move || annotations.get().unwrap_or_default();
+    let own = move || {
+        let user_id = user_id()?;
+        loaded()
+            .into_iter()
+            .find(|annotations| annotations.author_id == user_id)
+    };
+
+    let nag = RwSignal::new(None::<MoveNag>);
+    let comment = RwSignal::new(String::new());
+    Effect::new(move |_| {
+        let current = ply
+            .get()
+            .and_then(|ply| own().and_then(|own| own.at(ply as i32).cloned()));
+        nag.set(current.as_ref().and_then(|annotation| annotation.nag));
+        comment.set(
+            current
+                .map(|annotation| annotation.comment)
+                .unwrap_or_default(),
+        );
+    });
+
+    let submit = move |_| {
+        let (Some(game_id), Some(ply)) = (game_id.get_untracked(), ply.get_untracked()) else {
+            return;
+        };
+        save.dispatch(SaveMoveAnnotation {
+            game_id,
+            annotation: MoveAnnotation {
+                ply: ply as i32,
+                nag: nag.get_untracked(),
+                comment: comment.get_untracked(),
+            },
+        });
+    };
+    let toggle_public = move |ev| {
+        if let Some(game_id) = game_id.get_untracked() {
+            share.dispatch(SetAnnotationsPublic {
+                game_id,
+                public: event_target_checked(&ev),
+            });
+        }
+    };
+    let export = move |_| {
+        let moves = own()
+            .or_else(|| loaded().into_iter().next())
+            .map(|annotations| annotations.moves)
+            .unwrap_or_default();
+        game_response.with_untracked(|game| {
+            if let Some(game) = game {
+                download_pgn(game, &moves);
+            }
+        });
+    };
+    let error = move || {
+        let save_error = save.value().get().and_then(|result| result.err());
+        let share_error = share.value().get().and_then(|result| result.err());
+        save_error.or(share_error).map(|err| err.to_string())
+    };
+
+    view! {
+        <div class="flex flex-col gap-2 text-xs">
+            {move || {
+                let ply = ply.get()? as i32;
+                let notes = loaded()
+                    .into_iter()
+                    .filter_map(|annotations| {
+                        let GameAnnotations { author, moves, .. } = annotations;
+                        moves
+                            .into_iter()
+                            .find(|annotation| annotation.ply == ply)
+                            .map(|annotation| (author, annotation))
+                    })
+                    .map(|(author, annotation)| {
+                        let symbol = annotation.nag.map(|nag| nag.symbol()).unwrap_or_default();
+                        view! {
+                            <li>
+                                <span class="font-semibold">{author}</span>
+                                {format!(" {symbol} {}", annotation.comment)}
+                            </li>
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (!notes.is_empty()).then(|| view! { <ul class="flex flex-col gap-1">{notes}</ul> })
+            }}
+            <Show when=move || is_player() && ply.get().is_some()>
+                <div class="flex flex-wrap gap-1">
+                    {MoveNag::all()
+                        .into_iter()
+                        .map(|symbol| {
+                            view! {
+                                <button
+                                    type="button"
+                                    title=symbol.to_string()
+                                    class=move || {
+                                        if nag.get() == Some(symbol) {
+                                            "ui-button ui-button-primary ui-button-sm"
+                                        } else {
+                                            "ui-button ui-button-secondary ui-button-sm"
+                                        }
+                                    }
+                                    on:click=move |_| {
+                                        nag.update(|nag| {
+                                            *nag = (*nag != Some(symbol)).then_some(symbol);
+                                        })
+                                    }
+                                >
+                                    {symbol.symbol()}
+                                </button>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+                <textarea
+                    class="ui-field-input text-xs"
+                    rows="2"
+                    placeholder="Comment on this move"
+                    maxlength=MAX_MOVE_COMMENT_LENGTH.to_string()
+                    prop:value=comment
+                    on:input=move |ev| comment.set(event_target_value(&ev))
+                ></textarea>
+                <div class="flex gap-2 items-center">
+                    <button
+                        type="button"
+                        class="flex-1 ui-button ui-button-secondary ui-button-sm"
+                        prop:disabled=move || save.pending().get()
+                        on:click=submit
+                    >
+                        "Save note"
+                    </button>
+                    <label class="flex gap-1 items-center">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || own().is_some_and(|own| own.public)
+                            prop:disabled=move || own().is_none()
+                            on:change=toggle_public
+                        />
+                        "Share notes"
+                    </label>
+                </div>
+            </Show>
+            <Show when=move || !loaded().is_empty()>
+                <button
+                    type="button"
+                    class="w-full ui-button ui-button-secondary ui-button-sm"
+                    on:click=export
+                >
+                    "Download PGN with notes"
+                </button>
+            </Show>
+            {move || error().map(|err| view! { <p class="text-ladybug-red">{err}</p> })}
+        </div>
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{components::molecules::empty_state::EmptyState, functions::users::get_opening_stats};
+use hive_lib::Color;
+use leptos::prelude::*;
+use shared_types::{GameProgress, GamesQueryOptions, OpeningStats as OpeningStatsRow};
+
+/// How many openings are listed before the rest are left to the archive.
+const OPENING_ROWS: usize = 10;
+
+#[component]
+pub fn OpeningStats(username: String) -> impl IntoView {
+    let username = StoredValue::new(username);
+    // Only fetched once the section is opened; most visitors never look.
+    let opened = RwSignal::new(false);
+    let stats = LocalResource::new(move || {
+        let opened = opened.get();
+        let username = username.get_value();
+        async move {
+            if opened {
+                get_opening_stats(username).await.map(Some)
+            } else {
+                Ok(None)
+            }
+        }
+    });
+
+    view! {
+        <details
+            class="px-3 w-full"
+            on:toggle=move |_| {
+                if !opened.get_untracked() {
+                    opened.set(true);
+                }
+            }
+        >
+            <summary class="text-sm font-semibold text-gray-700 cursor-pointer dark:text-gray-300">
+                "Openings"
+            </summary>
+            <Transition>
+                {move || {
+                    stats
+                        .get()
+                        .map(|result| match result {
+                            Ok(Some(rows)) if !rows.is_empty() => {
+                                view! {
+                                        <table class="mt-1 w-full text-sm">
+                                            <thead>
+                                                <tr class="text-xs text-left text-gray-500 dark:text-gray-400">
+                                                    <th class="font-normal">"Opening"</th>
+                                                    <th class="font-normal">"As"</th>
+                                                    <th class="font-normal text-right">"Games"</th>
+                                                    <th class="font-normal text-right">"W / D / L"</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                                {rows
+                                                    .into_iter()
+                                                    .take(OPENING_ROWS)
+                                                    .map(|row| opening_row(&username.get_value(), row))
+                                                    .collect_view()}
+                                            </tbody>
+                                        </table>
+                                }
+                                    .into_any()
+                            }
+                            Ok(Some(_)) => {
+                                view! { <EmptyState title="No finished games yet" class="m-2" /> }
+                                    .into_any()
+                            }
+                            Ok(None) => ().into_any(),
+                            Err(_) => {
+                                view! { <EmptyState title="Couldn't load openings" class="m-2" /> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </details>
+    }
+}
+
+fn opening_row(username: &str, row: OpeningStatsRow) -> impl IntoView {
+    let options = GamesQueryOptions {
+        player1: Some(username.to_string()),
+        opening: Some(row.code.clone()),
+        game_progress: GameProgress::Finished,
+        ..GamesQueryOptions::default()
+    };
+    let color = match row.color {
+        Color::White => "White",
+        Color::Black => "Black",
+    };
+    view! {
+        <tr class="ui-divider-bottom">
+            <td class="py-1 truncate max-w-[14rem]">
+                <a href=format!("/archive{options}") class="ui-text-link" title=row.name.clone()>
+                    {row.name}
+                </a>
+            </td>
+            <td>{color}</td>
+            <td class="text-right">{row.games}</td>
+            <td class="text-right whitespace-nowrap">
+                {format!("{} / {} / {}", row.wins, row.draws, row.losses)}
+            </td>
+        </tr>
+    }
+}
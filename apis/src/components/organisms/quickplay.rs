@@ -1,18 +1,24 @@
 use crate::{
-    common::ChallengeAction,
+    common::SeekAction,
     components::{atoms::rating::icon_for_speed, molecules::modal::Modal},
     hooks::tap_feedback::use_tap_feedback,
     i18n::*,
     pages::{challenge_bot::ChallengeBot, challenge_create::ChallengeCreate},
-    providers::{challenge_params_cookie, ApiRequestsProvider, AuthContext, ChallengeParams},
+    providers::{
+        challenge_params_cookie,
+        challenges::ChallengeStateSignal,
+        ApiRequestsProvider,
+        AuthContext,
+        ChallengeParams,
+    },
 };
-use hive_lib::{ColorChoice, GameType};
+use hive_lib::GameType;
 use leptos::{ev, html::Dialog, prelude::*};
 use leptos_icons::*;
 use leptos_router::hooks::use_navigate;
 use leptos_use::use_event_listener;
 use reactive_stores::Store;
-use shared_types::{ChallengeDetails, ChallengeVisibility, GameSpeed::*, TimeMode};
+use shared_types::{GameSpeed::*, SeekDetails};
 
 pub enum QuickPlayTimeControl {
     Bullet1p2,
@@ -52,30 +58,40 @@ pub fn GridButton(time_control: QuickPlayTimeControl) -> impl IntoView {
     };
     let hover_text = format!("{speed_name}\n{base} min base time\n+{increment} sec per move");
     let mark_pressed = use_tap_feedback(".quickplay-hex-button");
+    let details = SeekDetails {
+        game_type: GameType::MLP,
+        rated: true,
+        time_base: base * 60,
+        time_increment: increment,
+    };
+    let challenges = expect_context::<ChallengeStateSignal>();
+    let seeking = Signal::derive(move || {
+        challenges
+            .signal
+            .with(|state| state.seek.is_some_and(|seek| seek == details))
+    });
     view! {
         <button
             type="button"
             class="quickplay-hex-button ui-button"
             data-speed=speed_name
-            title=hover_text
+            data-seeking=move || seeking.get().to_string()
+            title=move || {
+                if seeking.get() {
+                    "Looking for an opponent, click to stop".to_string()
+                } else {
+                    hover_text.clone()
+                }
+            }
             on:pointerdown=move |event| mark_pressed.run(event)
             on:click=move |_| {
                 if auth_context.user.with(|a| a.is_some()) {
-                    let api = api.get();
-                    let details = ChallengeDetails {
-                        rated: true,
-                        game_type: GameType::MLP,
-                        visibility: ChallengeVisibility::Public,
-                        opponent: None,
-                        color_choice: ColorChoice::Random,
-                        time_mode: TimeMode::RealTime,
-                        time_base: Some(base * 60),
-                        time_increment: Some(increment),
-                        band_upper: None,
-                        band_lower: None,
+                    let action = if seeking.get_untracked() {
+                        SeekAction::Cancel
+                    } else {
+                        SeekAction::Join(details)
                     };
-                    let challenge_action = ChallengeAction::Create(details);
-                    api.challenge(challenge_action);
+                    api.get().seek(action);
                 } else {
                     let navigate = use_navigate();
                     navigate("/login", Default::default());
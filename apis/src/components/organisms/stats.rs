@@ -11,7 +11,7 @@ use crate::{
 };
 use leptos::{html::Dialog, prelude::*};
 use leptos_icons::*;
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 
 const METRIC_TILE_CLASS: &str =
     "rounded-lg border border-black/5 bg-odd-light/70 text-center dark:border-white/10 dark:bg-surface-muted";
@@ -24,11 +24,15 @@ const WARNING_METRIC_TEXT_CLASS: &str = "text-yellow-600 dark:text-yellow-400";
 #[component]
 pub fn Stats(user: UserResponse) -> impl IntoView {
     let user_ratings = StoredValue::new(
-        GameSpeed::all_rated_games()
-            .iter()
-            .filter_map(|speed| {
-                user.ratings
-                    .get(speed)
+        VariantClass::all()
+            .into_iter()
+            .flat_map(|variant| {
+                GameSpeed::all_rated_games()
+                    .into_iter()
+                    .map(move |speed| (speed, variant))
+            })
+            .filter_map(|(speed, variant)| {
+                user.rating(&speed, variant)
                     .filter(|rating| rating.played > 0)
                     .cloned()
             })
@@ -77,7 +81,7 @@ pub fn Stats(user: UserResponse) -> impl IntoView {
 
                             <div class="hidden lg:flex lg:flex-col lg:items-start lg:min-w-0">
                                 <div class="max-w-full text-sm font-medium text-gray-900 dark:text-gray-100 truncate">
-                                    {rating.speed.to_string()}
+                                    {format!("{} · {}", rating.speed, rating.variant)}
                                 </div>
                                 <div class="text-xs text-gray-500 dark:text-gray-400">
                                     {rating.played} " games"
@@ -99,7 +103,8 @@ pub fn Stats(user: UserResponse) -> impl IntoView {
                                 <div class="flex gap-2 items-center mb-4">
                                     <Icon icon=icon_for_speed(rating.speed) attr:class="size-6" />
                                     <h3 class="text-lg font-semibold text-gray-900 dark:text-gray-100">
-                                        {rating.speed.to_string()} " Statistics"
+                                        {format!("{} · {}", rating.speed, rating.variant)}
+                                        " Statistics"
                                     </h3>
                                 </div>
                                 <div class="space-y-3">
@@ -180,7 +185,11 @@ pub fn Stats(user: UserResponse) -> impl IntoView {
 
                                 </div>
                             </div>
-                            <RatingGraph user_id=rating.user_uid game_speed=rating.speed />
+                            <RatingGraph
+                                user_id=rating.user_uid
+                                game_speed=rating.speed
+                                variant=rating.variant
+                            />
                         }
                     })
             }}
@@ -7,7 +7,7 @@ use crate::{
 use leptos::prelude::*;
 use leptos_icons::*;
 use reactive_stores::Store;
-use shared_types::{CorrespondenceMode, GameSpeed, TimeMode};
+use shared_types::{ClockKind, CorrespondenceMode, GameSpeed, TimeMode};
 #[component]
 pub fn TimeSelect(
     is_tournament: bool,
@@ -62,6 +62,18 @@ pub fn TimeSelect(
     let seconds_calllback = Callback::new(move |new: i32| {
         params.time_signals().step_sec().update(|v| *v = new);
     });
+    let clock_kind = move || params.time_signals().clock_kind().get();
+    let is_byo_yomi = move || matches!(clock_kind(), ClockKind::ByoYomi { .. });
+    let set_clock_kind = move |kind: ClockKind| {
+        params.time_signals().clock_kind().set(kind);
+    };
+    let periods_callback = Callback::new(move |new: i32| {
+        let periods = new.clamp(1, i32::from(ClockKind::MAX_PERIODS)) as u8;
+        params
+            .time_signals()
+            .clock_kind()
+            .set(ClockKind::ByoYomi { periods });
+    });
     let days_calllback = Callback::new(move |new: i32| {
         params.time_signals().corr_days().update(|v| *v = new);
     });
@@ -138,6 +150,57 @@ pub fn TimeSelect(
                         step=1
                     />
                 </label>
+                <div class="flex flex-row flex-wrap gap-2 justify-center p-2">
+                    {[ClockKind::Fischer, ClockKind::Bronstein, ClockKind::SimpleDelay]
+                        .into_iter()
+                        .map(|kind| {
+                            view! {
+                                <button
+                                    type="button"
+                                    on:click=move |_| set_clock_kind(kind)
+                                    class=move || radio_style(clock_kind() == kind)
+                                >
+                                    {kind.label()}
+                                </button>
+                            }
+                        })
+                        .collect_view()}
+                    <button
+                        type="button"
+                        on:click=move |_| {
+                            if !is_byo_yomi() {
+                                set_clock_kind(ClockKind::ByoYomi { periods: 3 })
+                            }
+                        }
+                        class=move || radio_style(is_byo_yomi())
+                    >
+                        "Byo-yomi"
+                    </button>
+                </div>
+                <Show when=move || {
+                    !clock_kind().is_fischer()
+                        && params.time_signals().with(|ts| ts.sec_per_move()) == 0
+                }>
+                    <p class="text-sm text-center text-gray-600 dark:text-gray-400">
+                        "Needs a delay or period above 0 seconds"
+                    </p>
+                </Show>
+                <Show when=is_byo_yomi>
+                    <label class="flex-col items-center">
+                        <div>{move || clock_kind().label()}</div>
+                        <InputSliderWithCallback
+                            signal=Signal::derive(move || match clock_kind() {
+                                ClockKind::ByoYomi { periods } => i32::from(periods),
+                                _ => 0,
+                            })
+                            callback=periods_callback
+                            name="periods"
+                            min=1
+                            max=i32::from(ClockKind::MAX_PERIODS)
+                            step=1
+                        />
+                    </label>
+                </Show>
             </div>
         </Show>
         <Show when=move || time_mode() == TimeMode::Correspondence>
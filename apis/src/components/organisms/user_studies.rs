@@ -0,0 +1,76 @@
+use crate::{components::molecules::empty_state::EmptyState, functions::studies::get_user_studies};
+use leptos::prelude::*;
+
+#[component]
+pub fn UserStudies(username: String) -> impl IntoView {
+    let username = StoredValue::new(username);
+    let opened = RwSignal::new(false);
+    let studies = LocalResource::new(move || {
+        let opened = opened.get();
+        let username = username.get_value();
+        async move {
+            if opened {
+                get_user_studies(username).await.map(Some)
+            } else {
+                Ok(None)
+            }
+        }
+    });
+
+    view! {
+        <details
+            class="px-3 w-full"
+            on:toggle=move |_| {
+                if !opened.get_untracked() {
+                    opened.set(true);
+                }
+            }
+        >
+            <summary class="text-sm font-semibold text-gray-700 cursor-pointer dark:text-gray-300">
+                "Studies"
+            </summary>
+            <Transition>
+                {move || {
+                    studies
+                        .get()
+                        .map(|result| match result {
+                            Ok(Some(studies)) if !studies.is_empty() => {
+                                view! {
+                                    <ul class="mt-1 text-sm">
+                                        {studies
+                                            .into_iter()
+                                            .map(|study| {
+                                                view! {
+                                                    <li class="flex gap-2 justify-between py-1 ui-divider-bottom">
+                                                        <a
+                                                            href=format!("/study/{}", study.nanoid)
+                                                            class="truncate ui-text-link"
+                                                        >
+                                                            {study.title}
+                                                        </a>
+                                                        <span class="text-xs text-gray-500 dark:text-gray-400">
+                                                            {study.visibility.to_string()}
+                                                        </span>
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                                    .into_any()
+                            }
+                            Ok(Some(_)) => {
+                                view! { <EmptyState title="No studies yet" class="m-2" /> }
+                                    .into_any()
+                            }
+                            Ok(None) => ().into_any(),
+                            Err(_) => {
+                                view! { <EmptyState title="Couldn't load studies" class="m-2" /> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </details>
+    }
+}
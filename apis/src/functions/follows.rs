@@ -0,0 +1,65 @@
+use crate::responses::FollowedUserResponse;
+#[cfg(feature = "ssr")]
+use db_lib::db_error::DbError;
+use leptos::prelude::*;
+#[cfg(feature = "ssr")]
+use log::error;
+use server_fn::codec;
+use uuid::Uuid;
+
+#[cfg(feature = "ssr")]
+fn follows_error(context: &'static str, err: DbError) -> ServerFnError {
+    match err {
+        DbError::InvalidInput { info, .. } => ServerFnError::new(info),
+        DbError::NotFound { .. } => ServerFnError::new("User not found"),
+        err => {
+            error!("follows server function failed while {context}: {err}");
+            ServerFnError::new("Unable to update follows")
+        }
+    }
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn set_user_followed(followed_id: Uuid, followed: bool) -> Result<bool, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::helpers::{follow_user, unfollow_user};
+
+    let follower_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    if followed {
+        follow_user(&mut conn, follower_id, followed_id)
+            .await
+            .map_err(|err| follows_error("following user", err))?;
+    } else {
+        unfollow_user(&mut conn, follower_id, followed_id)
+            .await
+            .map_err(|err| follows_error("unfollowing user", err))?;
+    }
+    Ok(followed)
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_is_following(user_id: Uuid) -> Result<bool, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::helpers::is_following;
+
+    let follower_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    is_following(&mut conn, follower_id, user_id)
+        .await
+        .map_err(|err| follows_error("checking follow", err))
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_following() -> Result<Vec<FollowedUserResponse>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+
+    let follower_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    FollowedUserResponse::for_follower(follower_id, &mut conn)
+        .await
+        .map_err(ServerFnError::new)
+}
@@ -0,0 +1,65 @@
+use crate::functions::games::analysis::server_fn::codec;
+use leptos::prelude::*;
+use shared_types::{AnalysisReport, GameId};
+
+/// Queues a finished game for engine analysis and returns where it stands.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn request_game_analysis(game_id: GameId) -> Result<AnalysisReport, ServerFnError> {
+    use crate::{
+        functions::{auth::identity::uuid, db::pool},
+        jobs::game_analysis::NO_QUEEN_RULE,
+        uhp::UhpConfig,
+    };
+    use db_lib::{
+        get_conn,
+        models::{Game, GameAnalysis},
+    };
+    let user_id = uuid().await?;
+    if UhpConfig::from_env().is_none() {
+        return Err(ServerFnError::new("Engine analysis is not available"));
+    }
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    if !game.finished {
+        return Err(ServerFnError::new("Only finished games can be analysed"));
+    }
+    if !game.tournament_queen_rule {
+        return Err(ServerFnError::new(NO_QUEEN_RULE));
+    }
+    let analysis = GameAnalysis::request(game.id, user_id, &mut conn).await?;
+    Ok(AnalysisReport {
+        status: analysis.status(),
+        engine: analysis.engine,
+        error: analysis.error,
+        plies: Vec::new(),
+    })
+}
+
+/// The game's engine analysis, `None` until someone requested it.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_game_analysis(game_id: GameId) -> Result<Option<AnalysisReport>, ServerFnError> {
+    use crate::functions::db::pool;
+    use db_lib::{
+        get_conn,
+        models::{Game, GameAnalysis},
+    };
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    let Some(analysis) = GameAnalysis::find(game.id, &mut conn).await? else {
+        return Ok(None);
+    };
+    let plies = analysis
+        .plies(&mut conn)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    Ok(Some(AnalysisReport {
+        status: analysis.status(),
+        engine: analysis.engine,
+        error: analysis.error,
+        plies,
+    }))
+}
@@ -0,0 +1,80 @@
+#[cfg(feature = "ssr")]
+use db_lib::db_error::DbError;
+use leptos::prelude::*;
+#[cfg(feature = "ssr")]
+use log::error;
+use server_fn::codec;
+use shared_types::{GameAnnotations, GameId, MoveAnnotation};
+
+#[cfg(feature = "ssr")]
+fn annotations_error(context: &'static str, err: DbError) -> ServerFnError {
+    match err {
+        DbError::InvalidInput { info, .. } | DbError::InvalidAction { info } => {
+            ServerFnError::new(info)
+        }
+        DbError::NotFound { .. } => ServerFnError::new("Game not found"),
+        DbError::Unauthorized => ServerFnError::new("Only the players can annotate this game"),
+        err => {
+            error!("annotations server function failed while {context}: {err}");
+            ServerFnError::new("Unable to save the annotation")
+        }
+    }
+}
+
+/// The caller's own annotations on the game followed by everyone's shared ones.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_game_annotations(game_id: GameId) -> Result<Vec<GameAnnotations>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{
+        get_conn,
+        models::{Game, GameAnnotation},
+    };
+
+    let user_id = uuid().await.ok();
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    let mut annotations = GameAnnotation::for_game(game.id, user_id, &mut conn)
+        .await
+        .map_err(|err| annotations_error("loading annotations", err))?;
+    annotations.sort_by_key(|annotations| Some(annotations.author_id) != user_id);
+    Ok(annotations)
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn save_move_annotation(
+    game_id: GameId,
+    annotation: MoveAnnotation,
+) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{
+        get_conn,
+        models::{Game, GameAnnotation},
+    };
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    GameAnnotation::save(&game, user_id, annotation, &mut conn)
+        .await
+        .map_err(|err| annotations_error("saving annotation", err))
+}
+
+/// Shares the caller's annotations with everyone who opens the game, or hides them again.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn set_annotations_public(game_id: GameId, public: bool) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{
+        get_conn,
+        models::{Game, GameAnnotation},
+    };
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    GameAnnotation::set_public(&game, user_id, public, &mut conn)
+        .await
+        .map_err(|err| annotations_error("sharing annotations", err))
+}
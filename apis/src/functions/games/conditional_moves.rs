@@ -0,0 +1,46 @@
+use crate::functions::games::conditional_moves::server_fn::codec;
+use leptos::prelude::*;
+use shared_types::GameId;
+
+/// A conditional line: the opponent's expected move, the reply, and so on.
+pub type ConditionalLine = Vec<(String, String)>;
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_conditional_moves(game_id: GameId) -> Result<Vec<ConditionalLine>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{
+        get_conn,
+        models::{ConditionalMove, Game},
+    };
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    let lines = ConditionalMove::for_player(game.id, user_id, &mut conn).await?;
+    Ok(lines
+        .into_iter()
+        .filter(|line| line.history == game.history)
+        .map(|line| line.lines())
+        .collect())
+}
+
+/// Replaces the caller's conditional moves for a game, an empty list clears them.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn set_conditional_moves(
+    game_id: GameId,
+    lines: Vec<ConditionalLine>,
+) -> Result<Vec<ConditionalLine>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{
+        get_conn,
+        models::{ConditionalMove, Game},
+    };
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    let saved = ConditionalMove::replace(&game, user_id, lines, &mut conn)
+        .await
+        .map_err(ServerFnError::new)?;
+    Ok(saved.into_iter().map(|line| line.lines()).collect())
+}
@@ -1,9 +1,10 @@
 use crate::{
+    common::RematchStatus,
     functions::games::get::server_fn::codec,
     responses::{GameBatchResponse, GameResponse, RatingHistoryResponse},
 };
 use leptos::prelude::*;
-use shared_types::{GameId, GameSpeed, GamesQueryOptions};
+use shared_types::{GameId, GameSpeed, GamesQueryOptions, VariantClass};
 use uuid::Uuid;
 
 #[server(input = codec::Cbor, output = codec::Cbor)]
@@ -28,6 +29,31 @@ pub async fn get_game_from_nanoid(game_id: GameId) -> Result<GameResponse, Serve
         .map_err(ServerFnError::new)
 }
 
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_rematch_status(game_id: GameId) -> Result<Option<RematchStatus>, ServerFnError> {
+    use crate::functions::db::pool;
+    use db_lib::{
+        get_conn,
+        models::{Game, Rematch},
+    };
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = Game::find_by_game_id(&game_id, &mut conn).await?;
+    let Some(rematch) = Rematch::for_game(game.id, &mut conn).await? else {
+        return Ok(None);
+    };
+    let status = match rematch.new_game_id {
+        Some(new_game_id) => {
+            let new_game = Game::find_by_uuid(&new_game_id, &mut conn).await?;
+            RematchStatus::Started(GameId(new_game.nanoid))
+        }
+        None => RematchStatus::Offered {
+            by: rematch.offered_by,
+        },
+    };
+    Ok(Some(status))
+}
+
 #[server(input = codec::Cbor, output = codec::Cbor)]
 pub async fn get_batch_from_options(
     options: GamesQueryOptions,
@@ -45,12 +71,18 @@ pub async fn get_batch_from_options(
 pub async fn get_rating_history_resource(
     user_id: Uuid,
     game_speed: GameSpeed,
+    variant: VariantClass,
 ) -> Result<Vec<RatingHistoryResponse>, ServerFnError> {
     use crate::functions::db::pool;
     use db_lib::get_conn;
     let pool = pool().await?;
     let mut conn = get_conn(&pool).await?;
-    RatingHistoryResponse::get_rating_history_from_uuid_and_speed(&user_id, &game_speed, &mut conn)
-        .await
-        .map_err(ServerFnError::new)
+    RatingHistoryResponse::get_rating_history_from_uuid_and_speed(
+        &user_id,
+        &game_speed,
+        variant,
+        &mut conn,
+    )
+    .await
+    .map_err(ServerFnError::new)
 }
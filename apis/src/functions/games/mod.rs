@@ -1 +1,4 @@
+pub mod analysis;
+pub mod annotations;
+pub mod conditional_moves;
 pub mod get;
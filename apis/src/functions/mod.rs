@@ -6,6 +6,7 @@ pub mod chat;
 #[cfg(feature = "ssr")]
 pub mod db;
 pub mod devices;
+pub mod follows;
 pub mod games;
 pub mod home_banner;
 pub mod notification_preferences;
@@ -14,11 +15,17 @@ pub mod oauth;
 pub mod og;
 pub mod opening_explorer;
 pub mod push_metrics;
+pub mod puzzles;
 #[cfg(feature = "ssr")]
 pub mod pwa;
 pub mod schedules;
+pub mod studies;
 pub mod telemetry;
+#[cfg(feature = "ssr")]
+pub mod tournament_export;
+pub mod tournament_series;
 pub mod tournaments;
+pub mod tv;
 pub mod users;
 #[cfg(feature = "ssr")]
 pub mod web_push_http;
@@ -60,6 +60,7 @@ pub async fn set_notification_preferences(
         tournament: dedup(payload.tournament),
         schedules: dedup(payload.schedules),
         dms: dedup(payload.dms),
+        following: dedup(payload.following),
     };
     let updated = NotificationPreferences::update_for_user(user_id, upd, &mut conn)
         .await
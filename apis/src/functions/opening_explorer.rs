@@ -1,7 +1,7 @@
 use crate::responses::ExplorerResponse;
 use leptos::prelude::*;
 use server_fn::codec;
-use shared_types::ExplorerFilters;
+use shared_types::{ExplorerFilters, GameId, GameReview};
 
 /// Opening explorer for a single position, identified by its canonical board hash. `hash == 0`
 /// is the empty board: the response lists the opening roots (first moves) instead of running a
@@ -47,6 +47,8 @@ pub async fn opening_explorer(
             black_wins: moves.iter().map(|m| m.black_wins).sum(),
             draws: moves.iter().map(|m| m.draws).sum(),
             avg_rating: None,
+            player_wins: moves.iter().map(|m| m.player_wins).sum(),
+            player_losses: moves.iter().map(|m| m.player_losses).sum(),
         };
 
         return Ok(ExplorerResponse {
@@ -97,3 +99,104 @@ pub async fn opening_explorer(
         recent_games,
     })
 }
+
+/// Replays a finished game against the explorer under `filters` until it leaves book, next
+/// to its engine analysis if it has one. The game's own contribution is taken back out of
+/// every count.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn review_game(
+    game_id: GameId,
+    filters: ExplorerFilters,
+) -> Result<GameReview, ServerFnError> {
+    use crate::{functions::db::pool, responses::GameResponse};
+    use db_lib::{
+        get_conn,
+        models::{GameAnalysis, GameHash},
+    };
+    use hive_lib::{Color, GameStatus, State};
+    use shared_types::{AnalysisStatus, PlyAnalysis, ReviewedPly};
+    use std::collections::HashMap;
+
+    /// Past this many plies a game is deep enough in book that the rest is not worth
+    /// looking up.
+    const MAX_REVIEW_PLIES: usize = 40;
+
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let game = GameResponse::new_from_game_id(&game_id, &mut conn)
+        .await
+        .map_err(ServerFnError::new)?;
+    let GameStatus::Finished(result) = &game.game_status else {
+        return Err(ServerFnError::new("Only finished games can be reviewed"));
+    };
+    if game.game_type != filters.game_type {
+        return Err(ServerFnError::new("The filters are for another game type"));
+    }
+    let counted = GameHash::counts_game(game.uuid, &filters, &mut conn)
+        .await
+        .map_err(ServerFnError::new)?;
+    let player = filters.player.as_ref().and_then(|player| {
+        let username = player.username.to_lowercase();
+        if game.white_player.username.to_lowercase() == username {
+            Some(Color::White)
+        } else if game.black_player.username.to_lowercase() == username {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    });
+
+    // Every position is looked up at once: the opening roots, whose sum is the games at
+    // the empty board as in the explorer, then the game's own positions
+    let roots = State::opening_hashes(game.game_type)
+        .into_iter()
+        .map(|(_, _, hash)| hash as i64)
+        .collect::<Vec<_>>();
+    let positions = game
+        .hashes
+        .iter()
+        .take(MAX_REVIEW_PLIES)
+        .map(|&hash| hash as i64);
+    let hashes = roots.iter().copied().chain(positions).collect::<Vec<_>>();
+    let mut stats = GameHash::aggregate_many(&hashes, &filters, &mut conn)
+        .await
+        .map_err(ServerFnError::new)?;
+    let positions = stats.split_off(roots.len());
+    let mut before = stats.iter().map(|stats| stats.total).sum::<i64>();
+    if counted {
+        before = (before - 1).max(0);
+    }
+    let mut analysis = match GameAnalysis::find(game.uuid, &mut conn).await? {
+        Some(analysis) if analysis.status() == AnalysisStatus::Done => analysis
+            .plies(&mut conn)
+            .await?
+            .into_iter()
+            .map(|ply| (ply.ply, PlyAnalysis::from(ply)))
+            .collect::<HashMap<_, _>>(),
+        _ => HashMap::new(),
+    };
+
+    let mut review = GameReview::default();
+    for (ply, mut after) in positions.into_iter().enumerate() {
+        if counted {
+            after.discount(result, player);
+        }
+        let (piece, position) = game.history.get(ply).cloned().unwrap_or_default();
+        after.piece = piece;
+        after.position = position;
+        let next_before = after.total;
+        let out_of_book = after.total == 0;
+        review.plies.push(ReviewedPly {
+            ply: ply as i32,
+            before,
+            after,
+            analysis: analysis.remove(&(ply as i32)),
+        });
+        if out_of_book {
+            review.novelty = Some(ply as i32);
+            break;
+        }
+        before = next_before;
+    }
+    Ok(review)
+}
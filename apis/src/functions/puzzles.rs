@@ -0,0 +1,190 @@
+use crate::responses::{PuzzleAttemptResponse, PuzzleOutcome, PuzzleResponse};
+#[cfg(feature = "ssr")]
+use db_lib::db_error::DbError;
+use leptos::prelude::*;
+#[cfg(feature = "ssr")]
+use log::error;
+use server_fn::codec;
+use shared_types::VariantClass;
+
+pub const PUZZLE_HISTORY_LIMIT: i64 = 30;
+pub const MAX_SKIPPED_PUZZLES: usize = 100;
+pub const PUZZLE_CANDIDATES_LIMIT: i64 = 50;
+
+#[cfg(feature = "ssr")]
+fn puzzles_error(context: &'static str, err: DbError) -> ServerFnError {
+    match err {
+        DbError::InvalidInput { info, .. } => ServerFnError::new(info),
+        DbError::NotFound { .. } => ServerFnError::new("Puzzle not found"),
+        err => {
+            error!("puzzles server function failed while {context}: {err}");
+            ServerFnError::new("Unable to load puzzles")
+        }
+    }
+}
+
+/// Puzzles are served to players once approved. Candidates and rejected
+/// puzzles are only shown to admins, who review them from the admin page.
+#[cfg(feature = "ssr")]
+async fn ensure_servable(
+    puzzle: &db_lib::models::Puzzle,
+    conn: &mut db_lib::DbConn<'_>,
+) -> Result<(), ServerFnError> {
+    use crate::functions::auth::identity::ensure_admin;
+    use shared_types::PuzzleStatus;
+
+    if puzzle.status() == PuzzleStatus::Approved || ensure_admin(conn).await.is_ok() {
+        Ok(())
+    } else {
+        Err(ServerFnError::new("Puzzle not found"))
+    }
+}
+
+/// The untried puzzle closest to the player's puzzle rating, or to the
+/// starting rating when nobody is logged in. `skip` lists puzzles seen this
+/// session, which only matters for anonymous solvers.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn next_puzzle(
+    variant: VariantClass,
+    skip: Vec<String>,
+) -> Result<Option<PuzzleResponse>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::models::{Puzzle, Rating, INITIAL_RATING};
+    use shared_types::GameSpeed;
+
+    let user_id = uuid().await.ok();
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let rating = match user_id {
+        Some(user_id) => {
+            Rating::for_uuid(&user_id, &GameSpeed::Puzzle, variant, &mut conn)
+                .await
+                .map_err(|err| puzzles_error("loading puzzle rating", err))?
+                .rating
+        }
+        None => INITIAL_RATING,
+    };
+    let skip = &skip[skip.len().saturating_sub(MAX_SKIPPED_PUZZLES)..];
+    let puzzle = Puzzle::next_for(rating, variant, user_id, skip, &mut conn)
+        .await
+        .map_err(|err| puzzles_error("picking next puzzle", err))?;
+    puzzle
+        .map(|puzzle| PuzzleResponse::from_model(&puzzle).map_err(ServerFnError::new))
+        .transpose()
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_puzzle(nanoid: String) -> Result<PuzzleResponse, ServerFnError> {
+    use crate::functions::db::pool;
+    use db_lib::models::Puzzle;
+
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let puzzle = Puzzle::find_by_nanoid(&nanoid, &mut conn)
+        .await
+        .map_err(|err| puzzles_error("loading puzzle", err))?;
+    ensure_servable(&puzzle, &mut conn).await?;
+    PuzzleResponse::from_model(&puzzle).map_err(ServerFnError::new)
+}
+
+/// Judges the last move of `line`, the moves played from the puzzle position
+/// so far. Finished attempts of logged in players are recorded and rated,
+/// except when an admin tries a puzzle that hasn't been approved yet.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn puzzle_move(
+    nanoid: String,
+    line: Vec<(String, String)>,
+) -> Result<PuzzleOutcome, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::models::{Puzzle, PuzzleStep};
+    use shared_types::PuzzleStatus;
+
+    let user_id = uuid().await.ok();
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let puzzle = Puzzle::find_by_nanoid(&nanoid, &mut conn)
+        .await
+        .map_err(|err| puzzles_error("loading puzzle", err))?;
+    ensure_servable(&puzzle, &mut conn).await?;
+    let solved = match puzzle
+        .check_line(&line)
+        .map_err(|err| puzzles_error("checking puzzle move", err))?
+    {
+        PuzzleStep::Continue { reply } => return Ok(PuzzleOutcome::Continue { reply }),
+        PuzzleStep::Solved => true,
+        PuzzleStep::Failed => false,
+    };
+    let rating_change = match user_id {
+        Some(user_id) if puzzle.status() == PuzzleStatus::Approved => {
+            puzzle
+                .record_attempt(user_id, solved, &mut conn)
+                .await
+                .map_err(|err| puzzles_error("recording puzzle attempt", err))?
+                .rating_change
+        }
+        _ => None,
+    };
+    Ok(if solved {
+        PuzzleOutcome::Solved { rating_change }
+    } else {
+        PuzzleOutcome::Failed {
+            rating_change,
+            solution: puzzle.solution_moves(),
+        }
+    })
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_puzzle_history() -> Result<Vec<PuzzleAttemptResponse>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::models::PuzzleAttempt;
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    Ok(
+        PuzzleAttempt::history_for_user(user_id, PUZZLE_HISTORY_LIMIT, &mut conn)
+            .await
+            .map_err(|err| puzzles_error("loading puzzle history", err))?
+            .iter()
+            .map(|(attempt, puzzle)| PuzzleAttemptResponse::from_model(attempt, puzzle))
+            .collect(),
+    )
+}
+
+/// Mined puzzles waiting for an admin to approve or reject them.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_puzzle_candidates() -> Result<Vec<PuzzleResponse>, ServerFnError> {
+    use crate::functions::{auth::identity::ensure_admin, db::pool};
+    use db_lib::models::Puzzle;
+
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    ensure_admin(&mut conn).await?;
+    Puzzle::candidates(PUZZLE_CANDIDATES_LIMIT, &mut conn)
+        .await
+        .map_err(|err| puzzles_error("loading puzzle candidates", err))?
+        .iter()
+        .map(|puzzle| PuzzleResponse::from_model(puzzle).map_err(ServerFnError::new))
+        .collect()
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn review_puzzle(nanoid: String, approve: bool) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::ensure_admin, db::pool};
+    use db_lib::models::Puzzle;
+    use shared_types::PuzzleStatus;
+
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    ensure_admin(&mut conn).await?;
+    let status = if approve {
+        PuzzleStatus::Approved
+    } else {
+        PuzzleStatus::Rejected
+    };
+    Puzzle::set_status(&nanoid, status, &mut conn)
+        .await
+        .map_err(|err| puzzles_error("reviewing puzzle", err))?;
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use crate::responses::GameResponse;
 use chrono::{DateTime, Utc};
 use leptos::prelude::*;
+use server_fn::codec;
+use shared_types::AvailabilityWindow;
 
 #[server]
 pub async fn mark_schedule_seen(schedule_id: String) -> Result<(), ServerFnError> {
@@ -52,3 +54,29 @@ pub async fn get_upcoming_tournament_games(
 
     Ok(result)
 }
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_availability() -> Result<Vec<AvailabilityWindow>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{get_conn, models::Availability};
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    Availability::for_user(user_id, &mut conn)
+        .await
+        .map_err(ServerFnError::new)
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn set_availability(
+    windows: Vec<AvailabilityWindow>,
+) -> Result<Vec<AvailabilityWindow>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{get_conn, models::Availability};
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    Availability::replace_for_user(user_id, windows, &mut conn)
+        .await
+        .map_err(ServerFnError::new)
+}
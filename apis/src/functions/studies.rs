@@ -0,0 +1,205 @@
+use crate::responses::{StudyResponse, StudySummary};
+#[cfg(feature = "ssr")]
+use db_lib::db_error::DbError;
+use leptos::prelude::*;
+#[cfg(feature = "ssr")]
+use log::error;
+use server_fn::codec;
+use shared_types::StudyVisibility;
+
+#[cfg(feature = "ssr")]
+fn studies_error(context: &'static str, err: DbError) -> ServerFnError {
+    match err {
+        DbError::InvalidInput { info, .. } | DbError::InvalidAction { info } => {
+            ServerFnError::new(info)
+        }
+        DbError::NotFound { .. } => ServerFnError::new("Study not found"),
+        DbError::Unauthorized => ServerFnError::new("Only the owner can change this study"),
+        err => {
+            error!("studies server function failed while {context}: {err}");
+            ServerFnError::new("Unable to load the study")
+        }
+    }
+}
+
+/// Parses an analysis tree as saved by the analysis board, so only trees the board can
+/// load again get stored.
+#[cfg(feature = "ssr")]
+fn chapter(title: &str, tree: String) -> Result<db_lib::models::NewStudyChapter, ServerFnError> {
+    use crate::providers::analysis::AnalysisTree;
+    use db_lib::models::NewStudyChapter;
+
+    let parsed = serde_json::from_str::<AnalysisTree>(&tree)
+        .map_err(|_| ServerFnError::new("Not a valid analysis"))?;
+    NewStudyChapter::new(title, parsed.game_type, tree)
+        .map_err(|err| studies_error("checking chapter", err))
+}
+
+/// Loads a study the caller may see; private studies of others look missing.
+#[cfg(feature = "ssr")]
+async fn visible_study(
+    nanoid: &str,
+    user_id: Option<uuid::Uuid>,
+    conn: &mut db_lib::DbConn<'_>,
+) -> Result<db_lib::models::Study, ServerFnError> {
+    use db_lib::models::Study;
+
+    let study = Study::find_by_nanoid(nanoid, conn)
+        .await
+        .map_err(|err| studies_error("loading study", err))?;
+    if !study.can_view(user_id) {
+        return Err(ServerFnError::new("Study not found"));
+    }
+    Ok(study)
+}
+
+/// Saves an analysis as the first chapter of a new study and returns its nanoid.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn create_study(
+    title: String,
+    visibility: StudyVisibility,
+    chapter_title: String,
+    tree: String,
+) -> Result<String, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::models::Study;
+
+    let user_id = uuid().await?;
+    let chapter = chapter(&chapter_title, tree)?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = Study::create(user_id, &title, visibility, chapter, &mut conn)
+        .await
+        .map_err(|err| studies_error("creating study", err))?;
+    Ok(study.nanoid)
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_study(nanoid: String) -> Result<StudyResponse, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::models::User;
+
+    let user_id = uuid().await.ok();
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = visible_study(&nanoid, user_id, &mut conn).await?;
+    let owner = User::find_by_uuid(&study.owner_id, &mut conn).await?;
+    let source = study
+        .source(&mut conn)
+        .await
+        .map_err(|err| studies_error("loading study source", err))?
+        .filter(|source| source.can_view(user_id));
+    let chapters = study
+        .chapters(&mut conn)
+        .await
+        .map_err(|err| studies_error("loading chapters", err))?;
+    Ok(StudyResponse::from_model(
+        &study,
+        owner.username,
+        source.as_ref(),
+        chapters,
+    ))
+}
+
+/// The studies listed on a profile: all of them for the owner, the public ones for others.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_user_studies(username: String) -> Result<Vec<StudySummary>, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::models::{Study, User};
+
+    let user_id = uuid().await.ok();
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let owner = User::find_by_username(&username, &mut conn).await?;
+    Ok(Study::for_owner(owner.id, user_id, &mut conn)
+        .await
+        .map_err(|err| studies_error("listing studies", err))?
+        .iter()
+        .map(StudySummary::from_model)
+        .collect())
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn update_study(
+    nanoid: String,
+    title: String,
+    description: String,
+    visibility: StudyVisibility,
+) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = visible_study(&nanoid, Some(user_id), &mut conn).await?;
+    study
+        .update_details(user_id, &title, &description, visibility, &mut conn)
+        .await
+        .map_err(|err| studies_error("updating study", err))?;
+    Ok(())
+}
+
+/// Overwrites the chapter at `position`, or adds a new one at the end. Returns its position.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn save_study_chapter(
+    nanoid: String,
+    position: Option<i32>,
+    title: String,
+    tree: String,
+) -> Result<i32, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+
+    let user_id = uuid().await?;
+    let chapter = chapter(&title, tree)?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = visible_study(&nanoid, Some(user_id), &mut conn).await?;
+    study
+        .save_chapter(user_id, position, chapter, &mut conn)
+        .await
+        .map_err(|err| studies_error("saving chapter", err))
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn delete_study_chapter(nanoid: String, position: i32) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = visible_study(&nanoid, Some(user_id), &mut conn).await?;
+    study
+        .delete_chapter(user_id, position, &mut conn)
+        .await
+        .map_err(|err| studies_error("deleting chapter", err))
+}
+
+/// Copies a study into a new private one owned by the caller and returns its nanoid.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn fork_study(nanoid: String) -> Result<String, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = visible_study(&nanoid, Some(user_id), &mut conn).await?;
+    let fork = study
+        .fork(user_id, &mut conn)
+        .await
+        .map_err(|err| studies_error("forking study", err))?;
+    Ok(fork.nanoid)
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn delete_study(nanoid: String) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = db_lib::get_conn(&pool).await?;
+    let study = visible_study(&nanoid, Some(user_id), &mut conn).await?;
+    study
+        .delete(user_id, &mut conn)
+        .await
+        .map_err(|err| studies_error("deleting study", err))
+}
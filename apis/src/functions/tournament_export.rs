@@ -0,0 +1,382 @@
+use crate::responses::GameResponse;
+use actix_web::{
+    get,
+    http::header,
+    web::{Data, Path},
+    HttpResponse,
+};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{Game, Tournament, User},
+    DbPool,
+};
+use hive_lib::Color;
+use shared_types::{PrettyString, Standings, Tiebreaker, TournamentGameResult, TournamentMode};
+use std::{collections::HashMap, str::FromStr};
+use uuid::Uuid;
+
+/// A tournament game reduced to what the crosstable and TRF report need.
+#[derive(Debug, Clone)]
+struct PlayedGame {
+    white: Uuid,
+    black: Uuid,
+    result: TournamentGameResult,
+}
+
+impl PlayedGame {
+    fn from_model(game: &Game) -> Self {
+        Self {
+            white: game.white_id,
+            black: game.black_id,
+            result: TournamentGameResult::from_str(&game.tournament_game_result)
+                .unwrap_or(TournamentGameResult::Unknown),
+        }
+    }
+}
+
+/// Header fields of the TRF report.
+struct TrfHeader {
+    name: String,
+    start: String,
+    end: String,
+    mode: String,
+    time_control: String,
+}
+
+#[get("/export/tournament/{nanoid}/{format}")]
+pub async fn tournament_export(path: Path<(String, String)>, pool: Data<DbPool>) -> HttpResponse {
+    let (nanoid, format) = path.into_inner();
+    let (content_type, extension) = match format.as_str() {
+        "pgn" => ("text/plain; charset=utf-8", "pgn"),
+        "csv" => ("text/csv; charset=utf-8", "csv"),
+        "trf" => ("text/plain; charset=utf-8", "trf"),
+        _ => return HttpResponse::NotFound().finish(),
+    };
+    match render_export(&nanoid, &format, pool).await {
+        Ok(Some(body)) => HttpResponse::Ok()
+            .content_type(content_type)
+            .insert_header((
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{nanoid}.{extension}\""),
+            ))
+            .body(body),
+        Ok(None) => {
+            HttpResponse::BadRequest().body("TRF reports are only available for Swiss tournaments")
+        }
+        Err(DbError::NotFound { .. }) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            log::warn!("Tournament export {format} failed for {nanoid}: {err:?}");
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
+
+/// Builds the export body, `None` when the format does not apply to the tournament.
+async fn render_export(
+    nanoid: &str,
+    format: &str,
+    pool: Data<DbPool>,
+) -> Result<Option<String>, DbError> {
+    let mut conn = get_conn(&pool).await?;
+    let tournament = Tournament::from_nanoid(nanoid, &mut conn).await?;
+    let mut games = tournament.games(&mut conn).await?;
+    games.sort_by_key(|game| game.created_at);
+    let bye = User::bye_player_id(&mut conn).await?;
+    let names = tournament
+        .players(&mut conn)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user.username))
+        .collect::<HashMap<_, _>>();
+
+    match format {
+        "pgn" => {
+            let mut pgns = Vec::new();
+            for game in &games {
+                let game = GameResponse::from_model(game, &mut conn)
+                    .await
+                    .map_err(|_| DbError::InternalError)?;
+                pgns.push(game.pgn(&[
+                    ("Event", tournament.name.clone()),
+                    ("TournamentResult", game.tournament_game_result.to_string()),
+                ]));
+            }
+            Ok(Some(pgns.join("\n")))
+        }
+        "csv" => {
+            let standings = tournament.standings(&mut conn).await?;
+            Ok(Some(crosstable_csv(&standings, &names, bye)))
+        }
+        "trf" => {
+            let mode = TournamentMode::from_str(&tournament.mode).unwrap_or_default();
+            if mode != TournamentMode::DoubleSwiss {
+                return Ok(None);
+            }
+            let standings = tournament.standings(&mut conn).await?;
+            let header = TrfHeader {
+                name: tournament.name.clone(),
+                start: date(tournament.started_at),
+                end: date(tournament.ends_at),
+                mode: mode.pretty_string(),
+                time_control: match (tournament.time_base, tournament.time_increment) {
+                    (Some(base), Some(increment)) => format!("{base}+{increment}"),
+                    _ => tournament.time_mode.clone(),
+                },
+            };
+            let mut ratings = HashMap::new();
+            for game in &games {
+                ratings
+                    .entry(game.white_id)
+                    .or_insert(game.white_rating.unwrap_or(0.0));
+                ratings
+                    .entry(game.black_id)
+                    .or_insert(game.black_rating.unwrap_or(0.0));
+            }
+            let mut players = names
+                .iter()
+                .filter(|(id, _)| Some(**id) != bye)
+                .map(|(id, name)| {
+                    let rating = ratings.get(id).copied().unwrap_or(0.0).round() as i32;
+                    (*id, name.clone(), rating)
+                })
+                .collect::<Vec<_>>();
+            // Start ranks follow rating, as FIDE expects.
+            players.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+            let games = games.iter().map(PlayedGame::from_model).collect::<Vec<_>>();
+            Ok(Some(trf_report(&header, &players, &games, &standings, bye)))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn date(date: Option<chrono::DateTime<chrono::Utc>>) -> String {
+    date.map(|date| date.format("%Y/%m/%d").to_string())
+        .unwrap_or_default()
+}
+
+/// Final ranks in standings order, tied players share their group's rank.
+fn ranked(standings: &Standings, bye: Option<Uuid>) -> Vec<(Uuid, usize, i32)> {
+    let mut ranked = Vec::new();
+    for group in standings.results() {
+        let rank = ranked.len() + 1;
+        for (uuid, _, finished, _) in group {
+            if Some(uuid) != bye {
+                ranked.push((uuid, rank, finished));
+            }
+        }
+    }
+    ranked
+}
+
+fn csv_field(field: &str) -> String {
+    format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+/// Crosstable with one row per player: position, tiebreaker scores and the
+/// points scored against every other player.
+fn crosstable_csv(
+    standings: &Standings,
+    names: &HashMap<Uuid, String>,
+    bye: Option<Uuid>,
+) -> String {
+    let mut tiebreakers: Vec<Tiebreaker> = Vec::new();
+    for tiebreaker in &standings.tiebreakers {
+        if !tiebreakers.contains(tiebreaker) {
+            tiebreakers.push(tiebreaker.clone());
+        }
+    }
+    let ranked = ranked(standings, bye);
+    let name = |uuid: &Uuid| names.get(uuid).cloned().unwrap_or_else(|| uuid.to_string());
+
+    let mut header = vec![
+        csv_field("Position"),
+        csv_field("Player"),
+        csv_field("Games"),
+    ];
+    header.extend(tiebreakers.iter().map(|t| csv_field(t.pretty_str())));
+    header.extend(ranked.iter().map(|(uuid, _, _)| csv_field(&name(uuid))));
+    let mut lines = vec![header.join(",")];
+
+    for (uuid, rank, finished) in &ranked {
+        let scores = standings.players_scores.get(uuid);
+        let mut row = vec![
+            rank.to_string(),
+            csv_field(&name(uuid)),
+            finished.to_string(),
+        ];
+        row.extend(tiebreakers.iter().map(|tiebreaker| {
+            scores
+                .and_then(|scores| scores.get(tiebreaker))
+                .map(|score| score.to_string())
+                .unwrap_or_default()
+        }));
+        row.extend(ranked.iter().map(|(opponent, _, _)| {
+            if opponent == uuid || standings.pairings_between(*uuid, *opponent).is_empty() {
+                String::new()
+            } else {
+                standings.head_to_head_pair(*uuid, *opponent).0.to_string()
+            }
+        }));
+        lines.push(row.join(","));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// TRF-like report. Every game gets its own round column since Swiss rounds
+/// here are played as a pair of games with swapped colours.
+fn trf_report(
+    header: &TrfHeader,
+    players: &[(Uuid, String, i32)],
+    games: &[PlayedGame],
+    standings: &Standings,
+    bye: Option<Uuid>,
+) -> String {
+    let start_ranks = players
+        .iter()
+        .enumerate()
+        .map(|(index, (uuid, _, _))| (*uuid, index + 1))
+        .collect::<HashMap<_, _>>();
+    let ranks = ranked(standings, bye)
+        .into_iter()
+        .map(|(uuid, rank, _)| (uuid, rank))
+        .collect::<HashMap<_, _>>();
+
+    let mut lines = vec![
+        format!("012 {}", header.name),
+        format!("042 {}", header.start),
+        format!("052 {}", header.end),
+        format!("062 {}", players.len()),
+        format!("092 {}", header.mode),
+        format!("122 {}", header.time_control),
+    ];
+    for (uuid, name, rating) in players {
+        let points = standings.get_raw_points(*uuid);
+        let mut line = format!(
+            "001 {:>4} {:1}{:>3} {:<33} {:>4} {:>3} {:>11} {:>10} {:>4.1} {:>4}",
+            start_ranks[uuid],
+            "",
+            "",
+            name,
+            rating,
+            "",
+            "",
+            "",
+            points,
+            ranks.get(uuid).copied().unwrap_or(0),
+        );
+        for game in games {
+            let (opponent, color, won, lost) = if game.white == *uuid {
+                (game.black, 'w', Color::White, Color::Black)
+            } else if game.black == *uuid {
+                (game.white, 'b', Color::Black, Color::White)
+            } else {
+                continue;
+            };
+            let entry = if Some(opponent) == bye {
+                String::from("0000 - U")
+            } else {
+                let result = match &game.result {
+                    TournamentGameResult::Winner(color) if *color == won => '1',
+                    TournamentGameResult::Winner(color) if *color == lost => '0',
+                    TournamentGameResult::Winner(_) => ' ',
+                    TournamentGameResult::Draw => '=',
+                    TournamentGameResult::DoubeForfeit => '-',
+                    TournamentGameResult::Unknown => ' ',
+                };
+                let opponent = start_ranks.get(&opponent).copied().unwrap_or(0);
+                format!("{opponent:>4} {color} {result}")
+            };
+            line.push_str("  ");
+            line.push_str(&entry);
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standings(games: &[PlayedGame]) -> Standings {
+        let mut standings = Standings::new();
+        for game in games {
+            standings.add_result(game.white, game.black, 1500.0, 1500.0, game.result.clone());
+        }
+        standings.enforce_tiebreakers();
+        standings
+    }
+
+    #[test]
+    fn crosstable_lists_head_to_head_scores() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let games = [
+            PlayedGame {
+                white: alice,
+                black: bob,
+                result: TournamentGameResult::Winner(Color::White),
+            },
+            PlayedGame {
+                white: bob,
+                black: alice,
+                result: TournamentGameResult::Draw,
+            },
+        ];
+        let names = HashMap::from([(alice, "alice".to_string()), (bob, "bob".to_string())]);
+        let csv = crosstable_csv(&standings(&games), &names, None);
+        assert_eq!(
+            csv,
+            "\"Position\",\"Player\",\"Games\",\"Points\",\"alice\",\"bob\"\n\
+             1,\"alice\",2,1.5,,1.5\n\
+             2,\"bob\",2,0.5,0.5,\n"
+        );
+    }
+
+    #[test]
+    fn trf_lines_use_start_ranks_and_byes() {
+        let (alice, bob, bye) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let games = [
+            PlayedGame {
+                white: alice,
+                black: bob,
+                result: TournamentGameResult::Draw,
+            },
+            PlayedGame {
+                white: bob,
+                black: alice,
+                result: TournamentGameResult::Winner(Color::Black),
+            },
+            PlayedGame {
+                white: bye,
+                black: bob,
+                result: TournamentGameResult::Winner(Color::Black),
+            },
+        ];
+        let header = TrfHeader {
+            name: "Test Swiss".to_string(),
+            start: "2026/01/01".to_string(),
+            end: String::new(),
+            mode: "Double swiss".to_string(),
+            time_control: "600+5".to_string(),
+        };
+        let players = [
+            (alice, "alice".to_string(), 1700),
+            (bob, "bob".to_string(), 1600),
+        ];
+        let report = trf_report(&header, &players, &games, &standings(&games), Some(bye));
+        assert_eq!(
+            report.lines().collect::<Vec<_>>(),
+            [
+                "012 Test Swiss",
+                "042 2026/01/01",
+                "052 ",
+                "062 2",
+                "092 Double swiss",
+                "122 600+5",
+                "001    1      alice                             1700                             1.5    1     2 w =     2 b 1",
+                "001    2      bob                               1600                             1.5    1     1 b =     1 w 0  0000 - U",
+            ]
+        );
+    }
+}
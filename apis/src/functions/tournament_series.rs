@@ -0,0 +1,85 @@
+use crate::responses::{TournamentSeriesAbstractResponse, TournamentSeriesResponse};
+use leptos::prelude::*;
+use server_fn::codec;
+use shared_types::TournamentSeriesId;
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_series(
+    series_id: TournamentSeriesId,
+) -> Result<TournamentSeriesResponse, ServerFnError> {
+    use crate::functions::db::pool;
+    use db_lib::get_conn;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    if let Ok(series) = TournamentSeriesResponse::from_series_id(&series_id, &mut conn).await {
+        Ok(*series)
+    } else {
+        Err(ServerFnError::new("Could not find series"))
+    }
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_all_series() -> Result<Vec<TournamentSeriesAbstractResponse>, ServerFnError> {
+    use crate::functions::db::pool;
+    use db_lib::{get_conn, models::TournamentSeries};
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    Ok(TournamentSeries::get_all(&mut conn)
+        .await?
+        .iter()
+        .map(TournamentSeriesAbstractResponse::from_model)
+        .collect())
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn create_series(
+    name: String,
+    description: String,
+) -> Result<TournamentSeriesId, ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{
+        get_conn,
+        models::{NewTournamentSeries, TournamentSeries},
+    };
+
+    let name = name.trim().to_string();
+    if name.len() < 4 || name.len() > 50 {
+        return Err(ServerFnError::new(
+            "Series name must be between 4 and 50 characters long",
+        ));
+    }
+    if description.len() > 2000 {
+        return Err(ServerFnError::new(
+            "Description must be at most 2000 characters long",
+        ));
+    }
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let new_series = NewTournamentSeries::new(name, description);
+    let series = TournamentSeries::create(user_id, &new_series, &mut conn).await?;
+    Ok(TournamentSeriesId(series.nanoid))
+}
+
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn update_series_scoring(
+    series_id: TournamentSeriesId,
+    placement_points: Vec<i32>,
+    best_of: Option<i32>,
+) -> Result<(), ServerFnError> {
+    use crate::functions::{auth::identity::uuid, db::pool};
+    use db_lib::{get_conn, models::TournamentSeries};
+    use shared_types::SeriesScoring;
+
+    let user_id = uuid().await?;
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let scoring = SeriesScoring {
+        placement_points,
+        best_of,
+    };
+    let series = TournamentSeries::find_by_series_id(&series_id, &mut conn).await?;
+    series.update_scoring(&user_id, &scoring, &mut conn).await?;
+    Ok(())
+}
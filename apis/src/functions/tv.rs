@@ -0,0 +1,26 @@
+use leptos::prelude::*;
+use server_fn::codec;
+use shared_types::GameId;
+use std::collections::HashMap;
+
+/// More games than a TV page shows at once are ignored.
+pub const MAX_COUNTED_GAMES: usize = 64;
+
+/// Spectators per game. The players are looked up here so they aren't
+/// counted as watching.
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_spectator_counts(
+    mut game_ids: Vec<GameId>,
+) -> Result<HashMap<GameId, usize>, ServerFnError> {
+    use crate::{functions::db::pool, websocket::WsHub};
+    use actix_web::web::Data;
+    use db_lib::{get_conn, models::Game};
+    use std::sync::Arc;
+
+    let hub = leptos_actix::extract::<Data<Arc<WsHub>>>().await?;
+    game_ids.truncate(MAX_COUNTED_GAMES);
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let games = Game::players_by_nanoids(&game_ids, &mut conn).await?;
+    Ok(hub.spectator_counts(&games))
+}
@@ -1,7 +1,7 @@
 use crate::responses::UserResponse;
 use leptos::prelude::*;
 use server_fn::codec;
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, OpeningStats, VariantClass};
 use uuid::Uuid;
 
 #[server(input = codec::Cbor, output = codec::Cbor)]
@@ -27,6 +27,7 @@ pub async fn username_taken(username: String) -> Result<bool, ServerFnError> {
 #[server(input = codec::Cbor, output = codec::Cbor)]
 pub async fn get_top_users(
     game_speed: GameSpeed,
+    variant: VariantClass,
     limit: i64,
 ) -> Result<Vec<(usize, UserResponse)>, ServerFnError> {
     use crate::functions::{auth::identity::uuid, db::pool};
@@ -38,7 +39,7 @@ pub async fn get_top_users(
     let mut conn = get_conn(&pool).await?;
     let maybe_user = uuid().await.ok();
     let top_users: Vec<(User, Rating, i64)> =
-        User::get_top_users(&game_speed, maybe_user, limit, &mut conn).await?;
+        User::get_top_users(&game_speed, variant, maybe_user, limit, &mut conn).await?;
     let mut results: Vec<(usize, UserResponse)> = Vec::new();
     for (user, _rating, rank) in top_users.iter() {
         results.push((
@@ -62,6 +63,19 @@ pub async fn get_profile(username: String) -> Result<UserResponse, ServerFnError
         .map_err(ServerFnError::new)
 }
 
+#[server(input = codec::Cbor, output = codec::Cbor)]
+pub async fn get_opening_stats(username: String) -> Result<Vec<OpeningStats>, ServerFnError> {
+    use crate::functions::db::pool;
+    use db_lib::{
+        get_conn,
+        models::{Game, User},
+    };
+    let pool = pool().await?;
+    let mut conn = get_conn(&pool).await?;
+    let user = User::find_by_username(&username, &mut conn).await?;
+    Ok(Game::opening_stats(user.id, &mut conn).await?)
+}
+
 #[server(input = codec::Cbor, output = codec::Cbor)]
 pub async fn search_users(pattern: String) -> Result<Vec<UserResponse>, ServerFnError> {
     use crate::functions::db::pool;
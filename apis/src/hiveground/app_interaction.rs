@@ -19,6 +19,7 @@ use crate::{
 };
 use hive_lib::{Color, GameStatus, Piece, Position};
 use leptos::prelude::*;
+use shared_types::TimeMode;
 use uuid::Uuid;
 
 pub fn live_hiveground_interaction() -> HivegroundInteraction {
@@ -77,6 +78,67 @@ pub fn analysis_hiveground_interaction() -> HivegroundInteraction {
     HivegroundInteraction::new(capabilities, hiveground_actions(handler))
 }
 
+/// The solver moves for `solver` only while it's their turn. Moves are played
+/// on the local state and handed to `on_move` for the server to judge.
+pub fn puzzle_hiveground_interaction(
+    solver: Signal<Option<Color>>,
+    on_move: Callback<(String, String)>,
+) -> HivegroundInteraction {
+    let game_state = expect_context::<GameStateStore>();
+    let current_confirm = expect_context::<CurrentConfirm>().0;
+    let capabilities = Signal::derive(move || {
+        let turn_color = game_state.state().with(|state| state.turn_color);
+        if solver.get() == Some(turn_color) {
+            HivegroundCapabilities::analysis_selection()
+        } else {
+            HivegroundCapabilities::board_inspection()
+        }
+    });
+    let dispatch = Callback::new(move |action| match action {
+        HivegroundAction::SelectBoardPiece { piece, position } => {
+            game_state.show_moves(piece, position);
+        }
+        HivegroundAction::SelectReservePiece { piece, position } => {
+            game_state.show_spawns(piece, position);
+        }
+        HivegroundAction::SelectTarget { position } => {
+            let was_selected = game_state
+                .move_info()
+                .with_untracked(|move_info| move_info.target_position == Some(position));
+            game_state.set_target(position);
+            let confirm = current_confirm.get_untracked();
+            if confirm == MoveConfirm::Single || (confirm == MoveConfirm::Double && was_selected) {
+                let active = game_state
+                    .move_info()
+                    .with_untracked(|move_info| move_info.active.map(|(piece, _)| piece));
+                let Some(active) = active else {
+                    return;
+                };
+                let played = game_state
+                    .state()
+                    .with_untracked(|state| state.history.moves.len());
+                game_state.play_turn(active, position);
+                game_state.clear_selection();
+                let solver_move = game_state
+                    .state()
+                    .with_untracked(|state| state.history.moves.get(played).cloned());
+                if let Some(solver_move) = solver_move {
+                    on_move.run(solver_move);
+                }
+            }
+        }
+        HivegroundAction::ResetSelection => game_state.clear_selection(),
+        HivegroundAction::PreselectPiece { .. } => {}
+    });
+
+    HivegroundInteraction::new(
+        capabilities,
+        HivegroundActions {
+            dispatch: Some(dispatch),
+        },
+    )
+}
+
 struct HivegroundActionHandler {
     game_state: GameStateStore,
     analysis: Option<AnalysisSignal>,
@@ -121,36 +183,50 @@ impl HivegroundActionHandler {
 
     fn select_board_piece(&self, piece: Piece, position: Position) {
         let game_state = self.game_state;
-        if game_state.is_move_allowed(self.analysis.is_some()) {
+        if game_state.is_move_allowed(self.analysis.is_some()) || self.can_premove() {
+            game_state.cancel_premove(self.api.0.get_untracked());
             game_state.show_moves(piece, position);
         }
     }
 
     fn select_reserve_piece(&self, piece: Piece, position: Position) {
         let game_state = self.game_state;
-        if game_state.is_move_allowed(self.analysis.is_some()) {
+        if game_state.is_move_allowed(self.analysis.is_some()) || self.can_premove() {
+            game_state.cancel_premove(self.api.0.get_untracked());
             game_state.show_spawns(piece, position);
         }
     }
 
     fn select_target(&self, position: Position) {
         let game_state = self.game_state;
-        if game_state.is_move_allowed(self.analysis.is_some()) {
+        let premove = !game_state.is_move_allowed(self.analysis.is_some()) && self.can_premove();
+        if game_state.is_move_allowed(self.analysis.is_some()) || premove {
             let was_selected = game_state
                 .move_info()
                 .with_untracked(|move_info| move_info.target_position == Some(position));
             game_state.set_target(position);
             let confirm = self.current_confirm.get_untracked();
             if confirm == MoveConfirm::Single || (confirm == MoveConfirm::Double && was_selected) {
-                game_state.move_active(self.analysis, self.api.0.get_untracked());
+                if premove {
+                    game_state.queue_premove(self.api.0.get_untracked());
+                } else {
+                    game_state.move_active(self.analysis, self.api.0.get_untracked());
+                }
             }
         }
     }
 
     fn reset_selection(&self) {
+        self.game_state.cancel_premove(self.api.0.get_untracked());
         self.game_state.clear_selection();
     }
 
+    fn can_premove(&self) -> bool {
+        self.analysis.is_none()
+            && self.config.with_untracked(|config| config.allow_preselect)
+            && self.game_state.can_premove()
+    }
+
     fn preselect_piece(&self, piece: Piece, position: Position, piece_type: PieceType) {
         if self.analysis.is_none() {
             preselect_piece(
@@ -188,7 +264,12 @@ fn live_capabilities(
         GameStatus::Finished(_) | GameStatus::Adjudicated
     );
 
-    if is_current_player && !is_finished {
+    // Waiting players pick whole moves in real-time games, queued as premoves
+    let realtime = game_state.game_response().with(|game| {
+        game.as_ref()
+            .is_some_and(|game| game.time_mode == TimeMode::RealTime)
+    });
+    if (is_current_player || (is_player && allow_preselect && realtime)) && !is_finished {
         let mut capabilities = HivegroundCapabilities::live_selection();
         capabilities.preselect_piece = false;
         capabilities
@@ -7,7 +7,11 @@ mod model;
 mod paint;
 mod render;
 
-pub use app_interaction::{analysis_hiveground_interaction, live_hiveground_interaction};
+pub use app_interaction::{
+    analysis_hiveground_interaction,
+    live_hiveground_interaction,
+    puzzle_hiveground_interaction,
+};
 pub use build::{
     build_board_render_model,
     build_reserve_render_model,
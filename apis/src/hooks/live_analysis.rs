@@ -0,0 +1,119 @@
+use crate::{
+    common::{AnalysisAction, AnalysisSync},
+    providers::{
+        analysis::{AnalysisSignal, AnalysisTree},
+        game_state::GameStateStore,
+        live_analysis::LiveAnalysisContext,
+        websocket::{ConnectionReadyState, WebsocketContext},
+        ApiRequestsProvider,
+        AuthContext,
+        AuthIdentity,
+    },
+};
+use leptos::{prelude::*, reactive::effect::batch};
+use leptos_router::hooks::use_query_map;
+
+/// Joins the session named by `?live=` each time the socket opens, and leaves whatever
+/// session this tab is in when the page goes away.
+pub fn use_live_analysis_session() {
+    let live = expect_context::<LiveAnalysisContext>();
+    let api = expect_context::<ApiRequestsProvider>().0;
+    let ready_state = expect_context::<WebsocketContext>().ready_state;
+    let queries = use_query_map();
+    let live_id = Memo::new(move |_| queries.get().get("live"));
+
+    Effect::watch(
+        move || (ready_state.get(), live_id.get()),
+        move |(ready_state, live_id), previous, _| {
+            if *ready_state != ConnectionReadyState::Open {
+                return;
+            }
+            // The server forgets sessions of a closed socket, so a reconnect joins again.
+            let reconnected = previous
+                .is_none_or(|(previous_state, _)| *previous_state != ConnectionReadyState::Open);
+            if let Some(live_id) = live_id {
+                if reconnected || !live.is_current(live_id) {
+                    api.get_untracked()
+                        .analysis(AnalysisAction::Join(live_id.clone()));
+                }
+            }
+        },
+        true,
+    );
+
+    on_cleanup(move || {
+        if let Some(session) = live.session.get_untracked() {
+            api.get_untracked()
+                .analysis(AnalysisAction::Leave(session.session_id));
+        }
+        live.clear(None);
+    });
+}
+
+/// The tree without its selection, and the selected node. Comparing these tells a new
+/// variation or annotation apart from moving around the tree.
+fn sync_state(tree: &AnalysisTree) -> (String, Option<i32>) {
+    let mut shape = tree.clone();
+    shape.current_node = None;
+    (shape.to_saved(), tree.current_node_id())
+}
+
+/// Applies what others send to `analysis`, and sends local changes when this user may
+/// move the shared board.
+pub fn use_live_analysis_sync(analysis: AnalysisSignal) {
+    let live = expect_context::<LiveAnalysisContext>();
+    let api = expect_context::<ApiRequestsProvider>().0;
+    let game_state = expect_context::<GameStateStore>();
+    let identity = expect_context::<AuthContext>().identity;
+    // The state everyone in the session last agreed on. Unset until the session's tree
+    // has been applied here.
+    let agreed = StoredValue::new(None::<(String, Option<i32>)>);
+
+    Effect::new(move |_| {
+        let updates = live.incoming.get();
+        if updates.is_empty() {
+            return;
+        }
+        live.incoming.set(Vec::new());
+        for sync in updates {
+            match sync {
+                AnalysisSync::Tree(json) => batch(|| {
+                    if let Some(tree) = AnalysisTree::from_saved(&json, game_state) {
+                        analysis.tree.set(tree);
+                    }
+                }),
+                AnalysisSync::Node(node_id) => analysis.tree.update(|tree| {
+                    tree.update_node(node_id, Some(game_state));
+                }),
+            }
+        }
+        agreed.set_value(Some(analysis.tree.with_untracked(sync_state)));
+        analysis.sync_reserve_from_game_state(game_state);
+    });
+
+    Effect::new(move |_| {
+        let current = analysis.tree.with(sync_state);
+        let Some(session) = live.session.get() else {
+            agreed.set_value(None);
+            return;
+        };
+        if !session.can_move(identity.get().and_then(AuthIdentity::user_id)) {
+            return;
+        }
+        let Some((shape, node)) = agreed.get_value() else {
+            return;
+        };
+        let sync = if current.0 != shape {
+            AnalysisSync::Tree(analysis.tree.with_untracked(AnalysisTree::to_saved))
+        } else if let Some(node_id) = current.1.filter(|node_id| Some(*node_id) != node) {
+            AnalysisSync::Node(node_id)
+        } else {
+            return;
+        };
+        agreed.set_value(Some(current));
+        api.get_untracked().analysis(AnalysisAction::Sync {
+            session_id: session.session_id,
+            sync,
+        });
+    });
+}
@@ -1,6 +1,7 @@
 pub mod clipboard_copy;
 pub mod history_nav;
 pub mod install_nudge;
+pub mod live_analysis;
 pub mod sync_user_locale;
 pub mod tap_feedback;
 pub mod web_push_nav_listener;
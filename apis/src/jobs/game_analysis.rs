@@ -0,0 +1,165 @@
+use crate::uhp::{UhpConfig, UhpEngine};
+use db_lib::{
+    get_conn,
+    models::{AnalysisPly, Game, GameAnalysis},
+    DbPool,
+};
+use hive_lib::{Color, GameResult, GameStatus, GameType, State};
+use shared_types::{MoveJudgement, MATE_SCORE};
+use std::{str::FromStr, time::Duration};
+
+/// UHP engines play by the official rules, where the queen can't be placed on a player's
+/// first move, so games without that rule can't be replayed on them.
+pub const NO_QUEEN_RULE: &str = "Only games with the tournament queen rule can be analysed";
+/// How often an idle worker looks for new requests.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// How many moves the engine plays on by itself before a position is evaluated, one for
+/// each side. Each costs one `bestmove`, twice per move of the game.
+const PLAYOUT_PLIES: usize = 2;
+
+pub fn run(pool: DbPool, config: Option<UhpConfig>) {
+    let Some(config) = config else {
+        log::info!("game_analysis: ANALYSIS_ENGINE not set, engine analysis is off");
+        return;
+    };
+    actix_rt::spawn(async move {
+        match get_conn(&pool).await {
+            Ok(mut conn) => {
+                if let Err(e) = GameAnalysis::requeue_running(&mut conn).await {
+                    log::error!("game_analysis: failed to requeue running analyses: {e}");
+                }
+            }
+            Err(e) => log::error!("game_analysis: failed to get connection: {e}"),
+        }
+        let mut interval = actix_rt::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            // Work the queue down before sleeping again.
+            while work_one(&pool, &config).await {}
+        }
+    });
+}
+
+/// Analyses the next queued game. Returns whether there was one.
+async fn work_one(pool: &DbPool, config: &UhpConfig) -> bool {
+    let Ok(mut conn) = get_conn(pool).await else {
+        log::error!("game_analysis: failed to get connection");
+        return false;
+    };
+    let request = match GameAnalysis::claim_next(&mut conn).await {
+        Ok(Some(request)) => request,
+        Ok(None) => return false,
+        Err(e) => {
+            log::error!("game_analysis: claim failed: {e}");
+            return false;
+        }
+    };
+    let game_id = request.game_id;
+    let game = match Game::find_by_uuid(&game_id, &mut conn).await {
+        Ok(game) => game,
+        Err(e) => {
+            log::warn!("game_analysis: game {game_id} not found: {e}");
+            let _ = GameAnalysis::fail(game_id, "game not found", &mut conn).await;
+            return true;
+        }
+    };
+    let config = config.clone();
+    let outcome = actix_rt::task::spawn_blocking(move || analyze(&config, &game))
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+    let saved = match outcome {
+        Ok((engine, plies)) => GameAnalysis::finish(game_id, &engine, plies, &mut conn)
+            .await
+            .map(|_| ()),
+        Err(error) => {
+            log::warn!("game_analysis: {game_id} failed: {error}");
+            GameAnalysis::fail(game_id, &error, &mut conn).await
+        }
+    };
+    if let Err(e) = saved {
+        log::error!("game_analysis: could not save {game_id}: {e}");
+    }
+    true
+}
+
+/// Replays the game move by move. Before each move the engine picks its own, then the
+/// position after it and the one the player reached are both scored by `playout`.
+fn analyze(config: &UhpConfig, game: &Game) -> Result<(String, Vec<AnalysisPly>), String> {
+    if !game.tournament_queen_rule {
+        return Err(NO_QUEEN_RULE.to_string());
+    }
+    let game_type = GameType::from_str(&game.game_type).map_err(|e| e.to_string())?;
+    let moves = State::new_from_str(&game.history, &game.game_type)
+        .map_err(|e| e.to_string())?
+        .history
+        .moves;
+    let mut engine = UhpEngine::spawn(config).map_err(|e| e.to_string())?;
+    engine.new_game(game_type).map_err(|e| e.to_string())?;
+
+    let mut state = State::new(game_type, game.tournament_queen_rule);
+    let mut plies = Vec::with_capacity(moves.len());
+    for (ply, (piece, position)) in moves.iter().enumerate() {
+        let best_move = engine.best_move().map_err(|e| e.to_string())?;
+        let best_eval = playout(&mut engine, &state, Some(&best_move))
+            .map_err(|e| format!("engine move {best_move} at ply {ply}: {e}"))?;
+
+        let mover = state.turn_color;
+        let played_move = format!("{piece} {position}");
+        play(&mut engine, &mut state, played_move.trim())?;
+        let eval = playout(&mut engine, &state, None)?;
+
+        let (best, played) = match mover {
+            Color::White => (best_eval, eval),
+            Color::Black => (-best_eval, -eval),
+        };
+        plies.push(AnalysisPly {
+            game_id: game.id,
+            ply: ply as i32,
+            eval,
+            best_move,
+            best_eval,
+            judgement: MoveJudgement::judge(best, played).map(|judgement| judgement.to_string()),
+        });
+    }
+    Ok((engine.id.clone(), plies))
+}
+
+/// Scores the position after `first`, or the current one, by letting the engine play up
+/// to `PLAYOUT_PLIES` more moves against itself and evaluating where it ends up, or
+/// `MATE_SCORE` less the plies it took if that ends the game. Both positions compared for
+/// a move have the same side to move at the end of their lines. The engine is put back
+/// where it started.
+fn playout(engine: &mut UhpEngine, state: &State, first: Option<&str>) -> Result<i32, String> {
+    let mut state = state.clone();
+    let mut played = 0;
+    if let Some(first) = first {
+        play(engine, &mut state, first)?;
+        played += 1;
+    }
+    let mut continued = 0;
+    while continued < PLAYOUT_PLIES && state.game_status == GameStatus::InProgress {
+        let best_move = engine.best_move().map_err(|e| e.to_string())?;
+        play(engine, &mut state, &best_move)?;
+        played += 1;
+        continued += 1;
+    }
+    if played > 0 {
+        engine.undo(played).map_err(|e| e.to_string())?;
+    }
+    let mate = MATE_SCORE - continued as i32;
+    Ok(match state.game_status {
+        GameStatus::Finished(GameResult::Winner(Color::White)) => mate,
+        GameStatus::Finished(GameResult::Winner(Color::Black)) => -mate,
+        GameStatus::Finished(_) => 0,
+        _ => state.evaluate(),
+    })
+}
+
+/// Plays `mv`, in history notation, on both the engine and `state`.
+fn play(engine: &mut UhpEngine, state: &mut State, mv: &str) -> Result<(), String> {
+    let (piece, position) = mv.split_once(' ').unwrap_or((mv, ""));
+    state
+        .play_turn_from_history(piece, position)
+        .map_err(|e| e.to_string())?;
+    engine.play(mv).map_err(|e| e.to_string())
+}
@@ -1,11 +1,16 @@
 pub mod challenge_cleanup;
 pub mod email_cleanup;
 pub mod email_drain;
+pub mod game_analysis;
 pub mod game_cleanup;
 pub mod hash_backfill;
 pub mod heartbeat;
 pub mod ping;
 pub mod push_device_sweep;
+pub mod puzzle_mining;
+pub mod quick_pairing;
+pub mod rating_periods;
+pub mod schedule_assistant;
 pub mod timeout_sweeper;
 pub mod tournament_cleanup;
 pub mod tournament_start;
@@ -13,11 +18,16 @@ pub mod ws_telemetry;
 pub use challenge_cleanup::run as challenge_cleanup;
 pub use email_cleanup::run as email_cleanup;
 pub use email_drain::run as email_drain;
+pub use game_analysis::run as game_analysis;
 pub use game_cleanup::run as game_cleanup;
 pub use hash_backfill::run as hash_backfill;
 pub use heartbeat::run as heartbeat;
 pub use ping::run as ping;
 pub use push_device_sweep::run as push_device_sweep;
+pub use puzzle_mining::run as puzzle_mining;
+pub use quick_pairing::run as quick_pairing;
+pub use rating_periods::run as rating_periods;
+pub use schedule_assistant::run as schedule_assistant;
 pub use timeout_sweeper::run as timeout_sweeper;
 pub use tournament_cleanup::run as tournament_cleanup;
 pub use tournament_start::run as tournament_start;
@@ -0,0 +1,130 @@
+use db_lib::{
+    get_conn,
+    models::{Game, NewPuzzle, Puzzle},
+    DbConn,
+    DbPool,
+};
+use hive_lib::{ForcedWin, GameType, State};
+use std::time::Duration;
+
+const BATCH_SIZE: i64 = 50;
+/// How far back from the final position to look for a forced win.
+const LOOKBACK_PLIES: usize = 8;
+/// Longest win searched for, in the winner's moves. Mate in three already
+/// takes tens of seconds in crowded positions.
+const MAX_SOLVER_MOVES: usize = 2;
+
+pub fn run(pool: DbPool) {
+    actix_rt::spawn(async move {
+        let mut interval = actix_rt::time::interval(Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            let total = mine(&pool).await;
+            if total > 0 {
+                log::info!("puzzle_mining: {total} new puzzle candidates");
+            }
+        }
+    });
+}
+
+async fn mine(pool: &DbPool) -> u64 {
+    let mut last_id = None;
+    let mut total = 0u64;
+    loop {
+        let Ok(mut conn) = get_conn(pool).await else {
+            log::error!("puzzle_mining: failed to get connection");
+            break;
+        };
+        let batch = match Game::find_unmined_for_puzzles(last_id, BATCH_SIZE, &mut conn).await {
+            Ok(b) => b,
+            Err(e) => {
+                log::error!("puzzle_mining: query failed: {e}");
+                break;
+            }
+        };
+        if batch.is_empty() {
+            break;
+        }
+
+        for game in batch {
+            last_id = Some(game.id);
+            let game_id = game.id;
+            let nanoid = game.nanoid.clone();
+            let failure = match mine_game(game, &mut conn).await {
+                Ok(created) => {
+                    total += u64::from(created);
+                    None
+                }
+                Err(e) => {
+                    log::warn!("puzzle_mining: gave up on {nanoid} ({game_id}): {e}");
+                    Some(e)
+                }
+            };
+            if let Err(e) = Puzzle::mark_game_mined(game_id, failure, &mut conn).await {
+                log::warn!("puzzle_mining: could not mark {nanoid} ({game_id}): {e}");
+            }
+        }
+    }
+    total
+}
+
+/// Searches one game and stores its puzzle, if it has one. Returns whether a
+/// new puzzle was created.
+async fn mine_game(game: Game, conn: &mut DbConn<'_>) -> Result<bool, String> {
+    let found = actix_rt::task::spawn_blocking(move || search(&game))
+        .await
+        .map_err(|e| format!("search failed: {e}"))?;
+    let Some(new_puzzle) = found else {
+        return Ok(false);
+    };
+    Puzzle::create_if_new(new_puzzle, conn)
+        .await
+        .map(|created| created.is_some())
+        .map_err(|e| format!("could not store the puzzle: {e}"))
+}
+
+/// Walks the last plies of the game from the earliest position on and turns
+/// the first one with a unique forced win into a candidate, so longer
+/// combinations win over the final mate in one.
+fn search(game: &Game) -> Option<NewPuzzle> {
+    let game_type = game.game_type.parse::<GameType>().ok()?;
+    let moves = State::new_from_str(&game.history, &game.game_type)
+        .ok()?
+        .history
+        .moves;
+    let first = moves.len().saturating_sub(LOOKBACK_PLIES);
+    (first..moves.len()).find_map(|ply| {
+        let history = moves[..ply]
+            .iter()
+            .map(|(piece, position)| format!("{piece} {position};"))
+            .collect::<String>();
+        let state = State::new_from_str(&history, &game.game_type).ok()?;
+        let win = state.forced_win(MAX_SOLVER_MOVES)?;
+        if !win.unique {
+            return None;
+        }
+        NewPuzzle::new(
+            game_type,
+            &history,
+            &win.line,
+            themes(&win, game_type),
+            initial_rating(&win),
+            Some(game.id),
+        )
+        .ok()
+        .map(NewPuzzle::candidate)
+    })
+}
+
+fn themes(win: &ForcedWin, game_type: GameType) -> Vec<String> {
+    let mut themes = vec![format!("mate-in-{}", win.moves), game_type.to_string()];
+    if win.line.iter().any(|(piece, _)| piece == "pass") {
+        themes.push(String::from("forced-pass"));
+    }
+    themes
+}
+
+/// A starting guess that Glicko-2 corrects after the first few attempts.
+fn initial_rating(win: &ForcedWin) -> f64 {
+    1100.0 + 300.0 * win.moves as f64
+}
@@ -0,0 +1,17 @@
+use crate::websocket::WsHub;
+use actix_web::web::Data;
+use std::{sync::Arc, time::Duration};
+use tokio::time::MissedTickBehavior;
+
+pub fn run(hub: Data<Arc<WsHub>>) {
+    actix_rt::spawn(async move {
+        // Seekers' rating ranges widen while they wait, so pairs that were
+        // out of reach when they joined have to be found on a later pass.
+        let mut interval = actix_rt::time::interval(Duration::from_secs(2));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            hub.pair_seeks().await;
+        }
+    });
+}
@@ -0,0 +1,23 @@
+use db_lib::{
+    get_conn,
+    models::{Rating, RATING_PERIOD},
+    DbPool,
+};
+use std::time::Duration;
+
+pub fn run(pool: DbPool) {
+    actix_rt::spawn(async move {
+        let mut interval = actix_rt::time::interval(Duration::from_secs(60 * 60 * 6));
+        loop {
+            interval.tick().await;
+            let Ok(mut conn) = get_conn(&pool).await else {
+                continue;
+            };
+            match Rating::inflate_inactive(RATING_PERIOD, chrono::Utc::now(), &mut conn).await {
+                Ok(0) => {}
+                Ok(count) => log::info!("rating_periods: inflated {count} inactive ratings"),
+                Err(err) => log::warn!("rating_periods: inflating deviations failed: {err}"),
+            }
+        }
+    });
+}
@@ -0,0 +1,140 @@
+use crate::{
+    common::{ScheduleAction, ServerResult},
+    notifications::{notify, Event},
+    websocket::{server_handlers::schedules::ScheduleHandler, WsHub},
+};
+use actix_web::web::Data;
+use bytes::Bytes;
+use codee::{binary::MsgpackSerdeCodec, Encoder};
+use db_lib::{
+    get_conn,
+    models::{Availability, Game, Schedule, Tournament, User},
+    DbPool,
+};
+use shared_types::GameId;
+use std::{sync::Arc, time::Duration};
+
+const REMINDER_LEAD: chrono::Duration = chrono::Duration::minutes(30);
+
+pub fn run(pool: DbPool, hub: Data<Arc<WsHub>>) {
+    actix_rt::spawn(async move {
+        let mut interval = actix_rt::time::interval(Duration::from_secs(5 * 60));
+        loop {
+            interval.tick().await;
+            propose(&pool, &hub).await;
+            remind(&pool).await;
+        }
+    });
+}
+
+/// Proposes the earliest shared slot for unscheduled tournament games, on
+/// behalf of white. Black still has to accept it.
+async fn propose(pool: &DbPool, hub: &Data<Arc<WsHub>>) {
+    let mut conn = match get_conn(pool).await {
+        Ok(conn) => conn,
+        Err(err) => {
+            log::warn!("schedule_assistant: get_conn failed: {err}");
+            return;
+        }
+    };
+    let games = match Schedule::games_awaiting_proposal(&mut conn).await {
+        Ok(games) => games,
+        Err(err) => {
+            log::warn!("schedule_assistant: loading games failed: {err}");
+            return;
+        }
+    };
+    for game in games {
+        let Some(tournament_id) = game.tournament_id else {
+            continue;
+        };
+        let Ok(tournament) = Tournament::find(tournament_id, &mut conn).await else {
+            continue;
+        };
+        let slot = match Availability::suggest_for_game(&game, &tournament, &mut conn).await {
+            Ok(slots) => slots.first().copied(),
+            Err(err) => {
+                log::warn!(
+                    "schedule_assistant: suggesting for {} failed: {err}",
+                    game.nanoid
+                );
+                continue;
+            }
+        };
+        let Some(slot) = slot else {
+            continue;
+        };
+        let action = ScheduleAction::Propose(slot, GameId(game.nanoid.clone()));
+        let messages = match ScheduleHandler::new(game.white_id, action, pool).await {
+            Ok(handler) => handler.handle().await,
+            Err(err) => Err(err),
+        };
+        // Recorded whatever the outcome, a rejected proposal must not come straight back.
+        if let Err(err) = Schedule::mark_auto_proposed(game.id, &mut conn).await {
+            log::warn!(
+                "schedule_assistant: recording proposal for {} failed: {err}",
+                game.nanoid
+            );
+        }
+        match messages {
+            Ok(messages) => {
+                for message in messages {
+                    let serialized = ServerResult::Ok(Box::new(message.message));
+                    if let Ok(serialized) = MsgpackSerdeCodec::encode(&serialized) {
+                        hub.dispatch(&message.destination, Bytes::from(serialized))
+                            .await;
+                    }
+                }
+            }
+            Err(err) => {
+                log::warn!(
+                    "schedule_assistant: proposing for {} failed: {err}",
+                    game.nanoid
+                )
+            }
+        }
+    }
+}
+
+/// Reminds both players shortly before an agreed game time.
+async fn remind(pool: &DbPool) {
+    let Ok(mut conn) = get_conn(pool).await else {
+        return;
+    };
+    let schedules = match Schedule::due_reminders(REMINDER_LEAD, &mut conn).await {
+        Ok(schedules) => schedules,
+        Err(err) => {
+            log::warn!("schedule_assistant: loading reminders failed: {err}");
+            return;
+        }
+    };
+    for schedule in schedules {
+        let Ok(game) = Game::find_by_uuid(&schedule.game_id, &mut conn).await else {
+            continue;
+        };
+        let (Ok(proposer), Ok(opponent)) = (
+            User::get_username_by_id(&schedule.proposer_id, &mut conn).await,
+            User::get_username_by_id(&schedule.opponent_id, &mut conn).await,
+        ) else {
+            continue;
+        };
+        // Marked first, a reminder that fails to send is not worth a second one.
+        if Schedule::mark_reminded(schedule.id, &mut conn)
+            .await
+            .is_err()
+        {
+            continue;
+        }
+        for (recipient, opponent) in [
+            (schedule.proposer_id, opponent),
+            (schedule.opponent_id, proposer),
+        ] {
+            notify(Event::ScheduleReminder {
+                recipient,
+                opponent,
+                game_nanoid: game.nanoid.clone(),
+                when: schedule.start_t,
+            });
+        }
+    }
+}
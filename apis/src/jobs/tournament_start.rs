@@ -7,6 +7,7 @@ use crate::{
         ServerResult,
         TournamentUpdate,
     },
+    notifications::notify_followers_tournament_started,
     responses::GameResponse,
     websocket::{InternalServerMessage, MessageDestination, WsHub},
 };
@@ -44,6 +45,17 @@ pub fn run(pool: DbPool, hub: Data<Arc<WsHub>>) {
                                 }
 
                                 let players = tournament.players(tc).await?;
+                                let player_ids =
+                                    players.iter().map(|player| player.id).collect::<Vec<_>>();
+                                if let Err(e) = notify_followers_tournament_started(
+                                    &tournament,
+                                    &player_ids,
+                                    tc,
+                                )
+                                .await
+                                {
+                                    log::error!("notify followers {}: {e}", tournament.nanoid);
+                                }
                                 for player in players {
                                     messages.push(InternalServerMessage {
                                         destination: MessageDestination::User(player.id),
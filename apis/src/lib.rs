@@ -17,6 +17,8 @@ pub mod pages;
 pub mod providers;
 pub mod pwa;
 pub mod responses;
+#[cfg(feature = "ssr")]
+pub mod uhp;
 pub mod websocket;
 // leptos_i18n::load_locales!();
 include!(concat!(env!("OUT_DIR"), "/i18n/mod.rs"));
@@ -8,13 +8,13 @@ use actix_web::{
     cookie::{time::Duration, SameSite},
     middleware::Compress,
 };
-use apis::{api, email, functions, jobs, notifications};
+use apis::{api, email, functions, jobs, notifications, uhp};
 use apis::websocket::{self, WebsocketData};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     use websocket::{start_connection, WsHub};
-    use api::v1::bot::{games::{api_get_game, api_get_ongoing_games, api_get_pending_games}, play::{api_control, api_play}, challenges::{api_accept_challenge, api_create_challenge, api_get_challenges}};
+    use api::v1::bot::{games::{api_get_game, api_get_ongoing_games, api_get_pending_games}, play::{api_control, api_play}, rematch::{api_get_rematch_offers, api_rematch}, challenges::{api_accept_challenge, api_create_challenge, api_get_challenges}};
     use api::v1::auth::get_token_handler::get_token;
     use api::v1::auth::get_identity_handler::get_identity;
     use api::v1::auth::jwt_secret::JwtSecret;
@@ -121,14 +121,19 @@ async fn main() -> std::io::Result<()> {
     }
 
     jobs::hash_backfill(pool.clone());
+    jobs::puzzle_mining(pool.clone());
+    jobs::game_analysis(pool.clone(), uhp::UhpConfig::from_env());
     jobs::tournament_start(pool.clone(), Data::clone(&hub));
     jobs::heartbeat(Data::clone(&hub));
     jobs::ping(Data::clone(&hub));
+    jobs::quick_pairing(Data::clone(&hub));
     jobs::game_cleanup(pool.clone());
     jobs::challenge_cleanup(pool.clone());
     jobs::tournament_cleanup(pool.clone(), Data::clone(&hub));
+    jobs::schedule_assistant(pool.clone(), Data::clone(&hub));
     jobs::timeout_sweeper(pool.clone(), Data::clone(&hub));
     jobs::push_device_sweep(pool.clone());
+    jobs::rating_periods(pool.clone());
     jobs::email_drain(pool.clone(), email::EmailConfig::from_env());
     jobs::email_cleanup(pool.clone());
     let pwa_manifest = PwaManifest::from_site_root(&conf.leptos_options.site_root);
@@ -161,10 +166,13 @@ async fn main() -> std::io::Result<()> {
             .service(functions::web_push_http::web_subscription)
             .service(functions::oauth::callback)
             .service(functions::og::og_game_image)
+            .service(functions::tournament_export::tournament_export)
             .service(get_token)
             .service(get_identity)
             .service(api_play)
             .service(api_control)
+            .service(api_rematch)
+            .service(api_get_rematch_offers)
             .service(api_get_game)
             .service(api_get_ongoing_games)
             .service(api_get_pending_games)
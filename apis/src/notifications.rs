@@ -11,6 +11,8 @@ pub use dispatch::{
     game_end_reason_from,
     init,
     notify,
+    notify_followers_game_started,
+    notify_followers_tournament_started,
     notify_game_control,
     notify_game_ended,
     notify_game_ended_excluding,
@@ -176,3 +176,76 @@ pub fn notify_your_turn(game: &db_lib::models::Game, opponent: String) {
         speed: shared_types::GameSpeed::from_base_increment(game.time_base, game.time_increment),
     });
 }
+
+/// Tells everyone following either player that `game` has started. A player
+/// who follows their own opponent already knows.
+pub async fn notify_followers_game_started(
+    game: &db_lib::models::Game,
+    conn: &mut db_lib::DbConn<'_>,
+) -> anyhow::Result<()> {
+    let players = [game.white_id, game.black_id];
+    let followers = db_lib::helpers::followers_of(conn, &players).await?;
+    if followers.is_empty() {
+        return Ok(());
+    }
+    let users = User::find_by_uuids(&players, conn).await?;
+    let name = |id| {
+        users
+            .iter()
+            .find(|u| u.id == id)
+            .map(|u| u.username.clone())
+            .unwrap_or_default()
+    };
+    let speed = shared_types::GameSpeed::from_base_increment(game.time_base, game.time_increment);
+    let time_control = super::time_control_label(speed, game.time_base, game.time_increment);
+    let mut notified = std::collections::HashSet::new();
+    for (follower, followed) in followers {
+        if players.contains(&follower) || !notified.insert(follower) {
+            continue;
+        }
+        let opponent = if followed == game.white_id {
+            game.black_id
+        } else {
+            game.white_id
+        };
+        notify(Event::FollowedGameStarted {
+            recipient: follower,
+            player: name(followed),
+            opponent: name(opponent),
+            game_nanoid: game.nanoid.clone(),
+            time_control: time_control.clone(),
+            speed,
+        });
+    }
+    Ok(())
+}
+
+/// Tells followers of the tournament's players that it has begun, naming
+/// one player they follow.
+pub async fn notify_followers_tournament_started(
+    tournament: &db_lib::models::Tournament,
+    player_ids: &[uuid::Uuid],
+    conn: &mut db_lib::DbConn<'_>,
+) -> anyhow::Result<()> {
+    let followers = db_lib::helpers::followers_of(conn, player_ids).await?;
+    if followers.is_empty() {
+        return Ok(());
+    }
+    let users = User::find_by_uuids(player_ids, conn).await?;
+    let mut notified = std::collections::HashSet::new();
+    for (follower, followed) in followers {
+        if player_ids.contains(&follower) || !notified.insert(follower) {
+            continue;
+        }
+        let Some(player) = users.iter().find(|u| u.id == followed) else {
+            continue;
+        };
+        notify(Event::FollowedTournamentStarted {
+            recipient: follower,
+            player: player.username.clone(),
+            tournament_name: tournament.name.clone(),
+            tournament_nanoid: tournament.nanoid.clone(),
+        });
+    }
+    Ok(())
+}
@@ -97,6 +97,12 @@ pub enum Event {
         game_nanoid: String,
         when: DateTime<Utc>,
     },
+    ScheduleReminder {
+        recipient: Uuid,
+        opponent: String,
+        game_nanoid: String,
+        when: DateTime<Utc>,
+    },
     DirectMessage {
         recipient: Uuid,
         sender: String,
@@ -109,6 +115,20 @@ pub enum Event {
         kind: GameControlKind,
         speed: GameSpeed,
     },
+    FollowedGameStarted {
+        recipient: Uuid,
+        player: String,
+        opponent: String,
+        game_nanoid: String,
+        time_control: String,
+        speed: GameSpeed,
+    },
+    FollowedTournamentStarted {
+        recipient: Uuid,
+        player: String,
+        tournament_name: String,
+        tournament_nanoid: String,
+    },
     TestPush {
         recipient: Uuid,
     },
@@ -121,6 +141,7 @@ pub enum GameControlKind {
     DrawRejected,
     TakebackAccepted,
     TakebackRejected,
+    RematchOffered,
 }
 
 impl GameControlKind {
@@ -131,6 +152,7 @@ impl GameControlKind {
             GameControlKind::DrawRejected => "Draw declined",
             GameControlKind::TakebackAccepted => "Takeback accepted",
             GameControlKind::TakebackRejected => "Takeback declined",
+            GameControlKind::RematchOffered => "Rematch offer",
         }
     }
 
@@ -141,6 +163,7 @@ impl GameControlKind {
             GameControlKind::DrawRejected => "declined the draw",
             GameControlKind::TakebackAccepted => "accepted the takeback",
             GameControlKind::TakebackRejected => "declined the takeback",
+            GameControlKind::RematchOffered => "offered a rematch",
         }
     }
 }
@@ -171,8 +194,11 @@ impl Event {
             | Event::TournamentStarted { recipient, .. }
             | Event::SchedulePropose { recipient, .. }
             | Event::ScheduleAccept { recipient, .. }
+            | Event::ScheduleReminder { recipient, .. }
             | Event::DirectMessage { recipient, .. }
             | Event::GameControl { recipient, .. }
+            | Event::FollowedGameStarted { recipient, .. }
+            | Event::FollowedTournamentStarted { recipient, .. }
             | Event::TestPush { recipient, .. } => *recipient,
         }
     }
@@ -187,11 +213,14 @@ impl Event {
             Event::TournamentInvite { .. } | Event::TournamentStarted { .. } => {
                 NotificationCategory::Tournament
             }
-            Event::SchedulePropose { .. } | Event::ScheduleAccept { .. } => {
-                NotificationCategory::Schedules
-            }
+            Event::SchedulePropose { .. }
+            | Event::ScheduleAccept { .. }
+            | Event::ScheduleReminder { .. } => NotificationCategory::Schedules,
             Event::DirectMessage { .. } => NotificationCategory::Dms,
             Event::GameControl { .. } => NotificationCategory::YourTurn,
+            Event::FollowedGameStarted { .. } | Event::FollowedTournamentStarted { .. } => {
+                NotificationCategory::Following
+            }
             Event::TestPush { .. } => NotificationCategory::YourTurn,
         }
     }
@@ -206,8 +235,11 @@ impl Event {
             Event::TournamentStarted { .. } => "tournament_started",
             Event::SchedulePropose { .. } => "schedule_propose",
             Event::ScheduleAccept { .. } => "schedule_accept",
+            Event::ScheduleReminder { .. } => "schedule_reminder",
             Event::DirectMessage { .. } => "dm",
             Event::GameControl { .. } => "game_control",
+            Event::FollowedGameStarted { .. } => "followed_game_started",
+            Event::FollowedTournamentStarted { .. } => "followed_tournament_started",
             Event::TestPush { .. } => "test",
         }
     }
@@ -216,7 +248,8 @@ impl Event {
         match self {
             Event::YourTurn { speed, .. }
             | Event::GameStarted { speed, .. }
-            | Event::GameControl { speed, .. } => match speed {
+            | Event::GameControl { speed, .. }
+            | Event::FollowedGameStarted { speed, .. } => match speed {
                 GameSpeed::Bullet => 5 * 60,
                 GameSpeed::Blitz => 15 * 60,
                 GameSpeed::Rapid => 60 * 60,
@@ -233,7 +266,9 @@ impl Event {
             | Event::GameEnded { game_nanoid, .. }
             | Event::SchedulePropose { game_nanoid, .. }
             | Event::ScheduleAccept { game_nanoid, .. }
-            | Event::GameControl { game_nanoid, .. } => {
+            | Event::ScheduleReminder { game_nanoid, .. }
+            | Event::GameControl { game_nanoid, .. }
+            | Event::FollowedGameStarted { game_nanoid, .. } => {
                 Some(format!("https://hivegame.com/game/{game_nanoid}"))
             }
             Event::ChallengeReceived {
@@ -244,6 +279,9 @@ impl Event {
             }
             | Event::TournamentStarted {
                 tournament_nanoid, ..
+            }
+            | Event::FollowedTournamentStarted {
+                tournament_nanoid, ..
             } => Some(format!(
                 "https://hivegame.com/tournament/{tournament_nanoid}"
             )),
@@ -446,6 +484,19 @@ impl Event {
                     .to_string(),
                 )
             }
+            Event::ScheduleReminder { opponent, when, .. } => {
+                let when = when.format("%H:%M UTC").to_string();
+                (
+                    td_string!(locale, notifications.push.schedule_reminder_title).to_string(),
+                    td_string!(
+                        locale,
+                        notifications.push.schedule_reminder_body,
+                        opponent = opponent,
+                        when = when
+                    )
+                    .to_string(),
+                )
+            }
             Event::DirectMessage {
                 sender, preview, ..
             } => (sender.clone(), preview.clone()),
@@ -466,6 +517,9 @@ impl Event {
                     GameControlKind::TakebackRejected => {
                         td_string!(locale, notifications.push.takeback_declined_title).to_string()
                     }
+                    GameControlKind::RematchOffered => {
+                        td_string!(locale, notifications.push.rematch_offer_title).to_string()
+                    }
                 };
                 let body = match kind {
                     GameControlKind::DrawOffered => {
@@ -494,9 +548,46 @@ impl Event {
                         actor = actor
                     )
                     .to_string(),
+                    GameControlKind::RematchOffered => td_string!(
+                        locale,
+                        notifications.push.rematch_offered_body,
+                        actor = actor
+                    )
+                    .to_string(),
                 };
                 (title, body)
             }
+            Event::FollowedGameStarted {
+                player,
+                opponent,
+                time_control,
+                ..
+            } => (
+                td_string!(locale, notifications.push.followed_game_started_title).to_string(),
+                td_string!(
+                    locale,
+                    notifications.push.followed_game_started_body,
+                    player = player,
+                    opponent = opponent,
+                    time_control = time_control
+                )
+                .to_string(),
+            ),
+            Event::FollowedTournamentStarted {
+                player,
+                tournament_name,
+                ..
+            } => (
+                td_string!(locale, notifications.push.followed_tournament_started_title)
+                    .to_string(),
+                td_string!(
+                    locale,
+                    notifications.push.followed_tournament_started_body,
+                    player = player,
+                    tournament_name = tournament_name
+                )
+                .to_string(),
+            ),
             Event::TestPush { .. } => (
                 td_string!(locale, notifications.push.test_title).to_string(),
                 td_string!(locale, notifications.push.test_body).to_string(),
@@ -602,6 +693,15 @@ impl Event {
                 "[Schedule accepted](<https://hivegame.com/game/{game_nanoid}>) — {opponent} accepted {} for your game.",
                 when.format("%Y-%m-%d %H:%M UTC")
             ),
+            Event::ScheduleReminder {
+                opponent,
+                game_nanoid,
+                when,
+                ..
+            } => format!(
+                "[Game reminder](<https://hivegame.com/game/{game_nanoid}>) — your game with {opponent} starts at {}.",
+                when.format("%H:%M UTC")
+            ),
             Event::DirectMessage { sender, preview, .. } => {
                 format!("DM from {sender}: {preview}")
             }
@@ -614,6 +714,23 @@ impl Event {
                 "[Your game](<https://hivegame.com/game/{game_nanoid}>) — {actor} {}.",
                 kind.action()
             ),
+            Event::FollowedGameStarted {
+                player,
+                opponent,
+                game_nanoid,
+                time_control,
+                ..
+            } => format!(
+                "{player} started [a game](<https://hivegame.com/game/{game_nanoid}>) vs {opponent} · {time_control}."
+            ),
+            Event::FollowedTournamentStarted {
+                player,
+                tournament_name,
+                tournament_nanoid,
+                ..
+            } => format!(
+                "{player} is playing in [tournament {tournament_name}](<https://hivegame.com/tournament/{tournament_nanoid}>)."
+            ),
             Event::TestPush { .. } => "Test notification — push is working.".to_string(),
         }
     }
@@ -695,6 +812,15 @@ impl Event {
                     when.format("%Y-%m-%d %H:%M UTC")
                 ),
             ),
+            Event::ScheduleReminder {
+                opponent, when, ..
+            } => (
+                format!("Your game with {opponent} starts soon"),
+                format!(
+                    "Your scheduled game with {opponent} starts at {}. Open: {link}",
+                    when.format("%Y-%m-%d %H:%M UTC")
+                ),
+            ),
             Event::DirectMessage { sender, preview, .. } => (
                 format!("New message from {sender}"),
                 format!("{sender}: {preview}"),
@@ -703,6 +829,23 @@ impl Event {
                 format!("{actor} {}", kind.action()),
                 format!("{} in your game. Open: {link}", kind.title()),
             ),
+            Event::FollowedGameStarted {
+                player,
+                opponent,
+                time_control,
+                ..
+            } => (
+                format!("{player} started a game"),
+                format!("{player} is playing {opponent} ({time_control}). Watch: {link}"),
+            ),
+            Event::FollowedTournamentStarted {
+                player,
+                tournament_name,
+                ..
+            } => (
+                format!("{player} is playing in {tournament_name}"),
+                format!("{tournament_name} has begun with {player} in it. Follow along: {link}"),
+            ),
             Event::TestPush { .. } => (
                 "HiveGame test notification".to_string(),
                 "Push is working.".to_string(),
@@ -844,6 +987,24 @@ mod tests {
         assert!(lost.render_push(Locale::default()).body.ends_with("· -9"));
     }
 
+    #[test]
+    fn schedule_reminder_push_names_opponent_and_start() {
+        let e = Event::ScheduleReminder {
+            recipient: uid(),
+            opponent: "dana".into(),
+            game_nanoid: "g1".into(),
+            when: DateTime::parse_from_rfc3339("2026-07-21T19:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        };
+        let p = e.render_push(Locale::default());
+        assert_eq!(p.title, "Game reminder");
+        assert_eq!(p.body, "Your game with dana starts at 19:00 UTC");
+        assert_eq!(p.event_type, "schedule_reminder");
+        assert_eq!(p.link.as_deref(), Some("https://hivegame.com/game/g1"));
+        assert!(matches!(e.category(), NotificationCategory::Schedules));
+    }
+
     #[test]
     fn challenge_push_includes_time_control_and_rated() {
         let e = Event::ChallengeReceived {
@@ -874,6 +1035,37 @@ mod tests {
         assert_eq!(e.event_type_tag(), "challenge");
     }
 
+    #[test]
+    fn followed_player_events_have_their_own_category() {
+        let game = Event::FollowedGameStarted {
+            recipient: uid(),
+            player: "ann".into(),
+            opponent: "ben".into(),
+            game_nanoid: "g2".into(),
+            time_control: "Blitz 5+5".into(),
+            speed: GameSpeed::Blitz,
+        };
+        let p = game.render_push(Locale::default());
+        assert_eq!(p.body, "ann vs ben · Blitz 5+5");
+        assert_eq!(p.link.as_deref(), Some("https://hivegame.com/game/g2"));
+        assert!(matches!(game.category(), NotificationCategory::Following));
+
+        let tournament = Event::FollowedTournamentStarted {
+            recipient: uid(),
+            player: "ann".into(),
+            tournament_name: "Cup".into(),
+            tournament_nanoid: "t".into(),
+        };
+        assert!(tournament
+            .render_push(Locale::default())
+            .body
+            .contains("Cup"));
+        assert!(matches!(
+            tournament.category(),
+            NotificationCategory::Following
+        ));
+    }
+
     #[test]
     fn direct_message_has_no_link() {
         let e = Event::DirectMessage {
@@ -166,6 +166,7 @@ fn channels_for(prefs: &NotificationPreferences, category: NotificationCategory)
         NotificationCategory::Tournament => &prefs.tournament,
         NotificationCategory::Schedules => &prefs.schedules,
         NotificationCategory::Dms => &prefs.dms,
+        NotificationCategory::Following => &prefs.following,
     };
     parse_channels(raw)
 }
@@ -7,7 +7,10 @@ use crate::{
         organisms::chat::ResolvedChatWindow,
         update_from_event::update_from_input,
     },
-    functions::home_banner,
+    functions::{
+        home_banner,
+        puzzles::{get_puzzle_candidates, ReviewPuzzle},
+    },
     providers::AuthContext,
 };
 use leptos::prelude::*;
@@ -40,6 +43,9 @@ pub fn Admin() -> impl IntoView {
                         "Open push notification metrics"
                     </a>
                 </Panel>
+                <Panel title="Puzzle Candidates" body_class="space-y-2">
+                    <PuzzleCandidates />
+                </Panel>
             </Show>
         </PageShell>
     }
@@ -137,3 +143,72 @@ fn EditBanner() -> impl IntoView {
         </Transition>
     }
 }
+
+#[component]
+fn PuzzleCandidates() -> impl IntoView {
+    let review = ServerAction::<ReviewPuzzle>::new();
+    let candidates = LocalResource::new(move || {
+        review.version().track();
+        async move { get_puzzle_candidates().await.unwrap_or_default() }
+    });
+
+    view! {
+        <Transition>
+            {move || {
+                let candidates = candidates.get().unwrap_or_default();
+                if candidates.is_empty() {
+                    return view! { <p class="text-sm">"No mined puzzles waiting for review"</p> }
+                        .into_any();
+                }
+                view! {
+                    <ul class="flex flex-col gap-1 text-sm">
+                        {candidates
+                            .into_iter()
+                            .map(|puzzle| {
+                                let approve = puzzle.nanoid.clone();
+                                let reject = puzzle.nanoid.clone();
+                                view! {
+                                    <li class="flex flex-wrap gap-2 items-center">
+                                        <a
+                                            class="ui-text-link"
+                                            href=format!("/puzzles/{}", puzzle.nanoid)
+                                            target="_blank"
+                                        >
+                                            {puzzle.nanoid.clone()}
+                                        </a>
+                                        <span>{puzzle.themes.join(", ")}</span>
+                                        <button
+                                            class="ui-button ui-button-primary ui-button-sm"
+                                            on:click=move |_| {
+                                                review
+                                                    .dispatch(ReviewPuzzle {
+                                                        nanoid: approve.clone(),
+                                                        approve: true,
+                                                    });
+                                            }
+                                        >
+                                            "Approve"
+                                        </button>
+                                        <button
+                                            class="ui-button ui-button-secondary ui-button-sm"
+                                            on:click=move |_| {
+                                                review
+                                                    .dispatch(ReviewPuzzle {
+                                                        nanoid: reject.clone(),
+                                                        approve: false,
+                                                    });
+                                            }
+                                        >
+                                            "Reject"
+                                        </button>
+                                    </li>
+                                }
+                            })
+                            .collect_view()}
+                    </ul>
+                }
+                    .into_any()
+            }}
+        </Transition>
+    }
+}
@@ -17,9 +17,12 @@ use crate::{
             reserve::{Alignment, Reserve, MOBILE_RESERVE_VIEWBOX},
         },
     },
-    functions::games::get::get_game_from_nanoid,
+    functions::{games::get::get_game_from_nanoid, studies::get_study},
     hiveground::{analysis_hiveground_interaction, selected_history_state},
-    hooks::history_nav::use_analysis_history_keyboard_navigation,
+    hooks::{
+        history_nav::use_analysis_history_keyboard_navigation,
+        live_analysis::{use_live_analysis_session, use_live_analysis_sync},
+    },
     providers::{
         analysis::{AnalysisSignal, AnalysisTree},
         annotations::AnnotationsSignal,
@@ -59,6 +62,14 @@ pub fn Analysis() -> impl IntoView {
             .map(|n| n.saturating_sub(1))
     });
     let uhp_string = Memo::new(move |_| queries.get().get("uhp"));
+    let study_id = Memo::new(move |_| queries.get().get("study"));
+    let study_chapter = Memo::new(move |_| {
+        queries
+            .get()
+            .get("chapter")
+            .and_then(|s| s.parse::<i32>().ok())
+            .unwrap_or_default()
+    });
     let active_analysis = StoredValue::new(None::<AnalysisSignal>);
     let vertical = expect_context::<OrientationSignal>().orientation_vertical;
     let preview_snapshot = RwSignal::new(None::<AnalysisPreviewSnapshot>);
@@ -105,6 +116,7 @@ pub fn Analysis() -> impl IntoView {
     });
 
     provide_context(ToggleStates(RwSignal::new(HashSet::new())));
+    use_live_analysis_session();
     provide_context(CurrentConfirm(Memo::new(move |_| MoveConfirm::Single)));
     Effect::watch(
         turn_color,
@@ -195,6 +207,13 @@ pub fn Analysis() -> impl IntoView {
         }
     });
 
+    let study_resource = Resource::new(study_id, move |study_id| async move {
+        match study_id {
+            Some(nanoid) => get_study(nanoid).await.ok(),
+            None => None,
+        }
+    });
+
     view! {
         <div class=move || {
             format!(
@@ -223,10 +242,25 @@ pub fn Analysis() -> impl IntoView {
                                         .unwrap_or_default()
                                 }
                                 _ => {
-                                    uhp_string
-                                        .get()
-                                        .and_then(|uhp| {
-                                            AnalysisTree::from_uhp(game_state, uhp).ok()
+                                    let chapter = study_chapter.get();
+                                    study_resource
+                                        .with(|study| {
+                                            study
+                                                .as_ref()?
+                                                .as_ref()?
+                                                .chapters
+                                                .iter()
+                                                .find(|saved| saved.position == chapter)
+                                                .and_then(|saved| {
+                                                    AnalysisTree::from_saved(&saved.tree, game_state)
+                                                })
+                                        })
+                                        .or_else(|| {
+                                            uhp_string
+                                                .get()
+                                                .and_then(|uhp| {
+                                                    AnalysisTree::from_uhp(game_state, uhp).ok()
+                                                })
                                         })
                                         .unwrap_or_else(|| {
                                             AnalysisTree::new_blank_analysis(game_state, GameType::MLP)
@@ -243,6 +277,7 @@ pub fn Analysis() -> impl IntoView {
                     provide_context(analysis_signal);
                     provide_context(AnnotationsSignal::analysis(analysis_signal));
                     active_analysis.set_value(Some(analysis_signal));
+                    use_live_analysis_sync(analysis_signal);
                     let hiveground_interaction = analysis_hiveground_interaction();
 
                     view! {
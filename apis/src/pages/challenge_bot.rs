@@ -8,7 +8,7 @@ use crate::{
 };
 use hive_lib::GameType;
 use leptos::prelude::*;
-use shared_types::{ChallengeDetails, ChallengeVisibility, TimeMode};
+use shared_types::{ChallengeDetails, ChallengeVisibility, ClockKind, TimeMode};
 
 #[derive(Clone, Copy)]
 enum BotDifficulty {
@@ -57,6 +57,7 @@ pub fn ChallengeBot() -> impl IntoView {
             time_mode: TimeMode::Untimed,
             time_base: None,
             time_increment: None,
+            clock_kind: ClockKind::Fischer,
             band_upper: None,
             band_lower: None,
         };
@@ -11,7 +11,7 @@ use crate::{
 use hive_lib::GameType;
 use leptos::prelude::*;
 use reactive_stores::Store;
-use shared_types::{ChallengeDetails, ChallengeVisibility, GameSpeed, TimeMode};
+use shared_types::{ChallengeDetails, ChallengeVisibility, GameSpeed, TimeMode, VariantClass};
 
 #[component]
 pub fn ChallengeCreate(#[prop(optional, into)] opponent: Signal<Option<String>>) -> impl IntoView {
@@ -29,7 +29,12 @@ pub fn ChallengeCreate(#[prop(optional, into)] opponent: Signal<Option<String>>)
                 let time_data = params.time_signals().get();
                 let game_speed =
                     GameSpeed::from_base_increment(time_data.base(), time_data.increment());
-                let rating = account.user.rating_for_speed(&game_speed);
+                let variant = if params.with_expansions().get() {
+                    VariantClass::Expansions
+                } else {
+                    VariantClass::Base
+                };
+                let rating = account.user.rating_for_speed(&game_speed, variant);
 
                 let upper_slider = params.upper_slider().get();
                 let upper = if upper_slider > 500 || opponent_exists {
@@ -73,6 +78,7 @@ pub fn ChallengeCreate(#[prop(optional, into)] opponent: Signal<Option<String>>)
             time_mode: params.time_signals().time_mode().get_untracked(),
             time_base: params.time_signals().with(|ts| ts.base()),
             time_increment: params.time_signals().with(|ts| ts.increment()),
+            clock_kind: params.time_signals().with(|ts| ts.clock_kind()),
             band_upper: upper_rating,
             band_lower: lower_rating,
         };
@@ -2,7 +2,7 @@ use crate::{
     components::{
         atoms::logo::Logo,
         layouts::page_shell::{PageShell, PageShellVariant},
-        molecules::{online_users::OnlineUsers, rl_banner::RlBanner},
+        molecules::{friends_panel::FriendsPanel, online_users::OnlineUsers, rl_banner::RlBanner},
         organisms::{
             calendar::Calendar,
             challenges::Challenges,
@@ -59,6 +59,7 @@ pub fn Home() -> impl IntoView {
                         <div class="mx-auto w-full max-w-screen-md">
                             <div class="w-full lg:flow-root">
                                 <div class="hidden float-right w-64 lg:block lg:ml-6 2xl:hidden">
+                                    <FriendsPanel />
                                     <OnlineUsers />
                                 </div>
                                 <Tv />
@@ -67,6 +68,7 @@ pub fn Home() -> impl IntoView {
                     </div>
                 </div>
                 <div class="flex flex-col order-3 mx-auto w-full min-w-0 max-w-md lg:hidden 2xl:flex 2xl:order-none 2xl:col-start-3 2xl:row-start-1 2xl:mx-0 2xl:max-w-none">
+                    <FriendsPanel />
                     <OnlineUsers />
                 </div>
             </div>
@@ -25,8 +25,11 @@ pub mod resources;
 pub mod rules;
 pub mod rules_summary;
 pub mod strategy;
+pub mod study;
 pub mod top_players;
 pub mod tournament;
 pub mod tournament_create;
+pub mod tournament_series;
 pub mod tournaments;
 pub mod tutorial;
+pub mod tv;
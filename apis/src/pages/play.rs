@@ -282,6 +282,11 @@ pub fn Play() -> impl IntoView {
                     match gar.game_action.clone() {
                         GameReaction::Turn(turn) => {
                             sounds.play_sound(SoundType::Turn);
+                            // The server has already played or discarded it
+                            if game_state.premove().get_untracked().is_some() {
+                                game_state.premove().set(None);
+                                game_state.clear_selection();
+                            }
                             let (
                                 pos,
                                 reserve_pos,
@@ -4,10 +4,16 @@ use crate::{
         atoms::{
             block_toggle_button::BlockToggleButton,
             direct_challenge_button::DirectChallengeButton,
+            follow_toggle_button::FollowToggleButton,
             message_button::MessageButton,
         },
         molecules::{empty_state::EmptyState, user_identity::UserIdentity},
-        organisms::{games_filter::GamesFilter, stats::Stats},
+        organisms::{
+            games_filter::GamesFilter,
+            opening_stats::OpeningStats,
+            stats::Stats,
+            user_studies::UserStudies,
+        },
     },
     functions::users::get_profile,
     i18n::*,
@@ -177,6 +183,7 @@ pub fn ProfileView(children: ChildrenFn) -> impl IntoView {
                                                                             })
                                                                     })
                                                         }>
+                                                            <FollowToggleButton user_id=profile_user_id />
                                                             <MessageButton username=username.get_value() />
                                                             <BlockToggleButton blocked_user_id=profile_user_id />
                                                         </Show>
@@ -185,6 +192,8 @@ pub fn ProfileView(children: ChildrenFn) -> impl IntoView {
 
                                                 <div class="lg:flex lg:flex-col lg:items-center lg:mx-auto lg:max-w-4xl">
                                                     <Stats user />
+                                                    <OpeningStats username=username.get_value() />
+                                                    <UserStudies username=username.get_value() />
 
                                                     <div class="grid gap-1 items-start m-1 lg:flex lg:gap-4 lg:items-center lg:mt-4 grid-cols-[1fr_auto]">
                                                         <div class="flex flex-wrap gap-1 min-w-0">
@@ -1,18 +1,394 @@
-use crate::components::{
-    layouts::{page_header::PageHeader, page_shell::PageShell},
-    molecules::empty_state::EmptyState,
+use crate::{
+    common::{CurrentConfirm, MoveConfirm},
+    components::{
+        layouts::{page_header::PageHeader, page_shell::PageShell},
+        molecules::empty_state::EmptyState,
+        organisms::{
+            board::Board,
+            reserve::{Alignment, Reserve},
+        },
+    },
+    functions::puzzles::{get_puzzle, get_puzzle_history, next_puzzle, PuzzleMove},
+    hiveground::{puzzle_hiveground_interaction, selected_history_state},
+    providers::{
+        game_state::{GameStateStore, GameStateStoreFields},
+        AuthContext,
+        Config,
+    },
+    responses::{PuzzleOutcome, PuzzleResponse},
 };
-use leptos::prelude::*;
+use hive_lib::{Color, State};
+use leptos::{leptos_dom::helpers::set_timeout, logging::log, prelude::*};
+use leptos_router::hooks::{use_navigate, use_params_map};
+use shared_types::{GameSpeed, VariantClass};
+use std::time::Duration;
+
+const REPLY_DELAY: Duration = Duration::from_millis(400);
+const BUTTON_CLASS: &str = "ui-button ui-button-sm h-9 px-3 text-xs";
+
+#[derive(Clone, Debug, PartialEq)]
+enum PuzzleStatus {
+    Solving,
+    Checking,
+    Solved(Option<f64>),
+    Failed(Option<f64>),
+}
 
 #[component]
 pub fn Puzzles() -> impl IntoView {
+    let params = use_params_map();
+    let nanoid = Memo::new(move |_| params.with(|p| p.get("nanoid")));
+    let config = expect_context::<Config>().0;
+    let user = expect_context::<AuthContext>().user;
+    let variant = RwSignal::new(VariantClass::Base);
+    let next = RwSignal::new(0_u32);
+    let finished = RwSignal::new(0_u32);
+    let seen = StoredValue::new(Vec::<String>::new());
+    provide_context(CurrentConfirm(Memo::new(move |_| {
+        config.with(|cfg| {
+            cfg.confirm_mode
+                .get(&GameSpeed::Puzzle)
+                .cloned()
+                .unwrap_or(MoveConfirm::Single)
+        })
+    })));
+
+    let puzzle = LocalResource::new(move || {
+        next.track();
+        let nanoid = nanoid.get();
+        let variant = variant.get();
+        let skip = seen.get_value();
+        async move {
+            match nanoid {
+                Some(nanoid) => get_puzzle(nanoid).await.map(Some),
+                None => next_puzzle(variant, skip).await,
+            }
+        }
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(Some(puzzle))) = puzzle.get() {
+            seen.update_value(|seen| {
+                if !seen.contains(&puzzle.nanoid) {
+                    seen.push(puzzle.nanoid);
+                }
+            });
+        }
+    });
+    let navigate = use_navigate();
+    let load_next = Callback::new(move |_| {
+        if nanoid.get_untracked().is_some() {
+            navigate("/puzzles", Default::default());
+        } else {
+            next.update(|tick| *tick = tick.wrapping_add(1));
+        }
+    });
+    let on_finished = Callback::new(move |_| finished.update(|tick| *tick = tick.wrapping_add(1)));
+    let variant_class = move |choice: VariantClass| {
+        if variant.get() == choice {
+            "ui-choice ui-choice-sm ui-choice-active cursor-pointer"
+        } else {
+            "ui-choice ui-choice-sm ui-choice-inactive cursor-pointer"
+        }
+    };
+
     view! {
         <PageShell>
             <PageHeader title="Puzzles" />
-            <EmptyState
-                title="Puzzles page coming soon"
-                message="This section will host Hive tactics and training positions."
-            />
+            <Show when=move || nanoid.with(Option::is_none)>
+                <nav class="flex gap-1 my-2">
+                    {VariantClass::all()
+                        .into_iter()
+                        .map(|choice| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class=move || variant_class(choice)
+                                    on:click=move |_| variant.set(choice)
+                                >
+                                    {choice.to_string()}
+                                </button>
+                            }
+                        })
+                        .collect_view()}
+                </nav>
+            </Show>
+            <Transition fallback=|| view! { <div>"Loading puzzle..."</div> }>
+                {move || {
+                    puzzle
+                        .get()
+                        .map(|puzzle| match puzzle {
+                            Ok(Some(puzzle)) => {
+                                view! { <PuzzleBoard puzzle on_finished load_next /> }.into_any()
+                            }
+                            Ok(None) => {
+                                view! {
+                                    <EmptyState
+                                        title="No puzzles left"
+                                        message="You've tried every puzzle for this variant. New ones are added regularly."
+                                    />
+                                }
+                                    .into_any()
+                            }
+                            Err(err) => {
+                                view! { <EmptyState title="Puzzle not found" message=err.to_string() /> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+            <Show when=move || user.with(Option::is_some)>
+                <PuzzleHistory finished />
+            </Show>
         </PageShell>
     }
 }
+
+#[component]
+fn PuzzleBoard(
+    puzzle: PuzzleResponse,
+    on_finished: Callback<()>,
+    load_next: Callback<()>,
+) -> impl IntoView {
+    let game_state = expect_context::<GameStateStore>();
+    let start = StoredValue::new(
+        State::new_from_str(&puzzle.history, &puzzle.game_type.to_string())
+            .unwrap_or_else(|_| State::new(puzzle.game_type, false)),
+    );
+    game_state.reset_with_state(start.get_value());
+    let solver = puzzle.solver;
+    let nanoid = StoredValue::new(puzzle.nanoid.clone());
+    let status = RwSignal::new(PuzzleStatus::Solving);
+    let line = RwSignal::new(Vec::<(String, String)>::new());
+    let solution = RwSignal::new(None::<Vec<(String, String)>>);
+    let error = RwSignal::new(None::<String>);
+
+    // Puts the board back to the puzzle position followed by `moves`
+    let replay = move |moves: &[(String, String)]| {
+        let mut state = start.get_value();
+        for (piece, position) in moves {
+            if let Err(err) = state.play_turn_from_history(piece, position) {
+                log!("Could not replay puzzle move {piece} {position}: {err}");
+                break;
+            }
+        }
+        game_state.reset_with_state(state);
+    };
+
+    let judge = ServerAction::<PuzzleMove>::new();
+    let on_move = Callback::new(move |played: (String, String)| {
+        error.set(None);
+        line.update(|line| line.push(played));
+        status.set(PuzzleStatus::Checking);
+        judge.dispatch(PuzzleMove {
+            nanoid: nanoid.get_value(),
+            line: line.get_untracked(),
+        });
+    });
+    Effect::new(move |_| {
+        let Some(outcome) = judge.value().get() else {
+            return;
+        };
+        match outcome {
+            Ok(PuzzleOutcome::Continue { reply }) => {
+                set_timeout(
+                    move || {
+                        game_state.state().update(|state| {
+                            // The local board already passed for a shut out opponent
+                            let passed = state
+                                .history
+                                .moves
+                                .last()
+                                .is_some_and(|(piece, _)| piece == "pass");
+                            if reply.0 == "pass" && passed {
+                                return;
+                            }
+                            if let Err(err) = state.play_turn_from_history(&reply.0, &reply.1) {
+                                log!("Could not play puzzle reply: {err}");
+                            }
+                        });
+                        line.update(|line| line.push(reply));
+                        status.set(PuzzleStatus::Solving);
+                    },
+                    REPLY_DELAY,
+                );
+            }
+            Ok(PuzzleOutcome::Solved { rating_change }) => {
+                status.set(PuzzleStatus::Solved(rating_change));
+                on_finished.run(());
+            }
+            Ok(PuzzleOutcome::Failed {
+                rating_change,
+                solution: moves,
+            }) => {
+                solution.set(Some(moves));
+                status.set(PuzzleStatus::Failed(rating_change));
+                on_finished.run(());
+            }
+            Err(err) => {
+                error.set(Some(err.to_string()));
+                line.update(|line| {
+                    line.pop();
+                });
+                line.with_untracked(|line| replay(line));
+                status.set(PuzzleStatus::Solving);
+            }
+        }
+    });
+
+    let retry = move |_| {
+        line.set(Vec::new());
+        replay(&[]);
+        status.set(PuzzleStatus::Solving);
+    };
+    let show_solution = move |_| {
+        if let Some(moves) = solution.get_untracked() {
+            replay(&moves);
+        }
+    };
+    let solving = Signal::derive(move || (status.get() == PuzzleStatus::Solving).then_some(solver));
+    let interaction = puzzle_hiveground_interaction(solving, on_move);
+    let history_state = selected_history_state(game_state);
+    let rating_change =
+        |change: Option<f64>| change.map_or(String::new(), |change| format!(" ({change:+.0})"));
+    let message = move || match status.get() {
+        PuzzleStatus::Solving if line.with(Vec::is_empty) => {
+            format!("Find the best move for {}.", color_name(solver))
+        }
+        PuzzleStatus::Solving => "Correct, keep going.".to_string(),
+        PuzzleStatus::Checking => "Checking...".to_string(),
+        PuzzleStatus::Solved(change) => format!("Solved!{}", rating_change(change)),
+        PuzzleStatus::Failed(change) => format!("Not the move.{}", rating_change(change)),
+    };
+    let finished = move || {
+        matches!(
+            status.get(),
+            PuzzleStatus::Solved(_) | PuzzleStatus::Failed(_)
+        )
+    };
+    let solve_rate = if puzzle.attempts > 0 {
+        format!(
+            "{:.0}% of {} solvers",
+            puzzle.solves as f64 * 100.0 / puzzle.attempts as f64,
+            puzzle.attempts
+        )
+    } else {
+        "Not tried yet".to_string()
+    };
+    let themes = puzzle.themes.join(", ");
+    let moves = match puzzle.moves {
+        1 => "1 move".to_string(),
+        n => format!("{n} moves"),
+    };
+
+    view! {
+        <div class="flex flex-col gap-2 lg:flex-row">
+            <div class="flex flex-col min-w-0 grow">
+                <div class="h-14">
+                    <Reserve
+                        alignment=Alignment::SingleRow
+                        color=solver.opposite_color()
+                        interaction
+                        history_state
+                    />
+                </div>
+                <div class="flex relative min-h-0 h-[60vh]">
+                    <Board interaction history_state />
+                </div>
+                <div class="h-14">
+                    <Reserve alignment=Alignment::SingleRow color=solver interaction history_state />
+                </div>
+            </div>
+            <aside class="flex flex-col gap-2 p-2 w-full text-sm rounded border lg:w-72 border-black/5 dark:border-white/10">
+                <div class="font-bold">{move || message()}</div>
+                {move || error.get().map(|err| view! { <p class="text-ladybug-red">{err}</p> })}
+                <dl class="grid grid-cols-[auto_1fr] gap-x-2 text-xs">
+                    <dt>"Rating"</dt>
+                    <dd>{format!("{:.0}", puzzle.rating)}</dd>
+                    <dt>"Length"</dt>
+                    <dd>{moves}</dd>
+                    <dt>"Solved by"</dt>
+                    <dd>{solve_rate}</dd>
+                    {(!themes.is_empty())
+                        .then(|| view! {
+                            <dt>"Themes"</dt>
+                            <dd>{themes}</dd>
+                        })}
+                </dl>
+                <Show when=finished>
+                    <div class="flex gap-2">
+                        <Show when=move || solution.with(Option::is_some)>
+                            <button
+                                type="button"
+                                class=format!("{BUTTON_CLASS} ui-button-secondary")
+                                on:click=show_solution
+                            >
+                                "Show solution"
+                            </button>
+                            <button
+                                type="button"
+                                class=format!("{BUTTON_CLASS} ui-button-secondary")
+                                on:click=retry
+                            >
+                                "Retry"
+                            </button>
+                        </Show>
+                        <button
+                            type="button"
+                            class=format!("{BUTTON_CLASS} ui-button-primary")
+                            on:click=move |_| load_next.run(())
+                        >
+                            "Next puzzle"
+                        </button>
+                    </div>
+                </Show>
+            </aside>
+        </div>
+    }
+}
+
+#[component]
+fn PuzzleHistory(finished: RwSignal<u32>) -> impl IntoView {
+    let history = LocalResource::new(move || {
+        finished.track();
+        async move { get_puzzle_history().await.unwrap_or_default() }
+    });
+
+    view! {
+        <section class="mt-4">
+            <h2 class="mb-1 font-bold">"Recent puzzles"</h2>
+            <ul class="flex flex-wrap gap-1 text-xs">
+                {move || {
+                    history
+                        .get()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|attempt| {
+                            let change = attempt
+                                .rating_change
+                                .map_or(String::new(), |change| format!(" {change:+.0}"));
+                            let class = if attempt.solved {
+                                "px-2 py-1 rounded no-link-style bg-grasshopper-green/20"
+                            } else {
+                                "px-2 py-1 rounded no-link-style bg-ladybug-red/20"
+                            };
+                            view! {
+                                <li>
+                                    <a href=format!("/puzzles/{}", attempt.nanoid) class=class>
+                                        {format!("{:.0}{change}", attempt.rating)}
+                                    </a>
+                                </li>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ul>
+        </section>
+    }
+}
+
+fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::White => "White",
+        Color::Black => "Black",
+    }
+}
@@ -0,0 +1,479 @@
+use crate::{
+    components::{
+        layouts::{page_header::PageHeader, page_shell::PageShell},
+        molecules::{empty_state::EmptyState, thumbnail_pieces::ThumbnailPieces},
+    },
+    functions::studies::{get_study, DeleteStudy, DeleteStudyChapter, ForkStudy, UpdateStudy},
+    providers::{analysis::AnalysisTree, AuthContext},
+    responses::{StudyChapterResponse, StudyResponse},
+};
+use hive_lib::{History, State};
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_params_map, use_query_map};
+use shared_types::StudyVisibility;
+
+const BUTTON_CLASS: &str = "ui-button ui-button-secondary ui-button-sm h-9 px-3 text-xs";
+
+#[component]
+pub fn StudyView() -> impl IntoView {
+    let params = use_params_map();
+    let nanoid = Memo::new(move |_| params.with(|p| p.get("nanoid")));
+    let update = ServerAction::<UpdateStudy>::new();
+    let delete_chapter = ServerAction::<DeleteStudyChapter>::new();
+    let study = LocalResource::new(move || {
+        update.version().get();
+        delete_chapter.version().get();
+        let nanoid = nanoid.get();
+        async move {
+            match nanoid {
+                Some(nanoid) => get_study(nanoid).await,
+                None => Err(ServerFnError::new("Study not found")),
+            }
+        }
+    });
+
+    view! {
+        <PageShell>
+            <Transition fallback=|| view! { <div>"Loading study..."</div> }>
+                {move || {
+                    study
+                        .get()
+                        .map(|study| match study {
+                            Ok(study) => {
+                                view! { <StudyDetails study update delete_chapter /> }.into_any()
+                            }
+                            Err(err) => {
+                                view! { <EmptyState title="Study not found" message=err.to_string() /> }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </PageShell>
+    }
+}
+
+#[component]
+fn StudyDetails(
+    study: StudyResponse,
+    update: ServerAction<UpdateStudy>,
+    delete_chapter: ServerAction<DeleteStudyChapter>,
+) -> impl IntoView {
+    let user = expect_context::<AuthContext>().user;
+    let user_id = move || user.with(|account| account.as_ref().map(|account| account.user.uid));
+    let is_owner = {
+        let owner_id = study.owner_id;
+        move || user_id() == Some(owner_id)
+    };
+    let nanoid = StoredValue::new(study.nanoid.clone());
+    let owner = StoredValue::new(study.owner.clone());
+
+    let fork = ServerAction::<ForkStudy>::new();
+    let delete = ServerAction::<DeleteStudy>::new();
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(fork)) = fork.value().get() {
+            navigate(&format!("/study/{fork}"), Default::default());
+        }
+    });
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(())) = delete.value().get() {
+            navigate(&format!("/@/{}", owner.get_value()), Default::default());
+        }
+    });
+    let error = move || {
+        [
+            update.value().get().and_then(|result| result.err()),
+            delete_chapter.value().get().and_then(|result| result.err()),
+            fork.value().get().and_then(|result| result.err()),
+            delete.value().get().and_then(|result| result.err()),
+        ]
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|err| err.to_string())
+    };
+
+    // The iframe needs an absolute URL, which is only known in the browser
+    let embed_code = RwSignal::new(String::new());
+    Effect::new(move |_| {
+        let origin = window().location().origin().unwrap_or_default();
+        embed_code.set(format!(
+            r#"<iframe src="{origin}/embed/study/{}" width="400" height="600" frameborder="0"></iframe>"#,
+            nanoid.get_value()
+        ));
+    });
+
+    let subtitle = format!("by {} · {}", study.owner, study.visibility);
+    let StudyResponse {
+        title,
+        description,
+        visibility,
+        forked_from,
+        chapters,
+        ..
+    } = study;
+    let details = StoredValue::new((title.clone(), description.clone()));
+    view! {
+        <PageHeader title subtitle />
+        {forked_from
+            .map(|(source, title)| {
+                view! {
+                    <p class="text-sm">
+                        "Forked from " <a href=format!("/study/{source}") class="ui-text-link">
+                            {title}
+                        </a>
+                    </p>
+                }
+            })}
+        {(!description.is_empty())
+            .then(|| view! { <p class="my-2 whitespace-pre-line">{description}</p> })}
+        <ol class="flex flex-col gap-1 my-4">
+            {chapters
+                .into_iter()
+                .map(|chapter| {
+                    let position = chapter.position;
+                    view! {
+                        <li class="flex gap-2 justify-between items-center p-2 rounded ui-divider-bottom">
+                            <a
+                                href=format!(
+                                    "/analysis?study={}&chapter={position}",
+                                    nanoid.get_value(),
+                                )
+                                class="ui-text-link"
+                            >
+                                {format!("{}. {}", position + 1, chapter.title)}
+                            </a>
+                            <span class="text-xs text-gray-500 dark:text-gray-400">
+                                {chapter.game_type.to_string()}
+                            </span>
+                            <Show when=is_owner>
+                                <button
+                                    type="button"
+                                    class=BUTTON_CLASS
+                                    prop:disabled=move || delete_chapter.pending().get()
+                                    on:click=move |_| {
+                                        delete_chapter
+                                            .dispatch(DeleteStudyChapter {
+                                                nanoid: nanoid.get_value(),
+                                                position,
+                                            });
+                                    }
+                                >
+                                    "Delete"
+                                </button>
+                            </Show>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ol>
+        <div class="flex flex-wrap gap-2">
+            <Show when=move || user_id().is_some()>
+                <button
+                    type="button"
+                    class=BUTTON_CLASS
+                    prop:disabled=move || fork.pending().get()
+                    on:click=move |_| {
+                        fork.dispatch(ForkStudy {
+                            nanoid: nanoid.get_value(),
+                        });
+                    }
+                >
+                    "Fork"
+                </button>
+            </Show>
+            <Show when=is_owner>
+                <button
+                    type="button"
+                    class=BUTTON_CLASS
+                    prop:disabled=move || delete.pending().get()
+                    on:click=move |_| {
+                        delete
+                            .dispatch(DeleteStudy {
+                                nanoid: nanoid.get_value(),
+                            });
+                    }
+                >
+                    "Delete study"
+                </button>
+            </Show>
+        </div>
+        {move || error().map(|err| view! { <p class="text-sm text-ladybug-red">{err}</p> })}
+        <Show when=is_owner>
+            <StudyEditor
+                nanoid=nanoid.get_value()
+                title=details.with_value(|(title, _)| title.clone())
+                description=details.with_value(|(_, description)| description.clone())
+                visibility
+                update
+            />
+        </Show>
+        <label class="flex flex-col gap-1 mt-4">
+            <span class="ui-field-label">"Embed"</span>
+            <input
+                type="text"
+                class="ui-field-input"
+                readonly
+                prop:value=embed_code
+                on:focus=move |ev| {
+                    use wasm_bindgen::JsCast;
+                    if let Some(input) = ev
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::HtmlInputElement>().ok())
+                    {
+                        input.select();
+                    }
+                }
+            />
+        </label>
+    }
+}
+
+#[component]
+fn StudyEditor(
+    nanoid: String,
+    title: String,
+    description: String,
+    visibility: StudyVisibility,
+    update: ServerAction<UpdateStudy>,
+) -> impl IntoView {
+    let nanoid = StoredValue::new(nanoid);
+    let title = RwSignal::new(title);
+    let description = RwSignal::new(description);
+    let visibility = RwSignal::new(visibility);
+    let save = move |_| {
+        update.dispatch(UpdateStudy {
+            nanoid: nanoid.get_value(),
+            title: title.get_untracked(),
+            description: description.get_untracked(),
+            visibility: visibility.get_untracked(),
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-2 mt-6">
+            <label class="flex flex-col gap-1">
+                <span class="ui-field-label">"Title"</span>
+                <input
+                    type="text"
+                    class="ui-field-input"
+                    maxlength="100"
+                    prop:value=title
+                    on:input=move |ev| title.set(event_target_value(&ev))
+                />
+            </label>
+            <label class="flex flex-col gap-1">
+                <span class="ui-field-label">"Description"</span>
+                <textarea
+                    class="ui-field-textarea"
+                    rows="4"
+                    maxlength="2000"
+                    prop:value=description
+                    on:input=move |ev| description.set(event_target_value(&ev))
+                ></textarea>
+            </label>
+            <label class="flex flex-col gap-1">
+                <span class="ui-field-label">"Visibility"</span>
+                <select
+                    class="ui-field-select"
+                    on:change=move |ev| {
+                        if let Ok(choice) = event_target_value(&ev).parse() {
+                            visibility.set(choice);
+                        }
+                    }
+                >
+                    {StudyVisibility::all()
+                        .into_iter()
+                        .map(|choice| {
+                            view! {
+                                <option
+                                    value=choice.to_string()
+                                    selected=move || visibility.get() == choice
+                                >
+                                    {choice.to_string()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <span class="ui-field-helper">
+                    "Unlisted studies open for anyone with the link; only public ones show on your profile."
+                </span>
+            </label>
+            <button
+                type="button"
+                class="self-start ui-button ui-button-primary ui-button-sm"
+                prop:disabled=move || update.pending().get()
+                on:click=save
+            >
+                "Save details"
+            </button>
+        </div>
+    }
+}
+
+/// A read-only view of a study's main lines, without the site around it, for iframes.
+#[component]
+pub fn StudyEmbed() -> impl IntoView {
+    let params = use_params_map();
+    let queries = use_query_map();
+    let nanoid = Memo::new(move |_| params.with(|p| p.get("nanoid")));
+    let chapter = RwSignal::new(
+        queries
+            .get_untracked()
+            .get("chapter")
+            .and_then(|s| s.parse::<i32>().ok())
+            .unwrap_or_default(),
+    );
+    let study = LocalResource::new(move || {
+        let nanoid = nanoid.get();
+        async move {
+            match nanoid {
+                Some(nanoid) => get_study(nanoid).await.ok(),
+                None => None,
+            }
+        }
+    });
+
+    view! {
+        <div class="flex flex-col gap-2 p-2 size-full bg-light dark:bg-gray-950">
+            <Transition>
+                {move || {
+                    study
+                        .get()
+                        .map(|study| match study {
+                            Some(study) => {
+                                let nanoid = study.nanoid.clone();
+                                let chapters = study.chapters.clone();
+                                view! {
+                                    <a
+                                        href=format!("/study/{nanoid}")
+                                        target="_blank"
+                                        class="font-semibold truncate ui-text-link"
+                                    >
+                                        {study.title.clone()}
+                                    </a>
+                                    <Show when=move || { chapters.len() > 1 }>
+                                        <select
+                                            class="ui-field-select"
+                                            on:change=move |ev| {
+                                                if let Ok(position) = event_target_value(&ev).parse() {
+                                                    chapter.set(position);
+                                                }
+                                            }
+                                        >
+                                            {study
+                                                .chapters
+                                                .iter()
+                                                .map(|saved| {
+                                                    let position = saved.position;
+                                                    view! {
+                                                        <option
+                                                            value=position.to_string()
+                                                            selected=move || chapter.get() == position
+                                                        >
+                                                            {saved.title.clone()}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </select>
+                                    </Show>
+                                    {move || {
+                                        study
+                                            .chapters
+                                            .iter()
+                                            .find(|saved| saved.position == chapter.get())
+                                            .or(study.chapters.first())
+                                            .cloned()
+                                            .map(|saved| view! { <EmbeddedChapter chapter=saved /> })
+                                    }}
+                                }
+                                    .into_any()
+                            }
+                            None => view! { <EmptyState title="Study not found" /> }.into_any(),
+                        })
+                }}
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn EmbeddedChapter(chapter: StudyChapterResponse) -> impl IntoView {
+    let moves = StoredValue::new(
+        serde_json::from_str::<AnalysisTree>(&chapter.tree)
+            .map(|mut tree| {
+                tree.ensure_start_node();
+                tree.main_line()
+            })
+            .unwrap_or_default(),
+    );
+    let game_type = chapter.game_type;
+    let ply = RwSignal::new(0_usize);
+    let last = moves.with_value(Vec::len);
+    let board = move || {
+        let played = moves.with_value(|moves| moves[..ply.get().min(moves.len())].to_vec());
+        State::new_from_history(&History {
+            moves: played,
+            game_type,
+            ..History::new()
+        })
+        .unwrap_or_else(|_| State::new(game_type, false))
+        .board
+    };
+    let step = move |to: usize| ply.set(to.min(last));
+
+    view! {
+        <div class="flex-1 min-h-0">
+            {move || view! { <ThumbnailPieces board=StoredValue::new(board()) /> }}
+        </div>
+        <div class="flex gap-1 justify-center">
+            <button type="button" class=BUTTON_CLASS on:click=move |_| step(0)>
+                "⏮"
+            </button>
+            <button
+                type="button"
+                class=BUTTON_CLASS
+                on:click=move |_| step(ply.get_untracked().saturating_sub(1))
+            >
+                "◀"
+            </button>
+            <button
+                type="button"
+                class=BUTTON_CLASS
+                on:click=move |_| step(ply.get_untracked() + 1)
+            >
+                "▶"
+            </button>
+            <button type="button" class=BUTTON_CLASS on:click=move |_| step(last)>
+                "⏭"
+            </button>
+        </div>
+        <ol class="flex flex-wrap gap-1 text-xs">
+            {moves
+                .get_value()
+                .into_iter()
+                .enumerate()
+                .map(|(i, (piece, position))| {
+                    view! {
+                        <li
+                            class=move || {
+                                if ply.get() == i + 1 {
+                                    "px-1 rounded cursor-pointer bg-orange-twilight"
+                                } else {
+                                    "px-1 rounded cursor-pointer hover:bg-pillbug-teal"
+                                }
+                            }
+                            on:click=move |_| step(i + 1)
+                        >
+                            {format!("{}. {piece} {position}", i + 1)}
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ol>
+    }
+}
@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 
 use crate::components::{
     layouts::{
@@ -11,16 +11,43 @@ use crate::components::{
 
 #[component]
 pub fn TopPlayers() -> impl IntoView {
+    let variant = RwSignal::new(VariantClass::Expansions);
+    let variant_choices = VariantClass::all()
+        .into_iter()
+        .map(|choice| {
+            view! {
+                <button
+                    class=move || {
+                        format!(
+                            "ui-choice ui-choice-sm {}",
+                            if variant.get() == choice {
+                                "ui-choice-active"
+                            } else {
+                                "ui-choice-inactive"
+                            },
+                        )
+                    }
+                    on:click=move |_| variant.set(choice)
+                >
+                    {choice.to_string()}
+                </button>
+            }
+        })
+        .collect_view();
     let leaderboards = GameSpeed::all_rated_games()
         .into_iter()
         .map(|speed| {
-            view! { <Leaderboard speed=speed /> }
+            view! { <Leaderboard speed=speed variant /> }
         })
         .collect_view();
     view! {
         <PageShell variant=PageShellVariant::Dashboard>
             <div class="flex flex-col gap-6 mx-auto w-full max-w-[114rem]">
-                <PageHeader title="Top Rated Players" subtitle="Highest rated players by speed." />
+                <PageHeader
+                    title="Top Rated Players"
+                    subtitle="Highest rated players by speed and variant."
+                />
+                <div class="flex gap-2">{variant_choices}</div>
                 <div class="flex flex-col flex-wrap gap-3 items-center w-full md:flex-row md:items-start">
                     {leaderboards}
                 </div>
@@ -7,6 +7,7 @@ use crate::{
             page_shell::{PageShell, PageShellVariant},
         },
         molecules::{
+            availability_editor::AvailabilityEditor,
             game_previews::GamePreviews,
             my_schedules::MySchedules,
             panel::Panel,
@@ -29,7 +30,7 @@ use crate::{
         AuthContext,
         UpdateNotifier,
     },
-    responses::{GameResponse, TournamentResponse},
+    responses::{GameResponse, TournamentResponse, TournamentSeriesAbstractResponse},
 };
 use chrono::Local;
 use hive_lib::GameStatus;
@@ -42,12 +43,17 @@ use shared_types::{
     GameSpeed,
     PrettyString,
     TimeInfo,
+    TournamentApplicant,
     TournamentGameResult,
     TournamentId,
+    TournamentJoinError,
     TournamentMode,
+    TournamentSeriesId,
     TournamentStatus,
+    VariantClass,
 };
 use std::collections::HashMap;
+use uuid::Uuid;
 
 const DETAILS_STYLE: &str = "w-full min-w-0 h-fit";
 
@@ -120,6 +126,7 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
         mode: t.time_mode,
         base: t.time_base,
         increment: t.time_increment,
+        clock: t.clock_kind,
     });
     let tournament_id = StoredValue::new(tournament.with_value(|t| t.tournament_id.clone()));
     Effect::new(move |_| {
@@ -184,6 +191,17 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
             send_action(TournamentAction::Finish(tournament_id.get_value()));
         }
     };
+    let paused = tournament.with_value(|t| t.paused_at.is_some());
+    let toggle_pause = move |_| {
+        if user_is_organizer_or_admin() {
+            let tournament_id = tournament_id.get_value();
+            send_action(if paused {
+                TournamentAction::Resume(tournament_id)
+            } else {
+                TournamentAction::Pause(tournament_id)
+            });
+        }
+    };
     let progress_to_next_round = move |_| {
         if user_is_organizer_or_admin() {
             send_action(TournamentAction::ProgressToNextRound(
@@ -197,40 +215,78 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
         }
     };
     let start_disabled = move || tournament.with_value(|t| t.min_seats) > number_of_players;
-    let join_disabled = move || {
-        if tournament.with_value(|t| t.seats) <= number_of_players {
-            return true;
-        }
+    let seats_full = move || tournament.with_value(|t| t.seats) <= number_of_players;
+    let user_waitlisted = move || {
+        user_id().is_some_and(|user_id| {
+            tournament.with_value(|t| t.waitlist.iter().any(|user| user.uid == user_id))
+        })
+    };
+    // Mirrors the server checks we have the data for. Account age is only
+    // known to the server, which reports it when joining.
+    let join_blocker = move || {
         account.with(|a| {
-            if let Some(account) = a.as_ref() {
-                let user = &account.user;
-                tournament.with_value(|t| {
-                    if t.invite_only {
-                        if t.invitees.iter().any(|invitee| invitee.uid == user.uid) {
-                            return false;
-                        }
-                        if t.organizers
-                            .iter()
-                            .any(|organizer| organizer.uid == user.uid)
-                        {
-                            return false;
-                        }
-                        return true;
-                    }
-                    let game_speed = GameSpeed::from_base_increment(t.time_base, t.time_increment);
-                    let rating = user.rating_for_speed(&game_speed) as i32;
-                    match (t.band_lower, t.band_upper) {
-                        (None, None) => false,
-                        (None, Some(upper)) => rating >= upper,
-                        (Some(lower), None) => rating <= lower,
-                        (Some(lower), Some(upper)) => rating <= lower || rating >= upper,
-                    }
-                })
-            } else {
-                true
-            }
+            let Some(account) = a.as_ref() else {
+                return Some(String::from("Log in to join"));
+            };
+            let user = &account.user;
+            tournament.with_value(|t| {
+                let invited = t.invitees.iter().any(|invitee| invitee.uid == user.uid)
+                    || t.organizers
+                        .iter()
+                        .any(|organizer| organizer.uid == user.uid);
+                if t.invite_only && !invited {
+                    return Some(TournamentJoinError::InviteOnly.to_string());
+                }
+                if invited {
+                    return None;
+                }
+                let game_speed = GameSpeed::from_base_increment(t.time_base, t.time_increment);
+                // Tournament games are played with all expansions.
+                let variant = VariantClass::Expansions;
+                let applicant = TournamentApplicant {
+                    rating: user.rating_for_speed(&game_speed, variant) as i32,
+                    rated_games: user
+                        .rating(&game_speed, variant)
+                        .map(|rating| rating.played)
+                        .unwrap_or_default(),
+                    account_age_days: i64::MAX,
+                    bot: user.bot,
+                };
+                t.requirements
+                    .check(&applicant)
+                    .err()
+                    .map(|error| error.to_string())
+            })
         })
     };
+    let join_disabled = move || join_blocker().is_some();
+    let requirements_summary = tournament.with_value(|t| {
+        let requirements = &t.requirements;
+        let mut summary = Vec::new();
+        match (requirements.band_lower, requirements.band_upper) {
+            (Some(lower), Some(upper)) => summary.push(format!("Rating {lower}-{upper}")),
+            (Some(lower), None) => summary.push(format!("Rating {lower}+")),
+            (None, Some(upper)) => summary.push(format!("Rating up to {upper}")),
+            (None, None) => {}
+        }
+        if requirements.min_rated_games > 0 {
+            summary.push(format!(
+                "{} rated games at this speed",
+                requirements.min_rated_games
+            ));
+        }
+        if requirements.min_account_age_days > 0 {
+            summary.push(format!(
+                "Account at least {} days old",
+                requirements.min_account_age_days
+            ));
+        }
+        if !requirements.allow_bots {
+            summary.push(String::from("No bots"));
+        }
+        summary.join(", ")
+    });
+    let has_requirements = !requirements_summary.is_empty();
 
     let starts = tournament.with_value(|tournament| {
         if matches!(tournament.status, TournamentStatus::NotStarted) {
@@ -518,8 +574,39 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
                             <span class="font-bold">"Minimum players: "</span>
                             {tournament.with_value(|t| t.min_seats)}
                         </div>
+                        <Show when=move || tournament.with_value(|t| !t.waitlist.is_empty())>
+                            <div>
+                                <span class="font-bold">"Waitlist: "</span>
+                                {tournament.with_value(|t| t.waitlist.len())}
+                            </div>
+                        </Show>
+                    </Show>
+                    <Show when=move || has_requirements>
+                        <div>
+                            <span class="font-bold">"Requirements: "</span>
+                            {requirements_summary.clone()}
+                        </div>
                     </Show>
                     <p class="ui-notice">{starts.clone()}</p>
+                    <Show when=move || paused>
+                        <p class="ui-notice">
+                            "The tournament is paused. Games without moves are on hold until the organizer resumes it."
+                        </p>
+                    </Show>
+                    <Show when=move || tournament.with_value(|t| !t.withdrawn.is_empty())>
+                        <div>
+                            <span class="font-bold">"Withdrawn: "</span>
+                            {tournament
+                                .with_value(|t| {
+                                    t.withdrawn
+                                        .iter()
+                                        .filter_map(|id| t.players.get(id))
+                                        .map(|user| user.username.clone())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                })}
+                        </div>
+                    </Show>
                     <div class="space-y-2 ui-setting-group">
                         <div class="flex flex-col gap-2">
                             <p class="font-bold">"Organized by"</p>
@@ -539,21 +626,58 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
                             </For>
                         </div>
                     </div>
+                    <SeriesRow
+                        tournament_id=tournament_id.get_value()
+                        series=tournament.with_value(|t| t.series.clone())
+                        user_is_organizer_or_admin
+                    />
                     <ProgressBar current=finished_games.into() total=total_games />
+                    <Show when=move || !not_started>
+                        <div class="flex flex-wrap gap-2 items-center">
+                            <span class="font-bold">"Export: "</span>
+                            <a
+                                class="ui-button ui-button-secondary ui-button-md"
+                                href=format!("/export/tournament/{}/pgn", tournament_id.get_value())
+                                rel="external"
+                            >
+                                "PGN"
+                            </a>
+                            <a
+                                class="ui-button ui-button-secondary ui-button-md"
+                                href=format!("/export/tournament/{}/csv", tournament_id.get_value())
+                                rel="external"
+                            >
+                                "Crosstable (CSV)"
+                            </a>
+                            <Show when=move || tournament_is_swiss>
+                                <a
+                                    class="ui-button ui-button-secondary ui-button-md"
+                                    href=format!(
+                                        "/export/tournament/{}/trf",
+                                        tournament_id.get_value(),
+                                    )
+                                    rel="external"
+                                >
+                                    "TRF"
+                                </a>
+                            </Show>
+                        </div>
+                    </Show>
                     <Show when=move || not_started>
                         <div class="flex flex-wrap gap-2">
                             <Show
-                                when=user_joined
+                                when=move || user_joined() || user_waitlisted()
                                 fallback=move || {
                                     view! {
                                         <button
                                             prop:disabled=join_disabled
+                                            title=move || join_blocker().unwrap_or_default()
                                             class="ui-button ui-button-primary ui-button-md"
                                             on:click=move |_| send_action(
                                                 TournamentAction::Join(tournament_id.get_value()),
                                             )
                                         >
-                                            Join
+                                            {move || if seats_full() { "Join waitlist" } else { "Join" }}
                                         </button>
                                     }
                                 }
@@ -565,7 +689,7 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
                                         TournamentAction::Leave(tournament_id.get_value()),
                                     )
                                 >
-                                    Leave
+                                    {move || if user_waitlisted() { "Leave waitlist" } else { "Leave" }}
                                 </button>
                             </Show>
                             <Show when=user_is_organizer_or_admin>
@@ -584,6 +708,12 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
                                 </button>
                             </Show>
                         </div>
+                        <Show when=move || {
+                            !user_joined() && !user_waitlisted() && user_id().is_some()
+                                && join_blocker().is_some()
+                        }>
+                            <p class="text-sm ui-notice">{join_blocker}</p>
+                        </Show>
                         <TournamentAdminControls
                             user_is_organizer=user_is_organizer_or_admin()
                             tournament
@@ -599,6 +729,12 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
                                 >
                                     {"Finish"}
                                 </button>
+                                <button
+                                    class="ui-button ui-button-secondary ui-button-md"
+                                    on:click=toggle_pause
+                                >
+                                    {if paused { "Resume" } else { "Pause" }}
+                                </button>
                             </div>
                             <Show when=move || { unstarted_games_count() > 0 }>
                                 <div class="flex flex-col gap-2">
@@ -709,6 +845,9 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
                             </Show>
                         </div>
                     </Show>
+                    <Show when=move || user_joined() && !finished>
+                        <ParticipationRow tournament user_id />
+                    </Show>
                 </Panel>
             </div>
             <Show when=move || !not_started>
@@ -722,10 +861,13 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
             <Show when=has_top_game_sections>
                 <div class=top_game_sections_layout>
                     <Show when=has_my_schedules>
-                        <MySchedules
-                            games_hashmap=Memo::new(move |_| games_hashmap.get_value())
-                            user_id
-                        />
+                        <div class="flex flex-col gap-4 min-w-0">
+                            <MySchedules
+                                games_hashmap=Memo::new(move |_| games_hashmap.get_value())
+                                user_id
+                            />
+                            <AvailabilityEditor />
+                        </div>
                     </Show>
                     <Show when=has_unplayed_games>
                         <div class="min-w-0">
@@ -788,3 +930,191 @@ fn LoadedTournament(tournament: TournamentResponse) -> impl IntoView {
         </Show>
     }
 }
+
+#[component]
+fn SeriesRow(
+    tournament_id: TournamentId,
+    series: Option<TournamentSeriesAbstractResponse>,
+    user_is_organizer_or_admin: Signal<bool>,
+) -> impl IntoView {
+    let api = expect_context::<ApiRequestsProvider>().0;
+    let tournament_id = StoredValue::new(tournament_id);
+    let has_series = series.is_some();
+    let series_input = RwSignal::new(String::new());
+    let add_to_series = move |_| {
+        let series_id = series_input.get_untracked().trim().to_string();
+        if !series_id.is_empty() {
+            api.get().tournament(TournamentAction::AddToSeries(
+                tournament_id.get_value(),
+                TournamentSeriesId(series_id),
+            ));
+            series_input.set(String::new());
+        }
+    };
+    let remove_from_series = move |_| {
+        api.get().tournament(TournamentAction::RemoveFromSeries(
+            tournament_id.get_value(),
+        ));
+    };
+    view! {
+        <Show when=move || has_series || user_is_organizer_or_admin()>
+            <div class="flex flex-wrap gap-2 items-center ui-setting-group">
+                <span class="font-bold">"Series: "</span>
+                {series
+                    .clone()
+                    .map(|series| {
+                        view! {
+                            <a class="truncate" href=format!("/series/{}", series.series_id)>
+                                {series.name}
+                            </a>
+                        }
+                    })}
+                <Show when=user_is_organizer_or_admin>
+                    <Show
+                        when=move || has_series
+                        fallback=move || {
+                            view! {
+                                <input
+                                    class="ui-field-input"
+                                    type="text"
+                                    placeholder="Series ID"
+                                    prop:value=series_input
+                                    on:input=update_from_input(series_input)
+                                />
+                                <button
+                                    class="ui-button ui-button-secondary ui-button-md"
+                                    prop:disabled=move || series_input.with(|s| s.trim().is_empty())
+                                    on:click=add_to_series
+                                >
+                                    "Add to series"
+                                </button>
+                            }
+                        }
+                    >
+                        <button
+                            class="ui-button ui-button-danger ui-button-md"
+                            on:click=remove_from_series
+                        >
+                            "Remove from series"
+                        </button>
+                    </Show>
+                </Show>
+            </div>
+        </Show>
+    }
+}
+
+/// Withdrawal and Swiss bye requests for the logged in player.
+#[component]
+fn ParticipationRow(
+    tournament: StoredValue<TournamentResponse>,
+    user_id: Signal<Option<Uuid>>,
+) -> impl IntoView {
+    let api = expect_context::<ApiRequestsProvider>().0;
+    let tournament_id = StoredValue::new(tournament.with_value(|t| t.tournament_id.clone()));
+    let inprogress = tournament.with_value(|t| t.status == TournamentStatus::InProgress);
+    let is_swiss = tournament.with_value(|t| {
+        matches!(
+            t.mode.parse::<TournamentMode>().ok(),
+            Some(TournamentMode::DoubleSwiss)
+        )
+    });
+    let withdrawn = move || {
+        user_id().is_some_and(|user_id| tournament.with_value(|t| t.withdrawn.contains(&user_id)))
+    };
+    let confirming_withdraw = RwSignal::new(false);
+    let withdraw = move |_| {
+        confirming_withdraw.set(false);
+        api.get()
+            .tournament(TournamentAction::Withdraw(tournament_id.get_value()));
+    };
+    let open_rounds = StoredValue::new(
+        tournament.with_value(|t| ((t.current_round + 1)..=t.rounds).collect::<Vec<i32>>()),
+    );
+    let has_bye = move |round: i32| {
+        user_id()
+            .is_some_and(|user_id| tournament.with_value(|t| t.byes.contains(&(user_id, round))))
+    };
+    view! {
+        <Show
+            when=move || !withdrawn()
+            fallback=|| {
+                view! { <p class="text-sm ui-notice">"You withdrew from this tournament."</p> }
+            }
+        >
+            <div class="flex flex-col gap-2 ui-setting-group">
+                <Show when=move || is_swiss && open_rounds.with_value(|rounds| !rounds.is_empty())>
+                    <div class="flex flex-wrap gap-2 items-center">
+                        <span class="font-bold">"Half-point byes: "</span>
+                        <For each=move || open_rounds.get_value() key=|round| *round let:round>
+                            <button
+                                class=move || {
+                                    if has_bye(round) {
+                                        "ui-button ui-button-primary ui-button-md"
+                                    } else {
+                                        "ui-button ui-button-secondary ui-button-md"
+                                    }
+                                }
+                                title=move || {
+                                    if has_bye(round) {
+                                        format!("Cancel the bye for round {round}")
+                                    } else {
+                                        format!("Sit out round {round} for half the points")
+                                    }
+                                }
+                                on:click=move |_| {
+                                    let tournament_id = tournament_id.get_value();
+                                    api.get()
+                                        .tournament(
+                                            if has_bye(round) {
+                                                TournamentAction::CancelBye(tournament_id, round)
+                                            } else {
+                                                TournamentAction::RequestBye(tournament_id, round)
+                                            },
+                                        );
+                                }
+                            >
+                                {format!("Round {round}")}
+                            </button>
+                        </For>
+                    </div>
+                </Show>
+                <Show when=move || inprogress>
+                    <Show
+                        when=confirming_withdraw
+                        fallback=move || {
+                            view! {
+                                <button
+                                    class="ui-button ui-button-danger ui-button-md"
+                                    on:click=move |_| confirming_withdraw.set(true)
+                                >
+                                    "Withdraw"
+                                </button>
+                            }
+                        }
+                    >
+                        <div class="flex flex-col gap-2 items-center">
+                            <div class="text-sm text-center">
+                                {"Your remaining games will be forfeited and you will not be paired again."}
+                            </div>
+                            <div class="flex gap-2">
+                                <button
+                                    class="ui-button ui-button-danger ui-button-md"
+                                    on:click=withdraw
+                                >
+                                    {"Confirm withdrawal"}
+                                </button>
+                                <button
+                                    class="ui-button ui-button-secondary ui-button-md"
+                                    on:click=move |_| confirming_withdraw.set(false)
+                                >
+                                    {"Cancel"}
+                                </button>
+                            </div>
+                        </div>
+                    </Show>
+                </Show>
+            </div>
+        </Show>
+    }
+}
@@ -44,6 +44,9 @@ pub struct TournamentSignals {
     pub series: RwSignal<Option<Uuid>>,
     pub starts_at: RwSignal<DateTime<Utc>>,
     pub round_duration: RwSignal<i32>,
+    pub min_rated_games: RwSignal<i32>,
+    pub min_account_age_days: RwSignal<i32>,
+    pub allow_bots: RwSignal<bool>,
 }
 
 impl TournamentSignals {
@@ -66,6 +69,9 @@ impl TournamentSignals {
             series: RwSignal::new(None),
             starts_at: RwSignal::new(Utc::now()),
             round_duration: RwSignal::new(7),
+            min_rated_games: RwSignal::new(0),
+            min_account_age_days: RwSignal::new(0),
+            allow_bots: RwSignal::new(true),
         }
     }
 }
@@ -165,8 +171,12 @@ pub fn TournamentCreate() -> impl IntoView {
             time_mode,
             time_base,
             time_increment,
+            clock_kind: params.time_signals().with_untracked(|ts| ts.clock_kind()),
             band_upper,
             band_lower,
+            min_rated_games: tournament.min_rated_games.get_untracked(),
+            min_account_age_days: tournament.min_account_age_days.get_untracked(),
+            allow_bots: tournament.allow_bots.get_untracked(),
             series: tournament.series.get_untracked(),
             start_mode: if organizer_start.get_untracked() {
                 StartMode::Manual
@@ -438,6 +448,47 @@ pub fn TournamentCreate() -> impl IntoView {
                         </div>
                     </Panel>
 
+                    <Panel title="Entry Requirements" body_class="space-y-4">
+                        <div class="grid gap-4 sm:grid-cols-2 lg:grid-cols-1 xl:grid-cols-2">
+                            <div class="ui-setting-group">
+                                <div class="flex gap-3 justify-between items-center">
+                                    <span class="ui-field-label">"Min rated games"</span>
+                                    <span class="font-bold text-gray-900 dark:text-gray-100">
+                                        {tournament.min_rated_games}
+                                    </span>
+                                </div>
+                                <InputSlider
+                                    signal_to_update=tournament.min_rated_games
+                                    name="Min rated games"
+                                    min=0
+                                    max=100
+                                    step=5
+                                />
+                            </div>
+                            <div class="ui-setting-group">
+                                <div class="flex gap-3 justify-between items-center">
+                                    <span class="ui-field-label">"Min account age"</span>
+                                    <span class="font-bold text-gray-900 dark:text-gray-100">
+                                        {tournament.min_account_age_days} " days"
+                                    </span>
+                                </div>
+                                <InputSlider
+                                    signal_to_update=tournament.min_account_age_days
+                                    name="Min account age"
+                                    min=0
+                                    max=365
+                                    step=5
+                                />
+                            </div>
+                        </div>
+                        <div class="flex gap-3 items-center ui-setting-group">
+                            <SimpleSwitch checked=tournament.allow_bots />
+                            <span class="text-sm font-medium text-gray-900 dark:text-gray-100">
+                                "Allow bots"
+                            </span>
+                        </div>
+                    </Panel>
+
                     <Panel title="Rounds" body_class="space-y-4">
                         <Show when=move || {
                             params.time_signals().time_mode().get() == TimeMode::RealTime
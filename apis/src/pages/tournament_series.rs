@@ -0,0 +1,288 @@
+use crate::{
+    components::{
+        layouts::{
+            page_header::PageHeader,
+            page_shell::{PageShell, PageShellVariant},
+        },
+        molecules::{panel::Panel, user_identity::UserIdentity},
+        update_from_event::update_from_input,
+    },
+    functions::tournament_series::{get_series, UpdateSeriesScoring},
+    providers::AuthContext,
+    responses::TournamentSeriesResponse,
+};
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+use shared_types::{PrettyString, SeriesStanding, TournamentSeriesId};
+
+#[component]
+pub fn TournamentSeries() -> impl IntoView {
+    let use_params = use_params_map();
+    let series_id = move || {
+        use_params
+            .get_untracked()
+            .get("nanoid")
+            .map(|s| TournamentSeriesId(s.to_string()))
+    };
+    let current_series = Action::new(move |_: &()| {
+        let series_id = series_id();
+        async move {
+            let series_id = series_id?;
+            get_series(series_id).await.ok()
+        }
+    });
+    Effect::new(move |_| {
+        current_series.dispatch(());
+    });
+    view! {
+        <PageShell variant=PageShellVariant::Dashboard>
+            <div class="flex flex-col gap-6 w-full max-w-6xl">
+                <Show
+                    when=move || current_series.value().get().flatten().is_some()
+                    fallback=|| {
+                        view! {
+                            <Panel>
+                                <p class="text-sm text-gray-600 dark:text-gray-300">
+                                    "Loading series..."
+                                </p>
+                            </Panel>
+                        }
+                    }
+                >
+                    <LoadedSeries
+                        series=current_series.value().get().flatten().expect("Series is some")
+                        reload=Callback::new(move |_| {
+                            current_series.dispatch(());
+                        })
+                    />
+                </Show>
+            </div>
+        </PageShell>
+    }
+}
+
+#[component]
+fn LoadedSeries(series: TournamentSeriesResponse, reload: Callback<()>) -> impl IntoView {
+    let series = StoredValue::new(series);
+    let auth_context = expect_context::<AuthContext>();
+    let user_is_organizer_or_admin = Signal::derive(move || {
+        auth_context.user.with(|a| {
+            a.as_ref().is_some_and(|account| {
+                account.user.admin
+                    || series.with_value(|s| s.organizers.iter().any(|o| o.uid == account.id))
+            })
+        })
+    });
+    let scoring_summary = series.with_value(|s| {
+        let points = s
+            .scoring
+            .placement_points
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match s.scoring.best_of {
+            Some(best_of) => {
+                format!("Points per placement: {points}. Best {best_of} results count.")
+            }
+            None => format!("Points per placement: {points}. All results count."),
+        }
+    });
+
+    view! {
+        <PageHeader
+            title=series.with_value(|s| s.name.clone())
+            subtitle=format!("Series ID: {}", series.with_value(|s| s.series_id.clone()))
+        />
+        <Panel body_class="space-y-3">
+            <p class="whitespace-pre-wrap break-words">
+                {series.with_value(|s| s.description.clone())}
+            </p>
+            <p class="ui-notice">{scoring_summary}</p>
+            <div class="flex flex-wrap gap-2 items-center">
+                <span class="font-bold">"Organized by"</span>
+                {series
+                    .with_value(|s| s.organizers.clone())
+                    .into_iter()
+                    .map(|user| view! { <UserIdentity user class="p-1 h-10" /> })
+                    .collect_view()}
+            </div>
+        </Panel>
+        <Show when=user_is_organizer_or_admin>
+            <ScoringForm series_id=series.with_value(|s| s.series_id.clone()) reload series />
+        </Show>
+        <Panel title="Leaderboard" body_class="overflow-x-auto">
+            <Show
+                when=move || series.with_value(|s| !s.leaderboard.is_empty())
+                fallback=|| view! { <p>"No finished tournaments yet."</p> }
+            >
+                <table class="w-full text-sm">
+                    <thead>
+                        <tr class="text-left">
+                            <th class="p-2">"#"</th>
+                            <th class="p-2">"Player"</th>
+                            <th class="p-2">"Points"</th>
+                            <th class="p-2">"Results"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {series
+                            .with_value(|s| s.leaderboard.clone())
+                            .into_iter()
+                            .map(|row| view! { <LeaderboardRow row series /> })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </Show>
+        </Panel>
+        <Panel title="Tournaments" body_class="space-y-2">
+            {series
+                .with_value(|s| s.tournaments.clone())
+                .into_iter()
+                .map(|tournament| {
+                    view! {
+                        <div class="flex flex-wrap gap-2 justify-between">
+                            <a href=format!("/tournament/{}", tournament.tournament_id)>
+                                {tournament.name}
+                            </a>
+                            <span>{tournament.status.pretty_string()}</span>
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </Panel>
+    }
+}
+
+#[component]
+fn LeaderboardRow(
+    row: SeriesStanding,
+    series: StoredValue<TournamentSeriesResponse>,
+) -> impl IntoView {
+    let player = series.with_value(|s| s.players.get(&row.player).cloned());
+    let results = row
+        .results
+        .iter()
+        .map(|result| {
+            let name = series.with_value(|s| {
+                s.tournaments
+                    .iter()
+                    .find(|t| t.tournament_id == result.tournament_id)
+                    .map(|t| t.name.clone())
+                    .unwrap_or_default()
+            });
+            let class = if result.counted {
+                "font-semibold"
+            } else {
+                "line-through opacity-60"
+            };
+            view! {
+                <span class=class title=name>
+                    {format!("{} ({})", result.points, result.placement)}
+                </span>
+            }
+        })
+        .collect_view();
+    view! {
+        <tr class="border-t border-gray-200 dark:border-gray-700">
+            <td class="p-2">{row.position}</td>
+            <td class="p-2">{player.map(|user| view! { <UserIdentity user class="h-8" /> })}</td>
+            <td class="p-2 font-bold">{row.total}</td>
+            <td class="flex flex-wrap gap-2 p-2">{results}</td>
+        </tr>
+    }
+}
+
+#[component]
+fn ScoringForm(
+    series_id: TournamentSeriesId,
+    series: StoredValue<TournamentSeriesResponse>,
+    reload: Callback<()>,
+) -> impl IntoView {
+    let update_scoring = ServerAction::<UpdateSeriesScoring>::new();
+    let placement_points = RwSignal::new(series.with_value(|s| {
+        s.scoring
+            .placement_points
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }));
+    let best_of = RwSignal::new(
+        series
+            .with_value(|s| s.scoring.best_of)
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+    );
+    let parsed_points = move || {
+        placement_points
+            .get()
+            .split(',')
+            .map(|p| p.trim().parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .ok()
+    };
+    let parsed_best_of = move || {
+        let best_of = best_of.get();
+        let best_of = best_of.trim();
+        if best_of.is_empty() {
+            Some(None)
+        } else {
+            best_of.parse::<i32>().ok().map(Some)
+        }
+    };
+    let submit = move |_| {
+        if let (Some(placement_points), Some(best_of)) = (parsed_points(), parsed_best_of()) {
+            update_scoring.dispatch(UpdateSeriesScoring {
+                series_id: series_id.clone(),
+                placement_points,
+                best_of,
+            });
+        }
+    };
+    Effect::new(move |_| {
+        if let Some(Ok(())) = update_scoring.value().get() {
+            reload.run(());
+        }
+    });
+    let error = move || {
+        update_scoring
+            .value()
+            .get()
+            .and_then(|result| result.err())
+            .map(|error| error.to_string())
+    };
+    view! {
+        <Panel title="Scoring" body_class="space-y-3">
+            <label class="flex flex-col gap-1">
+                "Points for 1st, 2nd, 3rd... place"
+                <input
+                    class="ui-field-input"
+                    type="text"
+                    prop:value=placement_points
+                    on:input=update_from_input(placement_points)
+                />
+            </label>
+            <label class="flex flex-col gap-1">
+                "Count only the best N results (leave empty to count all)"
+                <input
+                    class="ui-field-input"
+                    type="number"
+                    min="1"
+                    prop:value=best_of
+                    on:input=update_from_input(best_of)
+                />
+            </label>
+            <Show when=move || error().is_some()>
+                <p class="ui-notice">{error}</p>
+            </Show>
+            <button
+                class="ui-button ui-button-primary ui-button-md"
+                prop:disabled=move || parsed_points().is_none() || parsed_best_of().is_none()
+                on:click=submit
+            >
+                "Update scoring"
+            </button>
+        </Panel>
+    }
+}
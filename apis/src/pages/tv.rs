@@ -0,0 +1,258 @@
+use crate::{
+    common::{with_class, TvChannel},
+    components::{
+        layouts::{page_header::PageHeader, page_shell::PageShell},
+        molecules::{
+            empty_state::EmptyState,
+            game_previews::GamePreviews,
+            thumbnail_pieces::ThumbnailPieces,
+        },
+    },
+    functions::{follows::get_following, tv::get_spectator_counts},
+    providers::{games::GamesSignal, AuthContext},
+    responses::GameResponse,
+};
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+use leptos_use::{use_interval_fn, utils::Pausable};
+use shared_types::{GameId, GameSpeed, TournamentId};
+use std::collections::HashSet;
+
+const SPECTATOR_REFRESH_MS: u64 = 15_000;
+
+#[component]
+pub fn TvChannelView() -> impl IntoView {
+    let params = use_params_map();
+    // `None` for a channel name that doesn't exist, so a dead link says so
+    // instead of quietly showing another channel
+    let channel = Memo::new(move |_| {
+        params.with(|p| match p.get("channel") {
+            Some(channel) => channel.parse::<TvChannel>().ok(),
+            None => Some(TvChannel::default()),
+        })
+    });
+    let games = expect_context::<GamesSignal>();
+    let user = expect_context::<AuthContext>().user;
+    let logged_in = move || user.with(|account| account.is_some());
+    let following = LocalResource::new(move || {
+        let logged_in = logged_in();
+        async move {
+            if !logged_in {
+                return HashSet::new();
+            }
+            get_following()
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .map(|followed| followed.user.uid)
+                .collect()
+        }
+    });
+    let channel_games = Signal::derive(move || {
+        let following = following.get().unwrap_or_default();
+        games.live.with(|live| {
+            channel.with(|channel| {
+                channel
+                    .as_ref()
+                    .map(|channel| channel.games(live.live_games.values(), &following))
+                    .unwrap_or_default()
+            })
+        })
+    });
+
+    // Stay on the featured game while it's live, then move on to the best
+    // game left on the channel
+    let featured_id = RwSignal::new(None::<GameId>);
+    Effect::new(move |_| {
+        channel_games.with(|games| {
+            let current = featured_id.get_untracked();
+            if !games
+                .iter()
+                .any(|game| Some(&game.game_id) == current.as_ref())
+            {
+                featured_id.set(games.first().map(|game| game.game_id.clone()));
+            }
+        });
+    });
+    let featured = Signal::derive(move || {
+        let featured_id = featured_id.get()?;
+        channel_games.with(|games| {
+            games
+                .iter()
+                .find(|game| game.game_id == featured_id)
+                .cloned()
+        })
+    });
+    let others = Signal::derive(move || {
+        let featured_id = featured_id.get();
+        channel_games.with(|games| {
+            games
+                .iter()
+                .filter(|game| Some(&game.game_id) != featured_id.as_ref())
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let refresh = RwSignal::new(0_u32);
+    let Pausable { .. } = use_interval_fn(
+        move || refresh.update(|tick| *tick = tick.wrapping_add(1)),
+        SPECTATOR_REFRESH_MS,
+    );
+    // Moves don't change who's being watched, so only refetch on new games
+    let watched = Memo::new(move |_| {
+        channel_games.with(|games| {
+            games
+                .iter()
+                .map(|game| game.game_id.clone())
+                .collect::<Vec<_>>()
+        })
+    });
+    let spectators = LocalResource::new(move || {
+        refresh.track();
+        let watched = watched.get();
+        async move {
+            if watched.is_empty() {
+                return Default::default();
+            }
+            get_spectator_counts(watched).await.unwrap_or_default()
+        }
+    });
+    let spectators_of = move |game_id: &GameId| {
+        spectators
+            .get()
+            .and_then(|counts| counts.get(game_id).copied())
+            .unwrap_or_default()
+    };
+
+    // Tournaments only get a tab while one of their games is live
+    let tournaments = Memo::new(move |_| {
+        let mut seen = HashSet::new();
+        games.live.with(|live| {
+            live.live_games
+                .values()
+                .filter_map(|game| game.tournament.as_ref())
+                .filter(|tournament| seen.insert(tournament.tournament_id.clone()))
+                .map(|tournament| (tournament.tournament_id.clone(), tournament.name.clone()))
+                .collect::<Vec<_>>()
+        })
+    });
+    let tabs = move || {
+        let mut tabs = vec![(TvChannel::Top, "Top rated".to_string())];
+        tabs.extend(
+            GameSpeed::real_time_speeds()
+                .into_iter()
+                .map(|speed| (TvChannel::Speed(speed), speed.to_string())),
+        );
+        if logged_in() {
+            tabs.push((TvChannel::Following, "Following".to_string()));
+        }
+        tabs.extend(
+            tournaments
+                .get()
+                .into_iter()
+                .map(|(tournament_id, name)| (TvChannel::Tournament(tournament_id), name)),
+        );
+        let current = channel.get();
+        if let Some(TvChannel::Tournament(TournamentId(id))) = &current {
+            if !tabs.iter().any(|(tab, _)| Some(tab) == current.as_ref()) {
+                tabs.push((TvChannel::Tournament(TournamentId(id.clone())), id.clone()));
+            }
+        }
+        tabs
+    };
+    let tab_class = move |tab: &TvChannel| {
+        with_class(
+            if channel.with(|channel| channel.as_ref() == Some(tab)) {
+                "ui-choice ui-choice-sm ui-choice-active cursor-pointer"
+            } else {
+                "ui-choice ui-choice-sm ui-choice-inactive cursor-pointer"
+            },
+            "no-link-style",
+        )
+    };
+
+    view! {
+        <PageShell>
+            <PageHeader title="Hive TV" />
+            <nav class="flex flex-wrap gap-1 my-2">
+                {move || {
+                    tabs()
+                        .into_iter()
+                        .map(|(tab, label)| {
+                            view! {
+                                <a href=format!("/tv/{tab}") class=tab_class(&tab)>
+                                    {label}
+                                </a>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </nav>
+            <Show
+                when=move || channel.with(Option::is_some)
+                fallback=|| {
+                    view! {
+                        <EmptyState
+                            title="Channel not found"
+                            message="There is no TV channel by that name. Pick one of the channels above."
+                        />
+                    }
+                }
+            >
+                <Show
+                    when=move || featured.with(Option::is_some)
+                    fallback=|| {
+                        view! {
+                            <EmptyState
+                                title="Nothing on this channel"
+                                message="No live games match right now. The next one shows up here as soon as it starts."
+                            />
+                        }
+                    }
+                >
+                    {move || {
+                        featured
+                            .get()
+                            .map(|game| {
+                                let watching = spectators_of(&game.game_id);
+                                view! { <FeaturedGame game watching /> }
+                            })
+                    }}
+                    <GamePreviews games=others show_time=true />
+                </Show>
+            </Show>
+        </PageShell>
+    }
+}
+
+#[component]
+fn FeaturedGame(game: GameResponse, watching: usize) -> impl IntoView {
+    let board = StoredValue::new(game.create_state().board);
+    let rating = |rating: Option<f64>| rating.map_or(String::new(), |r| format!(" ({r:.0})"));
+    let players = format!(
+        "{}{} vs {}{}",
+        game.white_player.username,
+        rating(game.white_rating),
+        game.black_player.username,
+        rating(game.black_rating),
+    );
+    let watching = match watching {
+        0 => String::new(),
+        1 => "1 watching".to_string(),
+        n => format!("{n} watching"),
+    };
+
+    view! {
+        <div class="flex flex-col gap-1 items-center mx-auto mb-4 w-full max-w-md">
+            <div class="font-bold text-center">{players}</div>
+            <div class="flex gap-2 text-xs">
+                <span>{game.speed.to_string()}</span>
+                <span>{watching}</span>
+            </div>
+            <a class="block relative size-96 max-w-full" href=format!("/game/{}", game.game_id)>
+                <ThumbnailPieces board />
+            </a>
+        </div>
+    }
+}
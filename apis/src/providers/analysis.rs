@@ -157,6 +157,48 @@ impl AnalysisTree {
             })
     }
 
+    /// Every variation below `node_id`, root to leaf, as (piece, position) moves.
+    pub fn lines_from(&self, node_id: i32) -> Vec<Vec<(String, String)>> {
+        let children = self
+            .tree
+            .get_node_by_id(&node_id)
+            .and_then(|node| node.get_children_ids().ok())
+            .unwrap_or_default();
+        let mut lines = Vec::new();
+        for child in children {
+            let Some(value) = self
+                .tree
+                .get_node_by_id(&child)
+                .and_then(|node| node.get_value().ok().flatten())
+            else {
+                continue;
+            };
+            let turn = (value.piece, value.position);
+            let tails = self.lines_from(child);
+            if tails.is_empty() {
+                lines.push(vec![turn]);
+            }
+            for mut tail in tails {
+                tail.insert(0, turn.clone());
+                lines.push(tail);
+            }
+        }
+        lines
+    }
+
+    /// The line that follows the first child at every node.
+    pub fn main_line(&self) -> Vec<(String, String)> {
+        self.lines_from(START_NODE_ID)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// The node of the last move a game response recorded, see `from_game_response`.
+    pub fn game_node_id(game_response: &GameResponse) -> i32 {
+        game_response.history.len() as i32 - 1
+    }
+
     pub fn current_hash(&self) -> u64 {
         self.current_node_id()
             .and_then(|id| self.hashes.get_by_right(&id).copied())
@@ -324,6 +366,22 @@ impl AnalysisTree {
         Some(analysis_tree)
     }
 
+    /// The JSON `DownloadTree` writes and studies store.
+    pub fn to_saved(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Restores a tree from `to_saved` JSON and puts the board on its current node.
+    pub fn from_saved(json: &str, game_state: GameStateStore) -> Option<Self> {
+        let mut tree = serde_json::from_str::<AnalysisTree>(json).ok()?;
+        tree.ensure_start_node();
+        game_state.full_reset();
+        if let Some(node_id) = tree.current_node_id() {
+            tree.update_node(node_id, Some(game_state));
+        }
+        Some(tree)
+    }
+
     pub fn from_uhp(
         game_state: GameStateStore,
         uhp_string: impl Into<String>,
@@ -485,6 +543,7 @@ mod tests {
     use hive_lib::GameStatus;
     use leptos::prelude::Owner;
     use shared_types::{
+        ClockKind,
         Conclusion,
         GameId,
         GameSpeed,
@@ -556,6 +615,7 @@ mod tests {
             time_mode: TimeMode::Untimed,
             time_base: None,
             time_increment: None,
+            clock_kind: ClockKind::Fischer,
             speed: GameSpeed::Untimed,
             black_time_left: None,
             white_time_left: None,
@@ -604,6 +664,29 @@ mod tests {
         assert_eq!(analysis.current_node_id(), Some(1));
     }
 
+    #[test]
+    fn lines_from_lists_every_variation() {
+        let mut analysis = AnalysisTree::default();
+        let node = |id: i32| (format!("wA{id}"), String::new());
+
+        analysis.add_node(node(1), 1);
+        analysis.add_node(node(2), 2);
+        analysis.add_node(node(3), 3);
+        analysis.current_node = analysis.tree.get_node_by_id(&0);
+        analysis.add_node(node(4), 4);
+
+        assert_eq!(
+            analysis.lines_from(START_NODE_ID),
+            vec![vec![node(1), node(2), node(3)], vec![node(1), node(4)]]
+        );
+        assert_eq!(
+            analysis.lines_from(0),
+            vec![vec![node(2), node(3)], vec![node(4)]]
+        );
+        assert!(analysis.lines_from(2).is_empty());
+        assert_eq!(analysis.main_line(), vec![node(1), node(2), node(3)]);
+    }
+
     #[test]
     fn legacy_tree_is_normalized_with_start_root() {
         let mut tree = Tree::new(Some("analysis"));
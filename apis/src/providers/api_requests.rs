@@ -1,6 +1,15 @@
 use super::{auth_context, challenges::ChallengeStateSignal, games::GamesSignal, websocket};
 use crate::{
-    common::{ChallengeAction, ClientRequest, GameAction, ScheduleAction, TournamentAction},
+    common::{
+        AnalysisAction,
+        ChallengeAction,
+        ClientRequest,
+        GameAction,
+        RematchAction,
+        ScheduleAction,
+        SeekAction,
+        TournamentAction,
+    },
     providers::websocket::WebsocketContext,
     responses::{create_challenge_handler, AccountResponse},
 };
@@ -42,6 +51,14 @@ impl ApiRequests {
         games.own_games_remove(&game_id);
     }
 
+    pub fn premove(&self, game_id: GameId, turn: Option<Turn>) {
+        let msg = ClientRequest::Game {
+            game_id,
+            action: GameAction::Premove(turn),
+        };
+        self.websocket.send(&msg);
+    }
+
     pub fn pong(&self, nonce: u64) {
         let msg = ClientRequest::Pong(nonce);
         self.websocket.send(&msg);
@@ -60,6 +77,14 @@ impl ApiRequests {
         self.websocket.send(&msg);
     }
 
+    pub fn rematch(&self, game_id: GameId, action: RematchAction) {
+        let msg = ClientRequest::Game {
+            game_id,
+            action: GameAction::Rematch(action),
+        };
+        self.websocket.send(&msg);
+    }
+
     pub fn tournament_game_start(&self, game_id: GameId) {
         let msg = ClientRequest::Game {
             game_id,
@@ -135,6 +160,16 @@ impl ApiRequests {
         self.websocket.send(&msg);
     }
 
+    pub fn analysis(&self, action: AnalysisAction) {
+        let msg = ClientRequest::Analysis(action);
+        self.websocket.send(&msg);
+    }
+
+    pub fn seek(&self, action: SeekAction) {
+        let msg = ClientRequest::Seek(action);
+        self.websocket.send(&msg);
+    }
+
     pub fn join(&self, game_id: GameId) {
         let msg = ClientRequest::Game {
             game_id,
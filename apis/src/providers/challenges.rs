@@ -1,7 +1,7 @@
 use super::snapshot::apply_snapshot_hash_map;
 use crate::responses::ChallengeResponse;
 use leptos::prelude::*;
-use shared_types::ChallengeId;
+use shared_types::{ChallengeId, SeekDetails};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Debug, Copy)]
@@ -85,12 +85,15 @@ impl ChallengeStateSignal {
 #[derive(Clone, Debug)]
 pub struct ChallengeState {
     pub challenges: HashMap<ChallengeId, ChallengeResponse>,
+    /// The user's quick-pairing seek while it is queued.
+    pub seek: Option<SeekDetails>,
 }
 
 impl ChallengeState {
     pub fn new() -> Self {
         Self {
             challenges: HashMap::new(),
+            seek: None,
         }
     }
 }
@@ -111,7 +114,7 @@ mod tests {
     use crate::responses::{ChallengeResponse, UserResponse};
     use chrono::Utc;
     use hive_lib::ColorChoice;
-    use shared_types::{ChallengeVisibility, GameSpeed, Takeback, TimeMode};
+    use shared_types::{ChallengeVisibility, ClockKind, GameSpeed, Takeback, TimeMode};
     use std::collections::HashMap;
     use uuid::Uuid;
 
@@ -144,6 +147,7 @@ mod tests {
             time_mode: TimeMode::Untimed,
             time_base: None,
             time_increment: None,
+            clock_kind: ClockKind::Fischer,
             speed: GameSpeed::Untimed,
             band_upper: None,
             band_lower: None,
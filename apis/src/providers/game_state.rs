@@ -7,7 +7,7 @@ use crate::{
 use hive_lib::{Color, GameControl, GameStatus, GameType, Piece, Position, State, Turn};
 use leptos::{logging::log, prelude::*, reactive::effect::batch};
 use reactive_stores::Store;
-use shared_types::{GameId, Takeback};
+use shared_types::{GameId, Takeback, TimeMode};
 use uuid::Uuid;
 
 use super::{
@@ -207,14 +207,76 @@ impl GameStateStore {
         }
     }
 
-    pub fn show_moves(&self, piece: Piece, position: Position) {
-        let target_positions = self.state().with_untracked(|state| {
-            state
-                .board
-                .moves(state.turn_color)
-                .get(&(piece, position))
-                .cloned()
+    /// True while the user waits on their opponent in a live real-time
+    /// game, when a move they pick is queued as a premove instead.
+    pub fn can_premove(&self) -> bool {
+        let realtime = self.game_response().with_untracked(|game| {
+            game.as_ref()
+                .is_some_and(|game| game.time_mode == TimeMode::RealTime && !game.finished)
+        });
+        let is_live = self.board_view().with_untracked(|view| !view.is_history());
+        realtime && is_live && self.premove_color().is_some()
+    }
+
+    /// The user's color when it isn't their turn
+    fn premove_color(&self) -> Option<Color> {
+        let user_id = expect_context::<AuthContext>()
+            .identity
+            .get_untracked()
+            .and_then(AuthIdentity::user_id)?;
+        let turn_color = self.state().with_untracked(|state| state.turn_color);
+        let color = if self.white_id().get_untracked() == Some(user_id) {
+            Color::White
+        } else if self.black_id().get_untracked() == Some(user_id) {
+            Color::Black
+        } else {
+            return None;
+        };
+        (color != turn_color).then_some(color)
+    }
+
+    /// Sends the selected move as a premove. The selection stays on the
+    /// board to show what is queued.
+    pub fn queue_premove(&self, api: ApiRequests) {
+        let (active, position) = self.move_info().with_untracked(|move_info| {
+            (
+                move_info.active.map(|(piece, _)| piece),
+                move_info.target_position,
+            )
         });
+        if let (Some(active), Some(position), Some(game_id)) =
+            (active, position, self.game_id().get_untracked())
+        {
+            let turn = Turn::Move(active, position);
+            api.premove(game_id, Some(turn.clone()));
+            self.premove().set(Some(turn));
+        }
+    }
+
+    pub fn cancel_premove(&self, api: ApiRequests) {
+        if self.premove().get_untracked().is_none() {
+            return;
+        }
+        if let Some(game_id) = self.game_id().get_untracked() {
+            api.premove(game_id, None);
+        }
+        self.premove().set(None);
+    }
+
+    fn selection_color(&self) -> Color {
+        if self.can_premove() {
+            if let Some(color) = self.premove_color() {
+                return color;
+            }
+        }
+        self.state().with_untracked(|state| state.turn_color)
+    }
+
+    pub fn show_moves(&self, piece: Piece, position: Position) {
+        let color = self.selection_color();
+        let target_positions = self
+            .state()
+            .with_untracked(|state| state.board.moves(color).get(&(piece, position)).cloned());
         self.move_info().update(|move_info| {
             move_info.reset();
             move_info.current_position = Some(position);
@@ -226,14 +288,15 @@ impl GameStateStore {
     }
 
     pub fn show_spawns(&self, piece: Piece, position: Position) {
+        let color = self.selection_color();
         let (target_positions, active) = self.state().with_untracked(|state| {
             let target_positions = state
                 .board
-                .spawnable_positions(state.turn_color)
+                .spawnable_positions(color)
                 .collect::<Vec<Position>>();
             let active = state
                 .board
-                .reserve(state.turn_color, state.game_type)
+                .reserve(color, state.game_type)
                 .get(&piece.bug())
                 .and_then(|pieces| pieces.first())
                 .and_then(|piece| Piece::from_str(piece).ok());
@@ -411,6 +474,8 @@ pub struct GameState {
     // Unanswered game_control
     pub game_control_pending: Option<GameControl>,
     pub game_response: Option<GameResponse>,
+    // Move queued on the server for when the opponent has played
+    pub premove: Option<Turn>,
 }
 
 impl Default for GameState {
@@ -440,6 +505,7 @@ impl GameState {
             board_view: BoardView::Live,
             game_control_pending,
             game_response: Some(game.clone()),
+            premove: None,
         }
     }
 
@@ -458,6 +524,7 @@ impl GameState {
             board_view: BoardView::Live,
             game_control_pending: None,
             game_response: None,
+            premove: None,
         }
     }
 }
@@ -486,7 +553,14 @@ mod tests {
     use chrono::Utc;
     use hive_lib::{Direction as BoardDirection, History};
     use leptos::prelude::Owner;
-    use shared_types::{Conclusion, GameSpeed, GameStart, TimeMode, TournamentGameResult};
+    use shared_types::{
+        ClockKind,
+        Conclusion,
+        GameSpeed,
+        GameStart,
+        TimeMode,
+        TournamentGameResult,
+    };
     use std::collections::HashMap;
 
     fn piece(piece: &str) -> Piece {
@@ -537,6 +611,7 @@ mod tests {
             time_mode: TimeMode::Untimed,
             time_base: None,
             time_increment: None,
+            clock_kind: ClockKind::Fischer,
             speed: GameSpeed::Untimed,
             black_time_left: None,
             white_time_left: None,
@@ -2,12 +2,16 @@ use leptos::prelude::{provide_context, RwSignal};
 use shared_types::{GameId, ReadyUser, TournamentId};
 use std::collections::HashMap;
 
-use crate::{common::GameActionResponse, responses::HeartbeatResponse};
+use crate::{
+    common::{GameActionResponse, RematchStatus},
+    responses::HeartbeatResponse,
+};
 
 #[derive(Clone)]
 pub struct UpdateNotifier {
     pub game_response: RwSignal<Option<GameActionResponse>>,
     pub heartbeat: RwSignal<HeartbeatResponse>,
+    pub rematches: RwSignal<HashMap<GameId, RematchStatus>>,
     pub tournament_ready: RwSignal<HashMap<GameId, Vec<ReadyUser>>>,
     pub tournament_update: RwSignal<TournamentId>,
 }
@@ -16,6 +20,7 @@ pub fn provide_server_updates() {
     provide_context(UpdateNotifier {
         game_response: RwSignal::new(None),
         heartbeat: RwSignal::new(HeartbeatResponse::default()),
+        rematches: RwSignal::new(HashMap::new()),
         tournament_ready: RwSignal::new(HashMap::new()),
         tournament_update: RwSignal::new(TournamentId::default()),
     });
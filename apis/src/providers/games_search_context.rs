@@ -178,6 +178,7 @@ pub fn build_profile_query_options(
         game_progress: tab,
         include_total: false,
         position_hash: None,
+        opening: None,
     }
 }
 
@@ -0,0 +1,160 @@
+use crate::common::{AnalysisSync, LiveAnalysisSnapshot};
+use leptos::prelude::{provide_context, RwSignal, Set, Update, WithUntracked};
+use uuid::Uuid;
+
+/// Chat lines kept in memory for the session panel.
+const MAX_CHAT_LINES: usize = 100;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiveSession {
+    pub session_id: String,
+    pub owner_id: Uuid,
+    pub owner: String,
+    pub open_moves: bool,
+    pub participants: usize,
+}
+
+impl LiveSession {
+    /// Whether `user_id` may change the shared board.
+    pub fn can_move(&self, user_id: Option<Uuid>) -> bool {
+        user_id.is_some_and(|user_id| self.open_moves || user_id == self.owner_id)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiveChatLine {
+    pub username: String,
+    pub body: String,
+}
+
+/// The live analysis session this tab is in. Websocket updates land here; the analysis
+/// page applies `incoming` to its own tree, since that only exists while it is open.
+#[derive(Clone, Copy, Debug)]
+pub struct LiveAnalysisContext {
+    pub session: RwSignal<Option<LiveSession>>,
+    pub incoming: RwSignal<Vec<AnalysisSync>>,
+    pub chat: RwSignal<Vec<LiveChatLine>>,
+    pub notice: RwSignal<Option<String>>,
+}
+
+impl LiveAnalysisContext {
+    pub fn new() -> Self {
+        Self {
+            session: RwSignal::new(None),
+            incoming: RwSignal::new(Vec::new()),
+            chat: RwSignal::new(Vec::new()),
+            notice: RwSignal::new(None),
+        }
+    }
+
+    pub fn joined(&self, snapshot: LiveAnalysisSnapshot) {
+        let mut incoming = vec![AnalysisSync::Tree(snapshot.tree)];
+        incoming.extend(snapshot.node.map(AnalysisSync::Node));
+        self.session.set(Some(LiveSession {
+            session_id: snapshot.session_id,
+            owner_id: snapshot.owner_id,
+            owner: snapshot.owner,
+            open_moves: snapshot.open_moves,
+            participants: snapshot.participants,
+        }));
+        self.incoming.set(incoming);
+        self.chat.set(Vec::new());
+        self.notice.set(None);
+    }
+
+    pub fn is_current(&self, session_id: &str) -> bool {
+        self.session.with_untracked(|session| {
+            session
+                .as_ref()
+                .is_some_and(|session| session.session_id == session_id)
+        })
+    }
+
+    pub fn update_session(&self, session_id: &str, change: impl FnOnce(&mut LiveSession)) {
+        self.session.update(|session| {
+            if let Some(session) = session
+                .as_mut()
+                .filter(|session| session.session_id == session_id)
+            {
+                change(session);
+            }
+        });
+    }
+
+    pub fn push_chat(&self, line: LiveChatLine) {
+        self.chat.update(|chat| {
+            chat.push(line);
+            if chat.len() > MAX_CHAT_LINES {
+                chat.remove(0);
+            }
+        });
+    }
+
+    /// Forgets the session, e.g. after leaving it or when its owner ended it.
+    pub fn clear(&self, notice: Option<String>) {
+        self.session.set(None);
+        self.incoming.set(Vec::new());
+        self.notice.set(notice);
+    }
+}
+
+impl Default for LiveAnalysisContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn provide_live_analysis() {
+    provide_context(LiveAnalysisContext::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::{GetUntracked, Owner};
+
+    fn snapshot(node: Option<i32>) -> LiveAnalysisSnapshot {
+        LiveAnalysisSnapshot {
+            session_id: "room".to_string(),
+            owner_id: Uuid::new_v4(),
+            owner: "coach".to_string(),
+            open_moves: false,
+            tree: "{}".to_string(),
+            node,
+            participants: 1,
+        }
+    }
+
+    #[test]
+    fn joining_queues_the_tree_then_the_node() {
+        Owner::new().with(|| {
+            let live = LiveAnalysisContext::new();
+            live.joined(snapshot(Some(4)));
+            assert_eq!(
+                live.incoming.get_untracked(),
+                [AnalysisSync::Tree("{}".to_string()), AnalysisSync::Node(4)]
+            );
+            assert!(live.is_current("room"));
+            assert!(!live.is_current("other"));
+        });
+    }
+
+    #[test]
+    fn only_the_owner_moves_unless_moves_are_open() {
+        Owner::new().with(|| {
+            let live = LiveAnalysisContext::new();
+            let snapshot = snapshot(None);
+            let owner_id = snapshot.owner_id;
+            live.joined(snapshot);
+            let session = live.session.get_untracked().expect("joined");
+            assert!(session.can_move(Some(owner_id)));
+            assert!(!session.can_move(Some(Uuid::new_v4())));
+            assert!(!session.can_move(None));
+
+            live.update_session("room", |session| session.open_moves = true);
+            let session = live.session.get_untracked().expect("joined");
+            assert!(session.can_move(Some(Uuid::new_v4())));
+            assert!(!session.can_move(None));
+        });
+    }
+}
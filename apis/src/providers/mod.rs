@@ -12,6 +12,7 @@ pub mod game_state;
 mod game_updater;
 pub mod games;
 mod games_search_context;
+pub mod live_analysis;
 mod notifications;
 pub mod online_users;
 mod ping;
@@ -1,4 +1,5 @@
 use crate::responses::ScheduleResponse;
+use chrono::{DateTime, Utc};
 use leptos::prelude::{provide_context, RwSignal, StoredValue, UpdateValue, WithValue};
 use shared_types::GameId;
 use std::collections::{HashMap, HashSet};
@@ -10,6 +11,8 @@ type ScheduleMap = HashMap<GameId, HashMap<Uuid, ScheduleResponse>>;
 pub struct SchedulesContext {
     pub own: RwSignal<ScheduleMap>,
     pub tournament: RwSignal<ScheduleMap>,
+    /// Start times that fit both players' availability, per game.
+    pub suggestions: RwSignal<HashMap<GameId, Vec<DateTime<Utc>>>>,
     own_schedule_resync_dirty: StoredValue<HashSet<Uuid>>,
     own_game_resync_dirty: StoredValue<HashSet<GameId>>,
 }
@@ -19,6 +22,7 @@ impl SchedulesContext {
         Self {
             own: RwSignal::new(HashMap::new()),
             tournament: RwSignal::new(HashMap::new()),
+            suggestions: RwSignal::new(HashMap::new()),
             own_schedule_resync_dirty: StoredValue::new(HashSet::new()),
             own_game_resync_dirty: StoredValue::new(HashSet::new()),
         }
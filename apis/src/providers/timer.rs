@@ -5,7 +5,7 @@ use crate::{
 use chrono::{DateTime, Utc};
 use hive_lib::{Color, GameResult, GameStatus};
 use leptos::prelude::*;
-use shared_types::{Conclusion, GameId, TimeMode};
+use shared_types::{ClockKind, Conclusion, GameId, TimeMode};
 use std::time::Duration;
 
 #[derive(Clone, Debug, Copy)]
@@ -45,6 +45,8 @@ impl TimerSignal {
                 .time_increment
                 .map(|inc| Duration::from_secs(inc as u64));
             timer.time_mode = game.time_mode;
+            timer.clock_kind = game.clock_kind;
+            timer.delay_left = timer.fresh_delay();
             timer.last_interaction = game.last_interaction;
             timer.time_base = game.time_base.map(|base| Duration::from_secs(base as u64));
             timer.set_timed_out_color(timeout_loser(game));
@@ -67,7 +69,10 @@ fn history_time_left(
     color: Color,
 ) -> Option<Duration> {
     let base = match response.time_mode {
-        TimeMode::RealTime => Duration::from_secs(u64::try_from(response.time_base?).ok()?),
+        TimeMode::RealTime => response.clock_kind.initial_time(
+            Duration::from_secs(u64::try_from(response.time_base?).ok()?),
+            Duration::from_secs(u64::try_from(response.time_increment.unwrap_or(0)).unwrap_or(0)),
+        ),
         TimeMode::Correspondence => match (response.time_base, response.time_increment) {
             (Some(base), None) => Duration::from_secs(u64::try_from(base).ok()?),
             (None, Some(increment)) => {
@@ -113,6 +118,9 @@ pub struct Timer {
     pub time_base: Option<Duration>,
     pub time_increment: Option<Duration>,
     pub time_mode: TimeMode,
+    pub clock_kind: ClockKind,
+    /// What is left of a simple delay before the mover's clock starts running
+    pub delay_left: Duration,
     pub last_interaction: Option<DateTime<Utc>>,
 }
 
@@ -129,9 +137,42 @@ impl Timer {
             time_base: None,
             time_increment: None,
             time_mode: TimeMode::Untimed,
+            clock_kind: ClockKind::Fischer,
+            delay_left: Duration::ZERO,
             last_interaction: None,
         }
     }
+
+    fn fresh_delay(&self) -> Duration {
+        match self.clock_kind {
+            ClockKind::SimpleDelay if !self.finished => self.time_increment.unwrap_or_default(),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Runs the mover's clock for `elapsed`, spending any simple delay first.
+    pub fn tick(&mut self, elapsed: Duration) {
+        let charged = elapsed.saturating_sub(self.delay_left);
+        self.delay_left = self.delay_left.saturating_sub(elapsed);
+        let time_left = if self.turn.is_multiple_of(2) {
+            &mut self.white_time_left
+        } else {
+            &mut self.black_time_left
+        };
+        *time_left = time_left.map(|t| t.saturating_sub(charged));
+    }
+
+    /// The time to show for `color`, and the byo-yomi periods left if any.
+    pub fn display_time(&self, color: Color) -> (Duration, Option<u8>) {
+        let time_left = self.time_left(color);
+        match self
+            .clock_kind
+            .periods_left(time_left, self.time_increment.unwrap_or_default())
+        {
+            Some((shown, periods)) => (shown, Some(periods)),
+            None => (time_left, None),
+        }
+    }
     pub fn time_left(&self, color: Color) -> Duration {
         match color {
             Color::White => self.white_time_left,
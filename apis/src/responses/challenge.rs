@@ -2,7 +2,15 @@ use crate::{common::ChallengeAction, responses::user::UserResponse};
 use chrono::prelude::*;
 use hive_lib::{ColorChoice, GameType};
 use serde::{Deserialize, Serialize};
-use shared_types::{ChallengeDetails, ChallengeId, ChallengeVisibility, GameSpeed, TimeMode};
+use shared_types::{
+    ChallengeDetails,
+    ChallengeId,
+    ChallengeVisibility,
+    ClockKind,
+    GameSpeed,
+    TimeMode,
+    VariantClass,
+};
 use std::{str, str::FromStr};
 use uuid::Uuid;
 
@@ -21,11 +29,18 @@ pub struct ChallengeResponse {
     pub time_mode: TimeMode,         // Correspondence, Timed, Untimed
     pub time_base: Option<i32>,      // Secons
     pub time_increment: Option<i32>, // Seconds
+    pub clock_kind: ClockKind,
     pub speed: GameSpeed,
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
 }
 
+impl ChallengeResponse {
+    pub fn variant_class(&self) -> VariantClass {
+        GameType::from_str(&self.game_type).map_or(VariantClass::Expansions, VariantClass::from)
+    }
+}
+
 use cfg_if::cfg_if;
 cfg_if! { if #[cfg(feature = "ssr")] {
 use db_lib::{
@@ -95,7 +110,9 @@ impl ChallengeResponse {
     ) -> Result<Self> {
         let game_speed =
             GameSpeed::from_base_increment(challenge.time_base, challenge.time_increment);
-        let challenger_rating = challenger.rating_for_speed(&game_speed);
+        let variant_class = GameType::from_str(&challenge.game_type)
+            .map_or(VariantClass::Expansions, VariantClass::from);
+        let challenger_rating = challenger.rating_for_speed(&game_speed, variant_class);
         Ok(ChallengeResponse {
             id: challenge.id,
             challenge_id: ChallengeId(challenge.nanoid.clone()),
@@ -110,6 +127,7 @@ impl ChallengeResponse {
             time_mode: TimeMode::from_str(&challenge.time_mode)?,
             time_base: challenge.time_base,
             time_increment: challenge.time_increment,
+            clock_kind: ClockKind::from_str(&challenge.clock_kind)?,
             speed: game_speed,
             band_upper: challenge.band_upper,
             band_lower: challenge.band_lower,
@@ -138,6 +156,7 @@ fn is_compatible(
         && new_challenge_details.time_mode == existing_challenge.time_mode
         && new_challenge_details.time_base == existing_challenge.time_base
         && new_challenge_details.time_increment == existing_challenge.time_increment
+        && new_challenge_details.clock_kind == existing_challenge.clock_kind
         && match new_challenge_details.color_choice {
             ColorChoice::Random => existing_challenge.color_choice == ColorChoice::Random,
             ColorChoice::White => existing_challenge.color_choice == ColorChoice::Black,
@@ -168,6 +187,7 @@ fn has_same_details(
         && new_challenge_details.time_mode == existing_challenge.time_mode
         && new_challenge_details.time_base == existing_challenge.time_base
         && new_challenge_details.time_increment == existing_challenge.time_increment
+        && new_challenge_details.clock_kind == existing_challenge.clock_kind
         && new_challenge_details.color_choice == existing_challenge.color_choice
         && challenge_opponent == new_challenge_details.opponent.as_deref()
         && existing_challenge.challenger.username == challenger_name
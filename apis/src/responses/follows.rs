@@ -0,0 +1,67 @@
+use super::user::UserResponse;
+use serde::{Deserialize, Serialize};
+use shared_types::{GameId, GameSpeed};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct LiveGameSummary {
+    pub game_id: GameId,
+    pub opponent: String,
+    pub speed: GameSpeed,
+}
+
+/// A followed player together with the real-time games they're in.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct FollowedUserResponse {
+    pub user: UserResponse,
+    pub live_games: Vec<LiveGameSummary>,
+}
+
+cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
+use anyhow::Result;
+use db_lib::{helpers::followed_users, models::{Game, User}, DbConn};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+impl FollowedUserResponse {
+    pub async fn for_follower(follower_id: Uuid, conn: &mut DbConn<'_>) -> Result<Vec<Self>> {
+        let users = followed_users(conn, follower_id).await?;
+        let ids = users.iter().map(|user| user.id).collect::<Vec<_>>();
+        let mut responses = UserResponse::from_uuids(&ids, conn).await?;
+        let games = Game::find_live_for_users(&ids, conn).await?;
+        let opponent_ids = games
+            .iter()
+            .flat_map(|game| [game.white_id, game.black_id])
+            .collect::<Vec<_>>();
+        let names = User::find_by_uuids(&opponent_ids, conn)
+            .await?
+            .into_iter()
+            .map(|user| (user.id, user.username))
+            .collect::<HashMap<_, _>>();
+        let mut live: HashMap<Uuid, Vec<LiveGameSummary>> = HashMap::new();
+        for game in &games {
+            for (player, opponent) in [
+                (game.white_id, game.black_id),
+                (game.black_id, game.white_id),
+            ] {
+                if !ids.contains(&player) {
+                    continue;
+                }
+                live.entry(player).or_default().push(LiveGameSummary {
+                    game_id: GameId(game.nanoid.clone()),
+                    opponent: names.get(&opponent).cloned().unwrap_or_default(),
+                    speed: GameSpeed::from_base_increment(game.time_base, game.time_increment),
+                });
+            }
+        }
+        Ok(users
+            .iter()
+            .filter_map(|user| {
+                Some(Self {
+                    user: responses.remove(&user.id)?,
+                    live_games: live.remove(&user.id).unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+}
+}}
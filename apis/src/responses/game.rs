@@ -1,18 +1,31 @@
 use crate::responses::user::UserResponse;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use hive_lib::{Bug, GameControl, GameResult, GameStatus, GameType, History, Position, State};
+use hive_lib::{
+    Bug,
+    Color,
+    GameControl,
+    GameResult,
+    GameStatus,
+    GameType,
+    History,
+    Position,
+    State,
+};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "ssr")]
 use shared_types::GamesQueryOptions;
 use shared_types::{
     BatchToken,
+    ClockKind,
     Conclusion,
     GameId,
     GameSpeed,
     GameStart,
+    MoveAnnotation,
     TimeMode,
     TournamentGameResult,
+    VariantClass,
 };
 use std::{cmp::Ordering, collections::HashMap, time::Duration};
 use uuid::Uuid;
@@ -61,6 +74,7 @@ pub struct GameResponse {
     pub time_mode: TimeMode,
     pub time_base: Option<i32>,
     pub time_increment: Option<i32>,
+    pub clock_kind: ClockKind,
     pub speed: GameSpeed,
     pub black_time_left: Option<Duration>,
     pub white_time_left: Option<Duration>,
@@ -117,11 +131,61 @@ impl GameResponse {
     }
 
     pub fn white_rating(&self) -> u64 {
-        self.white_player.rating_for_speed(&self.speed)
+        self.white_player
+            .rating_for_speed(&self.speed, VariantClass::from(self.game_type))
     }
 
     pub fn black_rating(&self) -> u64 {
-        self.black_player.rating_for_speed(&self.speed)
+        self.black_player
+            .rating_for_speed(&self.speed, VariantClass::from(self.game_type))
+    }
+
+    /// The game as a PGN-style text record, `extra_headers` go after the standard ones.
+    pub fn pgn(&self, extra_headers: &[(&str, String)]) -> String {
+        self.annotated_pgn(extra_headers, &[])
+    }
+
+    /// Like `pgn`, with each annotated move followed by its NAG and comment.
+    pub fn annotated_pgn(
+        &self,
+        extra_headers: &[(&str, String)],
+        annotations: &[MoveAnnotation],
+    ) -> String {
+        let date = self.created_at.format("%d-%b-%Y_%H:%M:%S").to_string();
+        let game_result = match &self.game_status {
+            GameStatus::Finished(result) => match result {
+                GameResult::Draw => "Draw".to_owned(),
+                GameResult::Unknown => "Unkown".to_owned(),
+                GameResult::Winner(Color::White) => "WhiteWins".to_owned(),
+                GameResult::Winner(Color::Black) => "BlackWins".to_owned(),
+            },
+            _ => self.game_status.to_string(),
+        };
+        let mut pgn = [
+            ("GameType", self.game_type.to_string()),
+            ("Date", date),
+            ("Site", String::from("hivegame.com")),
+            ("White", self.white_player.username.clone()),
+            ("Black", self.black_player.username.clone()),
+            ("Result", game_result.clone()),
+        ]
+        .iter()
+        .chain(extra_headers)
+        .map(|(name, value)| pgn_tag(name, value))
+        .collect::<String>();
+        pgn.push('\n');
+        for (i, (mv, dest)) in self.history.iter().enumerate() {
+            let suffix = annotations
+                .iter()
+                .find(|annotation| annotation.ply as usize == i)
+                .map(MoveAnnotation::pgn_suffix)
+                .unwrap_or_default();
+            pgn.push_str(&format!("{}. {} {}{suffix}\n", i + 1, mv, dest));
+        }
+        if self.finished {
+            pgn.push_str(&format!("\n{game_result}\n"));
+        }
+        pgn
     }
 
     pub fn create_state(&self) -> State {
@@ -203,6 +267,12 @@ impl GameResponse {
     }
 }
 
+/// A PGN tag pair, with quotes and backslashes in the value escaped as the PGN spec requires.
+fn pgn_tag(name: &str, value: &str) -> String {
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("[{name} \"{value}\"]\n")
+}
+
 use cfg_if::cfg_if;
 
 use super::tournament::TournamentAbstractResponse;
@@ -212,7 +282,7 @@ use db_lib::{
     DbConn,
 };
 use hive_lib::{
-    Color, GameStatus::Finished, Piece,
+    GameStatus::Finished, Piece,
 };
 use std::{str::FromStr, collections::HashSet};
 
@@ -325,9 +395,11 @@ impl GameResponse {
                     game.black_rating_change,
                 )
             } else {
+                let speed = GameSpeed::from_str(&game.speed)?;
+                let variant_class = game.variant_class()?;
                 (
-                    Some(white_player.rating_for_speed(&GameSpeed::from_str(&game.speed)?) as f64),
-                    Some(black_player.rating_for_speed(&GameSpeed::from_str(&game.speed)?) as f64),
+                    Some(white_player.rating_for_speed(&speed, variant_class) as f64),
+                    Some(black_player.rating_for_speed(&speed, variant_class) as f64),
                     None,
                     None,
                 )
@@ -371,6 +443,7 @@ impl GameResponse {
             time_mode: TimeMode::from_str(&game.time_mode)?,
             time_base: game.time_base,
             time_increment: game.time_increment,
+            clock_kind: game.clock(),
             last_interaction: game.last_interaction,
             speed: GameSpeed::from_str(&game.speed)?,
             created_at: game.created_at,
@@ -412,3 +485,17 @@ impl GameResponse {
     }
 }
 }}
+
+#[cfg(test)]
+mod tests {
+    use super::pgn_tag;
+
+    #[test]
+    fn pgn_tags_escape_quotes_and_backslashes() {
+        assert_eq!(pgn_tag("Event", "Spring Cup"), "[Event \"Spring Cup\"]\n");
+        assert_eq!(
+            pgn_tag("Event", r#"The "Hive" \ Cup"#),
+            "[Event \"The \\\"Hive\\\" \\\\ Cup\"]\n"
+        );
+    }
+}
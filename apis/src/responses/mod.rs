@@ -1,5 +1,6 @@
 mod account;
 mod challenge;
+mod follows;
 mod game;
 mod heartbeat;
 mod home_banner;
@@ -7,14 +8,17 @@ mod invitation;
 mod notification_preferences;
 mod opening_explorer;
 mod push_device;
+mod puzzle;
 mod rating;
 mod rating_history;
 mod schedules;
+mod study;
 mod tournament;
 mod tournament_series;
 mod user;
 pub use account::AccountResponse;
 pub use challenge::{create_challenge_handler, ChallengeResponse};
+pub use follows::{FollowedUserResponse, LiveGameSummary};
 pub use game::{GameBatchResponse, GameResponse};
 pub use heartbeat::HeartbeatResponse;
 pub use home_banner::HomeBanner;
@@ -22,8 +26,11 @@ pub use invitation::InvitationResponse;
 pub use notification_preferences::NotificationPreferencesResponse;
 pub use opening_explorer::ExplorerResponse;
 pub use push_device::PushDeviceResponse;
+pub use puzzle::{PuzzleAttemptResponse, PuzzleOutcome, PuzzleResponse};
 pub use rating::RatingResponse;
 pub use rating_history::RatingHistoryResponse;
 pub use schedules::ScheduleResponse;
+pub use study::{StudyChapterResponse, StudyResponse, StudySummary};
 pub use tournament::{TournamentAbstractResponse, TournamentResponse};
+pub use tournament_series::{TournamentSeriesAbstractResponse, TournamentSeriesResponse};
 pub use user::UserResponse;
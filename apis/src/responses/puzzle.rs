@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use hive_lib::{Color, GameType};
+use serde::{Deserialize, Serialize};
+
+/// A puzzle as the solver sees it; the solution stays on the server.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PuzzleResponse {
+    pub nanoid: String,
+    pub game_type: GameType,
+    pub history: String,
+    pub themes: Vec<String>,
+    pub rating: f64,
+    pub attempts: i64,
+    pub solves: i64,
+    pub solver: Color,
+    /// Number of moves the solver has to find.
+    pub moves: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum PuzzleOutcome {
+    Continue {
+        reply: (String, String),
+    },
+    Solved {
+        rating_change: Option<f64>,
+    },
+    Failed {
+        rating_change: Option<f64>,
+        solution: Vec<(String, String)>,
+    },
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PuzzleAttemptResponse {
+    pub nanoid: String,
+    pub rating: f64,
+    pub themes: Vec<String>,
+    pub solved: bool,
+    pub rating_change: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
+use anyhow::Result;
+use db_lib::models::{Puzzle, PuzzleAttempt};
+use std::str::FromStr;
+
+impl PuzzleResponse {
+    pub fn from_model(puzzle: &Puzzle) -> Result<Self> {
+        Ok(Self {
+            nanoid: puzzle.nanoid.clone(),
+            game_type: GameType::from_str(&puzzle.game_type)?,
+            history: puzzle.history.clone(),
+            themes: puzzle.themes(),
+            rating: puzzle.rating,
+            attempts: puzzle.attempts,
+            solves: puzzle.solves,
+            solver: puzzle.solver_color()?,
+            moves: puzzle.solution_moves().len().div_ceil(2),
+        })
+    }
+}
+
+impl PuzzleAttemptResponse {
+    pub fn from_model(attempt: &PuzzleAttempt, puzzle: &Puzzle) -> Self {
+        Self {
+            nanoid: puzzle.nanoid.clone(),
+            rating: puzzle.rating,
+            themes: puzzle.themes(),
+            solved: attempt.solved,
+            rating_change: attempt.rating_change,
+            created_at: attempt.created_at,
+        }
+    }
+}
+}}
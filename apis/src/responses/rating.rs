@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
-use shared_types::{Certainty, GameSpeed};
+use shared_types::{Certainty, GameSpeed, VariantClass};
 use uuid::Uuid;
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct RatingResponse {
     pub speed: GameSpeed,
+    pub variant: VariantClass,
     pub rating: u64,
     pub played: i64,
     pub win: i64,
@@ -22,25 +23,42 @@ use db_lib::{
 use std::str::FromStr;
 use anyhow::Result;
 impl RatingResponse {
-    pub async fn from_uuid(id: &Uuid, game_speed: &GameSpeed, conn: &mut DbConn<'_>) -> Result<Self> {
-        let rating = Rating::for_uuid(id, game_speed, conn).await?;
+    pub async fn from_uuid(
+        id: &Uuid,
+        game_speed: &GameSpeed,
+        variant: VariantClass,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self> {
+        let rating = Rating::for_uuid(id, game_speed, variant, conn).await?;
         Ok(Self::from_rating(&rating))
     }
 
-    pub async fn from_user(user: &User, game_speed: &GameSpeed, conn: &mut DbConn<'_>) -> Result<Self> {
-        let rating = Rating::for_uuid(&user.id, game_speed, conn).await?;
+    pub async fn from_user(
+        user: &User,
+        game_speed: &GameSpeed,
+        variant: VariantClass,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self> {
+        let rating = Rating::for_uuid(&user.id, game_speed, variant, conn).await?;
         Ok(Self::from_rating(&rating))
     }
 
-    pub async fn from_username(username: &str, game_speed: &GameSpeed, conn: &mut DbConn<'_>) -> Result<Self> {
+    pub async fn from_username(
+        username: &str,
+        game_speed: &GameSpeed,
+        variant: VariantClass,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self> {
         let user = User::find_by_username(username, conn).await?;
-        let rating = Rating::for_uuid(&user.id, game_speed, conn).await?;
+        let rating = Rating::for_uuid(&user.id, game_speed, variant, conn).await?;
         Ok(Self::from_rating(&rating))
     }
 
     pub fn from_rating(rating: &Rating) -> Self {
         Self {
             speed: GameSpeed::from_str(&rating.speed).expect("Rating to have a valid GameSpeed"),
+            variant: VariantClass::from_str(&rating.variant)
+                .expect("Rating to have a valid VariantClass"),
             rating: rating.rating.floor() as u64,
             played: rating.played,
             win: rating.won,
@@ -13,16 +13,17 @@ use db_lib::{
     models::{Game},
     DbConn,
 };
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 use uuid::Uuid;
 use anyhow::Result;
 impl RatingHistoryResponse {
     pub async fn get_rating_history_from_uuid_and_speed(
         id: &Uuid,
         game_speed: &GameSpeed,
+        variant: VariantClass,
         conn: &mut DbConn<'_>
     ) -> Result<Vec<Self>> {
-        let games = Game::get_rating_history_for_player(*id, game_speed, conn).await?;
+        let games = Game::get_rating_history_for_player(*id, game_speed, variant, conn).await?;
         Ok(
             games
                 .into_iter()
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use hive_lib::GameType;
+use serde::{Deserialize, Serialize};
+use shared_types::StudyVisibility;
+use uuid::Uuid;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct StudyResponse {
+    pub nanoid: String,
+    pub title: String,
+    pub description: String,
+    pub visibility: StudyVisibility,
+    pub owner_id: Uuid,
+    pub owner: String,
+    /// Nanoid and title of the study this one was forked from.
+    pub forked_from: Option<(String, String)>,
+    pub chapters: Vec<StudyChapterResponse>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct StudyChapterResponse {
+    pub position: i32,
+    pub title: String,
+    pub game_type: GameType,
+    /// A serialized `AnalysisTree`.
+    pub tree: String,
+}
+
+/// A study in a list, without its chapters.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct StudySummary {
+    pub nanoid: String,
+    pub title: String,
+    pub visibility: StudyVisibility,
+    pub updated_at: DateTime<Utc>,
+}
+
+cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
+use db_lib::models::{Study, StudyChapter};
+
+impl StudyResponse {
+    pub fn from_model(
+        study: &Study,
+        owner: String,
+        source: Option<&Study>,
+        chapters: Vec<StudyChapter>,
+    ) -> Self {
+        Self {
+            nanoid: study.nanoid.clone(),
+            title: study.title.clone(),
+            description: study.description.clone(),
+            visibility: study.visibility(),
+            owner_id: study.owner_id,
+            owner,
+            forked_from: source.map(|source| (source.nanoid.clone(), source.title.clone())),
+            chapters: chapters
+                .into_iter()
+                .map(StudyChapterResponse::from_model)
+                .collect(),
+            updated_at: study.updated_at,
+        }
+    }
+}
+
+impl StudyChapterResponse {
+    pub fn from_model(chapter: StudyChapter) -> Self {
+        Self {
+            position: chapter.position,
+            title: chapter.title,
+            game_type: chapter.game_type.parse().unwrap_or_default(),
+            tree: chapter.tree,
+        }
+    }
+}
+
+impl StudySummary {
+    pub fn from_model(study: &Study) -> Self {
+        Self {
+            nanoid: study.nanoid.clone(),
+            title: study.title.clone(),
+            visibility: study.visibility(),
+            updated_at: study.updated_at,
+        }
+    }
+}
+}}
@@ -1,13 +1,15 @@
-use super::{GameResponse, UserResponse};
+use super::{GameResponse, TournamentSeriesAbstractResponse, UserResponse};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use shared_types::{
+    ClockKind,
     ScoringMode,
     Standings,
     StartMode,
     Tiebreaker,
     TimeMode,
     TournamentId,
+    TournamentRequirements,
     TournamentStatus,
 };
 use std::collections::{HashMap, HashSet};
@@ -28,6 +30,7 @@ pub struct TournamentAbstractResponse {
     pub time_mode: TimeMode,
     pub time_base: Option<i32>,
     pub time_increment: Option<i32>,
+    pub clock_kind: ClockKind,
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
     pub status: TournamentStatus,
@@ -49,6 +52,7 @@ pub struct TournamentResponse {
     pub tiebreakers: Vec<Tiebreaker>,
     pub invitees: Vec<UserResponse>,
     pub players: HashMap<Uuid, UserResponse>,
+    pub waitlist: Vec<UserResponse>,
     pub organizers: Vec<UserResponse>,
     pub games: Vec<GameResponse>,
     pub seats: i32,
@@ -59,22 +63,32 @@ pub struct TournamentResponse {
     pub time_mode: TimeMode,
     pub time_base: Option<i32>,
     pub time_increment: Option<i32>,
+    pub clock_kind: ClockKind,
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
+    pub requirements: TournamentRequirements,
     pub status: TournamentStatus,
     pub start_mode: StartMode,
     pub starts_at: Option<DateTime<Utc>>,
     pub ends_at: Option<DateTime<Utc>>,
     pub started_at: Option<DateTime<Utc>>,
     pub round_duration: Option<i32>,
+    pub series: Option<TournamentSeriesAbstractResponse>,
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Players who left mid-tournament, they stay in the standings.
+    pub withdrawn: Vec<Uuid>,
+    /// Requested half-point byes as (player, round).
+    pub byes: Vec<(Uuid, i32)>,
+    /// Swiss rounds paired so far.
+    pub current_round: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
 use anyhow::Result;
-use db_lib::{models::Tournament, DbConn};
-use shared_types::TournamentGameResult;
+use db_lib::{models::{Tournament, TournamentSeries}, DbConn};
+use shared_types::{TournamentGameResult, TournamentMode};
 use std::str::FromStr;
 
 impl TournamentAbstractResponse {
@@ -112,6 +126,7 @@ impl TournamentAbstractResponse {
             time_mode: TimeMode::from_str(&tournament.time_mode)?,
             time_base: tournament.time_base,
             time_increment: tournament.time_increment,
+            clock_kind: ClockKind::from_str(&tournament.clock_kind)?,
             band_upper: tournament.band_upper,
             band_lower: tournament.band_lower,
             status: TournamentStatus::from_str(&tournament.status)?,
@@ -148,10 +163,25 @@ impl TournamentResponse {
         for user in tournament.players(conn).await? {
             players.insert(user.id, UserResponse::from_model(&user, conn).await?);
         }
+        let mut waitlist = Vec::new();
+        for user in tournament.waitlist(conn).await? {
+            waitlist.push(UserResponse::from_model(&user, conn).await?);
+        }
         let mut organizers = Vec::new();
         for user in tournament.organizers(conn).await? {
             organizers.push(UserResponse::from_model(&user, conn).await?);
         }
+        let withdrawn = tournament.withdrawn_players(conn).await?;
+        let byes = tournament
+            .byes(conn)
+            .await?
+            .into_iter()
+            .map(|bye| (bye.user_id, bye.round))
+            .collect();
+        let current_round = match TournamentMode::from_str(&tournament.mode)? {
+            TournamentMode::DoubleSwiss => tournament.current_round(conn).await?,
+            _ => 0,
+        };
         let games = tournament.games(conn).await?;
         let mut standings = Standings::new();
         for tiebreaker in tournament.tiebreaker.iter().flatten() {
@@ -168,6 +198,12 @@ impl TournamentResponse {
         }
         let game_responses = GameResponse::from_games_batch(games, conn).await?;
         standings.enforce_tiebreakers();
+        let series = match tournament.series {
+            Some(series_id) => Some(TournamentSeriesAbstractResponse::from_model(
+                &TournamentSeries::find(series_id, conn).await?,
+            )),
+            None => None,
+        };
         Ok(Box::new(Self {
             id: tournament.id,
             tournament_id: TournamentId(tournament.nanoid.clone()),
@@ -176,6 +212,7 @@ impl TournamentResponse {
             standings,
             scoring: ScoringMode::from_str(&tournament.scoring)?,
             players,
+            waitlist,
             organizers,
             games: game_responses,
             tiebreakers: tournament
@@ -191,14 +228,21 @@ impl TournamentResponse {
             time_mode: TimeMode::from_str(&tournament.time_mode)?,
             time_base: tournament.time_base,
             time_increment: tournament.time_increment,
+            clock_kind: ClockKind::from_str(&tournament.clock_kind)?,
             band_upper: tournament.band_upper,
             band_lower: tournament.band_lower,
+            requirements: tournament.requirements(),
             status: TournamentStatus::from_str(&tournament.status)?,
             start_mode: StartMode::from_str(&tournament.start_mode)?,
             starts_at: tournament.starts_at,
             ends_at: tournament.ends_at,
             started_at: tournament.started_at,
             round_duration: tournament.round_duration,
+            series,
+            paused_at: tournament.paused_at,
+            withdrawn,
+            byes,
+            current_round,
             created_at: tournament.created_at,
             updated_at: tournament.updated_at,
         }))
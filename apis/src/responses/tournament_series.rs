@@ -1 +1,103 @@
+use super::{TournamentAbstractResponse, UserResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared_types::{SeriesScoring, SeriesStanding, TournamentSeriesId};
+use std::collections::HashMap;
+use uuid::Uuid;
 
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TournamentSeriesAbstractResponse {
+    pub series_id: TournamentSeriesId,
+    pub name: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TournamentSeriesResponse {
+    pub id: Uuid,
+    pub series_id: TournamentSeriesId,
+    pub name: String,
+    pub description: String,
+    pub scoring: SeriesScoring,
+    pub organizers: Vec<UserResponse>,
+    pub tournaments: Vec<TournamentAbstractResponse>,
+    pub leaderboard: Vec<SeriesStanding>,
+    pub players: HashMap<Uuid, UserResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
+use anyhow::Result;
+use db_lib::{models::{TournamentSeries, User}, DbConn};
+use shared_types::{SeriesStandings, TournamentId, TournamentStatus};
+use std::str::FromStr;
+
+impl TournamentSeriesAbstractResponse {
+    pub fn from_model(series: &TournamentSeries) -> Self {
+        Self {
+            series_id: TournamentSeriesId(series.nanoid.clone()),
+            name: series.name.clone(),
+        }
+    }
+}
+
+impl TournamentSeriesResponse {
+    pub async fn from_series_id(
+        series_id: &TournamentSeriesId,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Box<Self>> {
+        let series = TournamentSeries::find_by_series_id(series_id, conn).await?;
+        Self::from_model(&series, conn).await
+    }
+
+    pub async fn from_model(series: &TournamentSeries, conn: &mut DbConn<'_>) -> Result<Box<Self>> {
+        let mut organizers = Vec::new();
+        for user in series.organizers(conn).await? {
+            organizers.push(UserResponse::from_model(&user, conn).await?);
+        }
+        let bye_player = User::bye_player_id(conn).await?;
+        let scoring = series.scoring();
+        let mut series_standings = SeriesStandings::new(scoring.clone());
+        let mut tournaments = Vec::new();
+        for tournament in series.tournaments(conn).await? {
+            // Only final placements score series points.
+            if TournamentStatus::from_str(&tournament.status)? == TournamentStatus::Finished {
+                let standings = tournament.standings(conn).await?;
+                let groups = standings
+                    .players_standings
+                    .iter()
+                    .map(|group| {
+                        group
+                            .iter()
+                            .filter(|player| Some(**player) != bye_player)
+                            .copied()
+                            .collect::<Vec<_>>()
+                    })
+                    .filter(|group| !group.is_empty())
+                    .collect::<Vec<_>>();
+                series_standings.add_tournament(TournamentId(tournament.nanoid.clone()), &groups);
+            }
+            tournaments.push(TournamentAbstractResponse::from_model(&tournament, conn).await?);
+        }
+        let leaderboard = series_standings.leaderboard();
+        let player_ids = leaderboard.iter().map(|row| row.player).collect::<Vec<_>>();
+        let mut players = HashMap::new();
+        for user in User::find_by_uuids(&player_ids, conn).await? {
+            players.insert(user.id, UserResponse::from_model(&user, conn).await?);
+        }
+        Ok(Box::new(Self {
+            id: series.id,
+            series_id: TournamentSeriesId(series.nanoid.clone()),
+            name: series.name.clone(),
+            description: series.description.clone(),
+            scoring,
+            organizers,
+            tournaments,
+            leaderboard,
+            players,
+            created_at: series.created_at,
+            updated_at: series.updated_at,
+        }))
+    }
+}
+}}
@@ -1,6 +1,6 @@
 use super::rating::RatingResponse;
 use serde::{Deserialize, Serialize};
-use shared_types::{GameSpeed, Takeback};
+use shared_types::{GameSpeed, Takeback, VariantClass};
 use std::collections::HashMap;
 use uuid::Uuid;
 
@@ -12,63 +12,26 @@ pub struct UserResponse {
     pub bot: bool,
     pub admin: bool,
     pub deleted: bool,
-    pub ratings: HashMap<GameSpeed, RatingResponse>,
+    pub ratings: HashMap<VariantClass, HashMap<GameSpeed, RatingResponse>>,
     pub takeback: Takeback,
     pub lang: Option<String>,
 }
 
 impl UserResponse {
-    pub fn rating_for_speed(&self, game_speed: &GameSpeed) -> u64 {
-        match game_speed {
-            GameSpeed::Blitz => self.blitz(),
-            GameSpeed::Correspondence | GameSpeed::Untimed => self.correspondence(),
-            GameSpeed::Bullet => self.bullet(),
-            GameSpeed::Rapid => self.rapid(),
-            GameSpeed::Classic => self.classic(),
-            GameSpeed::Puzzle => self.puzzle(),
-        }
-    }
-
-    pub fn bullet(&self) -> u64 {
-        if let Some(rating_response) = self.ratings.get(&GameSpeed::Bullet) {
-            return rating_response.rating;
-        }
-        0
-    }
-
-    pub fn puzzle(&self) -> u64 {
-        if let Some(rating_response) = self.ratings.get(&GameSpeed::Puzzle) {
-            return rating_response.rating;
-        }
-        0
-    }
-
-    pub fn blitz(&self) -> u64 {
-        if let Some(rating_response) = self.ratings.get(&GameSpeed::Blitz) {
-            return rating_response.rating;
-        }
-        0
-    }
-
-    pub fn correspondence(&self) -> u64 {
-        if let Some(rating_response) = self.ratings.get(&GameSpeed::Correspondence) {
-            return rating_response.rating;
-        }
-        0
-    }
-
-    pub fn classic(&self) -> u64 {
-        if let Some(rating_response) = self.ratings.get(&GameSpeed::Classic) {
-            return rating_response.rating;
-        }
-        0
+    /// The rating a game of this speed and variant counts towards.
+    pub fn rating(&self, game_speed: &GameSpeed, variant: VariantClass) -> Option<&RatingResponse> {
+        let game_speed = match game_speed {
+            GameSpeed::Untimed => GameSpeed::Correspondence,
+            speed => *speed,
+        };
+        self.ratings
+            .get(&variant)
+            .and_then(|ratings| ratings.get(&game_speed))
     }
 
-    pub fn rapid(&self) -> u64 {
-        if let Some(rating_response) = self.ratings.get(&GameSpeed::Rapid) {
-            return rating_response.rating;
-        }
-        0
+    pub fn rating_for_speed(&self, game_speed: &GameSpeed, variant: VariantClass) -> u64 {
+        self.rating(game_speed, variant)
+            .map_or(0, |rating_response| rating_response.rating)
     }
 }
 
@@ -92,12 +55,12 @@ impl UserResponse {
 
         let user_ids: Vec<Uuid> = users.iter().map(|user| user.id).collect();
         let rating_rows = Rating::for_uuids(&user_ids, conn).await?;
-        let mut ratings_by_user: HashMap<Uuid, HashMap<String, Rating>> = HashMap::new();
+        let mut ratings_by_user: HashMap<Uuid, HashMap<(String, String), Rating>> = HashMap::new();
         for rating in rating_rows {
             ratings_by_user
                 .entry(rating.user_uid)
                 .or_default()
-                .insert(rating.speed.clone(), rating);
+                .insert((rating.variant.clone(), rating.speed.clone()), rating);
         }
 
         let mut result = HashMap::new();
@@ -113,17 +76,24 @@ impl UserResponse {
 
     fn from_model_with_ratings(
         user: &User,
-        user_rating_rows: &HashMap<String, Rating>,
+        user_rating_rows: &HashMap<(String, String), Rating>,
     ) -> Result<Self> {
-        let mut ratings = HashMap::new();
-        for game_speed in GameSpeed::all_rated().into_iter() {
-            let rating = user_rating_rows
-                .get(&game_speed.to_string())
-                .ok_or_else(|| {
-                    anyhow::anyhow!("{} rating not found for user {}", game_speed, user.id)
-                })?;
-            let rating = RatingResponse::from_rating(rating);
-            ratings.insert(game_speed, rating);
+        let mut ratings: HashMap<VariantClass, HashMap<GameSpeed, RatingResponse>> = HashMap::new();
+        for variant in VariantClass::all() {
+            for game_speed in GameSpeed::all_rated().into_iter() {
+                let rating = user_rating_rows
+                    .get(&(variant.to_string(), game_speed.to_string()))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{} {} rating not found for user {}",
+                            variant,
+                            game_speed,
+                            user.id
+                        )
+                    })?;
+                let rating = RatingResponse::from_rating(rating);
+                ratings.entry(variant).or_default().insert(game_speed, rating);
+            }
         }
         Ok(UserResponse {
             username: user.username.clone(),
@@ -144,23 +114,12 @@ impl UserResponse {
     }
 
     pub async fn from_model(user: &User, conn: &mut DbConn<'_>) -> Result<Self> {
-        let mut ratings = HashMap::new();
-        for game_speed in GameSpeed::all_rated().into_iter() {
-            let rating = RatingResponse::from_user(user, &game_speed, conn).await?;
-            ratings.insert(game_speed, rating);
-        }
-        let response = UserResponse {
-            username: user.username.clone(),
-            uid: user.id,
-            patreon: user.patreon,
-            bot: user.bot,
-            admin: user.admin,
-            deleted: user.deleted,
-            takeback: Takeback::from_str_or_default(&user.takeback),
-            ratings,
-            lang: user.lang.clone(),
-        };
-        Ok(response)
+        let user_rating_rows = Rating::for_uuids(&[user.id], conn)
+            .await?
+            .into_iter()
+            .map(|rating| ((rating.variant.clone(), rating.speed.clone()), rating))
+            .collect();
+        Self::from_model_with_ratings(user, &user_rating_rows)
     }
     pub async fn search_usernames(pattern: &str, conn: &mut DbConn<'_>) -> Result<Vec<Self>> {
         let users = User::search_usernames(pattern, conn).await?;
@@ -0,0 +1,259 @@
+//! A minimal blocking client for engines speaking the Universal Hive Protocol, the same
+//! protocol hive-hydra drives its bots with. Used by the `game_analysis` job, which runs it
+//! on a blocking thread; the engine is a local binary, nothing goes over the network.
+
+use hive_lib::GameType;
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_BESTMOVE_ARGS: &str = "time 00:00:01";
+/// Longest the engine may take to finish any one response before it is killed.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug)]
+pub struct UhpConfig {
+    /// Program and arguments, split on whitespace, e.g. `nokamute uhp`.
+    pub command: String,
+    /// Everything after `bestmove`, e.g. `depth 4` or `time 00:00:01`.
+    pub bestmove_args: String,
+}
+
+impl UhpConfig {
+    /// `None` when `ANALYSIS_ENGINE` is unset, which turns engine analysis off.
+    pub fn from_env() -> Option<UhpConfig> {
+        let command = std::env::var("ANALYSIS_ENGINE")
+            .ok()
+            .filter(|command| !command.trim().is_empty())?;
+        let bestmove_args = std::env::var("ANALYSIS_BESTMOVE")
+            .ok()
+            .filter(|args| !args.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_BESTMOVE_ARGS.to_string());
+        Some(UhpConfig {
+            command,
+            bestmove_args,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum UhpError {
+    Io(io::Error),
+    /// The engine closed its output before answering.
+    Closed,
+    /// The engine didn't finish answering within `RESPONSE_TIMEOUT`.
+    Timeout,
+    /// An `err` or `invalidmove` response, with the engine's message.
+    Engine(String),
+}
+
+impl fmt::Display for UhpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UhpError::Io(e) => write!(f, "engine process error: {e}"),
+            UhpError::Closed => write!(f, "engine closed its output"),
+            UhpError::Timeout => write!(f, "engine did not answer in time"),
+            UhpError::Engine(msg) => write!(f, "engine error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for UhpError {}
+
+impl From<io::Error> for UhpError {
+    fn from(error: io::Error) -> Self {
+        UhpError::Io(error)
+    }
+}
+
+pub struct UhpEngine {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines of the engine's output, read on their own thread so a hung engine can't
+    /// block the caller past `RESPONSE_TIMEOUT`.
+    lines: Receiver<String>,
+    /// The `id` line of the greeting, e.g. `id nokamute 1.0`.
+    pub id: String,
+    bestmove_args: String,
+}
+
+impl UhpEngine {
+    pub fn spawn(config: &UhpConfig) -> Result<UhpEngine, UhpError> {
+        let mut parts = config.command.split_whitespace();
+        let program = parts.next().ok_or_else(|| {
+            UhpError::Io(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "empty engine command",
+            ))
+        })?;
+        let mut child = Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return Err(UhpError::Closed);
+        };
+        let (tx, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut engine = UhpEngine {
+            child,
+            stdin,
+            lines,
+            id: String::new(),
+            bestmove_args: config.bestmove_args.clone(),
+        };
+        let greeting = engine.read_response()?;
+        engine.id = greeting
+            .into_iter()
+            .find(|line| line.starts_with("id "))
+            .unwrap_or_default();
+        Ok(engine)
+    }
+
+    pub fn new_game(&mut self, game_type: GameType) -> Result<(), UhpError> {
+        self.command(&format!("newgame {game_type}")).map(|_| ())
+    }
+
+    /// Plays a move in UHP notation, e.g. `wA1 \bQ` or `pass`.
+    pub fn play(&mut self, mv: &str) -> Result<(), UhpError> {
+        self.command(&format!("play {mv}")).map(|_| ())
+    }
+
+    pub fn best_move(&mut self) -> Result<String, UhpError> {
+        let lines = self.command(&format!("bestmove {}", self.bestmove_args))?;
+        lines
+            .into_iter()
+            .next()
+            .ok_or_else(|| UhpError::Engine(String::from("no bestmove")))
+    }
+
+    /// Takes back the last `count` moves.
+    pub fn undo(&mut self, count: usize) -> Result<(), UhpError> {
+        self.command(&format!("undo {count}")).map(|_| ())
+    }
+
+    fn command(&mut self, command: &str) -> Result<Vec<String>, UhpError> {
+        writeln!(self.stdin, "{command}")?;
+        self.stdin.flush()?;
+        self.read_response()
+    }
+
+    /// An engine that stops answering is killed; it can't be trusted to line up with the
+    /// next command anyway.
+    fn read_response(&mut self) -> Result<Vec<String>, UhpError> {
+        let response = read_response(&self.lines, RESPONSE_TIMEOUT);
+        if matches!(response, Err(UhpError::Timeout)) {
+            let _ = self.child.kill();
+        }
+        response
+    }
+}
+
+impl Drop for UhpEngine {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Reads one response: the lines up to the closing `ok`, without it. The whole response
+/// has to arrive within `timeout`.
+fn read_response(output: &Receiver<String>, timeout: Duration) -> Result<Vec<String>, UhpError> {
+    let deadline = Instant::now() + timeout;
+    let mut lines = Vec::new();
+    loop {
+        let line = match output.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(line) => line,
+            Err(RecvTimeoutError::Timeout) => return Err(UhpError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => return Err(UhpError::Closed),
+        };
+        let line = line.trim();
+        if line == "ok" {
+            return Ok(lines);
+        }
+        if line.starts_with("err") || line.starts_with("invalidmove") {
+            let message = line.to_string();
+            // The response still ends with `ok`; drain it so the next command lines up.
+            let _ = read_response(output, deadline.saturating_duration_since(Instant::now()));
+            return Err(UhpError::Engine(message));
+        }
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::Sender;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn output(lines: &[&str]) -> (Sender<String>, Receiver<String>) {
+        let (tx, rx) = mpsc::channel();
+        for line in lines {
+            tx.send(line.to_string()).unwrap();
+        }
+        (tx, rx)
+    }
+
+    #[test]
+    fn reads_responses_up_to_ok() {
+        let (tx, output) = output(&["id nokamute 1.0", "ok", "wS1", "ok"]);
+        drop(tx);
+        assert_eq!(
+            read_response(&output, TIMEOUT).unwrap(),
+            vec!["id nokamute 1.0"]
+        );
+        assert_eq!(read_response(&output, TIMEOUT).unwrap(), vec!["wS1"]);
+        assert!(matches!(
+            read_response(&output, TIMEOUT),
+            Err(UhpError::Closed)
+        ));
+    }
+
+    #[test]
+    fn engine_errors_consume_their_ok() {
+        let (_tx, output) = output(&[
+            "invalidmove bad piece",
+            "ok",
+            "Base;NotStarted;White[1]",
+            "ok",
+        ]);
+        assert!(matches!(
+            read_response(&output, TIMEOUT),
+            Err(UhpError::Engine(msg)) if msg == "invalidmove bad piece"
+        ));
+        assert_eq!(
+            read_response(&output, TIMEOUT).unwrap(),
+            vec!["Base;NotStarted;White[1]"]
+        );
+    }
+
+    #[test]
+    fn silent_engines_time_out() {
+        let (_tx, output) = output(&["wS1"]);
+        assert!(matches!(
+            read_response(&output, TIMEOUT),
+            Err(UhpError::Timeout)
+        ));
+    }
+}
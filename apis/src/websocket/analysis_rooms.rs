@@ -0,0 +1,380 @@
+use crate::{
+    common::{AnalysisSync, AnalysisUpdate, LiveAnalysisSnapshot, ServerMessage, ServerResult},
+    websocket::{telemetry::DestKind, WsHub},
+};
+use bytes::Bytes;
+use codee::{binary::MsgpackSerdeCodec, Encoder};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Same bound studies put on a saved chapter.
+pub const MAX_LIVE_TREE_BYTES: usize = 512 * 1024;
+const MAX_PARTICIPANTS: usize = 200;
+
+/// A `(user_id, socket_id)` pair, as in the hub's membership indexes.
+type Participant = (Uuid, Uuid);
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum AnalysisRoomError {
+    #[error("Analysis session not found")]
+    NotFound,
+    #[error("Join the analysis session first")]
+    NotJoined,
+    #[error("Only the owner can change this analysis")]
+    NotAllowed,
+    #[error("Analysis is too large to share")]
+    TooLarge,
+    #[error("Analysis could not be read")]
+    Unreadable,
+    #[error("Analysis session is full")]
+    Full,
+}
+
+struct AnalysisRoom {
+    owner: Participant,
+    owner_name: String,
+    open_moves: bool,
+    tree: String,
+    node: Option<i32>,
+    participants: HashSet<Participant>,
+}
+
+impl AnalysisRoom {
+    fn snapshot(&self, session_id: &str) -> LiveAnalysisSnapshot {
+        LiveAnalysisSnapshot {
+            session_id: session_id.to_string(),
+            owner_id: self.owner.0,
+            owner: self.owner_name.clone(),
+            open_moves: self.open_moves,
+            tree: self.tree.clone(),
+            node: self.node,
+            participants: self.participants.len(),
+        }
+    }
+
+    fn others(&self, participant: Participant) -> Vec<Participant> {
+        self.participants
+            .iter()
+            .copied()
+            .filter(|other| *other != participant)
+            .collect()
+    }
+}
+
+/// What leaving did to a session, and who should hear about it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Departure {
+    /// The owner left, so the session ended for everyone listed.
+    Closed(Vec<Participant>),
+    /// Someone else left; the rest remain.
+    Left(Vec<Participant>),
+}
+
+/// Live analysis sessions. They live in memory only and end when their owner's socket
+/// leaves.
+#[derive(Default)]
+pub struct AnalysisRooms {
+    rooms: Mutex<HashMap<String, AnalysisRoom>>,
+}
+
+impl AnalysisRooms {
+    /// Opens a session owned by `owner`, ending any other one that socket hosted.
+    pub fn host(
+        &self,
+        session_id: String,
+        owner: Participant,
+        owner_name: &str,
+        tree: String,
+    ) -> Result<(LiveAnalysisSnapshot, Vec<(String, Vec<Participant>)>), AnalysisRoomError> {
+        if tree.len() > MAX_LIVE_TREE_BYTES {
+            return Err(AnalysisRoomError::TooLarge);
+        }
+        let mut rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        let replaced: Vec<_> = rooms
+            .iter()
+            .filter(|(_, room)| room.owner == owner)
+            .map(|(id, _)| id.clone())
+            .collect();
+        let closed = replaced
+            .into_iter()
+            .filter_map(|id| {
+                let room = rooms.remove(&id)?;
+                Some((id, room.others(owner)))
+            })
+            .collect();
+        let room = AnalysisRoom {
+            owner,
+            owner_name: owner_name.to_string(),
+            open_moves: false,
+            tree,
+            node: None,
+            participants: HashSet::from([owner]),
+        };
+        let snapshot = room.snapshot(&session_id);
+        rooms.insert(session_id, room);
+        Ok((snapshot, closed))
+    }
+
+    /// Adds a participant. Returns the session as it stands and everyone else in it.
+    pub fn join(
+        &self,
+        session_id: &str,
+        participant: Participant,
+    ) -> Result<(LiveAnalysisSnapshot, Vec<Participant>), AnalysisRoomError> {
+        let mut rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        let room = rooms
+            .get_mut(session_id)
+            .ok_or(AnalysisRoomError::NotFound)?;
+        if !room.participants.contains(&participant) && room.participants.len() >= MAX_PARTICIPANTS
+        {
+            return Err(AnalysisRoomError::Full);
+        }
+        room.participants.insert(participant);
+        Ok((room.snapshot(session_id), room.others(participant)))
+    }
+
+    pub fn leave(&self, session_id: &str, participant: Participant) -> Option<Departure> {
+        let mut rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        Self::depart(&mut rooms, session_id, participant)
+    }
+
+    /// Takes a closed socket out of every session it was in.
+    pub fn leave_socket(&self, participant: Participant) -> Vec<(String, Departure)> {
+        let mut rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        let joined: Vec<_> = rooms
+            .iter()
+            .filter(|(_, room)| room.participants.contains(&participant))
+            .map(|(id, _)| id.clone())
+            .collect();
+        joined
+            .into_iter()
+            .filter_map(|id| {
+                let departure = Self::depart(&mut rooms, &id, participant)?;
+                Some((id, departure))
+            })
+            .collect()
+    }
+
+    /// Records a change from `participant` and returns who else should get it.
+    pub fn sync(
+        &self,
+        session_id: &str,
+        participant: Participant,
+        sync: &AnalysisSync,
+    ) -> Result<Vec<Participant>, AnalysisRoomError> {
+        let mut rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        let room = rooms
+            .get_mut(session_id)
+            .ok_or(AnalysisRoomError::NotFound)?;
+        if !room.participants.contains(&participant) {
+            return Err(AnalysisRoomError::NotJoined);
+        }
+        if room.owner.0 != participant.0 && !room.open_moves {
+            return Err(AnalysisRoomError::NotAllowed);
+        }
+        match sync {
+            AnalysisSync::Tree(tree) => {
+                if tree.len() > MAX_LIVE_TREE_BYTES {
+                    return Err(AnalysisRoomError::TooLarge);
+                }
+                room.tree.clone_from(tree);
+                room.node = None;
+            }
+            AnalysisSync::Node(node) => room.node = Some(*node),
+        }
+        Ok(room.others(participant))
+    }
+
+    /// Returns everyone in the session, the owner included.
+    pub fn set_open_moves(
+        &self,
+        session_id: &str,
+        user_id: Uuid,
+        open: bool,
+    ) -> Result<Vec<Participant>, AnalysisRoomError> {
+        let mut rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        let room = rooms
+            .get_mut(session_id)
+            .ok_or(AnalysisRoomError::NotFound)?;
+        if room.owner.0 != user_id {
+            return Err(AnalysisRoomError::NotAllowed);
+        }
+        room.open_moves = open;
+        Ok(room.participants.iter().copied().collect())
+    }
+
+    /// Everyone a chat line from `participant` goes to, the sender included.
+    pub fn chat_recipients(
+        &self,
+        session_id: &str,
+        participant: Participant,
+    ) -> Result<Vec<Participant>, AnalysisRoomError> {
+        let rooms = self.rooms.lock().unwrap_or_else(|p| p.into_inner());
+        let room = rooms.get(session_id).ok_or(AnalysisRoomError::NotFound)?;
+        if !room.participants.contains(&participant) {
+            return Err(AnalysisRoomError::NotJoined);
+        }
+        Ok(room.participants.iter().copied().collect())
+    }
+
+    fn depart(
+        rooms: &mut HashMap<String, AnalysisRoom>,
+        session_id: &str,
+        participant: Participant,
+    ) -> Option<Departure> {
+        let room = rooms.get_mut(session_id)?;
+        if room.owner == participant {
+            let room = rooms.remove(session_id)?;
+            return Some(Departure::Closed(room.others(participant)));
+        }
+        room.participants
+            .remove(&participant)
+            .then(|| Departure::Left(room.participants.iter().copied().collect()))
+    }
+}
+
+impl WsHub {
+    pub(in crate::websocket) fn send_analysis_update(
+        &self,
+        participants: &[Participant],
+        update: AnalysisUpdate,
+    ) {
+        let result = ServerResult::Ok(Box::new(ServerMessage::Analysis(update)));
+        if let Ok(serialized) = MsgpackSerdeCodec::encode(&result) {
+            let bytes = Bytes::from(serialized);
+            for (user_id, socket_id) in participants {
+                self.send_to_socket(user_id, socket_id, DestKind::Game, &bytes);
+            }
+        }
+    }
+
+    /// Tells the rest of a session that someone left, or that it ended.
+    pub(in crate::websocket) fn announce_departure(
+        &self,
+        session_id: String,
+        departure: Departure,
+    ) {
+        match departure {
+            Departure::Closed(participants) => {
+                self.send_analysis_update(&participants, AnalysisUpdate::Closed(session_id));
+            }
+            Departure::Left(participants) => {
+                let count = participants.len();
+                self.send_analysis_update(
+                    &participants,
+                    AnalysisUpdate::Participants { session_id, count },
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participant() -> Participant {
+        (Uuid::new_v4(), Uuid::new_v4())
+    }
+
+    fn hosted(rooms: &AnalysisRooms) -> Participant {
+        let owner = participant();
+        rooms
+            .host("room".to_string(), owner, "coach", "{}".to_string())
+            .expect("host");
+        owner
+    }
+
+    #[test]
+    fn only_the_owner_moves_until_moves_are_opened() {
+        let rooms = AnalysisRooms::default();
+        let owner = hosted(&rooms);
+        let student = participant();
+        let (snapshot, others) = rooms.join("room", student).expect("join");
+        assert_eq!(snapshot.participants, 2);
+        assert_eq!(others, [owner]);
+
+        let node = AnalysisSync::Node(3);
+        assert_eq!(rooms.sync("room", owner, &node), Ok(vec![student]));
+        assert_eq!(
+            rooms.sync("room", student, &node),
+            Err(AnalysisRoomError::NotAllowed)
+        );
+        assert_eq!(
+            rooms.set_open_moves("room", student.0, true),
+            Err(AnalysisRoomError::NotAllowed)
+        );
+        rooms.set_open_moves("room", owner.0, true).expect("open");
+        let tree = AnalysisSync::Tree("{\"moved\":true}".to_string());
+        assert_eq!(rooms.sync("room", student, &tree), Ok(vec![owner]));
+
+        let (snapshot, _) = rooms.join("room", participant()).expect("join");
+        assert_eq!(snapshot.tree, "{\"moved\":true}");
+        assert_eq!(snapshot.node, None);
+        assert!(snapshot.open_moves);
+    }
+
+    #[test]
+    fn outsiders_cannot_sync_or_chat() {
+        let rooms = AnalysisRooms::default();
+        hosted(&rooms);
+        let outsider = participant();
+        assert_eq!(
+            rooms.sync("room", outsider, &AnalysisSync::Node(0)),
+            Err(AnalysisRoomError::NotJoined)
+        );
+        assert_eq!(
+            rooms.chat_recipients("room", outsider),
+            Err(AnalysisRoomError::NotJoined)
+        );
+        assert_eq!(
+            rooms.join("missing", outsider).map(|_| ()),
+            Err(AnalysisRoomError::NotFound)
+        );
+    }
+
+    #[test]
+    fn the_session_ends_when_the_owner_leaves() {
+        let rooms = AnalysisRooms::default();
+        let owner = hosted(&rooms);
+        let (first, second) = (participant(), participant());
+        rooms.join("room", first).expect("join");
+        rooms.join("room", second).expect("join");
+
+        let departures = rooms.leave_socket(first);
+        assert_eq!(departures.len(), 1);
+        assert!(matches!(&departures[0].1, Departure::Left(rest) if rest.len() == 2));
+        assert_eq!(rooms.leave("room", first), None);
+
+        assert_eq!(
+            rooms.leave("room", owner),
+            Some(Departure::Closed(vec![second]))
+        );
+        assert_eq!(
+            rooms.join("room", first).map(|_| ()),
+            Err(AnalysisRoomError::NotFound)
+        );
+    }
+
+    #[test]
+    fn hosting_again_closes_the_previous_session() {
+        let rooms = AnalysisRooms::default();
+        let owner = hosted(&rooms);
+        let student = participant();
+        rooms.join("room", student).expect("join");
+
+        let (_, closed) = rooms
+            .host("next".to_string(), owner, "coach", "{}".to_string())
+            .expect("host again");
+        assert_eq!(closed, [("room".to_string(), vec![student])]);
+        let huge = "x".repeat(MAX_LIVE_TREE_BYTES + 1);
+        assert!(matches!(
+            rooms.host("huge".to_string(), owner, "coach", huge),
+            Err(AnalysisRoomError::TooLarge)
+        ));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    common::AnalysisUpdate,
+    providers::live_analysis::{LiveAnalysisContext, LiveChatLine},
+};
+use leptos::prelude::{expect_context, Set, Update, WithUntracked};
+
+pub fn handle_analysis(update: AnalysisUpdate) {
+    let live = expect_context::<LiveAnalysisContext>();
+    match update {
+        AnalysisUpdate::Joined(snapshot) => live.joined(snapshot),
+        AnalysisUpdate::Synced { session_id, sync } => {
+            if live.is_current(&session_id) {
+                live.incoming.update(|incoming| incoming.push(sync));
+            }
+        }
+        AnalysisUpdate::OpenMoves { session_id, open } => {
+            live.update_session(&session_id, |session| session.open_moves = open);
+        }
+        AnalysisUpdate::Participants { session_id, count } => {
+            live.update_session(&session_id, |session| session.participants = count);
+        }
+        AnalysisUpdate::Chat {
+            session_id,
+            username,
+            body,
+        } => {
+            if live.is_current(&session_id) {
+                live.push_chat(LiveChatLine { username, body });
+            }
+        }
+        AnalysisUpdate::Refused { reason, .. } => live.notice.set(Some(reason)),
+        AnalysisUpdate::Closed(session_id) => {
+            if live.is_current(&session_id) || live.session.with_untracked(Option::is_none) {
+                live.clear(Some("The live session has ended".to_string()));
+            }
+        }
+    }
+}
@@ -1,22 +1,25 @@
 use crate::{
-    common::ChallengeUpdate,
+    common::{ChallengeUpdate, SeekUpdate},
     providers::{challenges::ChallengeStateSignal, AuthContext, NotificationContext},
     responses::{AccountResponse, ChallengeResponse},
 };
 use leptos::prelude::*;
-use shared_types::ChallengeId;
+use shared_types::{ChallengeId, SeekDetails};
 
 fn is_visible_to_account(challenge: &ChallengeResponse, account: &AccountResponse) -> bool {
     if challenge.challenger.uid == account.id {
         return true;
     }
+    let rating = account
+        .user
+        .rating_for_speed(&challenge.speed, challenge.variant_class());
     if let Some(upper) = challenge.band_upper {
-        if account.user.rating_for_speed(&challenge.speed) > upper as u64 {
+        if rating > upper as u64 {
             return false;
         }
     }
     if let Some(lower) = challenge.band_lower {
-        if account.user.rating_for_speed(&challenge.speed) < lower as u64 {
+        if rating < lower as u64 {
             return false;
         }
     }
@@ -108,3 +111,16 @@ pub fn handle_challenge(challenge: ChallengeUpdate) {
         }
     }
 }
+
+pub fn handle_seek(update: SeekUpdate) {
+    let seek = match update {
+        SeekUpdate::Seeking(details) => Some(details),
+        SeekUpdate::Stopped => None,
+    };
+    handle_seek_snapshot(seek);
+}
+
+pub fn handle_seek_snapshot(seek: Option<SeekDetails>) {
+    let challenges = expect_context::<ChallengeStateSignal>();
+    challenges.signal.update(|state| state.seek = seek);
+}
@@ -1,4 +1,4 @@
-use super::reaction::{handle_control, handle_new_game};
+use super::reaction::{handle_control, handle_new_game, handle_premove, handle_rematch};
 use crate::{
     common::{ClientRequest, GameActionResponse, GameReaction, GameUpdate},
     providers::{
@@ -58,6 +58,12 @@ fn handle_reaction(gar: GameActionResponse) {
         GameReaction::Tv => {
             games.live_games_add(gar.game);
         }
+        GameReaction::Rematch(status) => {
+            handle_rematch(gar.game_id, status);
+        }
+        GameReaction::Premove(status) => {
+            handle_premove(gar.game_id, status);
+        }
         GameReaction::TimedOut => {
             let game_id = &gar.game.game_id;
             games.own_games_remove(game_id);
@@ -1,7 +1,8 @@
 use crate::{
-    common::GameActionResponse,
+    common::{GameActionResponse, PremoveStatus, RematchStatus},
     providers::{
         chat::Chat,
+        game_state::{GameStateStore, GameStateStoreFields},
         games::GamesSignal,
         AlertType,
         AlertsContext,
@@ -15,7 +16,7 @@ use crate::{
 use hive_lib::GameControl;
 use leptos::prelude::*;
 use leptos_router::hooks::{use_location, use_navigate};
-use shared_types::{GameStart, TimeMode};
+use shared_types::{GameId, GameStart, TimeMode};
 
 pub fn handle_control(game_control: GameControl, gar: GameActionResponse) {
     let mut games = expect_context::<GamesSignal>();
@@ -101,3 +102,44 @@ pub fn handle_new_game(game_response: GameResponse) {
         }
     }
 }
+
+/// Tracks the rematch offer of a finished game and moves both players from it
+/// to the new game once one exists.
+pub fn handle_rematch(game_id: GameId, status: RematchStatus) {
+    let update_notifier = expect_context::<UpdateNotifier>();
+    if let RematchStatus::Started(new_game_id) = &status {
+        let current_path = use_location().pathname.get_untracked();
+        if current_path == format!("/game/{game_id}") {
+            let navigate = use_navigate();
+            navigate(&format!("/game/{new_game_id}"), Default::default());
+        }
+    }
+    update_notifier.rematches.update(|rematches| {
+        if let RematchStatus::Declined { .. } = status {
+            rematches.remove(&game_id);
+        } else {
+            rematches.insert(game_id, status);
+        }
+    });
+}
+
+pub fn handle_premove(game_id: GameId, status: PremoveStatus) {
+    let game_state = expect_context::<GameStateStore>();
+    if game_state.game_id().get_untracked() != Some(game_id) {
+        return;
+    }
+    match status {
+        PremoveStatus::Queued(turn) => game_state.premove().set(Some(turn)),
+        PremoveStatus::Cancelled => game_state.premove().set(None),
+        PremoveStatus::Discarded(_) => {
+            game_state.premove().set(None);
+            game_state.clear_selection();
+            let alerts = expect_context::<AlertsContext>();
+            alerts.last_alert.update(|v| {
+                *v = Some(AlertType::Warn(
+                    "Premove discarded, it was not legal after your opponent's move".to_string(),
+                ));
+            });
+        }
+    }
+}
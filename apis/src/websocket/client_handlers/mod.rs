@@ -1,3 +1,4 @@
+pub mod analysis;
 pub mod challenge;
 pub mod chat;
 pub mod game;
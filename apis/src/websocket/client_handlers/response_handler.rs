@@ -1,5 +1,11 @@
 use super::{
-    challenge::handler::{handle_challenge, handle_challenge_snapshot},
+    analysis::handler::handle_analysis,
+    challenge::handler::{
+        handle_challenge,
+        handle_challenge_snapshot,
+        handle_seek,
+        handle_seek_snapshot,
+    },
     chat::handle::handle_chat,
     game::{handle_game, handle_tv_snapshot, handle_urgent_games_snapshot},
     oauth::handle::handle_oauth,
@@ -17,9 +23,12 @@ use crate::{
         SubscriptionAttempt,
         UserSettingsUpdate,
     },
-    providers::chat::Chat,
+    providers::{chat::Chat, AlertType, AlertsContext},
+};
+use leptos::{
+    logging::log,
+    prelude::{use_context, Set},
 };
-use leptos::{logging::log, prelude::use_context};
 use leptos_router::hooks::use_navigate;
 use shared_types::ConversationKey;
 
@@ -30,6 +39,7 @@ fn handle_lobby_snapshot(snapshot: LobbySnapshotPayload) {
     handle_challenge_snapshot(snapshot.challenges);
     handle_tv_snapshot(snapshot.tv_games);
     handle_user_status_snapshot(snapshot.online_users);
+    handle_seek_snapshot(snapshot.seek);
 }
 
 fn handle_user_settings(update: UserSettingsUpdate) {
@@ -82,6 +92,8 @@ pub fn handle_response(m: ServerResult) {
             Tournament(tournament_update) => handle_tournament(tournament_update),
             UserSettings(update) => handle_user_settings(update),
             Schedule(schedule_update) => handle_schedule(schedule_update),
+            Seek(seek_update) => handle_seek(seek_update),
+            Analysis(analysis_update) => handle_analysis(analysis_update),
             todo => {
                 log!("Got {todo:?} which is currently still unimplemented");
             }
@@ -107,6 +119,13 @@ pub fn handle_response(m: ServerResult) {
                         chat.handle_failed_chat_send(key, client_id, error.into());
                     }
                 }
+                ExternalServerError::TournamentJoin { error, .. } => {
+                    if let Some(alerts) = use_context::<AlertsContext>() {
+                        alerts
+                            .last_alert
+                            .set(Some(AlertType::Warn(format!("Cannot join: {error}"))));
+                    }
+                }
                 ExternalServerError::Request { .. } => {}
             }
         }
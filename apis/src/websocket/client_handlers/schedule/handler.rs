@@ -115,6 +115,9 @@ pub fn handle_schedule(schedule_update: ScheduleUpdate) {
             remove_schedule(ctx.own, &response);
         }
 
+        Suggestions(game_id, slots) => ctx.suggestions.update(|suggestions| {
+            suggestions.insert(game_id, slots);
+        }),
         TournamentSchedules(schedules) => ctx.tournament.set(schedules),
         OwnTournamentSchedules(schedules) => ctx.own.set(schedules),
     }
@@ -1,6 +1,6 @@
 use crate::{
     common::TournamentUpdate,
-    providers::{chat::Chat, NotificationContext, UpdateNotifier},
+    providers::{chat::Chat, AlertType, AlertsContext, NotificationContext, UpdateNotifier},
 };
 use leptos::prelude::*;
 use leptos_router::hooks::{use_location, use_navigate};
@@ -51,6 +51,41 @@ pub fn handle_tournament(tournament: TournamentUpdate) {
             notifications.tournament_invitation_remove(&tournament_id);
             chat.refresh_inbox_and_catalog();
         }
+        TournamentUpdate::Waitlisted(_) => {
+            // An invitation stays until the seat is taken, so it lets the
+            // player skip the requirements when promoted.
+            expect_context::<AlertsContext>()
+                .last_alert
+                .set(Some(AlertType::Notification(String::from(
+                    "The tournament is full, you have been added to the waitlist",
+                ))));
+        }
+        TournamentUpdate::Promoted(tournament_id) => {
+            notifications.tournament_invitation_remove(&tournament_id);
+            notify_update.set(tournament_id);
+            chat.refresh_inbox_and_catalog();
+            expect_context::<AlertsContext>()
+                .last_alert
+                .set(Some(AlertType::Notification(String::from(
+                    "A seat opened up, you moved from the waitlist into the tournament",
+                ))));
+        }
+        TournamentUpdate::Paused(tournament_id) => {
+            notify_update.set(tournament_id);
+            expect_context::<AlertsContext>()
+                .last_alert
+                .set(Some(AlertType::Notification(String::from(
+                    "A tournament you are in was paused, games without moves are on hold",
+                ))));
+        }
+        TournamentUpdate::Resumed(tournament_id) => {
+            notify_update.set(tournament_id);
+            expect_context::<AlertsContext>()
+                .last_alert
+                .set(Some(AlertType::Notification(String::from(
+                    "A tournament you are in was resumed",
+                ))));
+        }
         TournamentUpdate::Invited(tournament_id) => {
             notifications.tournament_invitation_insert(tournament_id);
         }
@@ -57,6 +57,7 @@ impl WsHub {
                 challenges: self.challenge_snapshot(conn, user_id, socket, user).await?,
                 tv_games: self.tv_snapshot(conn, user_id, socket).await?,
                 online_users: self.online_roster_snapshot(conn, user_id, socket).await?,
+                seek: self.seeks.seek_of(user_id),
             };
             self.ensure_socket_connected(user_id, socket)?;
             Ok(snapshot)
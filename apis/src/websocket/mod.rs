@@ -5,8 +5,10 @@ pub mod busybee;
 pub mod client_handlers;
 
 cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
+    mod analysis_rooms;
     mod lobby_snapshot;
     mod messages;
+    mod seek_pool;
     mod start_conn;
     mod telemetry;
     mod tournament_game_start;
@@ -0,0 +1,362 @@
+use crate::{
+    common::{
+        ChallengeUpdate,
+        GameActionResponse,
+        GameReaction,
+        GameUpdate,
+        SeekUpdate,
+        ServerMessage,
+        ServerResult,
+    },
+    notifications::notify_followers_game_started,
+    responses::GameResponse,
+    websocket::{telemetry::DestKind, InternalServerMessage, MessageDestination, WsHub},
+};
+use anyhow::Result;
+use bytes::Bytes;
+use codee::{binary::MsgpackSerdeCodec, Encoder};
+use db_lib::{
+    get_conn,
+    models::{Game, NewGame},
+};
+use diesel_async::AsyncConnection;
+use log::error;
+use shared_types::{SeekDetails, TimeMode};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+    time::Instant,
+};
+use uuid::Uuid;
+
+/// Rating gap two seekers accept as soon as they join.
+const INITIAL_RANGE: f64 = 100.0;
+/// How far that gap widens for every second spent waiting.
+const RANGE_GROWTH_PER_SECOND: f64 = 10.0;
+const MAX_RANGE: f64 = 600.0;
+
+#[derive(Debug, Clone)]
+pub struct Seeker {
+    pub user_id: Uuid,
+    pub socket_id: Uuid,
+    pub details: SeekDetails,
+    /// The seeker's rating for the seek's speed and variant.
+    pub rating: f64,
+    /// Users this seeker has blocked or been blocked by.
+    pub blocked: HashSet<Uuid>,
+    pub since: Instant,
+}
+
+impl Seeker {
+    fn range(&self, now: Instant) -> f64 {
+        let waited = now.saturating_duration_since(self.since).as_secs_f64();
+        (INITIAL_RANGE + RANGE_GROWTH_PER_SECOND * waited).min(MAX_RANGE)
+    }
+
+    fn can_play(&self, other: &Seeker, now: Instant) -> bool {
+        self.user_id != other.user_id
+            && self.details == other.details
+            && !self.blocked.contains(&other.user_id)
+            && !other.blocked.contains(&self.user_id)
+            && (self.rating - other.rating).abs() <= self.range(now).min(other.range(now))
+    }
+}
+
+/// Quick-pairing queues, one per time control. A user has at most one seek,
+/// owned by the socket that made it.
+#[derive(Default)]
+pub struct SeekPool {
+    queues: Mutex<HashMap<(i32, i32), Vec<Seeker>>>,
+}
+
+impl SeekPool {
+    /// Queues `seeker`, replacing any seek the user already had.
+    pub fn join(&self, seeker: Seeker) {
+        let mut queues = self.queues.lock().unwrap_or_else(|p| p.into_inner());
+        Self::remove_where(&mut queues, |queued| queued.user_id == seeker.user_id);
+        queues
+            .entry(seeker.details.time_control())
+            .or_default()
+            .push(seeker);
+    }
+
+    /// Returns whether the user had a seek.
+    pub fn cancel_user(&self, user_id: Uuid) -> bool {
+        let mut queues = self.queues.lock().unwrap_or_else(|p| p.into_inner());
+        Self::remove_where(&mut queues, |queued| queued.user_id == user_id)
+    }
+
+    /// Returns whether the socket had a seek.
+    pub fn cancel_socket(&self, socket_id: Uuid) -> bool {
+        let mut queues = self.queues.lock().unwrap_or_else(|p| p.into_inner());
+        Self::remove_where(&mut queues, |queued| queued.socket_id == socket_id)
+    }
+
+    pub fn seek_of(&self, user_id: Uuid) -> Option<SeekDetails> {
+        let queues = self.queues.lock().unwrap_or_else(|p| p.into_inner());
+        queues
+            .values()
+            .flatten()
+            .find(|queued| queued.user_id == user_id)
+            .map(|queued| queued.details)
+    }
+
+    /// Removes and returns every pair that can play at `now`. Whoever has
+    /// waited longest picks first and gets the closest rating available.
+    pub fn take_pairs(&self, now: Instant) -> Vec<(Seeker, Seeker)> {
+        let mut queues = self.queues.lock().unwrap_or_else(|p| p.into_inner());
+        let mut pairs = Vec::new();
+        for queue in queues.values_mut() {
+            queue.sort_by_key(|seeker| seeker.since);
+            let mut waiting = std::mem::take(queue);
+            while !waiting.is_empty() {
+                let first = waiting.remove(0);
+                let partner = waiting
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, other)| first.can_play(other, now))
+                    .min_by(|(_, a), (_, b)| {
+                        (a.rating - first.rating)
+                            .abs()
+                            .total_cmp(&(b.rating - first.rating).abs())
+                    })
+                    .map(|(index, _)| index);
+                match partner {
+                    Some(index) => pairs.push((first, waiting.remove(index))),
+                    None => queue.push(first),
+                }
+            }
+        }
+        queues.retain(|_, queue| !queue.is_empty());
+        pairs
+    }
+
+    fn remove_where(
+        queues: &mut HashMap<(i32, i32), Vec<Seeker>>,
+        matches: impl Fn(&Seeker) -> bool,
+    ) -> bool {
+        let mut removed = false;
+        for queue in queues.values_mut() {
+            let before = queue.len();
+            queue.retain(|queued| !matches(queued));
+            removed |= queue.len() != before;
+        }
+        queues.retain(|_, queue| !queue.is_empty());
+        removed
+    }
+}
+
+impl WsHub {
+    /// Starts a game for every pair of compatible seekers. Runs whenever a
+    /// seek is queued and periodically, so waiting seekers widen their range.
+    pub async fn pair_seeks(&self) {
+        for (first, second) in self.seeks.take_pairs(Instant::now()) {
+            if let Err(e) = self.start_seek_game(&first, &second).await {
+                error!(
+                    "quick pairing {} vs {} failed: {e}",
+                    first.user_id, second.user_id
+                );
+                for user_id in [first.user_id, second.user_id] {
+                    self.send_seek_update(user_id, SeekUpdate::Stopped);
+                    self.send_to_user_message(
+                        user_id,
+                        ServerMessage::Error("Could not start the paired game".to_string()),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Drops the user's seek and tells their other sockets about it.
+    pub(in crate::websocket) fn stop_seek(&self, user_id: Uuid) {
+        if self.seeks.cancel_user(user_id) {
+            self.send_seek_update(user_id, SeekUpdate::Stopped);
+        }
+    }
+
+    /// Players who just started a real-time game elsewhere stop seeking.
+    pub(in crate::websocket) fn stop_seeks_for_new_games(
+        &self,
+        messages: &[InternalServerMessage],
+    ) {
+        for message in messages {
+            if let ServerMessage::Game(update) = &message.message {
+                if let GameUpdate::Reaction(GameActionResponse {
+                    game_action: GameReaction::New,
+                    game,
+                    ..
+                }) = update.as_ref()
+                {
+                    if game.time_mode == TimeMode::RealTime {
+                        self.stop_seek(game.white_player.uid);
+                        self.stop_seek(game.black_player.uid);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(in crate::websocket) fn send_seek_update(&self, user_id: Uuid, update: SeekUpdate) {
+        self.send_to_user_message(user_id, ServerMessage::Seek(update));
+    }
+
+    fn send_to_user_message(&self, user_id: Uuid, message: ServerMessage) {
+        let result = ServerResult::Ok(Box::new(message));
+        if let Ok(serialized) = MsgpackSerdeCodec::encode(&result) {
+            self.send_to_user(&user_id, DestKind::User, &Bytes::from(serialized));
+        }
+    }
+
+    async fn start_seek_game(&self, first: &Seeker, second: &Seeker) -> Result<()> {
+        let (white_id, black_id) = if rand::random() {
+            (first.user_id, second.user_id)
+        } else {
+            (second.user_id, first.user_id)
+        };
+        let details = first.details;
+        let mut conn = get_conn(&self.pool).await?;
+        let (game, deleted_challenges, game_response) = conn
+            .transaction::<_, anyhow::Error, _>(async move |tc| {
+                let new_game = NewGame::new_from_seek(white_id, black_id, &details);
+                let (game, deleted_challenges) = Game::create_from_seek(new_game, tc).await?;
+                let game_response = GameResponse::from_model(&game, tc).await?;
+                Ok((game, deleted_challenges, game_response))
+            })
+            .await?;
+        if let Err(e) = notify_followers_game_started(&game, &mut conn).await {
+            log::error!("notify followers {}: {e}", game.nanoid);
+        }
+
+        let mut messages = Vec::new();
+        for (user_id, username) in [
+            (game.white_id, &game_response.white_player.username),
+            (game.black_id, &game_response.black_player.username),
+        ] {
+            messages.push(InternalServerMessage {
+                destination: MessageDestination::User(user_id),
+                message: ServerMessage::Seek(SeekUpdate::Stopped),
+            });
+            messages.push(InternalServerMessage {
+                destination: MessageDestination::User(user_id),
+                message: ServerMessage::Game(Box::new(GameUpdate::Reaction(GameActionResponse {
+                    game_action: GameReaction::New,
+                    game: game_response.clone(),
+                    game_id: game_response.game_id.clone(),
+                    user_id,
+                    username: username.to_owned(),
+                }))),
+            });
+        }
+        for challenge_nanoid in deleted_challenges {
+            messages.push(InternalServerMessage {
+                destination: MessageDestination::Global,
+                message: ServerMessage::Challenge(ChallengeUpdate::Removed(challenge_nanoid)),
+            });
+        }
+        for message in messages {
+            let serialized = ServerResult::Ok(Box::new(message.message));
+            if let Ok(serialized) = MsgpackSerdeCodec::encode(&serialized) {
+                self.dispatch(&message.destination, Bytes::from(serialized))
+                    .await;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_lib::GameType;
+    use std::time::Duration;
+
+    fn details(game_type: GameType) -> SeekDetails {
+        SeekDetails {
+            game_type,
+            rated: true,
+            time_base: 180,
+            time_increment: 3,
+        }
+    }
+
+    fn seeker(rating: f64, since: Instant) -> Seeker {
+        Seeker {
+            user_id: Uuid::new_v4(),
+            socket_id: Uuid::new_v4(),
+            details: details(GameType::MLP),
+            rating,
+            blocked: HashSet::new(),
+            since,
+        }
+    }
+
+    #[test]
+    fn close_ratings_pair_at_once_and_distant_ones_after_waiting() {
+        let now = Instant::now();
+        let pool = SeekPool::default();
+        let (low, high) = (seeker(1400.0, now), seeker(1700.0, now));
+        let (low_id, high_id) = (low.user_id, high.user_id);
+        pool.join(low);
+        pool.join(high);
+
+        assert!(pool.take_pairs(now).is_empty());
+        assert!(pool.take_pairs(now + Duration::from_secs(19)).is_empty());
+        let pairs = pool.take_pairs(now + Duration::from_secs(20));
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].0.user_id, pairs[0].1.user_id), (low_id, high_id));
+        assert!(pool.seek_of(low_id).is_none());
+    }
+
+    #[test]
+    fn longest_waiter_gets_the_closest_rating() {
+        let now = Instant::now();
+        let pool = SeekPool::default();
+        let first = seeker(1500.0, now);
+        let far = seeker(1580.0, now + Duration::from_secs(1));
+        let near = seeker(1520.0, now + Duration::from_secs(2));
+        let (first_id, near_id, far_id) = (first.user_id, near.user_id, far.user_id);
+        pool.join(far);
+        pool.join(near);
+        pool.join(first);
+
+        let pairs = pool.take_pairs(now + Duration::from_secs(2));
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(
+            (pairs[0].0.user_id, pairs[0].1.user_id),
+            (first_id, near_id)
+        );
+        assert!(pool.seek_of(far_id).is_some());
+    }
+
+    #[test]
+    fn blocked_players_and_other_variants_never_pair() {
+        let now = Instant::now();
+        let pool = SeekPool::default();
+        let blocker = seeker(1500.0, now);
+        let mut blocked = seeker(1500.0, now);
+        blocked.blocked.insert(blocker.user_id);
+        let mut base = seeker(1500.0, now);
+        base.details = details(GameType::Base);
+        pool.join(blocker);
+        pool.join(blocked);
+        pool.join(base);
+
+        assert!(pool.take_pairs(now + Duration::from_secs(600)).is_empty());
+    }
+
+    #[test]
+    fn rejoining_replaces_the_seek_and_disconnect_cancels_it() {
+        let now = Instant::now();
+        let pool = SeekPool::default();
+        let mut seek = seeker(1500.0, now);
+        pool.join(seek.clone());
+        seek.details.time_base = 600;
+        pool.join(seek.clone());
+        assert_eq!(pool.seek_of(seek.user_id), Some(seek.details));
+
+        assert!(!pool.cancel_socket(Uuid::new_v4()));
+        assert!(pool.cancel_socket(seek.socket_id));
+        assert!(pool.seek_of(seek.user_id).is_none());
+        assert!(!pool.cancel_user(seek.user_id));
+    }
+}
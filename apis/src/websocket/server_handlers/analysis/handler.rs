@@ -0,0 +1,150 @@
+use crate::{
+    common::{AnalysisAction, AnalysisSync, AnalysisUpdate},
+    providers::analysis::AnalysisTree,
+    websocket::{
+        analysis_rooms::{AnalysisRoomError, MAX_LIVE_TREE_BYTES},
+        messages::SocketTx,
+        WsHub,
+    },
+};
+use shared_types::normalize_chat_message;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct AnalysisHandler {
+    action: AnalysisAction,
+    user_id: Uuid,
+    username: String,
+    socket_id: Uuid,
+    hub: Arc<WsHub>,
+}
+
+impl AnalysisHandler {
+    pub fn new(
+        action: AnalysisAction,
+        user_id: Uuid,
+        username: &str,
+        socket: &SocketTx,
+        hub: Arc<WsHub>,
+    ) -> Self {
+        Self {
+            action,
+            user_id,
+            username: username.to_string(),
+            socket_id: socket.socket_id,
+            hub,
+        }
+    }
+
+    fn participant(&self) -> (Uuid, Uuid) {
+        (self.user_id, self.socket_id)
+    }
+
+    /// Room errors are answered on the socket instead of failing the request: they are
+    /// ordinary outcomes like a session that just ended.
+    pub fn handle(&self) {
+        let session_id = match &self.action {
+            AnalysisAction::Host(_) => String::new(),
+            AnalysisAction::Join(session_id)
+            | AnalysisAction::Leave(session_id)
+            | AnalysisAction::Sync { session_id, .. }
+            | AnalysisAction::OpenMoves { session_id, .. }
+            | AnalysisAction::Chat { session_id, .. } => session_id.clone(),
+        };
+        if let Err(error) = self.apply() {
+            let update = match error {
+                AnalysisRoomError::NotFound => AnalysisUpdate::Closed(session_id),
+                error => AnalysisUpdate::Refused {
+                    session_id,
+                    reason: error.to_string(),
+                },
+            };
+            self.hub.send_analysis_update(&[self.participant()], update);
+        }
+    }
+
+    fn apply(&self) -> Result<(), AnalysisRoomError> {
+        let rooms = &self.hub.analysis_rooms;
+        match &self.action {
+            AnalysisAction::Host(tree) => {
+                ensure_readable(tree)?;
+                let session_id = format!("{:016x}", rand::random::<u64>());
+                let (snapshot, closed) =
+                    rooms.host(session_id, self.participant(), &self.username, tree.clone())?;
+                for (session_id, participants) in closed {
+                    self.hub
+                        .send_analysis_update(&participants, AnalysisUpdate::Closed(session_id));
+                }
+                self.hub
+                    .send_analysis_update(&[self.participant()], AnalysisUpdate::Joined(snapshot));
+            }
+            AnalysisAction::Join(session_id) => {
+                let (snapshot, others) = rooms.join(session_id, self.participant())?;
+                let count = snapshot.participants;
+                self.hub
+                    .send_analysis_update(&[self.participant()], AnalysisUpdate::Joined(snapshot));
+                self.hub.send_analysis_update(
+                    &others,
+                    AnalysisUpdate::Participants {
+                        session_id: session_id.clone(),
+                        count,
+                    },
+                );
+            }
+            AnalysisAction::Leave(session_id) => {
+                if let Some(departure) = rooms.leave(session_id, self.participant()) {
+                    self.hub.announce_departure(session_id.clone(), departure);
+                }
+            }
+            AnalysisAction::Sync { session_id, sync } => {
+                if let AnalysisSync::Tree(tree) = sync {
+                    ensure_readable(tree)?;
+                }
+                let others = rooms.sync(session_id, self.participant(), sync)?;
+                self.hub.send_analysis_update(
+                    &others,
+                    AnalysisUpdate::Synced {
+                        session_id: session_id.clone(),
+                        sync: sync.clone(),
+                    },
+                );
+            }
+            AnalysisAction::OpenMoves { session_id, open } => {
+                let participants = rooms.set_open_moves(session_id, self.user_id, *open)?;
+                self.hub.send_analysis_update(
+                    &participants,
+                    AnalysisUpdate::OpenMoves {
+                        session_id: session_id.clone(),
+                        open: *open,
+                    },
+                );
+            }
+            AnalysisAction::Chat { session_id, body } => {
+                let body = normalize_chat_message(body);
+                if body.trim().is_empty() {
+                    return Ok(());
+                }
+                let participants = rooms.chat_recipients(session_id, self.participant())?;
+                self.hub.send_analysis_update(
+                    &participants,
+                    AnalysisUpdate::Chat {
+                        session_id: session_id.clone(),
+                        username: self.username.clone(),
+                        body,
+                    },
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Only trees the analysis board can load are relayed to other participants.
+fn ensure_readable(tree: &str) -> Result<(), AnalysisRoomError> {
+    if tree.len() > MAX_LIVE_TREE_BYTES {
+        return Err(AnalysisRoomError::TooLarge);
+    }
+    serde_json::from_str::<AnalysisTree>(tree)
+        .map(|_| ())
+        .map_err(|_| AnalysisRoomError::Unreadable)
+}
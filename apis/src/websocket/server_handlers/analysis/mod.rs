@@ -0,0 +1,2 @@
+mod handler;
+pub use handler::AnalysisHandler;
@@ -1,6 +1,6 @@
 use crate::{
     common::{ChallengeUpdate, GameActionResponse, GameReaction, GameUpdate, ServerMessage},
-    notifications::{notify, time_control_label, Event},
+    notifications::{notify, notify_followers_game_started, time_control_label, Event},
     responses::GameResponse,
     websocket::messages::{InternalServerMessage, MessageDestination},
 };
@@ -54,7 +54,7 @@ impl AcceptHandler {
         };
         challenge.validate_accepting_user(self.user_id)?;
         let speed = GameSpeed::from_base_increment(challenge.time_base, challenge.time_increment);
-        let rating = Rating::for_uuid(&self.user_id, &speed, &mut conn)
+        let rating = Rating::for_uuid(&self.user_id, &speed, challenge.variant_class()?, &mut conn)
             .await?
             .rating;
         if let Some(band_upper) = challenge.band_upper {
@@ -109,6 +109,9 @@ impl AcceptHandler {
             time_control: time_control_label(speed, game.time_base, game.time_increment),
             speed,
         });
+        if let Err(e) = notify_followers_game_started(&game, &mut conn).await {
+            log::error!("notify followers {}: {e}", game.nanoid);
+        }
 
         messages.push(InternalServerMessage {
             destination: MessageDestination::User(game.white_id),
@@ -12,12 +12,15 @@ const USER_SEND_LIMIT: u32 = 30;
 const USER_SEND_WINDOW: Duration = Duration::from_secs(10);
 const SOCKET_SEND_LIMIT: u32 = 15;
 const SOCKET_SEND_WINDOW: Duration = Duration::from_secs(10);
+const ANALYSIS_UPDATE_LIMIT: u32 = 40;
+const ANALYSIS_UPDATE_WINDOW: Duration = Duration::from_secs(10);
 const CLEANUP_INTERVAL: u64 = 256;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ChatLimitError {
     SubscriptionAttempts { retry_after: Duration },
     Send { retry_after: Duration },
+    AnalysisUpdates { retry_after: Duration },
 }
 
 impl ChatLimitError {
@@ -25,12 +28,15 @@ impl ChatLimitError {
         match self {
             Self::SubscriptionAttempts { .. } => "Too many chat subscription attempts",
             Self::Send { .. } => "Too many chat messages",
+            Self::AnalysisUpdates { .. } => "Too many live analysis updates",
         }
     }
 
     pub const fn retry_after(self) -> Duration {
         match self {
-            Self::SubscriptionAttempts { retry_after } | Self::Send { retry_after } => retry_after,
+            Self::SubscriptionAttempts { retry_after }
+            | Self::Send { retry_after }
+            | Self::AnalysisUpdates { retry_after } => retry_after,
         }
     }
 }
@@ -46,6 +52,7 @@ struct ChatLimitConfig {
     subscription_attempts: Limit,
     user_sends: Limit,
     socket_sends: Limit,
+    analysis_updates: Limit,
 }
 
 impl Default for ChatLimitConfig {
@@ -63,6 +70,10 @@ impl Default for ChatLimitConfig {
                 attempts: SOCKET_SEND_LIMIT,
                 window: SOCKET_SEND_WINDOW,
             },
+            analysis_updates: Limit {
+                attempts: ANALYSIS_UPDATE_LIMIT,
+                window: ANALYSIS_UPDATE_WINDOW,
+            },
         }
     }
 }
@@ -93,6 +104,7 @@ struct ChatLimitState {
     subscription_attempts: HashMap<Uuid, AttemptWindow>,
     user_sends: HashMap<Uuid, AttemptWindow>,
     socket_sends: HashMap<Uuid, AttemptWindow>,
+    analysis_updates: HashMap<Uuid, AttemptWindow>,
     operations: u64,
 }
 
@@ -145,6 +157,24 @@ impl ChatRateLimits {
         Ok(())
     }
 
+    /// Hosting and syncing live analysis relay whole trees, so they are limited per
+    /// socket like chat sends.
+    pub fn check_analysis_update(&self, socket_id: Uuid) -> Result<(), ChatLimitError> {
+        let now = Instant::now();
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Self::clean_if_due(&mut state, self.config, now);
+        Self::check_window(
+            &mut state.analysis_updates,
+            socket_id,
+            now,
+            self.config.analysis_updates,
+        )
+        .map_err(|retry_after| ChatLimitError::AnalysisUpdates { retry_after })
+    }
+
     pub fn remove_socket(&self, socket_id: Uuid) {
         let mut state = self
             .state
@@ -152,6 +182,7 @@ impl ChatRateLimits {
             .unwrap_or_else(|poisoned| poisoned.into_inner());
         state.subscription_attempts.remove(&socket_id);
         state.socket_sends.remove(&socket_id);
+        state.analysis_updates.remove(&socket_id);
     }
 
     fn check_window(
@@ -183,6 +214,9 @@ impl ChatRateLimits {
         state
             .socket_sends
             .retain(|_, window| now.duration_since(window.started_at) < config.socket_sends.window);
+        state.analysis_updates.retain(|_, window| {
+            now.duration_since(window.started_at) < config.analysis_updates.window
+        });
     }
 }
 
@@ -210,6 +244,10 @@ mod tests {
                     attempts: socket_sends,
                     window,
                 },
+                analysis_updates: Limit {
+                    attempts: socket_sends,
+                    window,
+                },
             },
             state: Mutex::new(ChatLimitState::default()),
         }
@@ -258,4 +296,19 @@ mod tests {
         ));
         assert_eq!(limits.check_send(user_id, Uuid::new_v4()), Ok(()));
     }
+
+    #[test]
+    fn analysis_updates_are_limited_per_socket() {
+        let limits = test_limits(10, 10, 2);
+        let socket_id = Uuid::new_v4();
+
+        assert_eq!(limits.check_analysis_update(socket_id), Ok(()));
+        assert_eq!(limits.check_analysis_update(socket_id), Ok(()));
+        assert!(matches!(
+            limits.check_analysis_update(socket_id),
+            Err(ChatLimitError::AnalysisUpdates { .. })
+        ));
+        assert_eq!(limits.check_send(Uuid::new_v4(), socket_id), Ok(()));
+        assert_eq!(limits.check_analysis_update(Uuid::new_v4()), Ok(()));
+    }
 }
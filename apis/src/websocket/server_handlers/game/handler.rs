@@ -1,6 +1,8 @@
 use super::{
     control_handler::GameControlHandler,
     join_handler::JoinHandler,
+    premove_handler::PremoveHandler,
+    rematch_handler::RematchHandler,
     start::StartHandler,
     timeout_handler::TimeoutHandler,
     turn_handler::TurnHandler,
@@ -79,6 +81,7 @@ impl GameActionHandler {
             GameAction::Turn(turn) => {
                 self.ensure_not_finished()?;
                 self.ensure_user_is_player()?;
+                self.ensure_tournament_not_paused().await?;
                 TurnHandler::new(
                     turn,
                     &self.game,
@@ -122,6 +125,7 @@ impl GameActionHandler {
             GameAction::Start => {
                 self.ensure_not_finished()?;
                 self.ensure_user_is_player()?;
+                self.ensure_tournament_not_paused().await?;
                 StartHandler::new(
                     &self.game,
                     self.user_id,
@@ -132,6 +136,36 @@ impl GameActionHandler {
                 .handle()
                 .await?
             }
+            GameAction::Premove(turn) => {
+                self.ensure_not_finished()?;
+                self.ensure_user_is_player()?;
+                PremoveHandler::new(
+                    turn,
+                    &self.game,
+                    &self.username,
+                    self.user_id,
+                    self.data.clone(),
+                    self.hub.clone(),
+                    &self.pool,
+                )
+                .handle()
+                .await?
+                .into()
+            }
+            GameAction::Rematch(action) => {
+                self.ensure_user_is_player()?;
+                RematchHandler::new(
+                    action,
+                    &self.game,
+                    &self.username,
+                    self.user_id,
+                    self.data.clone(),
+                    &self.pool,
+                )
+                .handle()
+                .await?
+                .into()
+            }
             GameAction::Unwatch => {
                 unreachable!("Unwatch is intercepted in handle_binary before GameActionHandler")
             }
@@ -151,6 +185,14 @@ impl GameActionHandler {
         Ok(())
     }
 
+    async fn ensure_tournament_not_paused(&self) -> Result<()> {
+        if self.game.tournament_id.is_some() {
+            let mut conn = get_conn(&self.pool).await?;
+            self.game.ensure_tournament_not_paused(&mut conn).await?;
+        }
+        Ok(())
+    }
+
     fn ensure_user_is_player(&self) -> Result<()> {
         if !self.game.user_is_player(self.user_id) {
             Err(GameError::NotPlayer {
@@ -1,6 +1,8 @@
 pub mod control_handler;
 pub mod handler;
 pub mod join_handler;
+pub mod premove_handler;
+pub mod rematch_handler;
 pub mod start;
 pub mod timeout_handler;
 pub mod turn_handler;
@@ -0,0 +1,138 @@
+use crate::{
+    common::{GameActionResponse, GameReaction, GameUpdate, PremoveStatus, ServerMessage},
+    responses::GameResponse,
+    websocket::{
+        messages::{InternalServerMessage, MessageDestination},
+        ws_hub::Premove,
+        WebsocketData,
+        WsHub,
+    },
+};
+use anyhow::Result;
+use db_lib::{get_conn, models::Game, DbPool};
+use hive_lib::{GameError, State, Turn};
+use shared_types::{GameId, TimeMode};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct PremoveHandler {
+    turn: Option<Turn>,
+    game: Game,
+    user_id: Uuid,
+    username: String,
+    data: Arc<WebsocketData>,
+    hub: Arc<WsHub>,
+    pool: DbPool,
+}
+
+impl PremoveHandler {
+    pub fn new(
+        turn: Option<Turn>,
+        game: &Game,
+        username: &str,
+        user_id: Uuid,
+        data: Arc<WebsocketData>,
+        hub: Arc<WsHub>,
+        pool: &DbPool,
+    ) -> Self {
+        Self {
+            turn,
+            game: game.to_owned(),
+            user_id,
+            username: username.to_owned(),
+            data,
+            hub,
+            pool: pool.clone(),
+        }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<InternalServerMessage>> {
+        if self.game.time_mode != TimeMode::RealTime.to_string() {
+            Err(self.invalid_turn())?;
+        }
+        let game_id = GameId(self.game.nanoid.clone());
+        let status = match self.turn.clone() {
+            None => {
+                self.hub.cancel_premove(&game_id, self.user_id);
+                PremoveStatus::Cancelled
+            }
+            // The opponent's move landed before the premove did
+            Some(turn) if self.game.current_player_id == self.user_id => {
+                PremoveStatus::Discarded(turn)
+            }
+            Some(turn) => {
+                self.ensure_own_piece(&turn)?;
+                self.hub.queue_premove(
+                    &game_id,
+                    Premove {
+                        user_id: self.user_id,
+                        turn: turn.clone(),
+                        queued_at: self.game.turn,
+                    },
+                );
+                PremoveStatus::Queued(turn)
+            }
+        };
+        let mut conn = get_conn(&self.pool).await?;
+        let response = self
+            .data
+            .get_or_build_response(&self.game, &mut conn)
+            .await?;
+        Ok(vec![premove_message(
+            self.user_id,
+            &self.username,
+            status,
+            (*response).clone(),
+        )])
+    }
+
+    /// A premove has to place or move one of the player's own pieces that
+    /// this game actually has, anything else could never be played.
+    fn ensure_own_piece(&self, turn: &Turn) -> Result<()> {
+        let Turn::Move(piece, _) = turn else {
+            return Err(self.invalid_turn().into());
+        };
+        let color = self
+            .game
+            .user_color(self.user_id)
+            .ok_or_else(|| self.invalid_turn())?;
+        let state = State::new_from_str(&self.game.history, &self.game.game_type)?;
+        let in_game = state.board.position_of_piece(*piece).is_some()
+            || state
+                .reserve(color)
+                .values()
+                .flatten()
+                .any(|reserved| *reserved == piece.to_string());
+        if !piece.is_color(color) || !in_game {
+            return Err(self.invalid_turn().into());
+        }
+        Ok(())
+    }
+
+    fn invalid_turn(&self) -> GameError {
+        GameError::InvalidTurn {
+            username: self.username.to_owned(),
+            game: self.game.nanoid.to_owned(),
+            turn: format!("{}", self.game.turn),
+        }
+    }
+}
+
+/// Tells the player, and only them, what happened to their premove.
+pub fn premove_message(
+    user_id: Uuid,
+    username: &str,
+    status: PremoveStatus,
+    game: GameResponse,
+) -> InternalServerMessage {
+    InternalServerMessage {
+        destination: MessageDestination::User(user_id),
+        message: ServerMessage::Game(Box::new(GameUpdate::Reaction(GameActionResponse {
+            game_id: game.game_id.clone(),
+            game,
+            game_action: GameReaction::Premove(status),
+            user_id,
+            username: username.to_owned(),
+        }))),
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    common::{
+        ChallengeUpdate,
+        GameActionResponse,
+        GameReaction,
+        GameUpdate,
+        RematchAction,
+        RematchStatus,
+        ServerMessage,
+    },
+    notifications::{
+        notify,
+        notify_followers_game_started,
+        notify_game_control,
+        time_control_label,
+        Event,
+        GameControlKind,
+    },
+    responses::GameResponse,
+    websocket::{
+        messages::{InternalServerMessage, MessageDestination},
+        WebsocketData,
+    },
+};
+use anyhow::Result;
+use db_lib::{
+    get_conn,
+    models::{Game, Rematch},
+    DbPool,
+};
+use diesel_async::AsyncConnection;
+use shared_types::{ChallengeId, GameId, GameSpeed};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RematchHandler {
+    action: RematchAction,
+    game: Game,
+    user_id: Uuid,
+    username: String,
+    data: Arc<WebsocketData>,
+    pool: DbPool,
+}
+
+impl RematchHandler {
+    pub fn new(
+        action: RematchAction,
+        game: &Game,
+        username: &str,
+        user_id: Uuid,
+        data: Arc<WebsocketData>,
+        pool: &DbPool,
+    ) -> Self {
+        Self {
+            action,
+            game: game.to_owned(),
+            user_id,
+            username: username.to_owned(),
+            data,
+            pool: pool.clone(),
+        }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<InternalServerMessage>> {
+        let mut conn = get_conn(&self.pool).await?;
+        let game = &self.game;
+        let user_id = self.user_id;
+        let action = self.action;
+        let outcome = conn
+            .transaction::<_, anyhow::Error, _>(async move |tc| {
+                // Two players pressing "Rematch" at once is an agreement
+                let accepts = match action {
+                    RematchAction::Accept => true,
+                    RematchAction::Offer => Rematch::for_game(game.id, tc)
+                        .await?
+                        .is_some_and(|rematch| rematch.offered_by != user_id),
+                    RematchAction::Decline => false,
+                };
+                if accepts {
+                    let (new_game, deleted) = Rematch::accept(game, user_id, tc).await?;
+                    let response = GameResponse::from_model(&new_game, tc).await?;
+                    return Ok(Outcome::Started(new_game, response, deleted));
+                }
+                match action {
+                    RematchAction::Decline => {
+                        Rematch::decline(game, user_id, tc).await?;
+                        Ok(Outcome::Status(RematchStatus::Declined { by: user_id }))
+                    }
+                    _ => {
+                        Rematch::offer(game, user_id, tc).await?;
+                        Ok(Outcome::Status(RematchStatus::Offered { by: user_id }))
+                    }
+                }
+            })
+            .await?;
+
+        let old_response = self.data.get_or_build_response(game, &mut conn).await?;
+        let mut messages = Vec::new();
+        match outcome {
+            Outcome::Status(status) => {
+                if let RematchStatus::Offered { .. } = status {
+                    notify_game_control(
+                        self.opponent_id(),
+                        self.username.clone(),
+                        game.nanoid.clone(),
+                        GameControlKind::RematchOffered,
+                        GameSpeed::from_base_increment(game.time_base, game.time_increment),
+                    );
+                }
+                self.push_to_players(&mut messages, GameReaction::Rematch(status), &old_response);
+            }
+            Outcome::Started(new_game, new_response, deleted) => {
+                let speed =
+                    GameSpeed::from_base_increment(new_game.time_base, new_game.time_increment);
+                notify(Event::GameStarted {
+                    recipient: self.opponent_id(),
+                    opponent: self.username.clone(),
+                    game_nanoid: new_game.nanoid.clone(),
+                    time_control: time_control_label(
+                        speed,
+                        new_game.time_base,
+                        new_game.time_increment,
+                    ),
+                    speed,
+                });
+                if let Err(e) = notify_followers_game_started(&new_game, &mut conn).await {
+                    log::error!("notify followers {}: {e}", new_game.nanoid);
+                }
+                self.push_to_players(&mut messages, GameReaction::New, &new_response);
+                self.push_to_players(
+                    &mut messages,
+                    GameReaction::Rematch(RematchStatus::Started(GameId(new_game.nanoid))),
+                    &old_response,
+                );
+                for challenge_id in deleted {
+                    messages.push(InternalServerMessage {
+                        destination: MessageDestination::Global,
+                        message: ServerMessage::Challenge(ChallengeUpdate::Removed(challenge_id)),
+                    });
+                }
+            }
+        }
+        Ok(messages)
+    }
+
+    fn opponent_id(&self) -> Uuid {
+        if self.user_id == self.game.white_id {
+            self.game.black_id
+        } else {
+            self.game.white_id
+        }
+    }
+
+    fn push_to_players(
+        &self,
+        messages: &mut Vec<InternalServerMessage>,
+        game_action: GameReaction,
+        game: &GameResponse,
+    ) {
+        for player in [self.game.white_id, self.game.black_id] {
+            messages.push(InternalServerMessage {
+                destination: MessageDestination::User(player),
+                message: ServerMessage::Game(Box::new(GameUpdate::Reaction(GameActionResponse {
+                    game_action: game_action.clone(),
+                    game: game.clone(),
+                    game_id: game.game_id.clone(),
+                    user_id: self.user_id,
+                    username: self.username.clone(),
+                }))),
+            });
+        }
+    }
+}
+
+enum Outcome {
+    Status(RematchStatus),
+    Started(Game, GameResponse, Vec<ChallengeId>),
+}
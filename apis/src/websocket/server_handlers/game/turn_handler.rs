@@ -1,7 +1,8 @@
+use super::premove_handler::premove_message;
 use crate::websocket::{WebsocketData, WsHub};
 
 use crate::{
-    common::{GameActionResponse, GameReaction, GameUpdate, ServerMessage},
+    common::{GameActionResponse, GameReaction, GameUpdate, PremoveStatus, ServerMessage},
     notifications::{game_end_reason_from, notify_game_ended, notify_your_turn, GameEndReason},
     websocket::messages::{
         GameFinalize,
@@ -14,11 +15,11 @@ use crate::{
 use anyhow::Result;
 use db_lib::{
     get_conn,
-    models::{Game, User},
+    models::{ConditionalMove, Game, TimeSpent, User},
     DbPool,
 };
 use diesel_async::AsyncConnection;
-use hive_lib::{GameError, State, Turn};
+use hive_lib::{GameError, GameStatus, Piece, State, Turn};
 use shared_types::{GameId, TimeMode};
 use std::sync::Arc;
 use uuid::Uuid;
@@ -98,11 +99,71 @@ impl TurnHandler {
             0.0
         };
 
-        let game = conn
+        let correspondence = self.game.time_mode == TimeMode::Correspondence.to_string();
+        let premove = if self.game.time_mode == TimeMode::RealTime.to_string() {
+            self.hub.pending_premove(
+                &GameId(self.game.nanoid.clone()),
+                self.game.not_current_player_id(),
+                self.game.turn,
+            )
+        } else {
+            None
+        };
+        let queued = premove.clone();
+        let (played, replied, discarded) = conn
             .transaction::<_, anyhow::Error, _>(async move |tc| {
-                Ok(self.game.update_gamestate(&state, comp, tc).await?)
+                let played = self
+                    .game
+                    .update_gamestate(&state, TimeSpent::Measured(comp), tc)
+                    .await?;
+                if correspondence {
+                    let Some(reply) = ConditionalMove::take_reply(&played, tc).await? else {
+                        return Ok((played, None, None));
+                    };
+                    return match conditional_reply(&state, &reply) {
+                        Ok((next, turn)) => {
+                            let replied = played
+                                .update_gamestate(&next, TimeSpent::Measured(0.0), tc)
+                                .await?;
+                            Ok((played, Some((replied, turn)), None))
+                        }
+                        Err(err) => {
+                            log::warn!("conditional reply game={} failed: {err}", played.nanoid);
+                            Ok((played, None, None))
+                        }
+                    };
+                }
+                let Some(premove) = premove else {
+                    return Ok((played, None, None));
+                };
+                if played.finished {
+                    return Ok((played, None, None));
+                }
+                let Turn::Move(piece, position) = premove.turn else {
+                    return Ok((played, None, Some(premove.turn)));
+                };
+                let mut next = state.clone();
+                if next.play_turn_from_position(piece, position).is_err() {
+                    return Ok((played, None, Some(premove.turn)));
+                }
+                let replied = played.update_gamestate(&next, TimeSpent::Zero, tc).await?;
+                Ok((played, Some((replied, premove.turn)), None))
             })
             .await?;
+        // Only now that the move is committed is the premove used up
+        if let Some(premove) = &queued {
+            self.hub
+                .clear_premove(&GameId(self.game.nanoid.clone()), premove);
+        }
+        // The player who answered automatically, if anyone did
+        let replier = match replied {
+            Some(_) => Some(User::find_by_uuid(&played.current_player_id, &mut conn).await?),
+            None => None,
+        };
+        let game = replied
+            .as_ref()
+            .map_or(&played, |(replied, _)| replied)
+            .clone();
 
         if !game.finished {
             let opponent_id = game.not_current_player_id();
@@ -125,19 +186,44 @@ impl TurnHandler {
             destination: MessageDestination::User(game.current_player_id),
             message: ServerMessage::Game(Box::new(GameUpdate::Urgent(game_responses))),
         });
-        let response = self.data.get_or_build_response(&game, &mut conn).await?;
-        let reactions = vec![Reaction {
+        let played_response = self.data.get_or_build_response(&played, &mut conn).await?;
+        let mut reactions = vec![Reaction {
             game_id: GameId(game.nanoid.to_owned()),
             white_id: game.white_id,
             black_id: game.black_id,
             gar: GameActionResponse {
                 game_id: GameId(game.nanoid.to_owned()),
-                game: (*response).clone(),
+                game: (*played_response).clone(),
                 game_action: GameReaction::Turn(self.turn.clone()),
                 user_id: self.user_id.to_owned(),
                 username: self.username.to_owned(),
             },
         }];
+        let response = self.data.get_or_build_response(&game, &mut conn).await?;
+        if let (Some((_, turn)), Some(replier)) = (replied, replier) {
+            reactions.push(Reaction {
+                game_id: GameId(game.nanoid.to_owned()),
+                white_id: game.white_id,
+                black_id: game.black_id,
+                gar: GameActionResponse {
+                    game_id: GameId(game.nanoid.to_owned()),
+                    game: (*response).clone(),
+                    game_action: GameReaction::Turn(turn),
+                    user_id: replier.id,
+                    username: replier.username,
+                },
+            });
+        }
+        if let Some(turn) = discarded {
+            let premover = played.current_player_id;
+            let premover_name = User::find_by_uuid(&premover, &mut conn).await?.username;
+            messages.push(premove_message(
+                premover,
+                &premover_name,
+                PremoveStatus::Discarded(turn),
+                (*played_response).clone(),
+            ));
+        }
         // TODO: Just add the few top games and keep them rated
         if response.time_mode == TimeMode::RealTime
             && self
@@ -190,3 +276,21 @@ impl TurnHandler {
         Ok(())
     }
 }
+
+/// Plays a stored conditional reply on top of the opponent's move, passing
+/// for the next player when they are shut out, like a live move would.
+fn conditional_reply(state: &State, reply: &(String, String)) -> Result<(State, Turn)> {
+    let mut next = state.clone();
+    next.play_turn_from_history(&reply.0, &reply.1)?;
+    let piece: Piece = reply.0.parse()?;
+    let position = next
+        .board
+        .position_of_piece(piece)
+        .ok_or_else(|| anyhow::anyhow!("{piece} is not on the board"))?;
+    if next.game_status == GameStatus::InProgress
+        && next.board.is_shutout(next.turn_color, next.game_type)
+    {
+        next.play_turn_from_history("pass", "")?;
+    }
+    Ok((next, Turn::Move(piece, position)))
+}
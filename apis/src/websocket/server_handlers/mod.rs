@@ -1,3 +1,4 @@
+pub mod analysis;
 pub mod challenges;
 pub mod chat;
 pub mod game;
@@ -5,5 +6,6 @@ pub mod oauth;
 pub mod request_handler;
 pub mod resync;
 pub mod schedules;
+pub mod seeks;
 pub mod tournaments;
 pub mod user_status;
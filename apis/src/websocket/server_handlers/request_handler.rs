@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use super::{
+    analysis::AnalysisHandler,
     challenges::handler::ChallengeHandler,
     chat::{
         handler::{ChatHandler, ChatHandlerError},
@@ -10,6 +11,7 @@ use super::{
     oauth::handler::OauthHandler,
     resync::ResyncHandler,
     schedules::ScheduleHandler,
+    seeks::SeekHandler,
     tournaments::handler::TournamentHandler,
     user_status::handler::UserStatusHandler,
 };
@@ -20,15 +22,15 @@ use crate::{
         authorize_chat_send,
         ChatAccessError,
     },
-    common::{ClientRequest, GameAction, ServerMessage},
+    common::{AnalysisAction, ClientRequest, GameAction, ServerMessage},
     websocket::{
         messages::{AuthError, HandlerOutput, InternalServerMessage, MessageDestination, SocketTx},
         WebsocketData,
         WsHub,
     },
 };
-use db_lib::{DbConn, DbPool};
-use shared_types::{normalize_chat_message, ConversationKey, SimpleUser};
+use db_lib::{db_error::DbError, DbConn, DbPool};
+use shared_types::{normalize_chat_message, ConversationKey, SimpleUser, TournamentJoinError};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -56,6 +58,17 @@ impl std::fmt::Display for RequestHandlerError {
 }
 
 impl RequestHandlerError {
+    /// The typed reason a tournament registration was refused, if that is what failed.
+    pub fn tournament_join_error(&self) -> Option<&TournamentJoinError> {
+        match self {
+            Self::InternalError(error) => match error.downcast_ref::<DbError>() {
+                Some(DbError::TournamentJoin(join_error)) => Some(join_error),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     pub fn user_safe_reason(&self) -> String {
         match self {
             Self::InternalError(_) => "Unable to complete request".to_string(),
@@ -140,6 +153,33 @@ impl RequestHandler {
 
     pub async fn handle(&self) -> Result<HandlerOutput> {
         let output: HandlerOutput = match self.command.clone() {
+            ClientRequest::Analysis(action) => {
+                match action {
+                    AnalysisAction::Join(_) | AnalysisAction::Leave(_) => {}
+                    AnalysisAction::Chat { .. } => {
+                        self.ensure_auth()?;
+                        self.hub
+                            .check_chat_send(self.user_id, self.received_from.socket_id)
+                            .map_err(RequestHandlerError::RateLimited)?;
+                    }
+                    AnalysisAction::Host(_) | AnalysisAction::Sync { .. } => {
+                        self.ensure_auth()?;
+                        self.hub
+                            .check_analysis_update(self.received_from.socket_id)
+                            .map_err(RequestHandlerError::RateLimited)?;
+                    }
+                    AnalysisAction::OpenMoves { .. } => self.ensure_auth()?,
+                }
+                AnalysisHandler::new(
+                    action,
+                    self.user_id,
+                    &self.username,
+                    &self.received_from,
+                    self.hub.clone(),
+                )
+                .handle();
+                HandlerOutput::empty()
+            }
             ClientRequest::LinkDiscord => {
                 self.ensure_auth()?;
                 OauthHandler::new(self.user_id).handle().await?.into()
@@ -233,7 +273,10 @@ impl RequestHandler {
                 game_id,
             } => {
                 match game_action {
-                    GameAction::Turn(_) | GameAction::Control(_) => self.ensure_auth()?,
+                    GameAction::Turn(_)
+                    | GameAction::Control(_)
+                    | GameAction::Premove(_)
+                    | GameAction::Rematch(_) => self.ensure_auth()?,
                     _ => {}
                 };
                 GameActionHandler::new(
@@ -251,7 +294,7 @@ impl RequestHandler {
             }
             ClientRequest::Challenge(challenge_action) => {
                 self.ensure_auth()?;
-                ChallengeHandler::new(
+                let messages = ChallengeHandler::new(
                     challenge_action,
                     &self.username,
                     self.user_id,
@@ -260,8 +303,22 @@ impl RequestHandler {
                 )
                 .await?
                 .handle()
-                .await?
-                .into()
+                .await?;
+                self.hub.stop_seeks_for_new_games(&messages);
+                messages.into()
+            }
+            ClientRequest::Seek(seek_action) => {
+                self.ensure_auth()?;
+                SeekHandler::new(
+                    seek_action,
+                    self.user_id,
+                    self.received_from.socket_id,
+                    self.hub.clone(),
+                    &self.pool,
+                )
+                .handle()
+                .await?;
+                HandlerOutput::empty()
             }
             ClientRequest::NotificationSeen { game_id } => {
                 self.ensure_auth()?;
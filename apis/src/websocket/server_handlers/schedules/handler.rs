@@ -1,6 +1,6 @@
 use crate::{
     common::{
-        ScheduleAction::{self, Accept, Cancel, Propose, TournamentOwn, TournamentPublic},
+        ScheduleAction::{self, Accept, Cancel, Propose, Suggest, TournamentOwn, TournamentPublic},
         ScheduleUpdate,
         ServerMessage,
     },
@@ -10,8 +10,9 @@ use crate::{
 };
 use anyhow::Result;
 use db_lib::{
+    db_error::DbError,
     get_conn,
-    models::{Game, NewSchedule, Schedule, Tournament},
+    models::{Availability, Game, NewSchedule, Schedule, Tournament},
     DbPool,
 };
 use diesel_async::AsyncConnection;
@@ -85,6 +86,24 @@ impl ScheduleHandler {
                         ];
                         (ScheduleUpdate::Proposed(schedule_response), destinations)
                     }
+                    Suggest(game_id) => {
+                        let game = Game::find_by_game_id(&game_id, tc).await?;
+                        if !game.user_is_player(self.user_id) {
+                            return Err(DbError::Unauthorized.into());
+                        }
+                        let Some(tournament_id) = game.tournament_id else {
+                            return Err(DbError::InvalidAction {
+                                info: String::from("Only tournament games can be scheduled"),
+                            }
+                            .into());
+                        };
+                        let tournament = Tournament::find(tournament_id, tc).await?;
+                        let slots = Availability::suggest_for_game(&game, &tournament, tc).await?;
+                        (
+                            ScheduleUpdate::Suggestions(game_id, slots),
+                            vec![MessageDestination::User(self.user_id)],
+                        )
+                    }
                     TournamentPublic(id) => {
                         let tournament = Tournament::from_nanoid(&id.to_string(), tc).await?;
                         let game_ids =
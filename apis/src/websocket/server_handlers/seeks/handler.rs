@@ -0,0 +1,74 @@
+use crate::{
+    common::{SeekAction, SeekUpdate},
+    websocket::{seek_pool::Seeker, WsHub},
+};
+use anyhow::Result;
+use db_lib::{get_conn, helpers::block_partner_ids, models::Rating, DbPool};
+use shared_types::SeekDetails;
+use std::{sync::Arc, time::Instant};
+use uuid::Uuid;
+
+pub struct SeekHandler {
+    action: SeekAction,
+    user_id: Uuid,
+    socket_id: Uuid,
+    hub: Arc<WsHub>,
+    pool: DbPool,
+}
+
+impl SeekHandler {
+    pub fn new(
+        action: SeekAction,
+        user_id: Uuid,
+        socket_id: Uuid,
+        hub: Arc<WsHub>,
+        pool: &DbPool,
+    ) -> Self {
+        Self {
+            action,
+            user_id,
+            socket_id,
+            hub,
+            pool: pool.clone(),
+        }
+    }
+
+    pub async fn handle(&self) -> Result<()> {
+        match self.action {
+            SeekAction::Join(details) => self.join(details).await,
+            SeekAction::Cancel => {
+                self.hub.stop_seek(self.user_id);
+                Ok(())
+            }
+        }
+    }
+
+    async fn join(&self, details: SeekDetails) -> Result<()> {
+        details.validate()?;
+        let mut conn = get_conn(&self.pool).await?;
+        let rating = Rating::for_uuid(
+            &self.user_id,
+            &details.speed(),
+            details.variant_class(),
+            &mut conn,
+        )
+        .await?
+        .rating;
+        let blocked = block_partner_ids(&mut conn, self.user_id).await?;
+        drop(conn);
+
+        self.hub.seeks.join(Seeker {
+            user_id: self.user_id,
+            socket_id: self.socket_id,
+            details,
+            rating,
+            blocked: blocked.into_iter().collect(),
+            since: Instant::now(),
+        });
+        // Announce the seek before pairing so a match's Stopped arrives last.
+        self.hub
+            .send_seek_update(self.user_id, SeekUpdate::Seeking(details));
+        self.hub.pair_seeks().await;
+        Ok(())
+    }
+}
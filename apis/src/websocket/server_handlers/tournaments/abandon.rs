@@ -17,10 +17,18 @@ use hive_lib::GameControl;
 use shared_types::{GameId, TournamentGameResult, TournamentId};
 use uuid::Uuid;
 
+pub enum Departure {
+    /// Resigns the player's unfinished games.
+    Abandon,
+    /// Forfeits the remaining games and keeps the player out of later rounds.
+    Withdraw,
+}
+
 pub struct AbandonHandler {
     tournament_id: TournamentId,
     user_id: Uuid,
     username: String,
+    departure: Departure,
     pool: DbPool,
 }
 
@@ -29,26 +37,30 @@ impl AbandonHandler {
         tournament_id: TournamentId,
         user_id: Uuid,
         username: String,
+        departure: Departure,
         pool: &DbPool,
     ) -> Self {
         Self {
             tournament_id,
             user_id,
             username,
+            departure,
             pool: pool.clone(),
         }
     }
 
     pub async fn handle(&self) -> Result<HandlerOutput> {
-        // WARN: This currently only works for one round tournaments. For all other tournaments we
-        // need to also remove the player from players somehow. So that no future matches are
-        // scheduled against them.
+        // WARN: Abandoning leaves the player in future pairings, Departure::Withdraw takes them
+        // out of later rounds.
         let mut conn = get_conn(&self.pool).await?;
         let mut messages = Vec::new();
         let abandoned = conn
             .transaction::<_, DbError, _>(async move |tc| {
                 let mut abandoned = Vec::new();
                 let tournament = Tournament::find_by_tournament_id(&self.tournament_id, tc).await?;
+                if let Departure::Withdraw = self.departure {
+                    return tournament.withdraw(&self.user_id, tc).await;
+                }
                 // Lock all target games before rating updates so abandon cannot hold rating
                 // locks from one game while waiting on a later game row.
                 for game in tournament
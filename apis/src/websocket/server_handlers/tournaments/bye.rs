@@ -0,0 +1,60 @@
+use crate::{
+    common::{ServerMessage, TournamentUpdate},
+    websocket::messages::{InternalServerMessage, MessageDestination},
+};
+use anyhow::Result;
+use db_lib::{get_conn, models::Tournament, DbPool};
+use diesel_async::AsyncConnection;
+use shared_types::TournamentId;
+use uuid::Uuid;
+
+pub enum ByeRequest {
+    Request(i32),
+    Cancel(i32),
+}
+
+pub struct ByeHandler {
+    tournament_id: TournamentId,
+    user_id: Uuid,
+    request: ByeRequest,
+    pool: DbPool,
+}
+
+impl ByeHandler {
+    pub fn new(
+        tournament_id: TournamentId,
+        user_id: Uuid,
+        request: ByeRequest,
+        pool: &DbPool,
+    ) -> Self {
+        Self {
+            tournament_id,
+            user_id,
+            request,
+            pool: pool.clone(),
+        }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<InternalServerMessage>> {
+        let mut conn = get_conn(&self.pool).await?;
+        let tournament = Tournament::find_by_tournament_id(&self.tournament_id, &mut conn).await?;
+        let tournament = conn
+            .transaction::<_, anyhow::Error, _>(async move |tc| {
+                Ok(match self.request {
+                    ByeRequest::Request(round) => {
+                        tournament.request_bye(&self.user_id, round, tc).await?
+                    }
+                    ByeRequest::Cancel(round) => {
+                        tournament.cancel_bye(&self.user_id, round, tc).await?
+                    }
+                })
+            })
+            .await?;
+        Ok(vec![InternalServerMessage {
+            destination: MessageDestination::Global,
+            message: ServerMessage::Tournament(TournamentUpdate::StateChanged(TournamentId(
+                tournament.nanoid,
+            ))),
+        }])
+    }
+}
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use super::{
-    abandon::AbandonHandler,
+    abandon::{AbandonHandler, Departure},
     adjudicate_result::AdjudicateResultHandler,
     bulk_adjudicate::{BulkAdjudicateHandler, BulkAdjudication},
+    bye::{ByeHandler, ByeRequest},
     create::CreateHandler,
     delete::DeleteHandler,
     finish::FinishHandler,
@@ -14,7 +15,9 @@ use super::{
     join::JoinHandler,
     kick::KickHandler,
     leave::LeaveHandler,
+    pause::PauseHandler,
     progress_to_next_round::SwissRoundHandler,
+    series::{SeriesHandler, SeriesMembership},
     start::StartHandler,
 };
 use crate::{
@@ -147,11 +150,53 @@ impl TournamentHandler {
                     tournament_id,
                     self.user_id,
                     self.username.clone(),
+                    Departure::Abandon,
                     &self.pool,
                 )
                 .handle()
                 .await?
             }
+            TournamentAction::Withdraw(tournament_id) => {
+                AbandonHandler::new(
+                    tournament_id,
+                    self.user_id,
+                    self.username.clone(),
+                    Departure::Withdraw,
+                    &self.pool,
+                )
+                .handle()
+                .await?
+            }
+            TournamentAction::Pause(tournament_id) => {
+                PauseHandler::new(tournament_id, self.user_id, true, &self.pool)
+                    .handle()
+                    .await?
+                    .into()
+            }
+            TournamentAction::Resume(tournament_id) => {
+                PauseHandler::new(tournament_id, self.user_id, false, &self.pool)
+                    .handle()
+                    .await?
+                    .into()
+            }
+            TournamentAction::RequestBye(tournament_id, round) => ByeHandler::new(
+                tournament_id,
+                self.user_id,
+                ByeRequest::Request(round),
+                &self.pool,
+            )
+            .handle()
+            .await?
+            .into(),
+            TournamentAction::CancelBye(tournament_id, round) => ByeHandler::new(
+                tournament_id,
+                self.user_id,
+                ByeRequest::Cancel(round),
+                &self.pool,
+            )
+            .handle()
+            .await?
+            .into(),
             TournamentAction::Finish(tournament_id) => {
                 FinishHandler::new(tournament_id, self.user_id, &self.pool)
                     .handle()
@@ -164,6 +209,24 @@ impl TournamentHandler {
                     .await?
                     .into()
             }
+            TournamentAction::AddToSeries(tournament_id, series_id) => SeriesHandler::new(
+                tournament_id,
+                self.user_id,
+                SeriesMembership::Add(series_id),
+                &self.pool,
+            )
+            .handle()
+            .await?
+            .into(),
+            TournamentAction::RemoveFromSeries(tournament_id) => SeriesHandler::new(
+                tournament_id,
+                self.user_id,
+                SeriesMembership::Remove,
+                &self.pool,
+            )
+            .handle()
+            .await?
+            .into(),
         };
         // Invalidate cached recipients when an action changes membership or
         // deletes the tournament. The next dispatch rebuilds the entry.
@@ -189,7 +252,15 @@ impl TournamentHandler {
             | TournamentAction::InvitationCreate(_, _)
             | TournamentAction::InvitationDecline(_)
             | TournamentAction::InvitationRetract(_, _)
-            | TournamentAction::ProgressToNextRound(_) => None,
+            | TournamentAction::ProgressToNextRound(_)
+            | TournamentAction::AddToSeries(_, _)
+            | TournamentAction::RemoveFromSeries(_)
+            // Withdrawn players keep their seat for standings and chat.
+            | TournamentAction::Withdraw(_)
+            | TournamentAction::Pause(_)
+            | TournamentAction::Resume(_)
+            | TournamentAction::RequestBye(_, _)
+            | TournamentAction::CancelBye(_, _) => None,
         };
         if let Some(id) = invalidate_id {
             self.hub.invalidate_tournament_members(id);
@@ -3,7 +3,11 @@ use crate::{
     websocket::messages::{InternalServerMessage, MessageDestination},
 };
 use anyhow::Result;
-use db_lib::{get_conn, models::Tournament, DbPool};
+use db_lib::{
+    get_conn,
+    models::{Registration, Tournament},
+    DbPool,
+};
 use diesel_async::AsyncConnection;
 use shared_types::TournamentId;
 use uuid::Uuid;
@@ -26,20 +30,25 @@ impl JoinHandler {
     pub async fn handle(&self) -> Result<Vec<InternalServerMessage>> {
         let mut conn = get_conn(&self.pool).await?;
         let tournament = Tournament::find_by_tournament_id(&self.tournament_id, &mut conn).await?;
-        let tournament = conn
+        let registration = conn
             .transaction::<_, anyhow::Error, _>(async move |tc| {
                 Ok(tournament.join(&self.user_id, tc).await?)
             })
             .await?;
-        let response = TournamentId(tournament.nanoid.clone());
+        let update = match registration {
+            Registration::Joined => TournamentUpdate::Joined(self.tournament_id.clone()),
+            Registration::Waitlisted => TournamentUpdate::Waitlisted(self.tournament_id.clone()),
+        };
         Ok(vec![
             InternalServerMessage {
                 destination: MessageDestination::User(self.user_id),
-                message: ServerMessage::Tournament(TournamentUpdate::Joined(response.clone())),
+                message: ServerMessage::Tournament(update),
             },
             InternalServerMessage {
                 destination: MessageDestination::Global,
-                message: ServerMessage::Tournament(TournamentUpdate::StateChanged(response)),
+                message: ServerMessage::Tournament(TournamentUpdate::StateChanged(
+                    self.tournament_id.clone(),
+                )),
             },
         ])
     }
@@ -1,4 +1,4 @@
-use super::membership_removed_messages;
+use super::{membership_removed_messages, promoted_messages};
 use crate::websocket::messages::InternalServerMessage;
 use anyhow::Result;
 use db_lib::{get_conn, models::Tournament, DbPool};
@@ -25,14 +25,16 @@ impl LeaveHandler {
         let mut conn = get_conn(&self.pool).await?;
         let tournament = Tournament::find_by_tournament_id(&self.tournament_id, &mut conn).await?;
 
-        let tournament = conn
+        let (tournament, promoted) = conn
             .transaction::<_, anyhow::Error, _>(async move |tc| {
-                Ok(tournament.leave(&self.user_id, tc).await?)
+                let tournament = tournament.leave(&self.user_id, tc).await?;
+                let promoted = tournament.promote_from_waitlist(tc).await?;
+                Ok((tournament, promoted))
             })
             .await?;
-        Ok(membership_removed_messages(
-            TournamentId(tournament.nanoid.clone()),
-            self.user_id,
-        ))
+        let tournament_id = TournamentId(tournament.nanoid.clone());
+        let mut messages = promoted_messages(&tournament_id, &promoted);
+        messages.extend(membership_removed_messages(tournament_id, self.user_id));
+        Ok(messages)
     }
 }
@@ -1,6 +1,7 @@
 pub mod abandon;
 pub mod adjudicate_result;
 pub mod bulk_adjudicate;
+pub mod bye;
 pub mod create;
 pub mod delete;
 pub mod finish;
@@ -12,7 +13,9 @@ pub mod invitation_retract;
 pub mod join;
 pub mod kick;
 pub mod leave;
+pub mod pause;
 pub mod progress_to_next_round;
+pub mod series;
 pub mod start;
 
 use crate::{
@@ -37,3 +40,17 @@ fn membership_removed_messages(
         },
     ]
 }
+
+/// Tells players who moved up from the waitlist that they now have a seat.
+fn promoted_messages(
+    tournament_id: &TournamentId,
+    promoted: &[Uuid],
+) -> Vec<InternalServerMessage> {
+    promoted
+        .iter()
+        .map(|user_id| InternalServerMessage {
+            destination: MessageDestination::User(*user_id),
+            message: ServerMessage::Tournament(TournamentUpdate::Promoted(tournament_id.clone())),
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use crate::{
+    common::{ServerMessage, TournamentUpdate},
+    websocket::messages::{InternalServerMessage, MessageDestination, TournamentAudience},
+};
+use anyhow::Result;
+use db_lib::{get_conn, models::Tournament, DbPool};
+use diesel_async::AsyncConnection;
+use shared_types::TournamentId;
+use uuid::Uuid;
+
+pub struct PauseHandler {
+    tournament_id: TournamentId,
+    user_id: Uuid,
+    paused: bool,
+    pool: DbPool,
+}
+
+impl PauseHandler {
+    pub fn new(tournament_id: TournamentId, user_id: Uuid, paused: bool, pool: &DbPool) -> Self {
+        Self {
+            tournament_id,
+            user_id,
+            paused,
+            pool: pool.clone(),
+        }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<InternalServerMessage>> {
+        let mut conn = get_conn(&self.pool).await?;
+        let tournament = Tournament::find_by_tournament_id(&self.tournament_id, &mut conn).await?;
+        let tournament = conn
+            .transaction::<_, anyhow::Error, _>(async move |tc| {
+                Ok(if self.paused {
+                    tournament.pause(&self.user_id, tc).await?
+                } else {
+                    tournament.resume(&self.user_id, tc).await?
+                })
+            })
+            .await?;
+        let tournament_id = TournamentId(tournament.nanoid);
+        let update = if self.paused {
+            TournamentUpdate::Paused(tournament_id.clone())
+        } else {
+            TournamentUpdate::Resumed(tournament_id.clone())
+        };
+        Ok(vec![
+            InternalServerMessage {
+                destination: MessageDestination::Tournament {
+                    tournament_id: tournament_id.clone(),
+                    audience: TournamentAudience::Updates,
+                },
+                message: ServerMessage::Tournament(update),
+            },
+            InternalServerMessage {
+                destination: MessageDestination::Global,
+                message: ServerMessage::Tournament(TournamentUpdate::StateChanged(tournament_id)),
+            },
+        ])
+    }
+}
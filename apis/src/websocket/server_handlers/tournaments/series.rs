@@ -0,0 +1,65 @@
+use crate::{
+    common::{ServerMessage, TournamentUpdate},
+    websocket::messages::{InternalServerMessage, MessageDestination},
+};
+use anyhow::Result;
+use db_lib::{
+    get_conn,
+    models::{Tournament, TournamentSeries},
+    DbPool,
+};
+use diesel_async::AsyncConnection;
+use shared_types::{TournamentId, TournamentSeriesId};
+use uuid::Uuid;
+
+pub enum SeriesMembership {
+    Add(TournamentSeriesId),
+    Remove,
+}
+
+pub struct SeriesHandler {
+    tournament_id: TournamentId,
+    user_id: Uuid,
+    membership: SeriesMembership,
+    pool: DbPool,
+}
+
+impl SeriesHandler {
+    pub fn new(
+        tournament_id: TournamentId,
+        user_id: Uuid,
+        membership: SeriesMembership,
+        pool: &DbPool,
+    ) -> Self {
+        Self {
+            tournament_id,
+            user_id,
+            membership,
+            pool: pool.clone(),
+        }
+    }
+
+    pub async fn handle(&self) -> Result<Vec<InternalServerMessage>> {
+        let mut conn = get_conn(&self.pool).await?;
+        let tournament = Tournament::find_by_tournament_id(&self.tournament_id, &mut conn).await?;
+        let tournament = conn
+            .transaction::<_, anyhow::Error, _>(async move |tc| {
+                Ok(match &self.membership {
+                    SeriesMembership::Add(series_id) => {
+                        let series = TournamentSeries::find_by_series_id(series_id, tc).await?;
+                        tournament.add_to_series(&self.user_id, &series, tc).await?
+                    }
+                    SeriesMembership::Remove => {
+                        tournament.remove_from_series(&self.user_id, tc).await?
+                    }
+                })
+            })
+            .await?;
+        Ok(vec![InternalServerMessage {
+            destination: MessageDestination::Global,
+            message: ServerMessage::Tournament(TournamentUpdate::StateChanged(TournamentId(
+                tournament.nanoid,
+            ))),
+        }])
+    }
+}
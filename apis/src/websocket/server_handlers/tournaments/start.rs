@@ -1,6 +1,6 @@
 use crate::{
     common::{GameActionResponse, GameReaction, GameUpdate, ServerMessage, TournamentUpdate},
-    notifications::{notify, Event},
+    notifications::{notify, notify_followers_tournament_started, Event},
     responses::GameResponse,
     websocket::messages::{InternalServerMessage, MessageDestination, TournamentAudience},
 };
@@ -39,6 +39,12 @@ impl StartHandler {
             })
             .await?;
 
+        let player_ids = players.iter().map(|player| player.id).collect::<Vec<_>>();
+        if let Err(e) =
+            notify_followers_tournament_started(&tournament, &player_ids, &mut conn).await
+        {
+            log::error!("notify followers {}: {e}", tournament.nanoid);
+        }
         for player in players {
             notify(Event::TournamentStarted {
                 recipient: player.id,
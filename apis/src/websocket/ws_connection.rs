@@ -11,6 +11,7 @@ use crate::common::{
     GameAction,
     ServerResult,
     SubscriptionError,
+    TournamentAction,
 };
 use actix_ws::{AggregatedMessage, AggregatedMessageStream, Session};
 use bytes::Bytes;
@@ -265,6 +266,17 @@ fn external_server_error(
                 error: subscription_error,
             }
         }
+        ClientRequest::Tournament(TournamentAction::Join(tournament_id)) => {
+            match error.tournament_join_error() {
+                Some(join_error) => ExternalServerError::TournamentJoin {
+                    tournament_id: tournament_id.clone(),
+                    error: join_error.clone(),
+                },
+                None => ExternalServerError::Request {
+                    reason: error.user_safe_reason(),
+                },
+            }
+        }
         _ => ExternalServerError::Request {
             reason: error.user_safe_reason(),
         },
@@ -290,12 +302,12 @@ fn should_log_request_error(err: &RequestHandlerError) -> bool {
             | RequestHandlerError::Forbidden
             | RequestHandlerError::RateLimited(_)
             | RequestHandlerError::ChatClientIdConflict
-    )
+    ) && err.tournament_join_error().is_none()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::external_server_error;
+    use super::{external_server_error, should_log_request_error};
     use crate::{
         common::{
             ChatSendError,
@@ -304,13 +316,15 @@ mod tests {
             ExternalServerError,
             SubscriptionAttempt,
             SubscriptionError,
+            TournamentAction,
         },
         websocket::{
             messages::AuthError,
             server_handlers::{chat::limits::ChatLimitError, request_handler::RequestHandlerError},
         },
     };
-    use shared_types::{ConversationKey, GameId};
+    use db_lib::db_error::DbError;
+    use shared_types::{ConversationKey, GameId, TournamentId, TournamentJoinError};
     use std::time::Duration;
     use uuid::Uuid;
 
@@ -382,6 +396,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn tournament_join_refusal_is_typed_on_the_wire() {
+        let tournament_id = TournamentId("open-event".to_string());
+        let request = ClientRequest::Tournament(TournamentAction::Join(tournament_id.clone()));
+        let error = RequestHandlerError::InternalError(anyhow::Error::new(
+            DbError::TournamentJoin(TournamentJoinError::BotsNotAllowed),
+        ));
+
+        assert_eq!(
+            external_server_error(&request, &error),
+            ExternalServerError::TournamentJoin {
+                tournament_id,
+                error: TournamentJoinError::BotsNotAllowed,
+            },
+        );
+        assert!(!should_log_request_error(&error));
+    }
+
     #[test]
     fn client_id_conflict_remains_typed_on_the_wire() {
         let key = ConversationKey::direct(Uuid::new_v4());
@@ -1,5 +1,7 @@
 use super::{
+    analysis_rooms::AnalysisRooms,
     messages::{GameSpectatorAudience, MessageDestination, SocketTx, TournamentAudience},
+    seek_pool::SeekPool,
     server_handlers::chat::limits::{ChatLimitError, ChatRateLimits},
     telemetry::{
         read_proc_vm_bytes,
@@ -12,7 +14,7 @@ use super::{
     WebsocketData,
 };
 use crate::{
-    common::{GameUpdate, ServerMessage, ServerResult, UserStatus, UserUpdate},
+    common::{GameUpdate, SeekUpdate, ServerMessage, ServerResult, UserStatus, UserUpdate},
     notifications::{notify_game_ended, GameEndReason},
     responses::{HeartbeatResponse, UserResponse},
 };
@@ -27,7 +29,7 @@ use db_lib::{
     DbPool,
     DB_POOL_MAX_SIZE,
 };
-use hive_lib::GameStatus;
+use hive_lib::{GameStatus, Turn};
 use log::error;
 use rand::RngExt;
 use shared_types::{Conclusion, ConversationKey, GameId, SimpleUser, TimeMode, TournamentId};
@@ -106,6 +108,22 @@ pub struct WsHub {
     /// open. Authentication is cached on each socket, so central auth checks
     /// consult this process-local set before accepting user actions.
     revoked_users: DashSet<Uuid>,
+    /// Quick-pairing queues. A seek lives only as long as its socket.
+    pub(in crate::websocket) seeks: SeekPool,
+    /// At most one premove per real-time game, queued by the player waiting
+    /// on their opponent. Evicted on game finalization.
+    premoves: DashMap<GameId, Premove>,
+    /// Live analysis sessions. Each ends when its owner's socket goes away.
+    pub(in crate::websocket) analysis_rooms: AnalysisRooms,
+}
+
+/// A move queued while the opponent is thinking. `queued_at` is the game's
+/// turn at the time, so a takeback in between leaves it stale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(in crate::websocket) struct Premove {
+    pub user_id: Uuid,
+    pub turn: Turn,
+    pub queued_at: i32,
 }
 
 #[derive(Default)]
@@ -328,6 +346,9 @@ impl WsHub {
             last_resync: DashMap::new(),
             chat_limits: ChatRateLimits::default(),
             revoked_users: DashSet::new(),
+            seeks: SeekPool::default(),
+            premoves: DashMap::new(),
+            analysis_rooms: AnalysisRooms::default(),
         })
     }
 
@@ -444,6 +465,8 @@ impl WsHub {
     /// broadcasts Offline to the lobby.
     pub fn on_disconnect(&self, socket_id: Uuid, user: SimpleUser) {
         let user_id = user.user_id;
+        let seek_cancelled = self.seeks.cancel_socket(socket_id);
+        let analysis_departures = self.analysis_rooms.leave_socket((user_id, socket_id));
         // Lock order matches on_connect (membership → sessions): a racing
         // on_connect observing was_empty=true while we're partway through
         // would otherwise leave the active_users gauge overcounted.
@@ -491,6 +514,12 @@ impl WsHub {
         };
 
         self.refresh_membership_gauges();
+        if seek_cancelled {
+            self.send_seek_update(user_id, SeekUpdate::Stopped);
+        }
+        for (session_id, departure) in analysis_departures {
+            self.announce_departure(session_id, departure);
+        }
 
         // Step 3: if this was the last socket and the user hasn't reconnected,
         // broadcast Offline. Re-check sessions after dropping the lock for the
@@ -976,7 +1005,7 @@ impl WsHub {
         }
     }
 
-    fn send_to_user(&self, user_id: &Uuid, dest: DestKind, bytes: &Bytes) {
+    pub(in crate::websocket) fn send_to_user(&self, user_id: &Uuid, dest: DestKind, bytes: &Bytes) {
         if self.is_user_revoked(*user_id) {
             return;
         }
@@ -990,7 +1019,13 @@ impl WsHub {
 
     /// Send to one specific socket. Used for game-scoped dispatch where only the
     /// subscribed socket (not all of the user's tabs) should receive the message.
-    fn send_to_socket(&self, user_id: &Uuid, socket_id: &Uuid, dest: DestKind, bytes: &Bytes) {
+    pub(in crate::websocket) fn send_to_socket(
+        &self,
+        user_id: &Uuid,
+        socket_id: &Uuid,
+        dest: DestKind,
+        bytes: &Bytes,
+    ) {
         if self.is_user_revoked(*user_id) {
             return;
         }
@@ -1096,6 +1131,13 @@ impl WsHub {
         self.chat_limits.check_send(user_id, socket_id)
     }
 
+    pub(in crate::websocket) fn check_analysis_update(
+        &self,
+        socket_id: Uuid,
+    ) -> Result<(), ChatLimitError> {
+        self.chat_limits.check_analysis_update(socket_id)
+    }
+
     /// True iff any socket currently keeps `game_id` eligible for heartbeat
     /// lifecycle work. Used to avoid double-counting finalization if the
     /// dispatcher ran between the heartbeat's snapshot and this loop iteration.
@@ -1185,6 +1227,7 @@ impl WsHub {
 
         self.data.game_response_cache.remove(game_id);
         self.last_tv_broadcast.remove(game_id);
+        self.premoves.remove(game_id);
 
         // games_finalized_total is bumped by `finalize_game`, not here, so
         // tests that exercise on_game_finished standalone don't double-count.
@@ -1216,6 +1259,43 @@ impl WsHub {
         self.data.telemetry.inc_games_finalized();
     }
 
+    /// Queues a premove, replacing whatever the player had queued before.
+    pub(in crate::websocket) fn queue_premove(&self, game_id: &GameId, premove: Premove) {
+        self.premoves.insert(game_id.clone(), premove);
+    }
+
+    /// Returns true if `user_id` had a premove queued on the game.
+    pub(in crate::websocket) fn cancel_premove(&self, game_id: &GameId, user_id: Uuid) -> bool {
+        self.premoves
+            .remove_if(game_id, |_, premove| premove.user_id == user_id)
+            .is_some()
+    }
+
+    /// `user_id`'s premove if it was queued against the opponent's move at
+    /// `turn`. It stays queued until `clear_premove`, so a move that fails to
+    /// commit leaves it in place. A stale one is dropped.
+    pub(in crate::websocket) fn pending_premove(
+        &self,
+        game_id: &GameId,
+        user_id: Uuid,
+        turn: i32,
+    ) -> Option<Premove> {
+        self.premoves.remove_if(game_id, |_, premove| {
+            premove.user_id == user_id && premove.queued_at != turn
+        });
+        self.premoves
+            .get(game_id)
+            .map(|premove| premove.clone())
+            .filter(|premove| premove.user_id == user_id)
+    }
+
+    /// Drops `premove` once it was played or discarded, unless the player
+    /// replaced it in the meantime.
+    pub(in crate::websocket) fn clear_premove(&self, game_id: &GameId, premove: &Premove) {
+        self.premoves
+            .remove_if(game_id, |_, queued| queued == premove);
+    }
+
     /// Returns true and stamps the game iff a TV broadcast should go out now.
     /// When `is_final` is true (game just finished), always returns true and
     /// clears the throttle entry — the final lobby update must never be dropped.
@@ -1242,6 +1322,25 @@ impl WsHub {
         }
     }
 
+    /// Distinct users with a socket on each game, not counting its two
+    /// players. Games nobody is watching are left out.
+    pub fn spectator_counts(&self, games: &[(GameId, [Uuid; 2])]) -> HashMap<GameId, usize> {
+        let m = self.membership.read().unwrap_or_else(|p| p.into_inner());
+        games
+            .iter()
+            .filter_map(|(game_id, players)| {
+                let sockets = m.fanout.games_sockets.get(game_id)?;
+                let watching = sockets
+                    .iter()
+                    .map(|(user_id, _)| *user_id)
+                    .filter(|user_id| !players.contains(user_id))
+                    .collect::<HashSet<_>>()
+                    .len();
+                (watching > 0).then(|| (game_id.clone(), watching))
+            })
+            .collect()
+    }
+
     /// Returns true iff the specific socket we were spawned for is still in
     /// `sessions`. Used by `load_user_state` to bail when a fast disconnect
     /// (or disconnect+reconnect with a different socket_id) raced our DB load.
@@ -2092,6 +2191,69 @@ mod tests {
         assert!(!hub.should_send_tv(&game_id, false));
     }
 
+    #[tokio::test]
+    async fn premoves_only_fire_on_the_move_they_were_queued_against() {
+        let hub = make_hub().await;
+        let game_id = GameId("premove-game".to_string());
+        let (white, black) = (Uuid::new_v4(), Uuid::new_v4());
+        let premove = |queued_at| Premove {
+            user_id: black,
+            turn: Turn::Shutout,
+            queued_at,
+        };
+
+        hub.queue_premove(&game_id, premove(4));
+        assert!(hub.pending_premove(&game_id, white, 4).is_none());
+        let pending = hub
+            .pending_premove(&game_id, black, 4)
+            .expect("queued premove");
+        // Still queued until the move that played it has committed
+        assert!(hub.pending_premove(&game_id, black, 4).is_some());
+        hub.clear_premove(&game_id, &pending);
+        assert!(hub.pending_premove(&game_id, black, 4).is_none());
+
+        // A premove queued in the meantime survives clearing the old one
+        hub.queue_premove(
+            &game_id,
+            Premove {
+                user_id: white,
+                ..premove(4)
+            },
+        );
+        hub.clear_premove(&game_id, &pending);
+        assert!(hub.pending_premove(&game_id, white, 4).is_some());
+        assert!(hub.cancel_premove(&game_id, white));
+
+        // A takeback in between leaves the premove stale, and looking it up drops it
+        hub.queue_premove(&game_id, premove(4));
+        assert!(hub.pending_premove(&game_id, black, 2).is_none());
+        assert!(hub.pending_premove(&game_id, black, 4).is_none());
+
+        hub.queue_premove(&game_id, premove(6));
+        assert!(!hub.cancel_premove(&game_id, white));
+        assert!(hub.cancel_premove(&game_id, black));
+        assert!(hub.pending_premove(&game_id, black, 6).is_none());
+    }
+
+    #[tokio::test]
+    async fn spectator_counts_skip_players_and_extra_tabs() {
+        let hub = make_hub().await;
+        let game_id = GameId("tv-spectated-game".to_string());
+        let quiet = GameId("tv-quiet-game".to_string());
+        let (white, black, fan) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        hub.subscribe_game_fanout(white, Uuid::new_v4(), &game_id);
+        hub.subscribe_game_fanout(black, Uuid::new_v4(), &game_id);
+        hub.subscribe_game_fanout(fan, Uuid::new_v4(), &game_id);
+        hub.subscribe_game_fanout(fan, Uuid::new_v4(), &game_id);
+
+        let counts = hub.spectator_counts(&[
+            (game_id.clone(), [white, black]),
+            (quiet.clone(), [white, black]),
+        ]);
+        assert_eq!(counts.get(&game_id), Some(&1));
+        assert!(!counts.contains_key(&quiet));
+    }
+
     /// `visibilitychange` + `pageshow` fire in close succession on wake; the
     /// server-side cooldown stops a second snapshot from running for the same
     /// socket. Per-socket so multi-tab users still get fresh data for the tab
@@ -0,0 +1,2 @@
+alter table tournament_series drop column best_of;
+alter table tournament_series drop column placement_points;
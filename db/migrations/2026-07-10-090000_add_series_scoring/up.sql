@@ -0,0 +1,2 @@
+alter table tournament_series add column placement_points integer[] not null default '{10,7,5,3,2,1}';
+alter table tournament_series add column best_of integer;
@@ -0,0 +1,5 @@
+drop table tournaments_waitlist;
+
+alter table tournaments drop column allow_bots;
+alter table tournaments drop column min_account_age_days;
+alter table tournaments drop column min_rated_games;
@@ -0,0 +1,12 @@
+alter table tournaments add column min_rated_games integer not null default 0;
+alter table tournaments add column min_account_age_days integer not null default 0;
+alter table tournaments add column allow_bots boolean not null default true;
+
+create table tournaments_waitlist (
+  tournament_id uuid references tournaments(id) on delete cascade,
+  user_id uuid references users(id),
+  created_at timestamptz not null default now(),
+  primary key(tournament_id, user_id)
+);
+
+create index tournaments_waitlist_order_idx on tournaments_waitlist (tournament_id, created_at);
@@ -0,0 +1,4 @@
+drop table tournaments_byes;
+
+alter table tournaments_users drop column withdrawn_at;
+alter table tournaments drop column paused_at;
@@ -0,0 +1,10 @@
+alter table tournaments add column paused_at timestamptz;
+alter table tournaments_users add column withdrawn_at timestamptz;
+
+create table tournaments_byes (
+  tournament_id uuid references tournaments(id) on delete cascade,
+  user_id uuid references users(id),
+  round integer not null,
+  created_at timestamptz not null default now(),
+  primary key(tournament_id, user_id, round)
+);
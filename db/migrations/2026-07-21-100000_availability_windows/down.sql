@@ -0,0 +1,3 @@
+alter table schedules drop column reminded;
+
+drop table availability_windows;
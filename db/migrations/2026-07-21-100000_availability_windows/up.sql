@@ -0,0 +1,13 @@
+create table availability_windows (
+  id uuid default gen_random_uuid() primary key not null,
+  user_id uuid not null references users(id) on delete cascade,
+  weekday integer not null check (weekday between 0 and 6),
+  start_minute integer not null,
+  end_minute integer not null,
+  utc_offset_minutes integer not null,
+  check (0 <= start_minute and start_minute < end_minute and end_minute <= 1440)
+);
+
+create index availability_windows_user_id_idx on availability_windows (user_id);
+
+alter table schedules add column reminded boolean not null default false;
@@ -0,0 +1,4 @@
+-- The combined ratings are not restored, the expansion ratings stay in place.
+drop index ratings_user_speed_variant;
+delete from ratings where variant = 'Base';
+alter table ratings drop column variant;
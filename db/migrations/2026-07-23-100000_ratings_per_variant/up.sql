@@ -0,0 +1,165 @@
+-- Base and the expansion variants get their own ratings, seeded by replaying
+-- every finished game in the order the games ended.
+alter table ratings add column variant text not null default 'Expansions';
+
+insert into ratings (
+  user_uid, played, won, lost, draw, rating, deviation, volatility, created_at, updated_at, speed, variant
+)
+select user_uid, played, won, lost, draw, rating, deviation, volatility, created_at, updated_at, speed, 'Base'
+from ratings;
+
+update ratings
+set played = 0, won = 0, lost = 0, draw = 0, rating = 1500.0, deviation = 500.0, volatility = 0.09
+where speed <> 'Puzzle';
+
+create unique index ratings_user_speed_variant on ratings (user_uid, speed, variant);
+
+create function replay_glicko2_f(
+  x float8, delta float8, phi float8, v float8, volatility float8, tau float8
+) returns float8 as $$
+  select exp(x) * (delta ^ 2 - phi ^ 2 - v - exp(x)) / (2.0 * (phi ^ 2 + v + exp(x)) ^ 2)
+    - (x - ln(volatility ^ 2)) / tau ^ 2
+$$ language sql immutable;
+
+-- One Glicko-2 update for a single game, matching skillratings with tau 0.75.
+create function replay_glicko2(
+  rating float8,
+  deviation float8,
+  volatility float8,
+  opponent_rating float8,
+  opponent_deviation float8,
+  score float8,
+  out new_rating float8,
+  out new_deviation float8,
+  out new_volatility float8
+) as $$
+declare
+  scale constant float8 := 173.7178;
+  tau constant float8 := 0.75;
+  tolerance constant float8 := 0.000001;
+  mu float8 := (rating - 1500.0) / scale;
+  phi float8 := deviation / scale;
+  mu_j float8 := (opponent_rating - 1500.0) / scale;
+  phi_j float8 := opponent_deviation / scale;
+  g float8;
+  e float8;
+  v float8;
+  delta float8;
+  a float8;
+  b float8;
+  c float8;
+  fa float8;
+  fb float8;
+  fc float8;
+  k float8 := 1.0;
+begin
+  g := 1.0 / sqrt(1.0 + 3.0 * phi_j ^ 2 / pi() ^ 2);
+  e := 1.0 / (1.0 + exp(-g * (mu - mu_j)));
+  v := 1.0 / (g ^ 2 * e * (1.0 - e));
+  delta := v * g * (score - e);
+  a := ln(volatility ^ 2);
+  if delta ^ 2 > phi ^ 2 + v then
+    b := ln(delta ^ 2 - phi ^ 2 - v);
+  else
+    while replay_glicko2_f(a - k * tau, delta, phi, v, volatility, tau) < 0 loop
+      k := k + 1.0;
+    end loop;
+    b := a - k * tau;
+  end if;
+  fa := replay_glicko2_f(a, delta, phi, v, volatility, tau);
+  fb := replay_glicko2_f(b, delta, phi, v, volatility, tau);
+  while abs(b - a) > tolerance loop
+    c := a + (a - b) * fa / (fb - fa);
+    fc := replay_glicko2_f(c, delta, phi, v, volatility, tau);
+    if fc * fb <= 0 then
+      a := b;
+      fa := fb;
+    else
+      fa := fa / 2.0;
+    end if;
+    b := c;
+    fb := fc;
+  end loop;
+  new_volatility := exp(a / 2.0);
+  phi := 1.0 / sqrt(1.0 / (phi ^ 2 + new_volatility ^ 2) + 1.0 / v);
+  new_rating := (mu + phi ^ 2 * g * (score - e)) * scale + 1500.0;
+  new_deviation := phi * scale;
+end;
+$$ language plpgsql immutable;
+
+do $$
+declare
+  game record;
+  game_speed text;
+  game_variant text;
+  white_score float8;
+  white_row ratings%rowtype;
+  black_row ratings%rowtype;
+  white_new record;
+  black_new record;
+begin
+  for game in
+    select white_id, black_id, rated, speed, game_type, game_status, updated_at
+    from games
+    where game_status in ('Finished(1-0)', 'Finished(0-1)', 'Finished(½-½)')
+      and white_id <> black_id
+    order by updated_at, id
+  loop
+    game_speed := case when game.speed = 'Untimed' then 'Correspondence' else game.speed end;
+    game_variant := case when game.game_type = 'Base' then 'Base' else 'Expansions' end;
+    select * into white_row from ratings
+    where user_uid = game.white_id and speed = game_speed and variant = game_variant;
+    select * into black_row from ratings
+    where user_uid = game.black_id and speed = game_speed and variant = game_variant;
+    if white_row.id is null or black_row.id is null then
+      continue;
+    end if;
+    white_score := case game.game_status
+      when 'Finished(1-0)' then 1.0
+      when 'Finished(0-1)' then 0.0
+      else 0.5
+    end;
+
+    update ratings
+    set played = played + 1,
+      won = won + (white_score = 1.0)::int,
+      lost = lost + (white_score = 0.0)::int,
+      draw = draw + (white_score = 0.5)::int
+    where id = white_row.id;
+    update ratings
+    set played = played + 1,
+      won = won + (white_score = 0.0)::int,
+      lost = lost + (white_score = 1.0)::int,
+      draw = draw + (white_score = 0.5)::int
+    where id = black_row.id;
+
+    if game.rated then
+      white_new := replay_glicko2(
+        white_row.rating, white_row.deviation, white_row.volatility,
+        black_row.rating, black_row.deviation, white_score
+      );
+      black_new := replay_glicko2(
+        black_row.rating, black_row.deviation, black_row.volatility,
+        white_row.rating, white_row.deviation, 1.0 - white_score
+      );
+      update ratings
+      set rating = white_new.new_rating,
+        deviation = white_new.new_deviation,
+        volatility = white_new.new_volatility,
+        updated_at = game.updated_at
+      where id = white_row.id;
+      update ratings
+      set rating = black_new.new_rating,
+        deviation = black_new.new_deviation,
+        volatility = black_new.new_volatility,
+        updated_at = game.updated_at
+      where id = black_row.id;
+    end if;
+  end loop;
+end;
+$$;
+
+drop function replay_glicko2;
+drop function replay_glicko2_f;
+
+alter table ratings alter column variant drop default;
@@ -0,0 +1,6 @@
+create table rematches (
+  game_id uuid primary key not null references games(id) on delete cascade,
+  offered_by uuid not null references users(id) on delete cascade,
+  new_game_id uuid references games(id) on delete set null,
+  created_at timestamptz not null default now()
+);
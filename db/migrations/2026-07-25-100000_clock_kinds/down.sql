@@ -0,0 +1,3 @@
+alter table tournaments drop column clock_kind;
+alter table challenges drop column clock_kind;
+alter table games drop column clock_kind;
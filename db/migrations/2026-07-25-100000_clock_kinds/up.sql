@@ -0,0 +1,3 @@
+alter table games add column clock_kind text not null default 'Fischer';
+alter table challenges add column clock_kind text not null default 'Fischer';
+alter table tournaments add column clock_kind text not null default 'Fischer';
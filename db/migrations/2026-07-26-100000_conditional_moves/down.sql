@@ -0,0 +1 @@
+drop table conditional_moves;
@@ -0,0 +1,10 @@
+create table conditional_moves (
+  id uuid default gen_random_uuid() primary key not null,
+  game_id uuid not null references games(id) on delete cascade,
+  user_id uuid not null references users(id) on delete cascade,
+  history text not null,
+  moves text not null,
+  created_at timestamptz not null default now()
+);
+
+create index conditional_moves_game_id_idx on conditional_moves(game_id);
@@ -0,0 +1,14 @@
+alter table notification_preferences
+  drop constraint notification_preferences_channels_valid,
+  drop column following,
+  add constraint notification_preferences_channels_valid check (
+    your_turn    <@ array['push','email','discord']::text[] and
+    challenges   <@ array['push','email','discord']::text[] and
+    game_ended   <@ array['push','email','discord']::text[] and
+    tournament   <@ array['push','email','discord']::text[] and
+    schedules    <@ array['push','email','discord']::text[] and
+    general_chat <@ array['push','email','discord']::text[] and
+    dms          <@ array['push','email','discord']::text[]
+  );
+
+drop table user_follows;
@@ -0,0 +1,23 @@
+create table user_follows (
+  follower_id uuid not null references users(id) on delete cascade,
+  followed_id uuid not null references users(id) on delete cascade,
+  created_at  timestamptz not null default now(),
+  primary key (follower_id, followed_id),
+  check (follower_id != followed_id)
+);
+
+create index user_follows_followed_id_idx on user_follows (followed_id);
+
+alter table notification_preferences
+  add column following text[] not null default '{}',
+  drop constraint notification_preferences_channels_valid,
+  add constraint notification_preferences_channels_valid check (
+    your_turn    <@ array['push','email','discord']::text[] and
+    challenges   <@ array['push','email','discord']::text[] and
+    game_ended   <@ array['push','email','discord']::text[] and
+    tournament   <@ array['push','email','discord']::text[] and
+    schedules    <@ array['push','email','discord']::text[] and
+    general_chat <@ array['push','email','discord']::text[] and
+    dms          <@ array['push','email','discord']::text[] and
+    following    <@ array['push','email','discord']::text[]
+  );
@@ -0,0 +1,2 @@
+drop table puzzle_attempts;
+drop table puzzles;
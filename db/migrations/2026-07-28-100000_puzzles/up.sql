@@ -0,0 +1,30 @@
+create table puzzles (
+  id uuid default gen_random_uuid() primary key not null,
+  nanoid text not null unique,
+  game_type text not null,
+  history text not null,
+  solution text not null,
+  themes text[] not null default '{}',
+  rating double precision not null default 1500,
+  deviation double precision not null default 500,
+  volatility double precision not null default 0.09,
+  attempts bigint not null default 0,
+  solves bigint not null default 0,
+  source_game_id uuid references games(id) on delete set null,
+  created_at timestamptz not null default now()
+);
+
+create index puzzles_rating_idx on puzzles(rating);
+
+create table puzzle_attempts (
+  id uuid default gen_random_uuid() primary key not null,
+  puzzle_id uuid not null references puzzles(id) on delete cascade,
+  user_id uuid not null references users(id) on delete cascade,
+  solved boolean not null,
+  rated boolean not null,
+  rating_change double precision,
+  created_at timestamptz not null default now()
+);
+
+create index puzzle_attempts_user_id_idx on puzzle_attempts(user_id, created_at desc);
+create index puzzle_attempts_puzzle_id_idx on puzzle_attempts(puzzle_id);
@@ -0,0 +1,4 @@
+drop table puzzle_mined_games;
+drop index puzzles_position_idx;
+drop index puzzles_status_idx;
+alter table puzzles drop column status;
@@ -0,0 +1,9 @@
+alter table puzzles add column status text not null default 'Approved';
+
+create index puzzles_status_idx on puzzles(status);
+create unique index puzzles_position_idx on puzzles(game_type, history);
+
+create table puzzle_mined_games (
+  game_id uuid primary key not null references games(id) on delete cascade,
+  mined_at timestamptz not null default now()
+);
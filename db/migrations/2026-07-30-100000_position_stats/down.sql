@@ -0,0 +1,2 @@
+DROP TABLE position_stats;
+ALTER TABLE game_hashes DROP COLUMN game_rating;
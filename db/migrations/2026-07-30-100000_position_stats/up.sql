@@ -0,0 +1,81 @@
+-- Average rating of both players, denormalized for the explorer's rating filter.
+ALTER TABLE game_hashes ADD COLUMN game_rating DOUBLE PRECISION;
+
+UPDATE game_hashes gh
+SET game_rating = r.game_rating
+FROM (
+    SELECT game_id, AVG(rating) AS game_rating
+    FROM game_hashes
+    WHERE turn < 2
+    GROUP BY game_id
+    HAVING COUNT(rating) = 2
+) r
+WHERE r.game_id = gh.game_id;
+
+-- Explorer counts per position (next_hash = 0) and per move out of it (next_hash = the
+-- resulting position), bucketed by every aggregated filter. Games count once per row even
+-- when they repeat a position.
+CREATE TABLE position_stats (
+    hash BIGINT NOT NULL,
+    next_hash BIGINT NOT NULL,
+    game_type TEXT NOT NULL,
+    speed TEXT NOT NULL,
+    rated BOOL NOT NULL,
+    -- Lower bound of the 100 point band of game_rating, -1 when unrated.
+    rating_band INT NOT NULL,
+    played_month DATE NOT NULL,
+    -- At least MIN_PLIES (8) turns long.
+    long_game BOOL NOT NULL,
+    piece TEXT NOT NULL,
+    position TEXT NOT NULL,
+    total BIGINT NOT NULL,
+    white_wins BIGINT NOT NULL,
+    black_wins BIGINT NOT NULL,
+    draws BIGINT NOT NULL,
+    rating_sum DOUBLE PRECISION NOT NULL,
+    rating_count BIGINT NOT NULL,
+    PRIMARY KEY (hash, next_hash, game_type, speed, rated, rating_band, played_month, long_game)
+);
+
+INSERT INTO position_stats
+SELECT
+    gh.hash,
+    0,
+    gh.game_type,
+    gh.speed,
+    gh.rated,
+    COALESCE((floor(gh.game_rating / 100) * 100)::int, -1),
+    date_trunc('month', gh.played_at AT TIME ZONE 'UTC')::date,
+    gh.game_length >= 8,
+    '',
+    '',
+    COUNT(DISTINCT gh.game_id),
+    COUNT(DISTINCT gh.game_id) FILTER (WHERE gh.result = 'Finished(1-0)'),
+    COUNT(DISTINCT gh.game_id) FILTER (WHERE gh.result = 'Finished(0-1)'),
+    COUNT(DISTINCT gh.game_id) FILTER (WHERE gh.result = 'Finished(½-½)'),
+    COALESCE(SUM(gh.rating), 0),
+    COUNT(gh.rating)
+FROM game_hashes gh
+GROUP BY 1, 2, 3, 4, 5, 6, 7, 8;
+
+INSERT INTO position_stats
+SELECT
+    cur.hash,
+    nxt.hash,
+    nxt.game_type,
+    nxt.speed,
+    nxt.rated,
+    COALESCE((floor(nxt.game_rating / 100) * 100)::int, -1),
+    date_trunc('month', nxt.played_at AT TIME ZONE 'UTC')::date,
+    nxt.game_length >= 8,
+    COALESCE((array_agg(nxt.move_piece))[1], ''),
+    COALESCE((array_agg(nxt.move_position))[1], ''),
+    COUNT(DISTINCT nxt.game_id),
+    COUNT(DISTINCT nxt.game_id) FILTER (WHERE nxt.result = 'Finished(1-0)'),
+    COUNT(DISTINCT nxt.game_id) FILTER (WHERE nxt.result = 'Finished(0-1)'),
+    COUNT(DISTINCT nxt.game_id) FILTER (WHERE nxt.result = 'Finished(½-½)'),
+    COALESCE(SUM(nxt.rating), 0),
+    COUNT(nxt.rating)
+FROM game_hashes cur
+JOIN game_hashes nxt ON nxt.game_id = cur.game_id AND nxt.turn = cur.turn + 1
+GROUP BY 1, 2, 3, 4, 5, 6, 7, 8;
@@ -0,0 +1,2 @@
+drop table game_analysis_plies;
+drop table game_analyses;
@@ -0,0 +1,21 @@
+create table game_analyses (
+  game_id uuid primary key not null references games(id) on delete cascade,
+  requested_by uuid not null references users(id) on delete cascade,
+  status text not null default 'Pending',
+  engine text not null default '',
+  error text,
+  requested_at timestamptz not null default now(),
+  finished_at timestamptz
+);
+
+create index game_analyses_queue_idx on game_analyses(status, requested_at);
+
+create table game_analysis_plies (
+  game_id uuid not null references game_analyses(game_id) on delete cascade,
+  ply int not null,
+  eval int not null,
+  best_move text not null,
+  best_eval int not null,
+  judgement text,
+  primary key (game_id, ply)
+);
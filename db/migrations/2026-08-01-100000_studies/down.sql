@@ -0,0 +1,2 @@
+drop table study_chapters;
+drop table studies;
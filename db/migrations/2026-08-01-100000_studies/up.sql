@@ -0,0 +1,26 @@
+create table studies (
+  id uuid default gen_random_uuid() primary key not null,
+  nanoid text not null unique,
+  owner_id uuid not null references users(id) on delete cascade,
+  title text not null,
+  description text not null default '',
+  visibility text not null default 'Unlisted',
+  forked_from uuid references studies(id) on delete set null,
+  created_at timestamptz not null default now(),
+  updated_at timestamptz not null default now()
+);
+
+create index studies_owner_id_idx on studies(owner_id, updated_at desc);
+
+-- `tree` is a serialized analysis tree, the same JSON the analysis board saves to a file.
+create table study_chapters (
+  id uuid default gen_random_uuid() primary key not null,
+  study_id uuid not null references studies(id) on delete cascade,
+  position int not null,
+  title text not null,
+  game_type text not null,
+  tree text not null,
+  updated_at timestamptz not null default now(),
+  -- Deferred so that removing a chapter can shift the ones after it in one statement.
+  unique (study_id, position) deferrable initially deferred
+);
@@ -0,0 +1,2 @@
+drop table game_annotations;
+drop table game_annotators;
@@ -0,0 +1,17 @@
+create table game_annotators (
+  game_id uuid not null references games(id) on delete cascade,
+  user_id uuid not null references users(id) on delete cascade,
+  public boolean not null default false,
+  updated_at timestamptz not null default now(),
+  primary key (game_id, user_id)
+);
+
+create table game_annotations (
+  game_id uuid not null,
+  user_id uuid not null,
+  ply int not null,
+  nag text,
+  comment text not null default '',
+  primary key (game_id, user_id, ply),
+  foreign key (game_id, user_id) references game_annotators(game_id, user_id) on delete cascade
+);
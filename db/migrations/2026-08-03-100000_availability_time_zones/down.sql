@@ -0,0 +1,3 @@
+alter table availability_windows add column utc_offset_minutes integer not null default 0;
+alter table availability_windows alter column utc_offset_minutes drop default;
+alter table availability_windows drop column time_zone;
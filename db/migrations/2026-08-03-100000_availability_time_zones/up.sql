@@ -0,0 +1,10 @@
+alter table availability_windows add column time_zone text not null default 'UTC';
+
+-- Whole hour offsets map onto the fixed Etc zones, whose signs are inverted.
+-- Anything else falls back to UTC until the player saves their windows again.
+update availability_windows
+set time_zone = 'Etc/GMT' || case when utc_offset_minutes > 0 then '-' else '+' end || abs(utc_offset_minutes / 60)
+where utc_offset_minutes <> 0 and utc_offset_minutes % 60 = 0;
+
+alter table availability_windows alter column time_zone drop default;
+alter table availability_windows drop column utc_offset_minutes;
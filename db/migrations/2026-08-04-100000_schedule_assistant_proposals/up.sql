@@ -0,0 +1,4 @@
+create table schedule_assistant_proposals (
+  game_id uuid primary key not null references games(id) on delete cascade,
+  proposed_at timestamptz not null default now()
+);
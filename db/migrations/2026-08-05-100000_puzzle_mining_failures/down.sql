@@ -0,0 +1 @@
+alter table puzzle_mined_games drop column failure;
@@ -7,8 +7,8 @@ pub enum DbError {
     NotEnoughPlayers,
     #[error("Tournament is full")]
     TournamentFull,
-    #[error("Cannot join an invite only tournament")]
-    TournamentInviteOnly,
+    #[error("{0}")]
+    TournamentJoin(shared_types::TournamentJoinError),
     #[error("Invalid TournamentDetails")]
     InvalidTournamentDetails { info: String },
     #[error("Internal database error")]
@@ -1,4 +1,4 @@
-use super::chat::authorize_tournament_chat_access;
+use super::{chat::authorize_tournament_chat_access, remove_follows_between};
 use crate::{
     db_error::DbError,
     models::User,
@@ -33,7 +33,7 @@ pub async fn block_user(
         .execute(conn)
         .await
         .map_err(DbError::from)?;
-    Ok(())
+    remove_follows_between(conn, blocker_id, blocked_id).await
 }
 
 pub async fn unblock_user(
@@ -79,6 +79,18 @@ pub async fn blocked_user_ids(
         .map_err(DbError::from)
 }
 
+/// Everyone `user_id` has blocked or been blocked by.
+pub async fn block_partner_ids(conn: &mut DbConn<'_>, user_id: Uuid) -> Result<Vec<Uuid>, DbError> {
+    let blocked = blocked_user_ids(conn, user_id).await?;
+    let blocked_by: Vec<Uuid> = user_blocks::table
+        .filter(user_blocks::blocked_id.eq(user_id))
+        .select(user_blocks::blocker_id)
+        .load(conn)
+        .await
+        .map_err(DbError::from)?;
+    Ok(blocked.into_iter().chain(blocked_by).collect())
+}
+
 pub async fn is_tournament_chat_muted(
     conn: &mut DbConn<'_>,
     user_id: Uuid,
@@ -0,0 +1,130 @@
+use super::is_user_blocked;
+use crate::{
+    db_error::DbError,
+    models::User,
+    schema::{user_follows, users},
+    DbConn,
+};
+use diesel::{dsl::exists, prelude::*, select};
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+pub async fn follow_user(
+    conn: &mut DbConn<'_>,
+    follower_id: Uuid,
+    followed_id: Uuid,
+) -> Result<(), DbError> {
+    if follower_id == followed_id {
+        return Err(DbError::InvalidInput {
+            info: "Cannot follow yourself".to_string(),
+            error: "follower_id == followed_id".to_string(),
+        });
+    }
+    User::find_active_by_uuid(&followed_id, conn).await?;
+    if is_user_blocked(conn, follower_id, followed_id).await?
+        || is_user_blocked(conn, followed_id, follower_id).await?
+    {
+        return Err(DbError::InvalidInput {
+            info: "Cannot follow a blocked user".to_string(),
+            error: "block between follower and followed".to_string(),
+        });
+    }
+
+    diesel::insert_into(user_follows::table)
+        .values((
+            user_follows::follower_id.eq(follower_id),
+            user_follows::followed_id.eq(followed_id),
+        ))
+        .on_conflict((user_follows::follower_id, user_follows::followed_id))
+        .do_nothing()
+        .execute(conn)
+        .await
+        .map_err(DbError::from)?;
+    Ok(())
+}
+
+pub async fn unfollow_user(
+    conn: &mut DbConn<'_>,
+    follower_id: Uuid,
+    followed_id: Uuid,
+) -> Result<(), DbError> {
+    diesel::delete(
+        user_follows::table
+            .filter(user_follows::follower_id.eq(follower_id))
+            .filter(user_follows::followed_id.eq(followed_id)),
+    )
+    .execute(conn)
+    .await
+    .map_err(DbError::from)?;
+    Ok(())
+}
+
+/// Drops follows in both directions, used when either user blocks the other.
+pub async fn remove_follows_between(
+    conn: &mut DbConn<'_>,
+    user_a: Uuid,
+    user_b: Uuid,
+) -> Result<(), DbError> {
+    diesel::delete(
+        user_follows::table.filter(
+            user_follows::follower_id
+                .eq(user_a)
+                .and(user_follows::followed_id.eq(user_b))
+                .or(user_follows::follower_id
+                    .eq(user_b)
+                    .and(user_follows::followed_id.eq(user_a))),
+        ),
+    )
+    .execute(conn)
+    .await
+    .map_err(DbError::from)?;
+    Ok(())
+}
+
+pub async fn is_following(
+    conn: &mut DbConn<'_>,
+    follower_id: Uuid,
+    followed_id: Uuid,
+) -> Result<bool, DbError> {
+    select(exists(
+        user_follows::table
+            .filter(user_follows::follower_id.eq(follower_id))
+            .filter(user_follows::followed_id.eq(followed_id)),
+    ))
+    .get_result(conn)
+    .await
+    .map_err(DbError::from)
+}
+
+/// Active users `follower_id` follows, by username.
+pub async fn followed_users(
+    conn: &mut DbConn<'_>,
+    follower_id: Uuid,
+) -> Result<Vec<User>, DbError> {
+    users::table
+        .filter(
+            users::id.eq_any(
+                user_follows::table
+                    .filter(user_follows::follower_id.eq(follower_id))
+                    .select(user_follows::followed_id),
+            ),
+        )
+        .filter(users::deleted.eq(false))
+        .order(users::normalized_username.asc())
+        .load(conn)
+        .await
+        .map_err(DbError::from)
+}
+
+/// `(follower, followed)` pairs for everyone following any of `followed_ids`.
+pub async fn followers_of(
+    conn: &mut DbConn<'_>,
+    followed_ids: &[Uuid],
+) -> Result<Vec<(Uuid, Uuid)>, DbError> {
+    user_follows::table
+        .filter(user_follows::followed_id.eq_any(followed_ids))
+        .select((user_follows::follower_id, user_follows::followed_id))
+        .load(conn)
+        .await
+        .map_err(DbError::from)
+}
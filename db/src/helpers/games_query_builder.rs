@@ -9,7 +9,7 @@ use diesel::{
     ExpressionMethods,
     QueryDsl,
 };
-use hive_lib::{Color, GameResult, GameStatus, GameType};
+use hive_lib::{Color, GameResult, GameStatus, GameType, OpeningBook};
 use shared_types::{
     BatchToken,
     Conclusion,
@@ -93,7 +93,8 @@ impl GameQueryBuilder {
             .turn_range(options.turn_min, options.turn_max)
             .date_range(options.date_start, options.date_end)
             .tournament_filter(options.only_tournament)
-            .position_hash(options.position_hash);
+            .position_hash(options.position_hash)
+            .opening(options.opening.as_deref());
         self
     }
 
@@ -113,6 +114,28 @@ impl GameQueryBuilder {
         self
     }
 
+    /// Restrict to games that reached the opening's position at its ply, so more specific lines
+    /// under the code match too. Transpositions into it count as well.
+    pub fn opening(mut self, code: Option<&str>) -> Self {
+        if let Some(code) = code {
+            let positions = OpeningBook::find_in_all(code);
+            let ply = positions.first().map_or(0, |(_, opening)| opening.ply()) as i32;
+            let hashes = positions
+                .iter()
+                .map(|(_, opening)| opening.hash as i64)
+                .collect::<Vec<_>>();
+            self.query = self.query.filter(
+                games::id.eq_any(
+                    game_hashes::table
+                        .filter(game_hashes::turn.eq(ply))
+                        .filter(game_hashes::hash.eq_any(hashes))
+                        .select(game_hashes::game_id),
+                ),
+            );
+        }
+        self
+    }
+
     pub fn scope(mut self, progress: GameProgress) -> Self {
         match progress {
             GameProgress::Unstarted => {
@@ -763,6 +786,23 @@ mod tests {
         assert!(!none_sql.contains("game_hashes"));
     }
 
+    #[test]
+    fn opening_filters_on_the_openings_ply() {
+        let options = GamesQueryOptions {
+            opening: Some("SG".into()),
+            ..finished_defaults()
+        };
+        let prepared = options.validate_all().unwrap();
+        let sql = debug_query::<diesel::pg::Pg, _>(
+            &GameQueryBuilder::base_query(&prepared)
+                .build()
+                .select(games::all_columns),
+        )
+        .to_string();
+        assert!(sql.contains("game_hashes"));
+        assert!(sql.contains("turn"));
+    }
+
     #[test]
     fn player_loss_without_color_filters_winner() {
         let options = GamesQueryOptions {
@@ -1,8 +1,10 @@
 mod blocks_mutes;
 mod chat;
+mod follows;
 mod games_query_builder;
 
 pub use blocks_mutes::{
+    block_partner_ids,
     block_user,
     blocked_user_ids,
     is_tournament_chat_muted,
@@ -27,4 +29,12 @@ pub use chat::{
     unread_chat_count_for_channel,
     DbChatTarget,
 };
+pub use follows::{
+    follow_user,
+    followed_users,
+    followers_of,
+    is_following,
+    remove_follows_between,
+    unfollow_user,
+};
 pub use games_query_builder::GameQueryBuilder;
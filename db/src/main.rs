@@ -1,20 +1,111 @@
+use chrono::Utc;
 use db_lib::{
     config::DbConfig,
     get_conn,
     get_pool,
-    models::{NewUser, User},
+    models::{NewUser, RatingReplay, User},
+    DbConn,
 };
+use itertools::Itertools;
+use std::collections::HashMap;
+
+const USAGE: &str = "usage: db_bin [create-test-user | recompute-ratings [--apply]]";
 
 #[tokio::main]
 async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
     let config = DbConfig::from_env().expect("Failed to load config from env");
     let pool = &get_pool(&config.database_url)
         .await
         .expect("Failed to get pool");
-    let new_user = NewUser::new("leex", "hunter2", "leex").expect("Failed to make new_user");
     let mut conn = get_conn(pool).await.expect("to get connection");
-    let user = User::create(new_user, &mut conn)
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] | ["create-test-user"] => create_test_user(&mut conn).await,
+        ["recompute-ratings"] => recompute_ratings(false, &mut conn).await,
+        ["recompute-ratings", "--apply"] => recompute_ratings(true, &mut conn).await,
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
+
+async fn create_test_user(conn: &mut DbConn<'_>) {
+    let new_user = NewUser::new("leex", "hunter2", "leex").expect("Failed to make new_user");
+    let user = User::create(new_user, conn)
         .await
         .expect("Failed to create user");
     println!("User {user:?}");
 }
+
+/// Replays every finished game and prints what would change. With `apply`
+/// the changes are written, all or nothing.
+async fn recompute_ratings(apply: bool, conn: &mut DbConn<'_>) {
+    let now = Utc::now();
+    let replay = if apply {
+        RatingReplay::apply(now, conn).await
+    } else {
+        RatingReplay::compute(now, conn).await
+    }
+    .expect("Failed to replay ratings");
+
+    let user_ids = replay
+        .ratings
+        .iter()
+        .map(|diff| diff.after.user_uid)
+        .unique()
+        .collect::<Vec<_>>();
+    let usernames: HashMap<_, _> = User::find_by_uuids(&user_ids, conn)
+        .await
+        .expect("Failed to load users")
+        .into_iter()
+        .map(|user| (user.id, user.username))
+        .collect();
+    for diff in &replay.ratings {
+        let (before, after) = (&diff.before, &diff.after);
+        println!(
+            "{} {} {}: {:.1} ±{:.1} ({} games) -> {:.1} ±{:.1} ({} games)",
+            usernames
+                .get(&after.user_uid)
+                .map_or("<unknown>", String::as_str),
+            after.speed,
+            after.variant,
+            before.rating,
+            before.deviation,
+            before.played,
+            after.rating,
+            after.deviation,
+            after.played,
+        );
+    }
+    for diff in &replay.games {
+        println!(
+            "game {}: white {} black {} -> white {} black {}",
+            diff.nanoid,
+            rating_change(diff.before.white_rating_change),
+            rating_change(diff.before.black_rating_change),
+            rating_change(diff.after.white_rating_change),
+            rating_change(diff.after.black_rating_change),
+        );
+    }
+    println!(
+        "Replayed {} games, {} ratings and {} games {}",
+        replay.games_replayed,
+        replay.ratings.len(),
+        replay.games.len(),
+        if apply {
+            "updated"
+        } else {
+            "differ, run with --apply to write them"
+        }
+    );
+}
+
+fn rating_change(change: Option<f64>) -> String {
+    change.map_or_else(|| String::from("-"), |change| format!("{change:+.1}"))
+}
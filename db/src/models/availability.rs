@@ -0,0 +1,144 @@
+use crate::{
+    db_error::DbError,
+    models::{Game, Tournament},
+    schema::availability_windows,
+    DbConn,
+};
+use chrono::{DateTime, Duration, Utc};
+use diesel::{prelude::*, Identifiable, Insertable, Queryable, Selectable};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use shared_types::{overlapping_slots, AvailabilityWindow};
+use uuid::Uuid;
+
+/// A week fully split into hourly windows, anything beyond is a client bug.
+const MAX_WINDOWS_PER_USER: usize = 7 * 24;
+/// Slots are never suggested closer than this, so the opponent has time to accept.
+const SUGGESTION_LEAD: Duration = Duration::hours(1);
+/// How far ahead to look when the tournament has no end date.
+const SUGGESTION_HORIZON: Duration = Duration::weeks(2);
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = availability_windows)]
+struct NewAvailability {
+    user_id: Uuid,
+    weekday: i32,
+    start_minute: i32,
+    end_minute: i32,
+    time_zone: String,
+}
+
+#[derive(Queryable, Identifiable, Selectable, Debug, Clone)]
+#[diesel(table_name = availability_windows)]
+pub struct Availability {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub weekday: i32,
+    pub start_minute: i32,
+    pub end_minute: i32,
+    pub time_zone: String,
+}
+
+impl From<Availability> for AvailabilityWindow {
+    fn from(availability: Availability) -> Self {
+        Self {
+            weekday: availability.weekday,
+            start_minute: availability.start_minute,
+            end_minute: availability.end_minute,
+            time_zone: availability.time_zone,
+        }
+    }
+}
+
+impl Availability {
+    pub async fn for_user(
+        user_id: Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<AvailabilityWindow>, DbError> {
+        let windows: Vec<Availability> = availability_windows::table
+            .filter(availability_windows::user_id.eq(user_id))
+            .order((
+                availability_windows::weekday,
+                availability_windows::start_minute,
+            ))
+            .select(Availability::as_select())
+            .get_results(conn)
+            .await?;
+        Ok(windows.into_iter().map(AvailabilityWindow::from).collect())
+    }
+
+    /// Swaps the user's weekly windows for `windows`.
+    pub async fn replace_for_user(
+        user_id: Uuid,
+        windows: Vec<AvailabilityWindow>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<AvailabilityWindow>, DbError> {
+        if windows.len() > MAX_WINDOWS_PER_USER {
+            return Err(DbError::InvalidInput {
+                info: String::from("Too many availability windows"),
+                error: format!("Got {}, at most {MAX_WINDOWS_PER_USER}", windows.len()),
+            });
+        }
+        if let Some(window) = windows.iter().find(|window| !window.is_valid()) {
+            return Err(DbError::InvalidInput {
+                info: String::from("Invalid availability window"),
+                error: format!("{window:?}"),
+            });
+        }
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            diesel::delete(
+                availability_windows::table.filter(availability_windows::user_id.eq(user_id)),
+            )
+            .execute(tc)
+            .await?;
+            let rows: Vec<NewAvailability> = windows
+                .iter()
+                .map(|window| NewAvailability {
+                    user_id,
+                    weekday: window.weekday,
+                    start_minute: window.start_minute,
+                    end_minute: window.end_minute,
+                    time_zone: window.time_zone.clone(),
+                })
+                .collect();
+            if !rows.is_empty() {
+                diesel::insert_into(availability_windows::table)
+                    .values(&rows)
+                    .execute(tc)
+                    .await?;
+            }
+            Self::for_user(user_id, tc).await
+        })
+        .await
+    }
+
+    /// Start times that suit both players of a tournament game, earliest first,
+    /// ending before the tournament does.
+    pub async fn suggest_for_game(
+        game: &Game,
+        tournament: &Tournament,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<DateTime<Utc>>, DbError> {
+        let white = Self::for_user(game.white_id, conn).await?;
+        let black = Self::for_user(game.black_id, conn).await?;
+        if white.is_empty() || black.is_empty() {
+            return Ok(Vec::new());
+        }
+        let from = Utc::now() + SUGGESTION_LEAD;
+        let until = tournament.ends_at.unwrap_or(from + SUGGESTION_HORIZON);
+        Ok(overlapping_slots(
+            &white,
+            &black,
+            from,
+            until,
+            expected_length(game),
+        ))
+    }
+}
+
+/// Roughly how long a game takes: both base times plus 40 increments each,
+/// and never less than half an hour.
+fn expected_length(game: &Game) -> Duration {
+    let base = game.time_base.unwrap_or(0) as i64;
+    let increment = game.time_increment.unwrap_or(0) as i64;
+    Duration::seconds(2 * (base + 40 * increment)).max(Duration::minutes(30))
+}
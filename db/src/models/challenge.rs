@@ -11,9 +11,18 @@ use crate::{
 use chrono::prelude::*;
 use diesel::{dsl::exists, prelude::*, select};
 use diesel_async::RunQueryDsl;
+use hive_lib::GameType;
 use nanoid::nanoid;
 use serde::Serialize;
-use shared_types::{ChallengeDetails, ChallengeError, ChallengeId, ChallengeVisibility, TimeMode};
+use shared_types::{
+    ChallengeDetails,
+    ChallengeError,
+    ChallengeId,
+    ChallengeVisibility,
+    TimeMode,
+    VariantClass,
+};
+use std::str::FromStr;
 use uuid::Uuid;
 
 fn validate_opponent_visibility(
@@ -61,6 +70,7 @@ pub struct NewChallenge {
     pub time_increment: Option<i32>, // Seconds
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
+    pub clock_kind: String,
 }
 
 impl NewChallenge {
@@ -109,6 +119,15 @@ impl NewChallenge {
                 }
             }
         }
+        if !d.clock_kind.supports(d.time_mode, d.time_increment) {
+            return Err(DbError::InvalidInput {
+                info: format!(
+                    "{} needs a real-time game with a delay",
+                    d.clock_kind.label()
+                ),
+                error: format!("time_increment: {:?}", d.time_increment),
+            });
+        }
         validate_opponent_visibility(challenger_id, opponent_id, &d.visibility)?;
         let mut nanoid: String;
         loop {
@@ -135,6 +154,7 @@ impl NewChallenge {
             time_increment: d.time_increment,
             band_upper: d.band_upper,
             band_lower: d.band_lower,
+            clock_kind: d.clock_kind.to_string(),
         })
     }
 }
@@ -160,9 +180,19 @@ pub struct Challenge {
     pub time_increment: Option<i32>, // Seconds
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
+    pub clock_kind: String,
 }
 
 impl Challenge {
+    pub fn variant_class(&self) -> Result<VariantClass, DbError> {
+        GameType::from_str(&self.game_type)
+            .map(VariantClass::from)
+            .map_err(|err| DbError::InvalidInput {
+                info: format!("Invalid game type {}", self.game_type),
+                error: err.to_string(),
+            })
+    }
+
     pub fn validate_accepting_user(&self, user_id: Uuid) -> Result<(), ChallengeError> {
         if self.challenger_id == user_id {
             return Err(ChallengeError::OwnChallenge);
@@ -0,0 +1,223 @@
+use crate::{
+    db_error::DbError,
+    models::{
+        move_text::{invalid, join_moves, split_moves},
+        Game,
+    },
+    schema::conditional_moves::{self, dsl},
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{prelude::*, Insertable, Queryable, Selectable};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use hive_lib::{GameStatus, State};
+use shared_types::TimeMode;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub const MAX_CONDITIONAL_LINES: usize = 32;
+pub const MAX_CONDITIONAL_PLIES: usize = 24;
+
+/// One line of a player's conditional move tree: the opponent's expected
+/// moves alternating with the replies to play, in game history notation.
+/// `history` is the game history the line was written against, so any
+/// other move, a pass or a takeback leaves it stale.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = conditional_moves)]
+pub struct ConditionalMove {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub user_id: Uuid,
+    pub history: String,
+    pub moves: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = conditional_moves)]
+struct NewConditionalMove {
+    game_id: Uuid,
+    user_id: Uuid,
+    history: String,
+    moves: String,
+}
+
+impl ConditionalMove {
+    pub fn lines(&self) -> Vec<(String, String)> {
+        split_moves(&self.moves)
+    }
+
+    pub async fn for_player(
+        game_id: Uuid,
+        user_id: Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Self>, DbError> {
+        Ok(dsl::conditional_moves
+            .filter(dsl::game_id.eq(game_id))
+            .filter(dsl::user_id.eq(user_id))
+            .order(dsl::created_at.asc())
+            .load(conn)
+            .await?)
+    }
+
+    /// Replaces `user_id`'s tree for `game` with `lines`, each starting with
+    /// the opponent's next move. An empty list clears the tree.
+    pub async fn replace(
+        game: &Game,
+        user_id: Uuid,
+        lines: Vec<Vec<(String, String)>>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Self>, DbError> {
+        let lines = Self::normalize(game, user_id, lines)?;
+        let (game_id, history) = (game.id, game.history.clone());
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            diesel::delete(
+                dsl::conditional_moves
+                    .filter(dsl::game_id.eq(game_id))
+                    .filter(dsl::user_id.eq(user_id)),
+            )
+            .execute(tc)
+            .await?;
+            let rows = lines
+                .into_iter()
+                .map(|moves| NewConditionalMove {
+                    game_id,
+                    user_id,
+                    history: history.clone(),
+                    moves,
+                })
+                .collect::<Vec<_>>();
+            Ok(diesel::insert_into(conditional_moves::table)
+                .values(&rows)
+                .get_results(tc)
+                .await?)
+        })
+        .await
+    }
+
+    /// Called once a move was stored: finds the reply the player to move
+    /// prepared for it. Lines that didn't match are dropped, the matching
+    /// ones are advanced past the reply. Run it in the move's transaction.
+    pub async fn take_reply(
+        game: &Game,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Option<(String, String)>, DbError> {
+        let lines: Vec<Self> = dsl::conditional_moves
+            .filter(dsl::game_id.eq(game.id))
+            .for_update()
+            .load(conn)
+            .await?;
+        let mut reply = None;
+        let mut stale = Vec::new();
+        for line in lines {
+            // Still waiting on the player to move
+            if line.history == game.history && !game.finished {
+                continue;
+            }
+            let mut moves = line.lines();
+            let matched = line.user_id == game.current_player_id
+                && !game.finished
+                && moves.len() >= 2
+                && game.history == format!("{}{}", line.history, join_moves(&moves[..1]));
+            if !matched {
+                stale.push(line.id);
+                continue;
+            }
+            let played = moves.drain(..2).collect::<Vec<_>>();
+            reply = played.get(1).cloned();
+            if moves.is_empty() {
+                stale.push(line.id);
+            } else {
+                diesel::update(dsl::conditional_moves.find(line.id))
+                    .set((
+                        dsl::history.eq(format!("{}{}", line.history, join_moves(&played))),
+                        dsl::moves.eq(join_moves(&moves)),
+                    ))
+                    .execute(conn)
+                    .await?;
+            }
+        }
+        if !stale.is_empty() {
+            diesel::delete(dsl::conditional_moves.filter(dsl::id.eq_any(stale)))
+                .execute(conn)
+                .await?;
+        }
+        Ok(reply)
+    }
+
+    /// Checks every line against the game and rewrites it in the notation
+    /// the game history uses, so matching is a plain string comparison.
+    fn normalize(
+        game: &Game,
+        user_id: Uuid,
+        lines: Vec<Vec<(String, String)>>,
+    ) -> Result<Vec<String>, DbError> {
+        if !game.user_is_player(user_id) {
+            return Err(DbError::Unauthorized);
+        }
+        if game.finished || game.time_mode != TimeMode::Correspondence.to_string() {
+            return Err(DbError::InvalidAction {
+                info: "Conditional moves are for ongoing correspondence games".to_string(),
+            });
+        }
+        if game.current_player_id == user_id {
+            return Err(DbError::InvalidAction {
+                info: "Conditional moves wait on the opponent's move".to_string(),
+            });
+        }
+        if lines.len() > MAX_CONDITIONAL_LINES {
+            return Err(invalid(
+                "Too many conditional lines",
+                format!("{} > {MAX_CONDITIONAL_LINES}", lines.len()),
+            ));
+        }
+        let state = State::new_from_str(&game.history, &game.game_type)
+            .map_err(|e| invalid("Could not recover the game", e.to_string()))?;
+        let mut replies: HashMap<String, (String, String)> = HashMap::new();
+        let mut normalized = Vec::new();
+        for line in lines {
+            if line.is_empty() || !line.len().is_multiple_of(2) || line.len() > MAX_CONDITIONAL_PLIES {
+                return Err(invalid(
+                    "Each line needs a reply to every expected move",
+                    format!("{} plies", line.len()),
+                ));
+            }
+            let mut state = state.clone();
+            let last = line.len() - 1;
+            for (i, (piece, position)) in line.iter().enumerate() {
+                state
+                    .play_turn_from_history(piece, position)
+                    .map_err(|e| invalid("Invalid conditional move", e.to_string()))?;
+                let over = matches!(state.game_status, GameStatus::Finished(_));
+                if i < last && (over || state.board.is_shutout(state.turn_color, state.game_type)) {
+                    return Err(invalid(
+                        "Conditional lines must stop when the game ends or a player passes",
+                        format!("{piece} {position}"),
+                    ));
+                }
+            }
+            let moves = state.history.moves[game.turn as usize..].to_vec();
+            // Replies are chosen by the moves leading up to them, so two
+            // lines can't answer the same sequence differently
+            for reply in (1..moves.len()).step_by(2) {
+                let key = join_moves(&moves[..reply]);
+                match replies.get(&key) {
+                    Some(existing) if *existing != moves[reply] => {
+                        return Err(invalid(
+                            "Conflicting replies to the same move",
+                            format!("{} {}", moves[reply - 1].0, moves[reply - 1].1),
+                        ));
+                    }
+                    _ => {
+                        replies.insert(key, moves[reply].clone());
+                    }
+                }
+            }
+            let moves = join_moves(&moves);
+            if !normalized.contains(&moves) {
+                normalized.push(moves);
+            }
+        }
+        Ok(normalized)
+    }
+}
@@ -6,30 +6,40 @@ use crate::{
         challenges::{self, nanoid as nanoid_field},
         games::{self, dsl::*, tournament_game_result},
         games_users,
+        puzzle_mined_games,
     },
     DbConn,
 };
 use ::nanoid::nanoid;
 use chrono::{DateTime, Datelike, TimeZone, Utc};
-use diesel::{prelude::*, ExpressionMethods, Insertable};
+use diesel::{
+    prelude::*,
+    sql_types::{Array, BigInt, Bool, Integer, Nullable, Text, Uuid as SqlUuid},
+    ExpressionMethods,
+    Insertable,
+};
 use diesel_async::{AsyncConnection, RunQueryDsl};
-use hive_lib::{Color, GameControl, GameResult, GameStatus, GameType, History, State};
+use hive_lib::{Color, GameControl, GameResult, GameStatus, GameType, History, OpeningBook, State};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use shared_types::{
     BatchToken,
     ChallengeId,
+    ClockKind,
     Conclusion,
     GameId,
     GameSortKey,
     GameSpeed,
     GameStart,
     GamesQueryOptions,
+    OpeningStats,
+    SeekDetails,
     SortValue,
     TimeMode,
     TournamentGameResult,
+    VariantClass,
 };
-use std::{str::FromStr, time::Duration};
+use std::{collections::HashMap, str::FromStr, time::Duration};
 use uuid::Uuid;
 
 pub static NANOS_IN_SECOND: u64 = 1000000000_u64;
@@ -38,6 +48,24 @@ pub static NANOS_IN_SECOND: u64 = 1000000000_u64;
 /// repeating the type ascription diesel's set-tuple inference needs.
 pub(crate) const CLEAR_TIMEOUT_AT: Option<DateTime<Utc>> = None;
 
+/// The stored columns a clock runs on.
+#[derive(Clone, Copy)]
+struct ClockRules<'a> {
+    time_mode: &'a str,
+    clock_kind: &'a str,
+    time_increment: Option<i32>,
+}
+
+impl ClockRules<'_> {
+    fn kind(&self) -> ClockKind {
+        ClockKind::from_str(self.clock_kind).unwrap_or_default()
+    }
+
+    fn per_move(&self) -> Duration {
+        Duration::from_secs(self.time_increment.unwrap_or(0).max(0) as u64)
+    }
+}
+
 /// Single source of truth for timeout_at, so every site that mutates
 /// clock/turn/status derives it consistently.
 fn compute_timeout_at(
@@ -45,13 +73,13 @@ fn compute_timeout_at(
     white_left_nanos: Option<i64>,
     black_left_nanos: Option<i64>,
     new_turn: i32,
-    mode_str: &str,
+    clock: ClockRules<'_>,
     status_str: &str,
 ) -> Option<DateTime<Utc>> {
     if status_str == GameStatus::NotStarted.to_string() {
         return None;
     }
-    if matches!(TimeMode::from_str(mode_str), Ok(TimeMode::Untimed)) {
+    if matches!(TimeMode::from_str(clock.time_mode), Ok(TimeMode::Untimed)) {
         return None;
     }
     let last = interaction_at?;
@@ -60,7 +88,11 @@ fn compute_timeout_at(
     } else {
         black_left_nanos?
     };
-    Some(last + chrono::Duration::nanoseconds(running_nanos))
+    let to_flag = clock.kind().time_to_flag(
+        Duration::from_nanos(running_nanos.max(0) as u64),
+        clock.per_move(),
+    );
+    Some(last + chrono::Duration::nanoseconds(to_flag.as_nanos() as i64))
 }
 
 #[derive(Debug)]
@@ -127,38 +159,41 @@ pub struct NewGame {
     pub game_start: String,
     pub move_times: Vec<Option<i64>>,
     pub timeout_at: Option<DateTime<Utc>>,
+    pub clock_kind: String,
 }
 
 impl NewGame {
     pub fn new_from_tournament(white: Uuid, black: Uuid, tournament: &Tournament) -> Self {
-        let (time_left, start, status, interaction) =
-            match TimeMode::from_str(&tournament.time_mode).unwrap() {
-                TimeMode::Untimed => unreachable!("Tournaments cannot be untimed"),
-                TimeMode::RealTime => (
-                    tournament
-                        .time_base
-                        .map(|base| (base as u64 * NANOS_IN_SECOND) as i64),
-                    GameStart::Ready.to_string(),
-                    GameStatus::NotStarted.to_string(),
-                    None,
-                ),
-                TimeMode::Correspondence => (
-                    match (tournament.time_base, tournament.time_increment) {
-                        (Some(base), None) => Some((base as u64 * NANOS_IN_SECOND) as i64),
-                        (None, Some(inc)) => Some((inc as u64 * NANOS_IN_SECOND) as i64),
-                        _ => unreachable!(),
-                    },
-                    GameStart::Immediate.to_string(),
-                    GameStatus::InProgress.to_string(),
-                    Some(Utc::now()),
-                ),
-            };
+        let (start, status, interaction) = match TimeMode::from_str(&tournament.time_mode).unwrap()
+        {
+            TimeMode::Untimed => unreachable!("Tournaments cannot be untimed"),
+            TimeMode::RealTime => (
+                GameStart::Ready.to_string(),
+                GameStatus::NotStarted.to_string(),
+                None,
+            ),
+            TimeMode::Correspondence => (
+                GameStart::Immediate.to_string(),
+                GameStatus::InProgress.to_string(),
+                Some(Utc::now()),
+            ),
+        };
+        let time_left = initial_time_left(
+            &tournament.time_mode,
+            tournament.time_base,
+            tournament.time_increment,
+            &tournament.clock_kind,
+        );
         let initial_timeout_at = compute_timeout_at(
             interaction,
             time_left,
             time_left,
             0,
-            &tournament.time_mode,
+            ClockRules {
+                time_mode: &tournament.time_mode,
+                clock_kind: &tournament.clock_kind,
+                time_increment: tournament.time_increment,
+            },
             &status,
         );
 
@@ -196,6 +231,7 @@ impl NewGame {
             game_start: start,
             move_times: vec![],
             timeout_at: initial_timeout_at,
+            clock_kind: tournament.clock_kind.to_owned(),
         }
     }
 
@@ -206,30 +242,36 @@ impl NewGame {
                 error: String::new(),
             });
         }
-
-        let time_left = match TimeMode::from_str(&challenge.time_mode).unwrap() {
-            TimeMode::Untimed => None,
-            TimeMode::RealTime => challenge
-                .time_base
-                .map(|base| (base as u64 * NANOS_IN_SECOND) as i64),
-            TimeMode::Correspondence => match (challenge.time_base, challenge.time_increment) {
-                (Some(base), None) => Some((base as u64 * NANOS_IN_SECOND) as i64),
-                (None, Some(inc)) => Some((inc as u64 * NANOS_IN_SECOND) as i64),
-                _ => unreachable!(),
-            },
-        };
-
         Ok(Self {
             nanoid: challenge.nanoid.to_owned(),
+            ..Self::unstarted(white, black, GameSettings::from(challenge))
+        })
+    }
+
+    /// The same game again with colours swapped.
+    pub fn rematch(game: &Game) -> Self {
+        Self::unstarted(game.black_id, game.white_id, GameSettings::from(game))
+    }
+
+    /// A casual pairing that starts with the first move.
+    fn unstarted(white: Uuid, black: Uuid, settings: GameSettings) -> Self {
+        let time_left = initial_time_left(
+            &settings.time_mode,
+            settings.time_base,
+            settings.time_increment,
+            &settings.clock_kind,
+        );
+        Self {
+            nanoid: nanoid!(12),
             current_player_id: white,
             black_id: black,
             finished: false,
-            game_status: "NotStarted".to_owned(),
-            game_type: challenge.game_type.to_owned(),
+            game_status: GameStatus::NotStarted.to_string(),
+            game_type: settings.game_type,
             history: String::new(),
             game_control_history: String::new(),
-            rated: challenge.rated,
-            tournament_queen_rule: challenge.tournament_queen_rule,
+            rated: settings.rated,
+            tournament_queen_rule: settings.tournament_queen_rule,
             turn: 0,
             white_id: white,
             white_rating: None,
@@ -238,13 +280,13 @@ impl NewGame {
             black_rating_change: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
-            time_mode: challenge.time_mode.to_owned(),
-            time_base: challenge.time_base,
-            time_increment: challenge.time_increment,
+            time_mode: settings.time_mode,
+            time_base: settings.time_base,
+            time_increment: settings.time_increment,
             last_interaction: None,
             black_time_left: time_left,
             white_time_left: time_left,
-            speed: GameSpeed::from_base_increment(challenge.time_base, challenge.time_increment)
+            speed: GameSpeed::from_base_increment(settings.time_base, settings.time_increment)
                 .to_string(),
             hashes: vec![],
             conclusion: Conclusion::Unknown.to_string(),
@@ -253,10 +295,122 @@ impl NewGame {
             game_start: GameStart::Moves.to_string(),
             move_times: vec![],
             timeout_at: None,
-        })
+            clock_kind: settings.clock_kind,
+        }
+    }
+
+    /// A quick pairing from the seek pool.
+    pub fn new_from_seek(white: Uuid, black: Uuid, seek: &SeekDetails) -> Self {
+        Self::unstarted(white, black, GameSettings::from(seek))
+    }
+}
+
+/// What a challenge, a seek or a previous game decides about a new one.
+struct GameSettings {
+    game_type: String,
+    rated: bool,
+    tournament_queen_rule: bool,
+    time_mode: String,
+    time_base: Option<i32>,
+    time_increment: Option<i32>,
+    clock_kind: String,
+}
+
+impl From<&Challenge> for GameSettings {
+    fn from(challenge: &Challenge) -> Self {
+        Self {
+            game_type: challenge.game_type.clone(),
+            rated: challenge.rated,
+            tournament_queen_rule: challenge.tournament_queen_rule,
+            time_mode: challenge.time_mode.clone(),
+            time_base: challenge.time_base,
+            time_increment: challenge.time_increment,
+            clock_kind: challenge.clock_kind.clone(),
+        }
+    }
+}
+
+/// Seeks are always real-time Fischer games with the tournament queen rule.
+impl From<&SeekDetails> for GameSettings {
+    fn from(seek: &SeekDetails) -> Self {
+        Self {
+            game_type: seek.game_type.to_string(),
+            rated: seek.rated,
+            tournament_queen_rule: true,
+            time_mode: TimeMode::RealTime.to_string(),
+            time_base: Some(seek.time_base),
+            time_increment: Some(seek.time_increment),
+            clock_kind: ClockKind::Fischer.to_string(),
+        }
+    }
+}
+
+impl From<&Game> for GameSettings {
+    fn from(game: &Game) -> Self {
+        Self {
+            game_type: game.game_type.clone(),
+            rated: game.rated,
+            tournament_queen_rule: game.tournament_queen_rule,
+            time_mode: game.time_mode.clone(),
+            time_base: game.time_base,
+            time_increment: game.time_increment,
+            clock_kind: game.clock_kind.clone(),
+        }
     }
 }
 
+/// Nanoseconds each player starts with.
+fn initial_time_left(
+    time_mode: &str,
+    time_base: Option<i32>,
+    time_increment: Option<i32>,
+    clock_kind: &str,
+) -> Option<i64> {
+    match TimeMode::from_str(time_mode).unwrap() {
+        TimeMode::Untimed => None,
+        TimeMode::RealTime => time_base.map(|base| {
+            let clock = ClockKind::from_str(clock_kind).unwrap_or_default();
+            let per_move = Duration::from_secs(time_increment.unwrap_or(0) as u64);
+            clock
+                .initial_time(Duration::from_secs(base as u64), per_move)
+                .as_nanos() as i64
+        }),
+        TimeMode::Correspondence => match (time_base, time_increment) {
+            (Some(base), None) => Some((base as u64 * NANOS_IN_SECOND) as i64),
+            (None, Some(inc)) => Some((inc as u64 * NANOS_IN_SECOND) as i64),
+            _ => unreachable!(),
+        },
+    }
+}
+
+/// How much of the mover's clock a real time move used.
+#[derive(Clone, Copy, Debug)]
+pub enum TimeSpent {
+    /// The time since the last move, less this many seconds of lag compensation.
+    Measured(f64),
+    /// Premoves are played the moment they become legal.
+    Zero,
+}
+
+/// One line a user played, see `Game::opening_stats`.
+#[derive(QueryableByName, Debug)]
+struct OpeningLineRow {
+    #[diesel(sql_type = Text)]
+    game_type: String,
+    #[diesel(sql_type = Array<Nullable<BigInt>>)]
+    opening_hashes: Vec<Option<i64>>,
+    #[diesel(sql_type = Bool)]
+    as_white: bool,
+    #[diesel(sql_type = BigInt)]
+    games: i64,
+    #[diesel(sql_type = BigInt)]
+    white_wins: i64,
+    #[diesel(sql_type = BigInt)]
+    black_wins: i64,
+    #[diesel(sql_type = BigInt)]
+    draws: i64,
+}
+
 #[derive(
     Queryable, Identifiable, Serialize, Clone, Deserialize, Debug, AsChangeset, Selectable,
 )]
@@ -296,6 +450,7 @@ pub struct Game {
     pub game_start: String,
     pub move_times: Vec<Option<i64>>,
     pub timeout_at: Option<DateTime<Utc>>,
+    pub clock_kind: String,
 }
 
 impl Game {
@@ -306,6 +461,16 @@ impl Game {
             .collect()
     }
 
+    /// Which of the player's ratings this game counts towards.
+    pub fn variant_class(&self) -> Result<VariantClass, DbError> {
+        GameType::from_str(&self.game_type)
+            .map(VariantClass::from)
+            .map_err(|err| DbError::InvalidInput {
+                info: format!("Invalid game type {}", self.game_type),
+                error: err.to_string(),
+            })
+    }
+
     pub async fn create(new_game: NewGame, conn: &mut DbConn<'_>) -> Result<Game, DbError> {
         let game: Game = new_game.insert_into(games::table).get_result(conn).await?;
         let game_user_white = GameUser::new(game.id, game.white_id);
@@ -330,31 +495,50 @@ impl Game {
             .filter(nanoid_field.eq(game.nanoid.clone()))
             .first(conn)
             .await?;
-        let mut deleted = vec![];
-        if let Ok(TimeMode::RealTime) = TimeMode::from_str(&challenge.time_mode) {
-            let challenges: Vec<Challenge> = challenges::table
-                .filter(
-                    challenges::time_mode
-                        .eq(TimeMode::RealTime.to_string())
-                        .and(challenges::challenger_id.eq_any(&[game.white_id, game.black_id])),
-                )
-                .get_results(conn)
-                .await?;
-            for challenge in challenges {
-                deleted.push(ChallengeId(challenge.nanoid));
-                diesel::delete(challenges::table.find(challenge.id))
-                    .execute(conn)
-                    .await?;
-            }
+        let deleted = if let Ok(TimeMode::RealTime) = TimeMode::from_str(&challenge.time_mode) {
+            game.delete_real_time_challenges(conn).await?
         } else {
-            deleted.push(ChallengeId(challenge.nanoid));
             diesel::delete(challenges::table.find(challenge.id))
                 .execute(conn)
                 .await?;
+            vec![ChallengeId(challenge.nanoid)]
         };
         Ok((game, deleted))
     }
 
+    /// Creates a quick-pairing game. Like accepting a real-time challenge, it
+    /// withdraws both players' other real-time challenges.
+    pub async fn create_from_seek(
+        new_game: NewGame,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(Game, Vec<ChallengeId>), DbError> {
+        let game = Game::create(new_game, conn).await?;
+        let deleted = game.delete_real_time_challenges(conn).await?;
+        Ok((game, deleted))
+    }
+
+    pub(crate) async fn delete_real_time_challenges(
+        &self,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<ChallengeId>, DbError> {
+        let challenges: Vec<Challenge> = challenges::table
+            .filter(
+                challenges::time_mode
+                    .eq(TimeMode::RealTime.to_string())
+                    .and(challenges::challenger_id.eq_any(&[self.white_id, self.black_id])),
+            )
+            .get_results(conn)
+            .await?;
+        let mut deleted = vec![];
+        for challenge in challenges {
+            deleted.push(ChallengeId(challenge.nanoid));
+            diesel::delete(challenges::table.find(challenge.id))
+                .execute(conn)
+                .await?;
+        }
+        Ok(deleted)
+    }
+
     pub fn get_heartbeat(&self) -> Result<(GameId, Duration, Duration), DbError> {
         let (white, black) = self.get_time_left()?;
         Ok((GameId(self.nanoid.clone()), white, black))
@@ -366,22 +550,39 @@ impl Game {
         if self.game_status == GameStatus::NotStarted.to_string() {
             return Ok((white, black));
         }
-        if let Some(last) = self.last_interaction {
-            if let Ok(time_passed) = Utc::now().signed_duration_since(last).to_std() {
-                if self.turn % 2 == 0 {
-                    if white < time_passed {
-                        return Ok((Duration::from_secs(0), black));
-                    }
-                    return Ok((white - time_passed, black));
-                } else {
-                    if black < time_passed {
-                        return Ok((white, Duration::from_secs(0)));
-                    }
-                    return Ok((white, black - time_passed));
-                };
-            }
+        let Some(time_passed) = self.time_passed() else {
+            return Ok((white, black));
+        };
+        let clock = self.clock_rules();
+        if self.turn % 2 == 0 {
+            Ok((
+                clock.kind().running(white, time_passed, clock.per_move()),
+                black,
+            ))
+        } else {
+            Ok((
+                white,
+                clock.kind().running(black, time_passed, clock.per_move()),
+            ))
+        }
+    }
+
+    pub fn clock(&self) -> ClockKind {
+        self.clock_rules().kind()
+    }
+
+    fn clock_rules(&self) -> ClockRules<'_> {
+        ClockRules {
+            time_mode: &self.time_mode,
+            clock_kind: &self.clock_kind,
+            time_increment: self.time_increment,
         }
-        Ok((white, black))
+    }
+
+    /// Time since the player to move got the move.
+    fn time_passed(&self) -> Option<Duration> {
+        let last = self.last_interaction?;
+        Utc::now().signed_duration_since(last).to_std().ok()
     }
 
     pub async fn check_time(&self, conn: &mut DbConn<'_>) -> Result<Game, DbError> {
@@ -441,7 +642,12 @@ impl Game {
         };
         let time_left = self.time_left_duration(active_color)?;
         if let Ok(time_passed) = Utc::now().signed_duration_since(last_seen).to_std() {
-            if time_left > time_passed {
+            let clock = self.clock_rules();
+            if !clock
+                .kind()
+                .running(time_left, time_passed, clock.per_move())
+                .is_zero()
+            {
                 return Ok(None);
             }
         }
@@ -468,6 +674,7 @@ impl Game {
         ) = Rating::update(
             self.rated,
             self.speed.clone(),
+            self.variant_class()?,
             self.white_id,
             self.black_id,
             result,
@@ -528,6 +735,7 @@ impl Game {
         ) = Rating::update(
             self.rated,
             self.speed.clone(),
+            self.variant_class()?,
             self.white_id,
             self.black_id,
             result,
@@ -574,18 +782,8 @@ impl Game {
             })
     }
 
-    fn time_increment_duration(&self) -> Result<Duration, DbError> {
-        if let Some(increment) = self.time_increment {
-            Ok(Duration::from_secs(increment as u64))
-        } else {
-            Err(DbError::TimeNotFound {
-                reason: String::from("Could not find time_increment"),
-            })
-        }
-    }
-
     fn calculate_time_left(&self) -> Result<(Option<i64>, Option<i64>), DbError> {
-        let mut time_left = self.time_left_duration(if self.turn % 2 == 0 {
+        let time_left = self.time_left_duration(if self.turn % 2 == 0 {
             Color::White
         } else {
             Color::Black
@@ -593,61 +791,79 @@ impl Game {
         let (mut black_time, mut white_time) = (self.black_time_left, self.white_time_left);
         if let Some(last) = self.last_interaction {
             let time_passed = Utc::now().signed_duration_since(last).to_std().unwrap();
-            if time_left > time_passed {
-                // substract passed time and add time_increment
-                time_left -= time_passed;
-                if self.turn % 2 == 0 {
-                    white_time = Some(time_left.as_nanos() as i64);
-                } else {
-                    black_time = Some(time_left.as_nanos() as i64);
-                };
-            } else if self.turn % 2 == 0 {
-                white_time = Some(0);
+            let clock = self.clock_rules();
+            let running = clock
+                .kind()
+                .running(time_left, time_passed, clock.per_move())
+                .as_nanos() as i64;
+            if self.turn % 2 == 0 {
+                white_time = Some(running);
             } else {
-                black_time = Some(0);
-            }
+                black_time = Some(running);
+            };
         }
         Ok((white_time, black_time))
     }
 
+    /// Settles the mover's clock for the move just made. A shutout passes the
+    /// opponent's move too, which counts as instant.
     fn calculate_time_left_add_increment(
         &self,
         shutout: bool,
-        comp: f64,
+        spent: TimeSpent,
     ) -> Result<(Option<i64>, Option<i64>), DbError> {
-        let (mut white_time, mut black_time) = self.calculate_time_left()?;
+        let (white_time, black_time) = self.calculate_time_left()?;
         if let (Some(w), Some(b)) = (white_time, black_time) {
             if w == 0 || b == 0 {
                 return Ok((white_time, black_time));
             }
         }
-        let comp = (comp * 1_000_000_000.0) as i64;
-        let increment = self.time_increment_duration()?.as_nanos() as i64;
+        let clock = self.clock_rules();
+        let time_passed = match spent {
+            TimeSpent::Measured(comp) => self
+                .time_passed()
+                .unwrap_or_default()
+                .saturating_sub(Duration::try_from_secs_f64(comp).unwrap_or_default()),
+            TimeSpent::Zero => Duration::ZERO,
+        };
+        let settle = |time: Option<i64>, time_passed: Duration| {
+            time.map(|nanos| {
+                clock
+                    .kind()
+                    .after_move(
+                        Duration::from_nanos(nanos as u64),
+                        time_passed,
+                        clock.per_move(),
+                    )
+                    .map_or(0, |time| time.as_nanos() as i64)
+            })
+        };
+        let (mut white_time, mut black_time) = (self.white_time_left, self.black_time_left);
         if self.turn % 2 == 0 {
-            white_time = white_time.map(|time| time + increment + comp);
+            white_time = settle(white_time, time_passed);
         } else {
-            black_time = black_time.map(|time| time + increment + comp);
+            black_time = settle(black_time, time_passed);
         };
         if shutout {
             if self.turn % 2 == 0 {
-                black_time = black_time.map(|time| time + increment);
+                black_time = settle(black_time, Duration::ZERO);
             } else {
-                white_time = white_time.map(|time| time + increment);
+                white_time = settle(white_time, Duration::ZERO);
             };
         };
 
         Ok((white_time, black_time))
     }
 
-    fn get_time_info(&self, state: &State, comp: f64) -> Result<TimeInfo, DbError> {
+    fn get_time_info(&self, state: &State, spent: TimeSpent) -> Result<TimeInfo, DbError> {
         match TimeMode::from_str(&self.time_mode)? {
             TimeMode::Untimed => Ok(TimeInfo::new(state.game_status.clone())),
-            TimeMode::RealTime => self.get_realtime_time_info(state, comp),
+            TimeMode::RealTime => self.get_realtime_time_info(state, spent),
             TimeMode::Correspondence => self.get_correspondence_time_info(state),
         }
     }
 
-    fn get_realtime_time_info(&self, state: &State, comp: f64) -> Result<TimeInfo, DbError> {
+    fn get_realtime_time_info(&self, state: &State, spent: TimeSpent) -> Result<TimeInfo, DbError> {
         let mut time_info = TimeInfo::new(state.game_status.clone());
         if self.turn < 2
             && self.game_start == GameStart::Moves.to_string()
@@ -660,7 +876,7 @@ impl Game {
             time_info.black_time_left = self.black_time_left;
         } else {
             (time_info.white_time_left, time_info.black_time_left) =
-                self.calculate_time_left_add_increment(state.history.last_move_is_pass(), comp)?;
+                self.calculate_time_left_add_increment(state.history.last_move_is_pass(), spent)?;
             if self.turn % 2 == 0 {
                 if time_info.white_time_left == Some(0) {
                     time_info.timed_out = true;
@@ -755,10 +971,10 @@ impl Game {
     pub async fn update_gamestate(
         &self,
         state: &State,
-        comp: f64,
+        spent: TimeSpent,
         conn: &mut DbConn<'_>,
     ) -> Result<Game, DbError> {
-        let time_info = self.get_time_info(state, comp)?;
+        let time_info = self.get_time_info(state, spent)?;
         let new_history = state
             .history
             .moves
@@ -835,6 +1051,7 @@ impl Game {
                     ) = Rating::update(
                         game.rated,
                         game.speed.clone(),
+                        game.variant_class()?,
                         game.white_id,
                         game.black_id,
                         game_result,
@@ -884,7 +1101,7 @@ impl Game {
             time_info.white_time_left,
             time_info.black_time_left,
             new_turn,
-            &self.time_mode,
+            self.clock_rules(),
             &new_status_str,
         );
         let update = diesel::update(
@@ -1008,6 +1225,8 @@ impl Game {
 
     fn get_takeback_time_realtime(&self, popped: i32) -> (Option<i64>, Option<i64>) {
         let past_turn = self.turn - popped;
+        let clock = self.clock_rules();
+        let bonus = clock.kind().fixed_bonus(clock.per_move()).as_nanos() as i64;
         let mut times = self.move_times.clone();
         let mut black_time = self.black_time_left;
         let mut white_time = self.white_time_left;
@@ -1028,27 +1247,15 @@ impl Game {
 
         if past_turn > 1 {
             if self.turn % 2 == 0 {
-                black_time = Some(
-                    black_time.unwrap_or(0)
-                        - self.time_increment.unwrap_or(0) as i64 * NANOS_IN_SECOND as i64,
-                );
+                black_time = Some(black_time.unwrap_or(0) - bonus);
             } else {
-                white_time = Some(
-                    white_time.unwrap_or(0)
-                        - self.time_increment.unwrap_or(0) as i64 * NANOS_IN_SECOND as i64,
-                );
+                white_time = Some(white_time.unwrap_or(0) - bonus);
             }
             if popped == 2 {
                 if self.turn % 2 == 0 {
-                    white_time = Some(
-                        white_time.unwrap_or(0)
-                            - self.time_increment.unwrap_or(0) as i64 * NANOS_IN_SECOND as i64,
-                    );
+                    white_time = Some(white_time.unwrap_or(0) - bonus);
                 } else {
-                    black_time = Some(
-                        black_time.unwrap_or(0)
-                            - self.time_increment.unwrap_or(0) as i64 * NANOS_IN_SECOND as i64,
-                    );
+                    black_time = Some(black_time.unwrap_or(0) - bonus);
                 }
             }
         }
@@ -1119,7 +1326,7 @@ impl Game {
             white_time,
             black_time,
             new_turn,
-            &self.time_mode,
+            self.clock_rules(),
             &new_game_status,
         );
 
@@ -1243,6 +1450,23 @@ impl Game {
         Ok(checked_games)
     }
 
+    /// The white and black player of each of these games that exists.
+    pub async fn players_by_nanoids(
+        game_ids: &[GameId],
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<(GameId, [Uuid; 2])>, DbError> {
+        let nanoids: Vec<&str> = game_ids.iter().map(|g| g.0.as_str()).collect();
+        let players: Vec<(String, Uuid, Uuid)> = games::table
+            .filter(nanoid.eq_any(&nanoids))
+            .select((nanoid, white_id, black_id))
+            .load(conn)
+            .await?;
+        Ok(players
+            .into_iter()
+            .map(|(game, white, black)| (GameId(game), [white, black]))
+            .collect())
+    }
+
     /// Best-effort batched lookup used by the websocket heartbeat. Rows whose
     /// `time_mode` fails to parse or whose `check_time` returns an error are
     /// silently dropped from the result rather than aborting the whole batch
@@ -1365,6 +1589,25 @@ impl Game {
             })
     }
 
+    /// Real-time games any of `user_ids` is playing right now.
+    pub async fn find_live_for_users(
+        user_ids: &[Uuid],
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Game>, DbError> {
+        Ok(games::table
+            .filter(games::finished.eq(false))
+            .filter(games::game_status.eq(GameStatus::InProgress.to_string()))
+            .filter(games::time_mode.eq(TimeMode::RealTime.to_string()))
+            .filter(
+                games::white_id
+                    .eq_any(user_ids)
+                    .or(games::black_id.eq_any(user_ids)),
+            )
+            .order(games::created_at.desc())
+            .load(conn)
+            .await?)
+    }
+
     pub async fn get_rows_from_options(
         options: &GamesQueryOptions,
         conn: &mut DbConn<'_>,
@@ -1495,6 +1738,57 @@ impl Game {
         Ok(game)
     }
 
+    /// Awards the game to the opponent of `forfeiting` without touching ratings.
+    pub(crate) async fn forfeit(
+        &self,
+        forfeiting: Color,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        let game = Self::locked_unfinished(self.id, conn).await?;
+        let result = TournamentGameResult::Winner(forfeiting.opposite_color());
+        Ok(diesel::update(games::table.find(game.id))
+            .set((
+                finished.eq(true),
+                conclusion.eq(Conclusion::Forfeit.to_string()),
+                game_status.eq(GameStatus::Adjudicated.to_string()),
+                tournament_game_result.eq(result.to_string()),
+                updated_at.eq(Utc::now()),
+                last_interaction.eq(Some(Utc::now())),
+                timeout_at.eq(CLEAR_TIMEOUT_AT),
+            ))
+            .get_result(conn)
+            .await?)
+    }
+
+    /// Puts a game nobody has moved in back to not started, so its clock stops.
+    pub(crate) async fn stop_clock(&self, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        if self.finished || self.turn > 0 || self.game_status != GameStatus::InProgress.to_string()
+        {
+            return Ok(self.clone());
+        }
+        Ok(diesel::update(games::table.find(self.id))
+            .set((
+                game_status.eq(GameStatus::NotStarted.to_string()),
+                updated_at.eq(Utc::now()),
+                last_interaction.eq::<Option<DateTime<Utc>>>(None),
+                timeout_at.eq(CLEAR_TIMEOUT_AT),
+            ))
+            .get_result(conn)
+            .await?)
+    }
+
+    /// Untouched games of a paused tournament wait until the organizer resumes it.
+    pub async fn ensure_tournament_not_paused(&self, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        if let Some(tid) = self.tournament_id {
+            if self.turn == 0 && Tournament::find(tid, conn).await?.paused_at.is_some() {
+                return Err(DbError::InvalidAction {
+                    info: String::from("The tournament is paused"),
+                });
+            }
+        }
+        Ok(())
+    }
+
     pub async fn start(&self, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
         if self.finished || self.turn > 0 || self.game_status != GameStatus::NotStarted.to_string()
         {
@@ -1508,7 +1802,7 @@ impl Game {
             self.white_time_left,
             self.black_time_left,
             0,
-            &self.time_mode,
+            self.clock_rules(),
             &GameStatus::InProgress.to_string(),
         );
         Ok(diesel::update(games::table.find(self.id))
@@ -1543,12 +1837,19 @@ impl Game {
     pub async fn get_rating_history_for_player(
         player: Uuid,
         game_speed: &GameSpeed,
+        variant_class: VariantClass,
         conn: &mut DbConn<'_>,
     ) -> Result<Vec<GameRatings>, DbError> {
         if matches!(game_speed, GameSpeed::Untimed) {
             return Ok(vec![]);
         }
-        let games_preload = games::table
+        let mut query = games::table.into_boxed();
+        let base = GameType::Base.to_string();
+        query = match variant_class {
+            VariantClass::Base => query.filter(game_type.eq(base)),
+            VariantClass::Expansions => query.filter(game_type.ne(base)),
+        };
+        let games_preload = query
             .filter(rated.eq(true))
             .filter(finished.eq(true))
             .filter(speed.eq(game_speed.to_string()))
@@ -1591,6 +1892,44 @@ impl Game {
             .collect())
     }
 
+    /// Counts the player's finished games that moved their rating at this
+    /// speed and variant class. Unrated games also bump `Rating::played`, so
+    /// that counter can't stand in for this.
+    pub async fn count_rated_for_player(
+        player: Uuid,
+        game_speed: &GameSpeed,
+        variant_class: VariantClass,
+        conn: &mut DbConn<'_>,
+    ) -> Result<i64, DbError> {
+        // Untimed games share the correspondence rating.
+        let speeds = match game_speed {
+            GameSpeed::Untimed | GameSpeed::Correspondence => vec![
+                GameSpeed::Untimed.to_string(),
+                GameSpeed::Correspondence.to_string(),
+            ],
+            _ => vec![game_speed.to_string()],
+        };
+        let mut query = games::table.into_boxed();
+        let base = GameType::Base.to_string();
+        query = match variant_class {
+            VariantClass::Base => query.filter(game_type.eq(base)),
+            VariantClass::Expansions => query.filter(game_type.ne(base)),
+        };
+        Ok(query
+            .filter(rated.eq(true))
+            .filter(finished.eq(true))
+            .filter(speed.eq_any(speeds))
+            .filter(
+                white_id
+                    .eq(player)
+                    .and(white_rating_change.is_not_null())
+                    .or(black_id.eq(player).and(black_rating_change.is_not_null())),
+            )
+            .count()
+            .get_result(conn)
+            .await?)
+    }
+
     pub async fn count_needing_hash_backfill(conn: &mut DbConn<'_>) -> Result<i64, DbError> {
         Ok(games::table
             .filter(games::history.ne(""))
@@ -1619,6 +1958,104 @@ impl Game {
         Ok(query.load(conn).await?)
     }
 
+    /// The user's finished games grouped by book opening and colour, most played first.
+    /// Games that left the book before its first named position are left out.
+    pub async fn opening_stats(
+        user_id: Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<OpeningStats>, DbError> {
+        let status = |result| GameStatus::Finished(result).to_string();
+        // Games are grouped by the positions the book looks at, which leaves
+        // one row per line played instead of one per game
+        let rows: Vec<OpeningLineRow> = diesel::sql_query(
+            r#"
+            SELECT
+                game_type,
+                hashes[1:$2] AS opening_hashes,
+                white_id = $1 AS as_white,
+                COUNT(*) AS games,
+                COUNT(*) FILTER (WHERE game_status = $3) AS white_wins,
+                COUNT(*) FILTER (WHERE game_status = $4) AS black_wins,
+                COUNT(*) FILTER (WHERE game_status = $5) AS draws
+            FROM games
+            WHERE finished AND (white_id = $1 OR black_id = $1)
+            GROUP BY 1, 2, 3
+            "#,
+        )
+        .bind::<SqlUuid, _>(user_id)
+        .bind::<Integer, _>(OpeningBook::DEPTH as i32)
+        .bind::<Text, _>(status(GameResult::Winner(Color::White)))
+        .bind::<Text, _>(status(GameResult::Winner(Color::Black)))
+        .bind::<Text, _>(status(GameResult::Draw))
+        .load(conn)
+        .await?;
+        let mut stats: HashMap<(String, Color), OpeningStats> = HashMap::new();
+        for row in rows {
+            let Ok(variant) = GameType::from_str(&row.game_type) else {
+                continue;
+            };
+            let position_hashes = row
+                .opening_hashes
+                .into_iter()
+                .flatten()
+                .map(|h| h as u64)
+                .collect::<Vec<_>>();
+            let Some(opening) = OpeningBook::for_game_type(variant).classify(&position_hashes)
+            else {
+                continue;
+            };
+            let (color, wins, losses) = if row.as_white {
+                (Color::White, row.white_wins, row.black_wins)
+            } else {
+                (Color::Black, row.black_wins, row.white_wins)
+            };
+            let entry = stats
+                .entry((opening.code.clone(), color))
+                .or_insert_with(|| OpeningStats {
+                    code: opening.code.clone(),
+                    name: opening.name.clone(),
+                    color,
+                    games: 0,
+                    wins: 0,
+                    draws: 0,
+                    losses: 0,
+                });
+            entry.games += row.games;
+            entry.wins += wins;
+            entry.draws += row.draws;
+            entry.losses += losses;
+        }
+        let mut stats = stats.into_values().collect::<Vec<_>>();
+        stats.sort_by(|a, b| b.games.cmp(&a.games).then_with(|| a.code.cmp(&b.code)));
+        Ok(stats)
+    }
+
+    /// Finished games decided on the board or by resignation that the puzzle
+    /// miner hasn't searched yet.
+    pub async fn find_unmined_for_puzzles(
+        after_id: Option<Uuid>,
+        limit: i64,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Game>, DbError> {
+        let mut query = games::table
+            .filter(games::history.ne(""))
+            .filter(games::finished.eq(true))
+            .filter(games::conclusion.eq_any([
+                Conclusion::Board.to_string(),
+                Conclusion::Resigned.to_string(),
+            ]))
+            .filter(diesel::dsl::not(diesel::dsl::exists(
+                puzzle_mined_games::table.filter(puzzle_mined_games::game_id.eq(games::id)),
+            )))
+            .order(games::id.asc())
+            .limit(limit)
+            .into_boxed();
+        if let Some(after) = after_id {
+            query = query.filter(games::id.gt(after));
+        }
+        Ok(query.load(conn).await?)
+    }
+
     pub async fn set_hashes(
         game_id: Uuid,
         new_hashes: Vec<Option<i64>>,
@@ -0,0 +1,184 @@
+use crate::{
+    db_error::DbError,
+    schema::{
+        game_analyses::{self, dsl},
+        game_analysis_plies,
+    },
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use shared_types::{AnalysisStatus, PlyAnalysis};
+use uuid::Uuid;
+
+/// A request for computer analysis of a finished game, worked off in order by the
+/// `game_analysis` job.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = game_analyses, primary_key(game_id))]
+pub struct GameAnalysis {
+    pub game_id: Uuid,
+    pub requested_by: Uuid,
+    pub status: String,
+    pub engine: String,
+    pub error: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = game_analysis_plies)]
+pub struct AnalysisPly {
+    pub game_id: Uuid,
+    pub ply: i32,
+    pub eval: i32,
+    pub best_move: String,
+    pub best_eval: i32,
+    pub judgement: Option<String>,
+}
+
+impl From<AnalysisPly> for PlyAnalysis {
+    fn from(ply: AnalysisPly) -> Self {
+        PlyAnalysis {
+            ply: ply.ply,
+            eval: ply.eval,
+            best_move: ply.best_move,
+            best_eval: ply.best_eval,
+            judgement: ply.judgement.and_then(|judgement| judgement.parse().ok()),
+        }
+    }
+}
+
+impl GameAnalysis {
+    /// Queues `game_id`. Asking again is a no-op unless the last attempt failed, which
+    /// puts the game back at the end of the queue.
+    pub async fn request(
+        game_id: Uuid,
+        user_id: Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            let existing: Option<Self> = dsl::game_analyses
+                .find(game_id)
+                .for_update()
+                .first(tc)
+                .await
+                .optional()?;
+            match existing {
+                Some(analysis) if analysis.status() != AnalysisStatus::Failed => Ok(analysis),
+                Some(_) => Ok(diesel::update(dsl::game_analyses.find(game_id))
+                    .set((
+                        dsl::status.eq(AnalysisStatus::Pending.to_string()),
+                        dsl::requested_by.eq(user_id),
+                        dsl::requested_at.eq(Utc::now()),
+                        dsl::error.eq(None::<String>),
+                    ))
+                    .get_result(tc)
+                    .await?),
+                None => Ok(diesel::insert_into(game_analyses::table)
+                    .values((dsl::game_id.eq(game_id), dsl::requested_by.eq(user_id)))
+                    .get_result(tc)
+                    .await?),
+            }
+        })
+        .await
+    }
+
+    /// Takes the oldest pending request and marks it running. Concurrent workers skip
+    /// requests another one already holds.
+    pub async fn claim_next(conn: &mut DbConn<'_>) -> Result<Option<Self>, DbError> {
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            let next: Option<Uuid> = dsl::game_analyses
+                .filter(dsl::status.eq(AnalysisStatus::Pending.to_string()))
+                .order(dsl::requested_at.asc())
+                .select(dsl::game_id)
+                .for_update()
+                .skip_locked()
+                .first(tc)
+                .await
+                .optional()?;
+            let Some(game_id) = next else {
+                return Ok(None);
+            };
+            Ok(Some(
+                diesel::update(dsl::game_analyses.find(game_id))
+                    .set(dsl::status.eq(AnalysisStatus::Running.to_string()))
+                    .get_result(tc)
+                    .await?,
+            ))
+        })
+        .await
+    }
+
+    /// Puts requests a stopped worker left running back in the queue.
+    pub async fn requeue_running(conn: &mut DbConn<'_>) -> Result<usize, DbError> {
+        Ok(diesel::update(
+            dsl::game_analyses.filter(dsl::status.eq(AnalysisStatus::Running.to_string())),
+        )
+        .set(dsl::status.eq(AnalysisStatus::Pending.to_string()))
+        .execute(conn)
+        .await?)
+    }
+
+    pub async fn finish(
+        game_id: Uuid,
+        engine: &str,
+        plies: Vec<AnalysisPly>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        let engine = engine.to_string();
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            diesel::delete(
+                game_analysis_plies::table.filter(game_analysis_plies::game_id.eq(game_id)),
+            )
+            .execute(tc)
+            .await?;
+            diesel::insert_into(game_analysis_plies::table)
+                .values(&plies)
+                .execute(tc)
+                .await?;
+            Ok(diesel::update(dsl::game_analyses.find(game_id))
+                .set((
+                    dsl::status.eq(AnalysisStatus::Done.to_string()),
+                    dsl::engine.eq(engine),
+                    dsl::error.eq(None::<String>),
+                    dsl::finished_at.eq(Utc::now()),
+                ))
+                .get_result(tc)
+                .await?)
+        })
+        .await
+    }
+
+    pub async fn fail(game_id: Uuid, error: &str, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        diesel::update(dsl::game_analyses.find(game_id))
+            .set((
+                dsl::status.eq(AnalysisStatus::Failed.to_string()),
+                dsl::error.eq(error),
+                dsl::finished_at.eq(Utc::now()),
+            ))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn find(game_id: Uuid, conn: &mut DbConn<'_>) -> Result<Option<Self>, DbError> {
+        Ok(dsl::game_analyses
+            .find(game_id)
+            .first(conn)
+            .await
+            .optional()?)
+    }
+
+    pub async fn plies(&self, conn: &mut DbConn<'_>) -> Result<Vec<AnalysisPly>, DbError> {
+        Ok(game_analysis_plies::table
+            .filter(game_analysis_plies::game_id.eq(self.game_id))
+            .order(game_analysis_plies::ply.asc())
+            .load(conn)
+            .await?)
+    }
+
+    pub fn status(&self) -> AnalysisStatus {
+        self.status.parse().unwrap_or_default()
+    }
+}
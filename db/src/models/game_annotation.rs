@@ -0,0 +1,204 @@
+use crate::{
+    db_error::DbError,
+    models::Game,
+    schema::{
+        game_annotations::{self, dsl},
+        game_annotators,
+        users,
+    },
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use shared_types::{GameAnnotations, MoveAnnotation, MAX_MOVE_COMMENT_LENGTH};
+use uuid::Uuid;
+
+/// A player who annotated one of their games. Everything they wrote on it is shared or
+/// kept private together.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = game_annotators, primary_key(game_id, user_id))]
+pub struct GameAnnotator {
+    pub game_id: Uuid,
+    pub user_id: Uuid,
+    pub public: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Queryable, Selectable, Insertable, Debug, Clone)]
+#[diesel(table_name = game_annotations)]
+pub struct GameAnnotation {
+    pub game_id: Uuid,
+    pub user_id: Uuid,
+    pub ply: i32,
+    pub nag: Option<String>,
+    pub comment: String,
+}
+
+impl From<GameAnnotation> for MoveAnnotation {
+    fn from(annotation: GameAnnotation) -> Self {
+        MoveAnnotation {
+            ply: annotation.ply,
+            nag: annotation.nag.and_then(|nag| nag.parse().ok()),
+            comment: annotation.comment,
+        }
+    }
+}
+
+impl GameAnnotation {
+    /// Writes `annotation` on `game` for `user_id`, replacing what they had on that move. One
+    /// without a symbol or comment removes it.
+    pub async fn save(
+        game: &Game,
+        user_id: Uuid,
+        annotation: MoveAnnotation,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        ensure_annotator(game, user_id)?;
+        if annotation.ply < 0 || annotation.ply >= game.turn {
+            return Err(DbError::InvalidInput {
+                info: String::from("That move is not part of the game"),
+                error: format!("ply {} of {}", annotation.ply, game.turn),
+            });
+        }
+        let comment = annotation.comment.trim().to_string();
+        if comment.chars().count() > MAX_MOVE_COMMENT_LENGTH {
+            return Err(DbError::InvalidInput {
+                info: format!("Comments are limited to {MAX_MOVE_COMMENT_LENGTH} characters"),
+                error: String::new(),
+            });
+        }
+        let row = GameAnnotation {
+            game_id: game.id,
+            user_id,
+            ply: annotation.ply,
+            nag: annotation.nag.map(|nag| nag.to_string()),
+            comment,
+        };
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            touch_annotator(row.game_id, row.user_id, tc).await?;
+            if row.nag.is_none() && row.comment.is_empty() {
+                diesel::delete(dsl::game_annotations.find((row.game_id, row.user_id, row.ply)))
+                    .execute(tc)
+                    .await?;
+                return Ok(());
+            }
+            diesel::insert_into(game_annotations::table)
+                .values(&row)
+                .on_conflict((dsl::game_id, dsl::user_id, dsl::ply))
+                .do_update()
+                .set((
+                    dsl::nag.eq(excluded(dsl::nag)),
+                    dsl::comment.eq(excluded(dsl::comment)),
+                ))
+                .execute(tc)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Shares or hides everything `user_id` wrote on `game`.
+    pub async fn set_public(
+        game: &Game,
+        user_id: Uuid,
+        public: bool,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        ensure_annotator(game, user_id)?;
+        diesel::insert_into(game_annotators::table)
+            .values((
+                game_annotators::game_id.eq(game.id),
+                game_annotators::user_id.eq(user_id),
+                game_annotators::public.eq(public),
+            ))
+            .on_conflict((game_annotators::game_id, game_annotators::user_id))
+            .do_update()
+            .set((
+                game_annotators::public.eq(public),
+                game_annotators::updated_at.eq(Utc::now()),
+            ))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// The annotations on `game_id` that `viewer` may read: their own and the shared ones.
+    pub async fn for_game(
+        game_id: Uuid,
+        viewer: Option<Uuid>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<GameAnnotations>, DbError> {
+        let mut query = game_annotators::table
+            .inner_join(users::table)
+            .filter(game_annotators::game_id.eq(game_id))
+            .into_boxed();
+        query = match viewer {
+            Some(viewer) => query.filter(
+                game_annotators::public
+                    .eq(true)
+                    .or(game_annotators::user_id.eq(viewer)),
+            ),
+            None => query.filter(game_annotators::public.eq(true)),
+        };
+        let annotators: Vec<(GameAnnotator, String)> = query
+            .order(game_annotators::updated_at.desc())
+            .select((GameAnnotator::as_select(), users::username))
+            .load(conn)
+            .await?;
+        let author_ids: Vec<Uuid> = annotators
+            .iter()
+            .map(|(annotator, _)| annotator.user_id)
+            .collect();
+        let rows: Vec<GameAnnotation> = dsl::game_annotations
+            .filter(dsl::game_id.eq(game_id))
+            .filter(dsl::user_id.eq_any(author_ids))
+            .order(dsl::ply.asc())
+            .load(conn)
+            .await?;
+        Ok(annotators
+            .into_iter()
+            .map(|(annotator, author)| GameAnnotations {
+                author_id: annotator.user_id,
+                author,
+                public: annotator.public,
+                moves: rows
+                    .iter()
+                    .filter(|row| row.user_id == annotator.user_id)
+                    .cloned()
+                    .map(Into::into)
+                    .collect(),
+            })
+            .collect())
+    }
+}
+
+fn ensure_annotator(game: &Game, user_id: Uuid) -> Result<(), DbError> {
+    if !game.user_is_player(user_id) {
+        return Err(DbError::Unauthorized);
+    }
+    if !game.finished {
+        return Err(DbError::InvalidAction {
+            info: String::from("Games can be annotated once they are over"),
+        });
+    }
+    Ok(())
+}
+
+async fn touch_annotator(
+    game_id: Uuid,
+    user_id: Uuid,
+    conn: &mut DbConn<'_>,
+) -> Result<(), DbError> {
+    diesel::insert_into(game_annotators::table)
+        .values((
+            game_annotators::game_id.eq(game_id),
+            game_annotators::user_id.eq(user_id),
+        ))
+        .on_conflict((game_annotators::game_id, game_annotators::user_id))
+        .do_update()
+        .set(game_annotators::updated_at.eq(Utc::now()))
+        .execute(conn)
+        .await?;
+    Ok(())
+}
@@ -1,18 +1,29 @@
-use super::game::Game;
+use super::{game::Game, position_stats};
 use crate::{db_error::DbError, schema::game_hashes, DbConn};
 use chrono::{DateTime, Utc};
 use diesel::{
     prelude::*,
-    sql_types::{Array, BigInt, Bool, Double, Integer, Nullable, Text, Uuid as SqlUuid},
+    sql_types::{
+        Array,
+        BigInt,
+        Bool,
+        Double,
+        Integer,
+        Nullable,
+        Text,
+        Timestamptz,
+        Uuid as SqlUuid,
+    },
 };
 use diesel_async::RunQueryDsl;
 use hive_lib::{Color, GameResult, GameStatus};
 use shared_types::{ExplorerFilters, ExplorerMove};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// The three decisive `result` strings stored in `game_hashes.result`, derived from the engine
 /// so they always match what the finish path writes (`Finished(1-0)` / `(0-1)` / `(½-½)`).
-fn result_strings() -> (String, String, String) {
+pub(super) fn result_strings() -> (String, String, String) {
     (
         GameStatus::Finished(GameResult::Winner(Color::White)).to_string(),
         GameStatus::Finished(GameResult::Winner(Color::Black)).to_string(),
@@ -20,9 +31,51 @@ fn result_strings() -> (String, String, String) {
     )
 }
 
+/// `player_games(game_id, color)`: the games of the explorer's player filter and the colour
+/// they played. Empty when no player is set, so left-joining it costs next to nothing. The
+/// username is bound at `$param`, the optional colour at `$param + 1`.
+fn player_games_cte(param: usize) -> String {
+    let color = param + 1;
+    format!(
+        r#"
+        WITH player_games AS (
+            SELECT g.id AS game_id, CASE WHEN g.white_id = u.id THEN 'w' ELSE 'b' END AS color
+            FROM users u
+            JOIN games g ON g.white_id = u.id OR g.black_id = u.id
+            WHERE u.normalized_username = lower(${param})
+                AND NOT u.deleted
+                AND (${color}::text IS NULL OR (g.white_id = u.id) = (${color} = 'w'))
+        )"#
+    )
+}
+
+/// The rating and date range filters on the `game_hashes` row `alias`, bound at `$param` to
+/// `$param + 3` in the order of [`ExplorerFilters`].
+fn range_filters(alias: &str, param: usize) -> String {
+    let (max, start, end) = (param + 1, param + 2, param + 3);
+    format!(
+        r#"
+                AND (${param}::int IS NULL OR {alias}.game_rating >= ${param})
+                AND (${max}::int IS NULL OR {alias}.game_rating < ${max})
+                AND (${start}::timestamptz IS NULL OR {alias}.played_at >= ${start})
+                AND (${end}::timestamptz IS NULL OR {alias}.played_at < ${end})"#
+    )
+}
+
+/// The username and colour binds for [`player_games_cte`].
+fn player_binds(filters: &ExplorerFilters) -> (Option<String>, Option<String>) {
+    match &filters.player {
+        Some(player) => (
+            Some(player.username.clone()),
+            player.color.map(|color| color.to_string()),
+        ),
+        None => (None, None),
+    }
+}
+
 /// Raw-SQL row for the next-moves / single-position aggregates.
 #[derive(QueryableByName, Debug)]
-struct AggRow {
+pub(super) struct AggRow {
     #[diesel(sql_type = BigInt)]
     next_hash: i64,
     #[diesel(sql_type = Text)]
@@ -39,6 +92,10 @@ struct AggRow {
     draws: i64,
     #[diesel(sql_type = Nullable<Double>)]
     avg_rating: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    player_wins: i64,
+    #[diesel(sql_type = BigInt)]
+    player_losses: i64,
 }
 
 impl From<AggRow> for ExplorerMove {
@@ -52,16 +109,53 @@ impl From<AggRow> for ExplorerMove {
             black_wins: r.black_wins,
             draws: r.draws,
             avg_rating: r.avg_rating,
+            player_wins: r.player_wins,
+            player_losses: r.player_losses,
         }
     }
 }
 
+/// Stats of a position no game under the filters reached.
+fn unplayed(hash: i64) -> ExplorerMove {
+    ExplorerMove {
+        next_hash: hash,
+        piece: String::new(),
+        position: String::new(),
+        total: 0,
+        white_wins: 0,
+        black_wins: 0,
+        draws: 0,
+        avg_rating: None,
+        player_wins: 0,
+        player_losses: 0,
+    }
+}
+
+/// Lines up per-position rows with the hashes they were queried for, zeroing the ones
+/// without a row.
+pub(super) fn in_order(hashes: &[i64], rows: Vec<AggRow>) -> Vec<ExplorerMove> {
+    let rows = rows
+        .into_iter()
+        .map(|row| (row.next_hash, ExplorerMove::from(row)))
+        .collect::<HashMap<_, _>>();
+    hashes
+        .iter()
+        .map(|hash| rows.get(hash).cloned().unwrap_or_else(|| unplayed(*hash)))
+        .collect()
+}
+
 #[derive(QueryableByName, Debug)]
 struct GameIdRow {
     #[diesel(sql_type = SqlUuid)]
     game_id: Uuid,
 }
 
+#[derive(QueryableByName, Debug)]
+struct CountedRow {
+    #[diesel(sql_type = Bool)]
+    counted: bool,
+}
+
 #[derive(Queryable, Insertable, Debug)]
 #[diesel(table_name = game_hashes)]
 pub struct GameHash {
@@ -80,6 +174,8 @@ pub struct GameHash {
     pub move_position: String,
     /// Total number of turns in the game, denormalized for filtering out ultra-short games.
     pub game_length: i32,
+    /// Average rating of both players, for the explorer's rating filter.
+    pub game_rating: Option<f64>,
 }
 
 pub struct GameFinishContext {
@@ -123,6 +219,10 @@ impl GameHash {
         ctx: &GameFinishContext,
     ) -> Vec<Self> {
         let game_length = hashes.len() as i32;
+        let game_rating = ctx
+            .white_rating
+            .zip(ctx.black_rating)
+            .map(|(white, black)| (white + black) / 2.0);
         hashes
             .iter()
             .enumerate()
@@ -145,20 +245,29 @@ impl GameHash {
                     move_piece: piece,
                     move_position: position,
                     game_length,
+                    game_rating,
                 }
             })
             .collect()
     }
 
+    /// Inserts the rows and adds the games they belong to to the explorer's `position_stats`.
+    /// Games whose rows were already there are skipped, so they are never counted twice.
     pub async fn insert_batch(entries: &[GameHash], conn: &mut DbConn<'_>) -> Result<(), DbError> {
         if entries.is_empty() {
             return Ok(());
         }
-        diesel::insert_into(game_hashes::table)
+        let mut inserted: Vec<Uuid> = diesel::insert_into(game_hashes::table)
             .values(entries)
             .on_conflict_do_nothing()
-            .execute(conn)
+            .returning(game_hashes::game_id)
+            .get_results(conn)
             .await?;
+        inserted.sort();
+        inserted.dedup();
+        for game_id in inserted {
+            position_stats::record_game(game_id, conn).await?;
+        }
         Ok(())
     }
 
@@ -198,16 +307,23 @@ impl GameHash {
     /// through it. Self-joins each matching row to the next turn's row; suggestions are keyed
     /// by the resulting position hash (canonical, so rotations/transpositions merge). Counts
     /// distinct games (not row occurrences, which a repetition could inflate). Ordered by
-    /// popularity, capped at `limit` (default 8).
+    /// popularity, capped at `limit` (default 8). With a player filter only their games count,
+    /// and their own wins and losses are tallied too. Filters that fit `position_stats` are
+    /// answered from there instead.
     pub async fn next_moves(
         hash: i64,
         filters: &ExplorerFilters,
         limit: Option<i64>,
         conn: &mut DbConn<'_>,
     ) -> Result<Vec<ExplorerMove>, DbError> {
+        if filters.aggregated() {
+            return position_stats::next_moves(hash, filters, limit.unwrap_or(8), conn).await;
+        }
         let (white_res, black_res, draw_res) = result_strings();
-        let rows: Vec<AggRow> = diesel::sql_query(
+        let (player, player_color) = player_binds(filters);
+        let rows: Vec<AggRow> = diesel::sql_query(format!(
             r#"
+            {}
             SELECT
                 nxt.hash AS next_hash,
                 COALESCE((array_agg(nxt.move_piece))[1], '') AS piece,
@@ -216,20 +332,30 @@ impl GameHash {
                 COUNT(DISTINCT nxt.game_id) FILTER (WHERE nxt.result = $6) AS white_wins,
                 COUNT(DISTINCT nxt.game_id) FILTER (WHERE nxt.result = $7) AS black_wins,
                 COUNT(DISTINCT nxt.game_id) FILTER (WHERE nxt.result = $8) AS draws,
-                AVG(nxt.rating) AS avg_rating
+                AVG(nxt.rating) AS avg_rating,
+                COUNT(DISTINCT nxt.game_id) FILTER (
+                    WHERE (pg.color = 'w' AND nxt.result = $6) OR (pg.color = 'b' AND nxt.result = $7)
+                ) AS player_wins,
+                COUNT(DISTINCT nxt.game_id) FILTER (
+                    WHERE (pg.color = 'w' AND nxt.result = $7) OR (pg.color = 'b' AND nxt.result = $6)
+                ) AS player_losses
             FROM game_hashes cur
             JOIN game_hashes nxt
                 ON nxt.game_id = cur.game_id AND nxt.turn = cur.turn + 1
+            LEFT JOIN player_games pg ON pg.game_id = nxt.game_id
             WHERE cur.hash = $1
                 AND nxt.game_type = $2
                 AND (cardinality($3) = 0 OR nxt.speed = ANY($3))
                 AND ($4 IS NULL OR nxt.rated = $4)
                 AND ($5 IS NULL OR nxt.game_length >= $5)
+                AND ($10::text IS NULL OR pg.game_id IS NOT NULL){}
             GROUP BY nxt.hash
             ORDER BY total DESC
             LIMIT $9
             "#,
-        )
+            player_games_cte(10),
+            range_filters("nxt", 12)
+        ))
         .bind::<BigInt, _>(hash)
         .bind::<Text, _>(filters.game_type.to_string())
         .bind::<Array<Text>, _>(
@@ -245,6 +371,12 @@ impl GameHash {
         .bind::<Text, _>(black_res)
         .bind::<Text, _>(draw_res)
         .bind::<BigInt, _>(limit.unwrap_or(8))
+        .bind::<Nullable<Text>, _>(player)
+        .bind::<Nullable<Text>, _>(player_color)
+        .bind::<Nullable<Integer>, _>(filters.rating_min)
+        .bind::<Nullable<Integer>, _>(filters.rating_max)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_start)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_end)
         .load(conn)
         .await?;
         Ok(rows.into_iter().map(ExplorerMove::from).collect())
@@ -258,27 +390,53 @@ impl GameHash {
         filters: &ExplorerFilters,
         conn: &mut DbConn<'_>,
     ) -> Result<ExplorerMove, DbError> {
+        let mut stats = Self::aggregate_many(&[hash], filters, conn).await?;
+        Ok(stats.pop().unwrap_or_else(|| unplayed(hash)))
+    }
+
+    /// `aggregate_one` for every hash in `hashes` in a single query, in the same order.
+    pub async fn aggregate_many(
+        hashes: &[i64],
+        filters: &ExplorerFilters,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<ExplorerMove>, DbError> {
+        if filters.aggregated() {
+            return position_stats::aggregate_many(hashes, filters, conn).await;
+        }
         let (white_res, black_res, draw_res) = result_strings();
-        let row: AggRow = diesel::sql_query(
+        let (player, player_color) = player_binds(filters);
+        let rows: Vec<AggRow> = diesel::sql_query(format!(
             r#"
+            {}
             SELECT
-                $1 AS next_hash,
+                gh.hash AS next_hash,
                 '' AS piece,
                 '' AS position,
-                COUNT(DISTINCT game_id) AS total,
-                COUNT(DISTINCT game_id) FILTER (WHERE result = $6) AS white_wins,
-                COUNT(DISTINCT game_id) FILTER (WHERE result = $7) AS black_wins,
-                COUNT(DISTINCT game_id) FILTER (WHERE result = $8) AS draws,
-                AVG(rating) AS avg_rating
-            FROM game_hashes
-            WHERE hash = $1
-                AND game_type = $2
-                AND (cardinality($3) = 0 OR speed = ANY($3))
-                AND ($4 IS NULL OR rated = $4)
-                AND ($5 IS NULL OR game_length >= $5)
+                COUNT(DISTINCT gh.game_id) AS total,
+                COUNT(DISTINCT gh.game_id) FILTER (WHERE gh.result = $6) AS white_wins,
+                COUNT(DISTINCT gh.game_id) FILTER (WHERE gh.result = $7) AS black_wins,
+                COUNT(DISTINCT gh.game_id) FILTER (WHERE gh.result = $8) AS draws,
+                AVG(gh.rating) AS avg_rating,
+                COUNT(DISTINCT gh.game_id) FILTER (
+                    WHERE (pg.color = 'w' AND gh.result = $6) OR (pg.color = 'b' AND gh.result = $7)
+                ) AS player_wins,
+                COUNT(DISTINCT gh.game_id) FILTER (
+                    WHERE (pg.color = 'w' AND gh.result = $7) OR (pg.color = 'b' AND gh.result = $6)
+                ) AS player_losses
+            FROM game_hashes gh
+            LEFT JOIN player_games pg ON pg.game_id = gh.game_id
+            WHERE gh.hash = ANY($1)
+                AND gh.game_type = $2
+                AND (cardinality($3) = 0 OR gh.speed = ANY($3))
+                AND ($4 IS NULL OR gh.rated = $4)
+                AND ($5 IS NULL OR gh.game_length >= $5)
+                AND ($9::text IS NULL OR pg.game_id IS NOT NULL){}
+            GROUP BY gh.hash
             "#,
-        )
-        .bind::<BigInt, _>(hash)
+            player_games_cte(9),
+            range_filters("gh", 11)
+        ))
+        .bind::<Array<BigInt>, _>(hashes)
         .bind::<Text, _>(filters.game_type.to_string())
         .bind::<Array<Text>, _>(
             filters
@@ -292,9 +450,15 @@ impl GameHash {
         .bind::<Text, _>(white_res)
         .bind::<Text, _>(black_res)
         .bind::<Text, _>(draw_res)
-        .get_result(conn)
+        .bind::<Nullable<Text>, _>(player)
+        .bind::<Nullable<Text>, _>(player_color)
+        .bind::<Nullable<Integer>, _>(filters.rating_min)
+        .bind::<Nullable<Integer>, _>(filters.rating_max)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_start)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_end)
+        .load(conn)
         .await?;
-        Ok(row.into())
+        Ok(in_order(hashes, rows))
     }
 
     /// Game ids of the most recently played games that passed through `hash`, honoring the
@@ -305,20 +469,26 @@ impl GameHash {
         limit: Option<i64>,
         conn: &mut DbConn<'_>,
     ) -> Result<Vec<Uuid>, DbError> {
-        let rows: Vec<GameIdRow> = diesel::sql_query(
+        let (player, player_color) = player_binds(filters);
+        let rows: Vec<GameIdRow> = diesel::sql_query(format!(
             r#"
-            SELECT game_id
-            FROM game_hashes
-            WHERE hash = $1
-                AND game_type = $2
-                AND (cardinality($3) = 0 OR speed = ANY($3))
-                AND ($4 IS NULL OR rated = $4)
-                AND ($5 IS NULL OR game_length >= $5)
-            GROUP BY game_id
-            ORDER BY MAX(played_at) DESC
+            {}
+            SELECT gh.game_id
+            FROM game_hashes gh
+            LEFT JOIN player_games pg ON pg.game_id = gh.game_id
+            WHERE gh.hash = $1
+                AND gh.game_type = $2
+                AND (cardinality($3) = 0 OR gh.speed = ANY($3))
+                AND ($4 IS NULL OR gh.rated = $4)
+                AND ($5 IS NULL OR gh.game_length >= $5)
+                AND ($7::text IS NULL OR pg.game_id IS NOT NULL){}
+            GROUP BY gh.game_id
+            ORDER BY MAX(gh.played_at) DESC
             LIMIT $6
             "#,
-        )
+            player_games_cte(7),
+            range_filters("gh", 9)
+        ))
         .bind::<BigInt, _>(hash)
         .bind::<Text, _>(filters.game_type.to_string())
         .bind::<Array<Text>, _>(
@@ -331,6 +501,12 @@ impl GameHash {
         .bind::<Nullable<Bool>, _>(filters.rated)
         .bind::<Nullable<Integer>, _>(filters.min_game_length)
         .bind::<BigInt, _>(limit.unwrap_or(4))
+        .bind::<Nullable<Text>, _>(player)
+        .bind::<Nullable<Text>, _>(player_color)
+        .bind::<Nullable<Integer>, _>(filters.rating_min)
+        .bind::<Nullable<Integer>, _>(filters.rating_max)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_start)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_end)
         .load(conn)
         .await?;
         Ok(rows.into_iter().map(|r| r.game_id).collect())
@@ -345,21 +521,27 @@ impl GameHash {
         limit: Option<i64>,
         conn: &mut DbConn<'_>,
     ) -> Result<Vec<Uuid>, DbError> {
-        let rows: Vec<GameIdRow> = diesel::sql_query(
+        let (player, player_color) = player_binds(filters);
+        let rows: Vec<GameIdRow> = diesel::sql_query(format!(
             r#"
-            SELECT game_id
-            FROM game_hashes
-            WHERE hash = $1
-                AND game_type = $2
-                AND rating IS NOT NULL
-                AND (cardinality($3) = 0 OR speed = ANY($3))
-                AND ($4 IS NULL OR rated = $4)
-                AND ($5 IS NULL OR game_length >= $5)
-            GROUP BY game_id
-            ORDER BY MAX(rating) DESC
+            {}
+            SELECT gh.game_id
+            FROM game_hashes gh
+            LEFT JOIN player_games pg ON pg.game_id = gh.game_id
+            WHERE gh.hash = $1
+                AND gh.game_type = $2
+                AND gh.rating IS NOT NULL
+                AND (cardinality($3) = 0 OR gh.speed = ANY($3))
+                AND ($4 IS NULL OR gh.rated = $4)
+                AND ($5 IS NULL OR gh.game_length >= $5)
+                AND ($7::text IS NULL OR pg.game_id IS NOT NULL){}
+            GROUP BY gh.game_id
+            ORDER BY MAX(gh.rating) DESC
             LIMIT $6
             "#,
-        )
+            player_games_cte(7),
+            range_filters("gh", 9)
+        ))
         .bind::<BigInt, _>(hash)
         .bind::<Text, _>(filters.game_type.to_string())
         .bind::<Array<Text>, _>(
@@ -372,8 +554,63 @@ impl GameHash {
         .bind::<Nullable<Bool>, _>(filters.rated)
         .bind::<Nullable<Integer>, _>(filters.min_game_length)
         .bind::<BigInt, _>(limit.unwrap_or(8))
+        .bind::<Nullable<Text>, _>(player)
+        .bind::<Nullable<Text>, _>(player_color)
+        .bind::<Nullable<Integer>, _>(filters.rating_min)
+        .bind::<Nullable<Integer>, _>(filters.rating_max)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_start)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_end)
         .load(conn)
         .await?;
         Ok(rows.into_iter().map(|r| r.game_id).collect())
     }
+
+    /// Whether `game_id` is among the games the explorer counts under `filters`. The filters
+    /// only look at columns that are the same on every row of a game, so when it is, it adds
+    /// exactly one to every position it went through.
+    pub async fn counts_game(
+        game_id: Uuid,
+        filters: &ExplorerFilters,
+        conn: &mut DbConn<'_>,
+    ) -> Result<bool, DbError> {
+        let (player, player_color) = player_binds(filters);
+        let row: CountedRow = diesel::sql_query(format!(
+            r#"
+            {}
+            SELECT EXISTS (
+                SELECT 1
+                FROM game_hashes gh
+                LEFT JOIN player_games pg ON pg.game_id = gh.game_id
+                WHERE gh.game_id = $1
+                    AND gh.game_type = $2
+                    AND (cardinality($3) = 0 OR gh.speed = ANY($3))
+                    AND ($4 IS NULL OR gh.rated = $4)
+                    AND ($5 IS NULL OR gh.game_length >= $5)
+                    AND ($6::text IS NULL OR pg.game_id IS NOT NULL){}
+            ) AS counted
+            "#,
+            player_games_cte(6),
+            range_filters("gh", 8)
+        ))
+        .bind::<SqlUuid, _>(game_id)
+        .bind::<Text, _>(filters.game_type.to_string())
+        .bind::<Array<Text>, _>(
+            filters
+                .speeds
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>(),
+        )
+        .bind::<Nullable<Bool>, _>(filters.rated)
+        .bind::<Nullable<Integer>, _>(filters.min_game_length)
+        .bind::<Nullable<Text>, _>(player)
+        .bind::<Nullable<Text>, _>(player_color)
+        .bind::<Nullable<Integer>, _>(filters.rating_min)
+        .bind::<Nullable<Integer>, _>(filters.rating_max)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_start)
+        .bind::<Nullable<Timestamptz>, _>(filters.date_end)
+        .get_result(conn)
+        .await?;
+        Ok(row.counted)
+    }
 }
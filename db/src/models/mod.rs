@@ -1,33 +1,49 @@
+mod availability;
 mod challenge;
 mod chat_channel;
 mod chat_message;
+mod conditional_move;
 mod email_queue;
 mod email_request_log;
 mod email_state;
 mod email_token;
 mod game;
+mod game_analysis;
+mod game_annotation;
 mod game_hash;
 mod game_user;
 mod home_banner;
+mod move_text;
 mod notification_preferences;
+mod position_stats;
 mod push_device;
+mod puzzle;
 mod rating;
+mod rating_replay;
+mod rematch;
 mod schedule;
+mod study;
 mod tournament;
+mod tournament_bye;
 mod tournament_invitation;
 mod tournament_organizer;
 mod tournament_series;
 mod tournament_series_organizer;
 mod tournament_user;
+mod tournament_waitlist;
 mod user;
+pub use availability::Availability;
 pub use challenge::{Challenge, NewChallenge};
 pub use chat_channel::ChatChannelKind;
 pub use chat_message::ChatMessage;
+pub use conditional_move::{ConditionalMove, MAX_CONDITIONAL_LINES, MAX_CONDITIONAL_PLIES};
 pub use email_queue::{EmailQueueItem, NewEmailQueueItem};
 pub use email_request_log::{EmailRequestLog, NewEmailRequestLog};
 pub use email_state::EmailState;
 pub use email_token::{EmailToken, NewEmailToken};
-pub use game::{Game, NewGame};
+pub use game::{Game, NewGame, TimeSpent};
+pub use game_analysis::{AnalysisPly, GameAnalysis};
+pub use game_annotation::{GameAnnotation, GameAnnotator};
 pub use game_hash::{GameFinishContext, GameHash};
 pub use game_user::GameUser;
 pub use home_banner::HomeBanner;
@@ -37,12 +53,34 @@ pub use notification_preferences::{
     NotificationPreferencesUpdate,
 };
 pub use push_device::{NewPushDevice, PushDevice};
-pub use rating::{NewRating, Rating};
+pub use puzzle::{NewPuzzle, Puzzle, PuzzleAttempt, PuzzleStep, MAX_PUZZLE_PLIES};
+pub use rating::{
+    inflated_deviation,
+    NewRating,
+    Rating,
+    INITIAL_DEVIATION,
+    INITIAL_RATING,
+    INITIAL_VOLATILITY,
+    RATING_PERIOD,
+};
+pub use rating_replay::{GameRatingDiff, GameRatingFields, RatingDiff, RatingReplay};
+pub use rematch::Rematch;
 pub use schedule::{NewSchedule, Schedule};
-pub use tournament::{NewTournament, Tournament};
+pub use study::{
+    NewStudyChapter,
+    Study,
+    StudyChapter,
+    MAX_CHAPTER_TREE_BYTES,
+    MAX_STUDY_CHAPTERS,
+    MAX_STUDY_DESCRIPTION_LENGTH,
+    MAX_STUDY_TITLE_LENGTH,
+};
+pub use tournament::{NewTournament, Registration, Tournament};
+pub use tournament_bye::TournamentBye;
 pub use tournament_invitation::TournamentInvitation;
 pub use tournament_organizer::TournamentOrganizer;
 pub use tournament_series::{NewTournamentSeries, TournamentSeries};
 pub use tournament_series_organizer::TournamentSeriesOrganizer;
 pub use tournament_user::TournamentUser;
+pub use tournament_waitlist::TournamentWaitlist;
 pub use user::{NewUser, SoftDeleteReport, User};
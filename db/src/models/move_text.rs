@@ -0,0 +1,32 @@
+//! Moves stored as text, in the `piece position;` notation of game histories, and the
+//! input errors raised while checking them.
+
+use crate::db_error::DbError;
+
+pub(super) fn invalid(info: &str, error: String) -> DbError {
+    DbError::InvalidInput {
+        info: info.to_string(),
+        error,
+    }
+}
+
+pub(super) fn join_moves(moves: &[(String, String)]) -> String {
+    moves
+        .iter()
+        .map(|(piece, position)| format!("{piece} {position};"))
+        .collect()
+}
+
+pub(super) fn split_moves(moves: &str) -> Vec<(String, String)> {
+    moves
+        .split_terminator(';')
+        .filter_map(|turn| {
+            let mut parts = turn.trim().splitn(2, ' ');
+            let piece = parts.next().filter(|piece| !piece.is_empty())?;
+            Some((
+                piece.to_string(),
+                parts.next().unwrap_or_default().to_string(),
+            ))
+        })
+        .collect()
+}
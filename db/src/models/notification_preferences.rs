@@ -12,6 +12,7 @@ pub struct NotificationPreferencesUpdate {
     pub tournament: Vec<Option<String>>,
     pub schedules: Vec<Option<String>>,
     pub dms: Vec<Option<String>>,
+    pub following: Vec<Option<String>>,
 }
 
 #[derive(Insertable, Debug)]
@@ -32,6 +33,7 @@ pub struct NotificationPreferences {
     pub schedules: Vec<Option<String>>,
     pub general_chat: Vec<Option<String>>,
     pub dms: Vec<Option<String>>,
+    pub following: Vec<Option<String>>,
 }
 
 impl NotificationPreferences {
@@ -62,6 +64,7 @@ impl NotificationPreferences {
                 notification_preferences::tournament.eq(upd.tournament),
                 notification_preferences::schedules.eq(upd.schedules),
                 notification_preferences::dms.eq(upd.dms),
+                notification_preferences::following.eq(upd.following),
             ))
             .get_result(conn)
             .await?)
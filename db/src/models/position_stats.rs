@@ -0,0 +1,184 @@
+//! The `position_stats` aggregates behind the opening explorer. Each finished game adds one
+//! to every position and move it went through, so explorer queries that fit the aggregated
+//! buckets (see `ExplorerFilters::aggregated`) sum a handful of rows instead of self-joining
+//! `game_hashes`. Deleting a game does not take it back out.
+
+use super::game_hash::{in_order, result_strings, AggRow};
+use crate::{db_error::DbError, DbConn};
+use diesel::sql_types::{Array, BigInt, Bool, Date, Integer, Nullable, Text, Uuid as SqlUuid};
+use diesel_async::RunQueryDsl;
+use shared_types::{ExplorerFilters, ExplorerMove, MIN_PLIES};
+use uuid::Uuid;
+
+/// Adds a game's `game_hashes` rows to the aggregates. Must run exactly once per game, right
+/// after its rows were inserted.
+pub(super) async fn record_game(game_id: Uuid, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+    let (white_res, black_res, draw_res) = result_strings();
+    diesel::sql_query(
+        r#"
+        INSERT INTO position_stats AS ps
+        SELECT
+            gh.hash,
+            0,
+            gh.game_type,
+            gh.speed,
+            gh.rated,
+            COALESCE((floor(gh.game_rating / 100) * 100)::int, -1),
+            date_trunc('month', gh.played_at AT TIME ZONE 'UTC')::date,
+            gh.game_length >= $2,
+            '',
+            '',
+            1,
+            (gh.result = $3)::int,
+            (gh.result = $4)::int,
+            (gh.result = $5)::int,
+            COALESCE(SUM(gh.rating), 0),
+            COUNT(gh.rating)
+        FROM game_hashes gh
+        WHERE gh.game_id = $1
+        GROUP BY 1, 2, 3, 4, 5, 6, 7, 8, 12, 13, 14
+        UNION ALL
+        SELECT
+            cur.hash,
+            nxt.hash,
+            nxt.game_type,
+            nxt.speed,
+            nxt.rated,
+            COALESCE((floor(nxt.game_rating / 100) * 100)::int, -1),
+            date_trunc('month', nxt.played_at AT TIME ZONE 'UTC')::date,
+            nxt.game_length >= $2,
+            COALESCE((array_agg(nxt.move_piece ORDER BY nxt.turn))[1], ''),
+            COALESCE((array_agg(nxt.move_position ORDER BY nxt.turn))[1], ''),
+            1,
+            (nxt.result = $3)::int,
+            (nxt.result = $4)::int,
+            (nxt.result = $5)::int,
+            COALESCE(SUM(nxt.rating), 0),
+            COUNT(nxt.rating)
+        FROM game_hashes cur
+        JOIN game_hashes nxt ON nxt.game_id = cur.game_id AND nxt.turn = cur.turn + 1
+        WHERE cur.game_id = $1
+        GROUP BY 1, 2, 3, 4, 5, 6, 7, 8, 12, 13, 14
+        ON CONFLICT (hash, next_hash, game_type, speed, rated, rating_band, played_month, long_game)
+        DO UPDATE SET
+            total = ps.total + EXCLUDED.total,
+            white_wins = ps.white_wins + EXCLUDED.white_wins,
+            black_wins = ps.black_wins + EXCLUDED.black_wins,
+            draws = ps.draws + EXCLUDED.draws,
+            rating_sum = ps.rating_sum + EXCLUDED.rating_sum,
+            rating_count = ps.rating_count + EXCLUDED.rating_count
+        "#,
+    )
+    .bind::<SqlUuid, _>(game_id)
+    .bind::<Integer, _>(MIN_PLIES)
+    .bind::<Text, _>(white_res)
+    .bind::<Text, _>(black_res)
+    .bind::<Text, _>(draw_res)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// The bucket filters shared by both queries, `$2` to `$9`.
+const FILTERS: &str = r#"
+    ps.game_type = $2
+    AND (cardinality($3) = 0 OR ps.speed = ANY($3))
+    AND ($4 IS NULL OR ps.rated = $4)
+    AND ($5 OR ps.long_game)
+    AND (($6::int IS NULL AND $7::int IS NULL) OR ps.rating_band >= COALESCE($6, 0))
+    AND ($7::int IS NULL OR ps.rating_band < $7)
+    AND ($8::date IS NULL OR ps.played_month >= $8)
+    AND ($9::date IS NULL OR ps.played_month < $9)
+"#;
+
+/// Same result as `GameHash::next_moves` for filters that are `aggregated()`.
+pub(super) async fn next_moves(
+    hash: i64,
+    filters: &ExplorerFilters,
+    limit: i64,
+    conn: &mut DbConn<'_>,
+) -> Result<Vec<ExplorerMove>, DbError> {
+    let rows: Vec<AggRow> = diesel::sql_query(format!(
+        r#"
+        SELECT
+            ps.next_hash,
+            COALESCE((array_agg(ps.piece ORDER BY ps.total DESC))[1], '') AS piece,
+            COALESCE((array_agg(ps.position ORDER BY ps.total DESC))[1], '') AS position,
+            SUM(ps.total)::bigint AS total,
+            SUM(ps.white_wins)::bigint AS white_wins,
+            SUM(ps.black_wins)::bigint AS black_wins,
+            SUM(ps.draws)::bigint AS draws,
+            SUM(ps.rating_sum) / NULLIF(SUM(ps.rating_count), 0)::float8 AS avg_rating,
+            0::bigint AS player_wins,
+            0::bigint AS player_losses
+        FROM position_stats ps
+        WHERE ps.hash = $1 AND ps.next_hash <> 0 AND {FILTERS}
+        GROUP BY ps.next_hash
+        ORDER BY total DESC
+        LIMIT $10
+        "#
+    ))
+    .bind::<BigInt, _>(hash)
+    .bind::<Text, _>(filters.game_type.to_string())
+    .bind::<Array<Text>, _>(
+        filters
+            .speeds
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>(),
+    )
+    .bind::<Nullable<Bool>, _>(filters.rated)
+    .bind::<Bool, _>(filters.min_game_length.is_none())
+    .bind::<Nullable<Integer>, _>(filters.rating_min)
+    .bind::<Nullable<Integer>, _>(filters.rating_max)
+    .bind::<Nullable<Date>, _>(filters.date_start.map(|date| date.date_naive()))
+    .bind::<Nullable<Date>, _>(filters.date_end.map(|date| date.date_naive()))
+    .bind::<BigInt, _>(limit)
+    .load(conn)
+    .await?;
+    Ok(rows.into_iter().map(ExplorerMove::from).collect())
+}
+
+/// Same result as `GameHash::aggregate_many` for filters that are `aggregated()`.
+pub(super) async fn aggregate_many(
+    hashes: &[i64],
+    filters: &ExplorerFilters,
+    conn: &mut DbConn<'_>,
+) -> Result<Vec<ExplorerMove>, DbError> {
+    let rows: Vec<AggRow> = diesel::sql_query(format!(
+        r#"
+        SELECT
+            ps.hash AS next_hash,
+            '' AS piece,
+            '' AS position,
+            SUM(ps.total)::bigint AS total,
+            SUM(ps.white_wins)::bigint AS white_wins,
+            SUM(ps.black_wins)::bigint AS black_wins,
+            SUM(ps.draws)::bigint AS draws,
+            SUM(ps.rating_sum) / NULLIF(SUM(ps.rating_count), 0)::float8 AS avg_rating,
+            0::bigint AS player_wins,
+            0::bigint AS player_losses
+        FROM position_stats ps
+        WHERE ps.hash = ANY($1) AND ps.next_hash = 0 AND {FILTERS}
+        GROUP BY ps.hash
+        "#
+    ))
+    .bind::<Array<BigInt>, _>(hashes)
+    .bind::<Text, _>(filters.game_type.to_string())
+    .bind::<Array<Text>, _>(
+        filters
+            .speeds
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>(),
+    )
+    .bind::<Nullable<Bool>, _>(filters.rated)
+    .bind::<Bool, _>(filters.min_game_length.is_none())
+    .bind::<Nullable<Integer>, _>(filters.rating_min)
+    .bind::<Nullable<Integer>, _>(filters.rating_max)
+    .bind::<Nullable<Date>, _>(filters.date_start.map(|date| date.date_naive()))
+    .bind::<Nullable<Date>, _>(filters.date_end.map(|date| date.date_naive()))
+    .load(conn)
+    .await?;
+    Ok(in_order(hashes, rows))
+}
@@ -0,0 +1,456 @@
+use crate::{
+    db_error::DbError,
+    models::{
+        move_text::{invalid, join_moves, split_moves},
+        Rating,
+    },
+    schema::{
+        puzzle_attempts,
+        puzzle_mined_games,
+        puzzles::{self, dsl},
+        ratings,
+    },
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{dsl::exists, prelude::*, Insertable, Queryable, Selectable};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use hive_lib::{Color, GameResult, GameStatus, GameType, State};
+use nanoid::nanoid;
+use shared_types::{GameSpeed, PuzzleStatus, VariantClass};
+use skillratings::{
+    glicko2::{glicko2, Glicko2Rating},
+    Outcomes,
+};
+use uuid::Uuid;
+
+pub const MAX_PUZZLE_PLIES: usize = 15;
+
+/// A position to solve. `history` leads up to it in game history notation,
+/// `solution` holds the solver's moves alternating with the forced replies,
+/// so it always ends on a solver move.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = puzzles)]
+pub struct Puzzle {
+    pub id: Uuid,
+    pub nanoid: String,
+    pub game_type: String,
+    pub history: String,
+    pub solution: String,
+    pub themes: Vec<Option<String>>,
+    pub rating: f64,
+    pub deviation: f64,
+    pub volatility: f64,
+    pub attempts: i64,
+    pub solves: i64,
+    pub source_game_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub status: String,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = puzzles)]
+pub struct NewPuzzle {
+    pub nanoid: String,
+    pub game_type: String,
+    pub history: String,
+    pub solution: String,
+    pub themes: Vec<Option<String>>,
+    pub rating: f64,
+    pub source_game_id: Option<Uuid>,
+    pub status: String,
+}
+
+impl NewPuzzle {
+    /// Checks that `solution` is playable from `history` and ends on a move
+    /// of the side to move, then stores both in history notation.
+    pub fn new(
+        game_type: GameType,
+        history: &str,
+        solution: &[(String, String)],
+        themes: Vec<String>,
+        rating: f64,
+        source_game_id: Option<Uuid>,
+    ) -> Result<Self, DbError> {
+        let mut state = State::new_from_str(history, &game_type.to_string())
+            .map_err(|e| invalid("Could not recover the puzzle position", e.to_string()))?;
+        if solution.is_empty()
+            || solution.len().is_multiple_of(2)
+            || solution.len() > MAX_PUZZLE_PLIES
+        {
+            return Err(invalid(
+                "A solution must end on the solver's move",
+                format!("{} plies", solution.len()),
+            ));
+        }
+        let start = state.history.moves.len();
+        for (piece, position) in solution {
+            if matches!(state.game_status, GameStatus::Finished(_)) {
+                return Err(invalid(
+                    "The solution continues after the game ended",
+                    format!("{piece} {position}"),
+                ));
+            }
+            state
+                .play_turn_from_history(piece, position)
+                .map_err(|e| invalid("Invalid solution move", e.to_string()))?;
+        }
+        let solution = join_moves(&state.history.moves[start..]);
+        Ok(Self {
+            nanoid: nanoid!(10),
+            game_type: game_type.to_string(),
+            history: history.to_string(),
+            solution,
+            themes: themes.into_iter().map(Some).collect(),
+            rating,
+            source_game_id,
+            status: PuzzleStatus::Approved.to_string(),
+        })
+    }
+
+    /// Marks the puzzle as waiting for review instead of going live.
+    pub fn candidate(self) -> Self {
+        Self {
+            status: PuzzleStatus::Candidate.to_string(),
+            ..self
+        }
+    }
+}
+
+/// What the solver's latest move led to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleStep {
+    /// Correct so far; the opponent answers with `reply`.
+    Continue {
+        reply: (String, String),
+    },
+    Solved,
+    Failed,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = puzzle_attempts)]
+pub struct PuzzleAttempt {
+    pub id: Uuid,
+    pub puzzle_id: Uuid,
+    pub user_id: Uuid,
+    pub solved: bool,
+    pub rated: bool,
+    pub rating_change: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = puzzle_attempts)]
+struct NewPuzzleAttempt {
+    puzzle_id: Uuid,
+    user_id: Uuid,
+    solved: bool,
+    rated: bool,
+    rating_change: Option<f64>,
+}
+
+impl Puzzle {
+    pub async fn create(new_puzzle: NewPuzzle, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        Ok(diesel::insert_into(puzzles::table)
+            .values(&new_puzzle)
+            .get_result(conn)
+            .await?)
+    }
+
+    /// Inserts the puzzle unless the same position is already a puzzle.
+    pub async fn create_if_new(
+        new_puzzle: NewPuzzle,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Option<Self>, DbError> {
+        Ok(diesel::insert_into(puzzles::table)
+            .values(&new_puzzle)
+            .on_conflict((dsl::game_type, dsl::history))
+            .do_nothing()
+            .get_result(conn)
+            .await
+            .optional()?)
+    }
+
+    pub async fn find_by_nanoid(nanoid: &str, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        Ok(dsl::puzzles
+            .filter(dsl::nanoid.eq(nanoid))
+            .first(conn)
+            .await?)
+    }
+
+    /// Puzzles waiting for review, oldest first.
+    pub async fn candidates(limit: i64, conn: &mut DbConn<'_>) -> Result<Vec<Self>, DbError> {
+        Ok(dsl::puzzles
+            .filter(dsl::status.eq(PuzzleStatus::Candidate.to_string()))
+            .order(dsl::created_at.asc())
+            .limit(limit)
+            .load(conn)
+            .await?)
+    }
+
+    pub async fn set_status(
+        nanoid: &str,
+        status: PuzzleStatus,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        Ok(diesel::update(dsl::puzzles.filter(dsl::nanoid.eq(nanoid)))
+            .set(dsl::status.eq(status.to_string()))
+            .get_result(conn)
+            .await?)
+    }
+
+    /// Records that the miner is done with a game, with `failure` saying why
+    /// if it gave up on it, so it isn't picked up again either way.
+    pub async fn mark_game_mined(
+        game_id: Uuid,
+        failure: Option<String>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        diesel::insert_into(puzzle_mined_games::table)
+            .values((
+                puzzle_mined_games::game_id.eq(game_id),
+                puzzle_mined_games::failure.eq(failure),
+            ))
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub fn status(&self) -> PuzzleStatus {
+        self.status.parse().unwrap_or_default()
+    }
+
+    pub fn solution_moves(&self) -> Vec<(String, String)> {
+        split_moves(&self.solution)
+    }
+
+    pub fn themes(&self) -> Vec<String> {
+        self.themes.iter().flatten().cloned().collect()
+    }
+
+    pub fn state(&self) -> Result<State, DbError> {
+        State::new_from_str(&self.history, &self.game_type)
+            .map_err(|e| invalid("Could not recover the puzzle position", e.to_string()))
+    }
+
+    pub fn solver_color(&self) -> Result<Color, DbError> {
+        Ok(self.state()?.turn_color)
+    }
+
+    /// Replays `played` from the puzzle position and judges its last move,
+    /// which must be the solver's. Transpositions into the same position as
+    /// the solution count, and so does any move that wins on the spot.
+    pub fn check_line(&self, played: &[(String, String)]) -> Result<PuzzleStep, DbError> {
+        let solution = self.solution_moves();
+        if played.is_empty() || played.len().is_multiple_of(2) || played.len() > solution.len() {
+            return Err(invalid(
+                "Puzzle lines end on the solver's move",
+                format!("{} plies", played.len()),
+            ));
+        }
+        let mut state = self.state()?;
+        let solver = state.turn_color;
+        for (i, (piece, position)) in played.iter().enumerate() {
+            if i % 2 == 1 {
+                if (piece, position) != (&solution[i].0, &solution[i].1) {
+                    return Err(invalid(
+                        "Replies must follow the puzzle",
+                        format!("{piece} {position}"),
+                    ));
+                }
+                state
+                    .play_turn_from_history(piece, position)
+                    .map_err(|e| invalid("Invalid puzzle move", e.to_string()))?;
+                continue;
+            }
+            let mut expected = state.clone();
+            state
+                .play_turn_from_history(piece, position)
+                .map_err(|e| invalid("Invalid puzzle move", e.to_string()))?;
+            if state.game_status == GameStatus::Finished(GameResult::Winner(solver)) {
+                return Ok(PuzzleStep::Solved);
+            }
+            expected
+                .play_turn_from_history(&solution[i].0, &solution[i].1)
+                .map_err(|e| invalid("Puzzle solution no longer plays", e.to_string()))?;
+            if state.hashes.last() != expected.hashes.last() {
+                return Ok(PuzzleStep::Failed);
+            }
+            if i == solution.len() - 1 {
+                return Ok(PuzzleStep::Solved);
+            }
+            if i == played.len() - 1 {
+                return Ok(PuzzleStep::Continue {
+                    reply: solution[i + 1].clone(),
+                });
+            }
+        }
+        unreachable!("the last played move is always the solver's")
+    }
+
+    /// The puzzle rated closest to `rating` that the solver hasn't tried yet.
+    /// Attempts are only stored for logged in solvers, so anonymous ones pass
+    /// the puzzles they've already seen in `skip`.
+    pub async fn next_for(
+        rating: f64,
+        variant_class: VariantClass,
+        user_id: Option<Uuid>,
+        skip: &[String],
+        conn: &mut DbConn<'_>,
+    ) -> Result<Option<Self>, DbError> {
+        let candidates = || {
+            let mut query = dsl::puzzles
+                .filter(dsl::status.eq(PuzzleStatus::Approved.to_string()))
+                .filter(dsl::nanoid.ne_all(skip))
+                .into_boxed();
+            query = match variant_class {
+                VariantClass::Base => query.filter(dsl::game_type.eq(GameType::Base.to_string())),
+                VariantClass::Expansions => {
+                    query.filter(dsl::game_type.ne(GameType::Base.to_string()))
+                }
+            };
+            if let Some(user_id) = user_id {
+                query = query.filter(diesel::dsl::not(exists(
+                    puzzle_attempts::table
+                        .filter(puzzle_attempts::puzzle_id.eq(dsl::id))
+                        .filter(puzzle_attempts::user_id.eq(user_id)),
+                )));
+            }
+            query
+        };
+        let above: Option<Self> = candidates()
+            .filter(dsl::rating.ge(rating))
+            .order(dsl::rating.asc())
+            .first(conn)
+            .await
+            .optional()?;
+        let below: Option<Self> = candidates()
+            .filter(dsl::rating.lt(rating))
+            .order(dsl::rating.desc())
+            .first(conn)
+            .await
+            .optional()?;
+        Ok(match (above, below) {
+            (Some(above), Some(below)) => {
+                if above.rating - rating <= rating - below.rating {
+                    Some(above)
+                } else {
+                    Some(below)
+                }
+            }
+            (above, below) => above.or(below),
+        })
+    }
+
+    /// Records a finished attempt. Only the first try at a puzzle is rated:
+    /// the solver plays the puzzle as a Glicko-2 game on their Puzzle rating.
+    pub async fn record_attempt(
+        &self,
+        user_id: Uuid,
+        solved: bool,
+        conn: &mut DbConn<'_>,
+    ) -> Result<PuzzleAttempt, DbError> {
+        let puzzle = self.clone();
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            let puzzle: Self = dsl::puzzles.find(puzzle.id).for_update().first(tc).await?;
+            let rated = !diesel::select(exists(
+                puzzle_attempts::table
+                    .filter(puzzle_attempts::puzzle_id.eq(puzzle.id))
+                    .filter(puzzle_attempts::user_id.eq(user_id)),
+            ))
+            .get_result::<bool>(tc)
+            .await?;
+            let mut rating_change = None;
+            if rated {
+                let variant_class = VariantClass::from(
+                    puzzle
+                        .game_type
+                        .parse::<GameType>()
+                        .map_err(|e| invalid("Unknown puzzle game type", e.to_string()))?,
+                );
+                let user_rating = Rating::lock_for_update(
+                    user_id,
+                    &GameSpeed::Puzzle.to_string(),
+                    &variant_class.to_string(),
+                    tc,
+                )
+                .await?;
+                let user_glicko = Glicko2Rating {
+                    rating: user_rating.rating,
+                    deviation: user_rating.deviation,
+                    volatility: user_rating.volatility,
+                };
+                let puzzle_glicko = Glicko2Rating {
+                    rating: puzzle.rating,
+                    deviation: puzzle.deviation,
+                    volatility: puzzle.volatility,
+                };
+                let outcome = if solved {
+                    Outcomes::WIN
+                } else {
+                    Outcomes::LOSS
+                };
+                let (user_new, puzzle_new) = glicko2(
+                    &user_glicko,
+                    &puzzle_glicko,
+                    &outcome,
+                    &Rating::glicko2_config(),
+                );
+                diesel::update(ratings::table.find(user_rating.id))
+                    .set((
+                        ratings::updated_at.eq(Utc::now()),
+                        ratings::played.eq(ratings::played + 1),
+                        ratings::won.eq(ratings::won + i64::from(solved)),
+                        ratings::lost.eq(ratings::lost + i64::from(!solved)),
+                        ratings::rating.eq(user_new.rating),
+                        ratings::deviation.eq(user_new.deviation),
+                        ratings::volatility.eq(user_new.volatility),
+                    ))
+                    .execute(tc)
+                    .await?;
+                diesel::update(dsl::puzzles.find(puzzle.id))
+                    .set((
+                        dsl::rating.eq(puzzle_new.rating),
+                        dsl::deviation.eq(puzzle_new.deviation),
+                        dsl::volatility.eq(puzzle_new.volatility),
+                        dsl::attempts.eq(dsl::attempts + 1),
+                        dsl::solves.eq(dsl::solves + i64::from(solved)),
+                    ))
+                    .execute(tc)
+                    .await?;
+                rating_change = Some(user_new.rating - user_glicko.rating);
+            }
+            Ok(diesel::insert_into(puzzle_attempts::table)
+                .values(NewPuzzleAttempt {
+                    puzzle_id: puzzle.id,
+                    user_id,
+                    solved,
+                    rated,
+                    rating_change,
+                })
+                .get_result(tc)
+                .await?)
+        })
+        .await
+    }
+}
+
+impl PuzzleAttempt {
+    /// The user's latest attempts, newest first, with the puzzle each was on.
+    pub async fn history_for_user(
+        user_id: Uuid,
+        limit: i64,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<(Self, Puzzle)>, DbError> {
+        Ok(puzzle_attempts::table
+            .inner_join(puzzles::table)
+            .filter(puzzle_attempts::user_id.eq(user_id))
+            .order(puzzle_attempts::created_at.desc())
+            .limit(limit)
+            .select((Self::as_select(), Puzzle::as_select()))
+            .load(conn)
+            .await?)
+    }
+}
@@ -5,9 +5,10 @@ use crate::{
     DbConn,
 };
 use bb8::PooledConnection;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use diesel::{
     prelude::*,
+    sql_types::{Double, Timestamptz},
     AsChangeset,
     Associations,
     Identifiable,
@@ -22,7 +23,7 @@ use diesel_async::{
 };
 use hive_lib::{Color, GameResult};
 use serde::{Deserialize, Serialize};
-use shared_types::GameSpeed;
+use shared_types::{GameSpeed, VariantClass};
 use skillratings::{
     glicko2::{glicko2, Glicko2Config, Glicko2Rating},
     Outcomes,
@@ -30,6 +31,23 @@ use skillratings::{
 use std::str::FromStr;
 use uuid::Uuid;
 
+pub const INITIAL_RATING: f64 = 1500.0;
+/// Where every rating starts, and the most uncertain a rating can become again.
+pub const INITIAL_DEVIATION: f64 = 500.0;
+pub const INITIAL_VOLATILITY: f64 = 0.09;
+/// Players sitting out this long have their ratings grow less certain.
+pub const RATING_PERIOD: Duration = Duration::weeks(1);
+/// Converts between Glicko and Glicko-2 scale.
+const GLICKO2_SCALE: f64 = 173.7178;
+
+/// The deviation after `periods` rating periods without games, the same
+/// growth `Rating::inflate_inactive` applies in the database.
+pub fn inflated_deviation(deviation: f64, volatility: f64, periods: i64) -> f64 {
+    let phi = deviation / GLICKO2_SCALE;
+    let inflated = (phi.powi(2) + periods as f64 * volatility.powi(2)).sqrt() * GLICKO2_SCALE;
+    inflated.min(INITIAL_DEVIATION)
+}
+
 #[derive(Insertable, Debug)]
 #[diesel(table_name = ratings)]
 pub struct NewRating {
@@ -44,22 +62,24 @@ pub struct NewRating {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub speed: String,
+    pub variant: String,
 }
 
 impl NewRating {
-    pub fn for_uuid(uuid: &Uuid, game_speed: GameSpeed) -> Self {
+    pub fn for_uuid(uuid: &Uuid, game_speed: GameSpeed, variant_class: VariantClass) -> Self {
         Self {
             user_uid: uuid.to_owned(),
             played: 0,
             won: 0,
             lost: 0,
             draw: 0,
-            rating: 1500.0,
-            deviation: 500.0,
-            volatility: 0.09,
+            rating: INITIAL_RATING,
+            deviation: INITIAL_DEVIATION,
+            volatility: INITIAL_VOLATILITY,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             speed: game_speed.to_string(),
+            variant: variant_class.to_string(),
         }
     }
 }
@@ -74,6 +94,7 @@ impl NewRating {
     AsChangeset,
     Selectable,
     PartialEq,
+    Clone,
 )]
 #[serde(rename_all = "camelCase")]
 #[diesel(belongs_to(User, foreign_key = user_uid))]
@@ -92,10 +113,11 @@ pub struct Rating {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub speed: String,
+    pub variant: String,
 }
 
 impl Rating {
-    fn normalized_game_speed(game_speed: GameSpeed) -> String {
+    pub(crate) fn normalized_game_speed(game_speed: GameSpeed) -> String {
         match game_speed {
             GameSpeed::Untimed => GameSpeed::Correspondence.to_string(),
             _ => game_speed.to_string(),
@@ -105,15 +127,52 @@ impl Rating {
     pub async fn for_uuid(
         uuid: &Uuid,
         game_speed: &GameSpeed,
+        variant_class: VariantClass,
         conn: &mut DbConn<'_>,
     ) -> Result<Self, DbError> {
         let game_speed = Self::normalized_game_speed(*game_speed);
         Ok(ratings_table
             .filter(user_uid.eq(uuid).and(speed.eq(game_speed)))
+            .filter(variant.eq(variant_class.to_string()))
             .first(conn)
             .await?)
     }
 
+    /// Glicko-2 grows a player's deviation by their volatility for every
+    /// rating period without games: phi' = sqrt(phi^2 + n * sigma^2). Only whole
+    /// periods since the rating last changed are applied and `updated_at` moves
+    /// forward by them, so running this late or twice changes nothing.
+    pub async fn inflate_inactive(
+        period: Duration,
+        now: DateTime<Utc>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<usize, DbError> {
+        Ok(diesel::sql_query(
+            r#"
+            UPDATE ratings r
+            SET deviation = LEAST(
+                    $3,
+                    $4 * sqrt(power(r.deviation / $4, 2) + p.periods * power(r.volatility, 2))
+                ),
+                updated_at = r.updated_at + make_interval(secs => p.periods * $1)
+            FROM (
+                SELECT id, floor(extract(epoch FROM $2 - updated_at) / $1) AS periods
+                FROM ratings
+                WHERE played > 0
+                    AND deviation < $3
+                    AND updated_at <= $2 - make_interval(secs => $1)
+            ) p
+            WHERE r.id = p.id
+            "#,
+        )
+        .bind::<Double, _>(period.num_seconds() as f64)
+        .bind::<Timestamptz, _>(now)
+        .bind::<Double, _>(INITIAL_DEVIATION)
+        .bind::<Double, _>(GLICKO2_SCALE)
+        .execute(conn)
+        .await?)
+    }
+
     pub async fn for_uuids(uuids: &[Uuid], conn: &mut DbConn<'_>) -> Result<Vec<Self>, DbError> {
         Ok(ratings_table
             .filter(user_uid.eq_any(uuids))
@@ -126,6 +185,7 @@ impl Rating {
     pub(crate) async fn update(
         rated: bool,
         game_speed: String,
+        variant_class: VariantClass,
         white_id: Uuid,
         black_id: Uuid,
         game_result: GameResult,
@@ -133,6 +193,7 @@ impl Rating {
     ) -> Result<(f64, f64, Option<f64>, Option<f64>), DbError> {
         let game_speed =
             Self::normalized_game_speed(GameSpeed::from_str(&game_speed).expect("Valid GameSpeed"));
+        let variant_class = variant_class.to_string();
         if white_id == black_id {
             return Err(DbError::InvalidAction {
                 info: "Cannot update ratings for self-play".to_string(),
@@ -142,8 +203,10 @@ impl Rating {
         let first_id = white_id.min(black_id);
         let second_id = white_id.max(black_id);
 
-        let first_rating = Self::lock_for_update(first_id, &game_speed, conn).await?;
-        let second_rating = Self::lock_for_update(second_id, &game_speed, conn).await?;
+        let first_rating =
+            Self::lock_for_update(first_id, &game_speed, &variant_class, conn).await?;
+        let second_rating =
+            Self::lock_for_update(second_id, &game_speed, &variant_class, conn).await?;
 
         let (white_rating, black_rating) = if first_id == white_id {
             (first_rating, second_rating)
@@ -168,20 +231,29 @@ impl Rating {
         ))
     }
 
-    async fn lock_for_update(
+    pub(crate) async fn lock_for_update(
         player_id: Uuid,
         game_speed: &str,
+        variant_class: &str,
         conn: &mut DbConn<'_>,
     ) -> Result<Rating, DbError> {
         Ok(ratings_table
             .filter(user_uid.eq(player_id))
             .filter(speed.eq(game_speed))
+            .filter(variant.eq(variant_class))
             .for_update()
             .first(conn)
             .await?)
     }
 
-    fn calculate_glicko2(
+    pub(crate) fn glicko2_config() -> Glicko2Config {
+        Glicko2Config {
+            tau: 0.75,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn calculate_glicko2(
         white_rating: &Rating,
         black_rating: &Rating,
         game_result: GameResult,
@@ -198,10 +270,7 @@ impl Rating {
             volatility: black_rating.volatility,
         };
 
-        let config = Glicko2Config {
-            tau: 0.75,
-            ..Default::default()
-        };
+        let config = Self::glicko2_config();
         let outcome = match game_result {
             GameResult::Winner(winner) => {
                 if winner == Color::White {
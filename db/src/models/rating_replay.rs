@@ -0,0 +1,287 @@
+use crate::{
+    db_error::DbError,
+    models::{
+        rating::{
+            inflated_deviation,
+            INITIAL_DEVIATION,
+            INITIAL_RATING,
+            INITIAL_VOLATILITY,
+            RATING_PERIOD,
+        },
+        Rating,
+    },
+    schema::{games, ratings},
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{prelude::*, Queryable, Selectable};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use hive_lib::{Color, GameResult, GameStatus, GameType};
+use shared_types::{GameSpeed, VariantClass};
+use std::{collections::HashMap, str::FromStr};
+use uuid::Uuid;
+
+/// Differences below this are float noise, not a changed rating.
+const TOLERANCE: f64 = 1e-6;
+
+type RatingKey = (Uuid, String, String);
+
+#[derive(Queryable, Selectable, Debug)]
+#[diesel(table_name = games)]
+struct ReplayGame {
+    id: Uuid,
+    nanoid: String,
+    white_id: Uuid,
+    black_id: Uuid,
+    rated: bool,
+    speed: String,
+    game_type: String,
+    game_status: String,
+    updated_at: DateTime<Utc>,
+    white_rating: Option<f64>,
+    black_rating: Option<f64>,
+    white_rating_change: Option<f64>,
+    black_rating_change: Option<f64>,
+}
+
+/// The rating columns a finished game keeps: both ratings going into the game
+/// and what it changed them by.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GameRatingFields {
+    pub white_rating: Option<f64>,
+    pub black_rating: Option<f64>,
+    pub white_rating_change: Option<f64>,
+    pub black_rating_change: Option<f64>,
+}
+
+impl GameRatingFields {
+    fn differs(&self, other: &Self) -> bool {
+        [
+            (self.white_rating, other.white_rating),
+            (self.black_rating, other.black_rating),
+            (self.white_rating_change, other.white_rating_change),
+            (self.black_rating_change, other.black_rating_change),
+        ]
+        .into_iter()
+        .any(|pair| match pair {
+            (Some(ours), Some(theirs)) => (ours - theirs).abs() > TOLERANCE,
+            (ours, theirs) => ours.is_some() != theirs.is_some(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct GameRatingDiff {
+    pub game_id: Uuid,
+    pub nanoid: String,
+    pub before: GameRatingFields,
+    pub after: GameRatingFields,
+}
+
+#[derive(Debug)]
+pub struct RatingDiff {
+    pub before: Rating,
+    pub after: Rating,
+}
+
+/// Every rating recomputed from scratch by replaying finished games in the
+/// order they ended, and everything that differs from what is stored.
+/// Puzzle ratings do not come from games and are left alone.
+#[derive(Debug, Default)]
+pub struct RatingReplay {
+    pub ratings: Vec<RatingDiff>,
+    pub games: Vec<GameRatingDiff>,
+    pub games_replayed: usize,
+}
+
+impl RatingReplay {
+    pub async fn compute(now: DateTime<Utc>, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        let stored: Vec<Rating> = ratings::table
+            .filter(ratings::speed.ne(GameSpeed::Puzzle.to_string()))
+            .order(ratings::id)
+            .load(conn)
+            .await?;
+        let mut current: HashMap<RatingKey, Rating> = stored
+            .iter()
+            .map(|rating| {
+                let fresh = Rating {
+                    played: 0,
+                    won: 0,
+                    lost: 0,
+                    draw: 0,
+                    rating: INITIAL_RATING,
+                    deviation: INITIAL_DEVIATION,
+                    volatility: INITIAL_VOLATILITY,
+                    updated_at: rating.created_at,
+                    ..rating.clone()
+                };
+                (key(rating), fresh)
+            })
+            .collect();
+
+        let finished = [
+            GameStatus::Finished(GameResult::Winner(Color::White)),
+            GameStatus::Finished(GameResult::Winner(Color::Black)),
+            GameStatus::Finished(GameResult::Draw),
+        ]
+        .map(|status| status.to_string());
+        let replay_games: Vec<ReplayGame> = games::table
+            .filter(games::game_status.eq_any(finished))
+            .filter(games::white_id.ne(games::black_id))
+            .order((games::updated_at, games::id))
+            .select(ReplayGame::as_select())
+            .load(conn)
+            .await?;
+
+        let mut replay = Self::default();
+        for game in replay_games {
+            let Ok(GameStatus::Finished(result)) = GameStatus::from_str(&game.game_status) else {
+                continue;
+            };
+            let Ok(speed) = GameSpeed::from_str(&game.speed) else {
+                continue;
+            };
+            let Ok(game_type) = GameType::from_str(&game.game_type) else {
+                continue;
+            };
+            let speed = Rating::normalized_game_speed(speed);
+            let variant = VariantClass::from(game_type).to_string();
+            let white_key = (game.white_id, speed.clone(), variant.clone());
+            let black_key = (game.black_id, speed, variant);
+            let (Some(mut white), Some(mut black)) = (
+                current.get(&white_key).cloned(),
+                current.get(&black_key).cloned(),
+            ) else {
+                continue;
+            };
+            catch_up(&mut white, game.updated_at);
+            catch_up(&mut black, game.updated_at);
+
+            let (white_score, black_score) = match result {
+                GameResult::Winner(Color::White) => ((1, 0, 0), (0, 1, 0)),
+                GameResult::Winner(Color::Black) => ((0, 1, 0), (1, 0, 0)),
+                _ => ((0, 0, 1), (0, 0, 1)),
+            };
+            // `Rating::update` stamps `updated_at` on rated games and on unrated
+            // draws, but leaves it alone on unrated wins. Rating periods count
+            // from that stamp, so the replay has to follow the same rule.
+            let stamped = game.rated || matches!(result, GameResult::Draw);
+            let mut after = GameRatingFields {
+                white_rating: Some(white.rating),
+                black_rating: Some(black.rating),
+                ..Default::default()
+            };
+            if game.rated {
+                let (white_glicko, black_glicko, white_change, black_change) =
+                    Rating::calculate_glicko2(&white, &black, result);
+                after.white_rating_change = Some(white_change);
+                after.black_rating_change = Some(black_change);
+                for (rating, glicko) in [(&mut white, white_glicko), (&mut black, black_glicko)] {
+                    rating.rating = glicko.rating;
+                    rating.deviation = glicko.deviation;
+                    rating.volatility = glicko.volatility;
+                }
+            }
+            if stamped {
+                white.updated_at = game.updated_at;
+                black.updated_at = game.updated_at;
+            }
+            for (rating, (won, lost, draw)) in
+                [(&mut white, white_score), (&mut black, black_score)]
+            {
+                rating.played += 1;
+                rating.won += won;
+                rating.lost += lost;
+                rating.draw += draw;
+            }
+            current.insert(white_key, white);
+            current.insert(black_key, black);
+
+            let before = GameRatingFields {
+                white_rating: game.white_rating,
+                black_rating: game.black_rating,
+                white_rating_change: game.white_rating_change,
+                black_rating_change: game.black_rating_change,
+            };
+            if before.differs(&after) {
+                replay.games.push(GameRatingDiff {
+                    game_id: game.id,
+                    nanoid: game.nanoid,
+                    before,
+                    after,
+                });
+            }
+            replay.games_replayed += 1;
+        }
+
+        for before in stored {
+            let Some(mut after) = current.remove(&key(&before)) else {
+                continue;
+            };
+            catch_up(&mut after, now);
+            if ratings_differ(&before, &after) {
+                replay.ratings.push(RatingDiff { before, after });
+            }
+        }
+        Ok(replay)
+    }
+
+    /// Recomputes and writes the result in one transaction. Games and ratings
+    /// are locked against writes meanwhile, so no game can finish halfway.
+    pub async fn apply(now: DateTime<Utc>, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            diesel::sql_query("LOCK TABLE games, ratings IN SHARE ROW EXCLUSIVE MODE")
+                .execute(tc)
+                .await?;
+            let replay = Self::compute(now, tc).await?;
+            for diff in &replay.ratings {
+                diesel::update(ratings::table.find(diff.after.id))
+                    .set(&diff.after)
+                    .execute(tc)
+                    .await?;
+            }
+            for diff in &replay.games {
+                diesel::update(games::table.find(diff.game_id))
+                    .set((
+                        games::white_rating.eq(diff.after.white_rating),
+                        games::black_rating.eq(diff.after.black_rating),
+                        games::white_rating_change.eq(diff.after.white_rating_change),
+                        games::black_rating_change.eq(diff.after.black_rating_change),
+                    ))
+                    .execute(tc)
+                    .await?;
+            }
+            Ok(replay)
+        })
+        .await
+    }
+}
+
+fn key(rating: &Rating) -> RatingKey {
+    (
+        rating.user_uid,
+        rating.speed.clone(),
+        rating.variant.clone(),
+    )
+}
+
+/// Applies the rating periods a player sat out before `until`, the way the
+/// periodic job would have.
+fn catch_up(rating: &mut Rating, until: DateTime<Utc>) {
+    if rating.played == 0 || rating.deviation >= INITIAL_DEVIATION {
+        return;
+    }
+    let periods = (until - rating.updated_at).num_seconds() / RATING_PERIOD.num_seconds();
+    if periods > 0 {
+        rating.deviation = inflated_deviation(rating.deviation, rating.volatility, periods);
+        rating.updated_at += RATING_PERIOD * periods as i32;
+    }
+}
+
+fn ratings_differ(before: &Rating, after: &Rating) -> bool {
+    (before.played, before.won, before.lost, before.draw)
+        != (after.played, after.won, after.lost, after.draw)
+        || (before.rating - after.rating).abs() > TOLERANCE
+        || (before.deviation - after.deviation).abs() > TOLERANCE
+        || (before.volatility - after.volatility).abs() > TOLERANCE
+}
@@ -0,0 +1,138 @@
+use crate::{
+    db_error::DbError,
+    models::{Game, NewGame},
+    schema::{games, rematches},
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{prelude::*, Insertable, Queryable, Selectable};
+use diesel_async::RunQueryDsl;
+use shared_types::{ChallengeId, TimeMode};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// A rematch offer on a finished game, and the game it led to once accepted.
+#[derive(Insertable, Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = rematches)]
+pub struct Rematch {
+    pub game_id: Uuid,
+    pub offered_by: Uuid,
+    pub new_game_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Rematch {
+    pub async fn for_game(game_id: Uuid, conn: &mut DbConn<'_>) -> Result<Option<Self>, DbError> {
+        Ok(rematches::table
+            .find(game_id)
+            .first(conn)
+            .await
+            .optional()?)
+    }
+
+    /// Finished games whose opponent is waiting on `user_id` to answer a rematch.
+    pub async fn offered_to(user_id: Uuid, conn: &mut DbConn<'_>) -> Result<Vec<Game>, DbError> {
+        let pending = rematches::table
+            .filter(rematches::new_game_id.is_null())
+            .filter(rematches::offered_by.ne(user_id))
+            .select(rematches::game_id);
+        Ok(games::table
+            .filter(games::id.eq_any(pending))
+            .filter(games::white_id.eq(user_id).or(games::black_id.eq(user_id)))
+            .select(Game::as_select())
+            .load(conn)
+            .await?)
+    }
+
+    /// Records `user_id`'s offer. Offering again is a no-op, offering after
+    /// the opponent already did is refused so the caller can accept instead.
+    pub async fn offer(game: &Game, user_id: Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        Self::ensure_rematchable(game, user_id)?;
+        diesel::insert_into(rematches::table)
+            .values(Self {
+                game_id: game.id,
+                offered_by: user_id,
+                new_game_id: None,
+                created_at: Utc::now(),
+            })
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+        let rematch: Self = rematches::table.find(game.id).first(conn).await?;
+        if rematch.offered_by != user_id || rematch.new_game_id.is_some() {
+            return Err(DbError::InvalidAction {
+                info: "A rematch was already offered".to_string(),
+            });
+        }
+        Ok(rematch)
+    }
+
+    /// Starts the rematch the opponent offered. Call inside a transaction:
+    /// the offer row is locked so only one new game can come out of it.
+    pub async fn accept(
+        game: &Game,
+        user_id: Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(Game, Vec<ChallengeId>), DbError> {
+        Self::ensure_rematchable(game, user_id)?;
+        let rematch: Option<Self> = rematches::table
+            .find(game.id)
+            .for_update()
+            .first(conn)
+            .await
+            .optional()?;
+        let Some(rematch) = rematch
+            .filter(|rematch| rematch.offered_by != user_id && rematch.new_game_id.is_none())
+        else {
+            return Err(DbError::NotFound {
+                reason: "No rematch offer to accept".to_string(),
+            });
+        };
+        let new_game = Game::create(NewGame::rematch(game), conn).await?;
+        let deleted = if let Ok(TimeMode::RealTime) = TimeMode::from_str(&new_game.time_mode) {
+            new_game.delete_real_time_challenges(conn).await?
+        } else {
+            Vec::new()
+        };
+        diesel::update(rematches::table.find(rematch.game_id))
+            .set(rematches::new_game_id.eq(new_game.id))
+            .execute(conn)
+            .await?;
+        Ok((new_game, deleted))
+    }
+
+    /// Withdraws or declines a pending offer, whichever player asks.
+    pub async fn decline(game: &Game, user_id: Uuid, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        Self::ensure_rematchable(game, user_id)?;
+        let deleted = diesel::delete(
+            rematches::table
+                .find(game.id)
+                .filter(rematches::new_game_id.is_null()),
+        )
+        .execute(conn)
+        .await?;
+        if deleted == 0 {
+            return Err(DbError::NotFound {
+                reason: "No rematch offer to decline".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn ensure_rematchable(game: &Game, user_id: Uuid) -> Result<(), DbError> {
+        if !game.user_is_player(user_id) {
+            return Err(DbError::Unauthorized);
+        }
+        if !game.finished {
+            return Err(DbError::InvalidAction {
+                info: "Only finished games can be rematched".to_string(),
+            });
+        }
+        if game.tournament_id.is_some() {
+            return Err(DbError::InvalidAction {
+                info: "Tournament games cannot be rematched".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
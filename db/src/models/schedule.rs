@@ -1,16 +1,23 @@
 use super::Game;
 use crate::{
     db_error::DbError,
-    schema::{games, schedules, tournaments},
+    schema::{availability_windows, games, schedule_assistant_proposals, schedules, tournaments},
     DbConn,
 };
-use chrono::{DateTime, Utc};
-use diesel::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+use diesel::{
+    dsl::{exists, not},
+    prelude::*,
+};
 use diesel_async::RunQueryDsl;
+use hive_lib::GameStatus;
 use serde::{Deserialize, Serialize};
-use shared_types::GameId;
+use shared_types::{GameId, TimeMode, TournamentStatus};
 use uuid::Uuid;
 
+/// A rejected automatic proposal comes back at most this often.
+const PROPOSAL_COOLDOWN: Duration = Duration::days(1);
+
 #[derive(Insertable, Debug)]
 #[diesel(table_name = schedules)]
 pub struct NewSchedule {
@@ -71,6 +78,7 @@ pub struct Schedule {
     pub start_t: DateTime<Utc>,
     pub agreed: bool,
     pub notified: bool,
+    pub reminded: bool,
 }
 
 impl Schedule {
@@ -225,4 +233,74 @@ impl Schedule {
 
         Ok(results)
     }
+
+    /// Agreed games starting within `lead` whose players have not been reminded yet.
+    pub async fn due_reminders(
+        lead: Duration,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Self>, DbError> {
+        let now = Utc::now();
+        Ok(schedules::table
+            .inner_join(games::table)
+            .filter(schedules::agreed.eq(true))
+            .filter(schedules::reminded.eq(false))
+            .filter(schedules::start_t.between(now, now + lead))
+            .filter(games::finished.eq(false))
+            .select(schedules::all_columns)
+            .get_results(conn)
+            .await?)
+    }
+
+    pub async fn mark_reminded(schedule_id: Uuid, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        diesel::update(schedules::table.find(schedule_id))
+            .set(schedules::reminded.eq(true))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Games of running real time tournaments that nobody proposed a time for
+    /// yet, where both players have published their availability and the
+    /// assistant has not proposed one recently.
+    pub async fn games_awaiting_proposal(conn: &mut DbConn<'_>) -> Result<Vec<Game>, DbError> {
+        let recently_proposed = schedule_assistant_proposals::table
+            .filter(schedule_assistant_proposals::proposed_at.gt(Utc::now() - PROPOSAL_COOLDOWN))
+            .select(schedule_assistant_proposals::game_id);
+        let running = tournaments::table
+            .filter(tournaments::status.eq(TournamentStatus::InProgress.to_string()))
+            .filter(tournaments::time_mode.eq(TimeMode::RealTime.to_string()))
+            .filter(tournaments::paused_at.is_null())
+            .select(tournaments::id.nullable());
+        Ok(games::table
+            .filter(games::tournament_id.eq_any(running))
+            .filter(games::finished.eq(false))
+            .filter(games::game_status.eq(GameStatus::NotStarted.to_string()))
+            .filter(not(exists(
+                schedules::table.filter(schedules::game_id.eq(games::id)),
+            )))
+            .filter(games::id.ne_all(recently_proposed))
+            .filter(exists(
+                availability_windows::table
+                    .filter(availability_windows::user_id.eq(games::white_id)),
+            ))
+            .filter(exists(
+                availability_windows::table
+                    .filter(availability_windows::user_id.eq(games::black_id)),
+            ))
+            .select(Game::as_select())
+            .get_results(conn)
+            .await?)
+    }
+
+    /// Remembers that the assistant proposed a time for the game.
+    pub async fn mark_auto_proposed(game_id: Uuid, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        diesel::insert_into(schedule_assistant_proposals::table)
+            .values(schedule_assistant_proposals::game_id.eq(game_id))
+            .on_conflict(schedule_assistant_proposals::game_id)
+            .do_update()
+            .set(schedule_assistant_proposals::proposed_at.eq(Utc::now()))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,383 @@
+use crate::{
+    db_error::DbError,
+    models::move_text::invalid,
+    schema::{
+        studies::{self, dsl},
+        study_chapters,
+    },
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{dsl::max, prelude::*, Insertable, Queryable, Selectable};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use hive_lib::GameType;
+use nanoid::nanoid;
+use shared_types::StudyVisibility;
+use uuid::Uuid;
+
+pub const MAX_STUDY_CHAPTERS: usize = 64;
+pub const MAX_STUDY_TITLE_LENGTH: usize = 100;
+pub const MAX_STUDY_DESCRIPTION_LENGTH: usize = 2_000;
+/// Limit on one serialized chapter tree, which is stored as is.
+pub const MAX_CHAPTER_TREE_BYTES: usize = 512 * 1024;
+
+/// A collection of analysis trees shared under a short link.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = studies)]
+pub struct Study {
+    pub id: Uuid,
+    pub nanoid: String,
+    pub owner_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub visibility: String,
+    pub forked_from: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = studies)]
+struct NewStudy {
+    nanoid: String,
+    owner_id: Uuid,
+    title: String,
+    description: String,
+    visibility: String,
+    forked_from: Option<Uuid>,
+}
+
+/// One analysis tree of a study, at `position` among its chapters. `tree` is the JSON the
+/// analysis board saves; the database keeps it opaque.
+#[derive(Queryable, Selectable, Identifiable, Debug, Clone)]
+#[diesel(table_name = study_chapters)]
+pub struct StudyChapter {
+    pub id: Uuid,
+    pub study_id: Uuid,
+    pub position: i32,
+    pub title: String,
+    pub game_type: String,
+    pub tree: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = study_chapters)]
+pub struct NewStudyChapter {
+    pub study_id: Uuid,
+    pub position: i32,
+    pub title: String,
+    pub game_type: String,
+    pub tree: String,
+}
+
+impl NewStudyChapter {
+    /// A chapter not yet placed in a study; `Study::create` and `Study::save_chapter` fill in
+    /// where it goes.
+    pub fn new(title: &str, game_type: GameType, tree: String) -> Result<Self, DbError> {
+        if tree.len() > MAX_CHAPTER_TREE_BYTES {
+            return Err(invalid(
+                "This chapter is too large to save",
+                format!("{} > {MAX_CHAPTER_TREE_BYTES} bytes", tree.len()),
+            ));
+        }
+        Ok(Self {
+            study_id: Uuid::nil(),
+            position: 0,
+            title: title_text(title, "Chapter title")?,
+            game_type: game_type.to_string(),
+            tree,
+        })
+    }
+}
+
+impl Study {
+    pub async fn create(
+        owner_id: Uuid,
+        title: &str,
+        visibility: StudyVisibility,
+        chapter: NewStudyChapter,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        let new_study = NewStudy {
+            nanoid: nanoid!(10),
+            owner_id,
+            title: title_text(title, "Study title")?,
+            description: String::new(),
+            visibility: visibility.to_string(),
+            forked_from: None,
+        };
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            let study: Study = diesel::insert_into(studies::table)
+                .values(&new_study)
+                .get_result(tc)
+                .await?;
+            diesel::insert_into(study_chapters::table)
+                .values(NewStudyChapter {
+                    study_id: study.id,
+                    position: 0,
+                    ..chapter
+                })
+                .execute(tc)
+                .await?;
+            Ok(study)
+        })
+        .await
+    }
+
+    pub async fn find_by_nanoid(nanoid: &str, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        Ok(dsl::studies
+            .filter(dsl::nanoid.eq(nanoid))
+            .first(conn)
+            .await?)
+    }
+
+    /// The owner's studies, newest first. Others only see the public ones.
+    pub async fn for_owner(
+        owner_id: Uuid,
+        viewer: Option<Uuid>,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Self>, DbError> {
+        let mut query = dsl::studies
+            .filter(dsl::owner_id.eq(owner_id))
+            .order(dsl::updated_at.desc())
+            .into_boxed();
+        if viewer != Some(owner_id) {
+            query = query.filter(dsl::visibility.eq(StudyVisibility::Public.to_string()));
+        }
+        Ok(query.load(conn).await?)
+    }
+
+    pub fn visibility(&self) -> StudyVisibility {
+        self.visibility.parse().unwrap_or_default()
+    }
+
+    pub fn can_view(&self, user_id: Option<Uuid>) -> bool {
+        self.visibility() != StudyVisibility::Private || user_id == Some(self.owner_id)
+    }
+
+    fn ensure_owner(&self, user_id: Uuid) -> Result<(), DbError> {
+        if user_id == self.owner_id {
+            Ok(())
+        } else {
+            Err(DbError::Unauthorized)
+        }
+    }
+
+    pub async fn chapters(&self, conn: &mut DbConn<'_>) -> Result<Vec<StudyChapter>, DbError> {
+        Ok(study_chapters::table
+            .filter(study_chapters::study_id.eq(self.id))
+            .order(study_chapters::position.asc())
+            .load(conn)
+            .await?)
+    }
+
+    pub async fn update_details(
+        &self,
+        user_id: Uuid,
+        title: &str,
+        description: &str,
+        visibility: StudyVisibility,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        self.ensure_owner(user_id)?;
+        let title = title_text(title, "Study title")?;
+        let description = description.trim();
+        if description.chars().count() > MAX_STUDY_DESCRIPTION_LENGTH {
+            return Err(invalid(
+                "Study description is too long",
+                format!("> {MAX_STUDY_DESCRIPTION_LENGTH} characters"),
+            ));
+        }
+        Ok(diesel::update(dsl::studies.find(self.id))
+            .set((
+                dsl::title.eq(title),
+                dsl::description.eq(description),
+                dsl::visibility.eq(visibility.to_string()),
+                dsl::updated_at.eq(Utc::now()),
+            ))
+            .get_result(conn)
+            .await?)
+    }
+
+    /// Overwrites the chapter at `position`, or appends `chapter` when `position` is `None`.
+    /// Returns the chapter's position.
+    pub async fn save_chapter(
+        &self,
+        user_id: Uuid,
+        position: Option<i32>,
+        chapter: NewStudyChapter,
+        conn: &mut DbConn<'_>,
+    ) -> Result<i32, DbError> {
+        self.ensure_owner(user_id)?;
+        let study_id = self.id;
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            // Serializes saves to the same study.
+            diesel::update(dsl::studies.find(study_id))
+                .set(dsl::updated_at.eq(Utc::now()))
+                .execute(tc)
+                .await?;
+            if let Some(position) = position {
+                let updated = diesel::update(
+                    study_chapters::table
+                        .filter(study_chapters::study_id.eq(study_id))
+                        .filter(study_chapters::position.eq(position)),
+                )
+                .set((
+                    study_chapters::title.eq(&chapter.title),
+                    study_chapters::game_type.eq(&chapter.game_type),
+                    study_chapters::tree.eq(&chapter.tree),
+                    study_chapters::updated_at.eq(Utc::now()),
+                ))
+                .execute(tc)
+                .await?;
+                if updated == 0 {
+                    return Err(DbError::NotFound {
+                        reason: format!("Chapter {position} not found"),
+                    });
+                }
+                return Ok(position);
+            }
+            let last: Option<i32> = study_chapters::table
+                .filter(study_chapters::study_id.eq(study_id))
+                .select(max(study_chapters::position))
+                .get_result(tc)
+                .await?;
+            let position = last.map_or(0, |last| last + 1);
+            if position as usize >= MAX_STUDY_CHAPTERS {
+                return Err(DbError::InvalidAction {
+                    info: format!("A study holds at most {MAX_STUDY_CHAPTERS} chapters"),
+                });
+            }
+            diesel::insert_into(study_chapters::table)
+                .values(NewStudyChapter {
+                    study_id,
+                    position,
+                    ..chapter
+                })
+                .execute(tc)
+                .await?;
+            Ok(position)
+        })
+        .await
+    }
+
+    /// Removes a chapter and moves the ones after it up. The last chapter can't go; delete
+    /// the study instead.
+    pub async fn delete_chapter(
+        &self,
+        user_id: Uuid,
+        position: i32,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        self.ensure_owner(user_id)?;
+        let study_id = self.id;
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            diesel::update(dsl::studies.find(study_id))
+                .set(dsl::updated_at.eq(Utc::now()))
+                .execute(tc)
+                .await?;
+            let count: i64 = study_chapters::table
+                .filter(study_chapters::study_id.eq(study_id))
+                .count()
+                .get_result(tc)
+                .await?;
+            if count <= 1 {
+                return Err(DbError::InvalidAction {
+                    info: "A study needs at least one chapter".to_string(),
+                });
+            }
+            let deleted = diesel::delete(
+                study_chapters::table
+                    .filter(study_chapters::study_id.eq(study_id))
+                    .filter(study_chapters::position.eq(position)),
+            )
+            .execute(tc)
+            .await?;
+            if deleted == 0 {
+                return Err(DbError::NotFound {
+                    reason: format!("Chapter {position} not found"),
+                });
+            }
+            diesel::update(
+                study_chapters::table
+                    .filter(study_chapters::study_id.eq(study_id))
+                    .filter(study_chapters::position.gt(position)),
+            )
+            .set(study_chapters::position.eq(study_chapters::position - 1))
+            .execute(tc)
+            .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Copies a study the user can see into a private one of their own.
+    pub async fn fork(&self, user_id: Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        if !self.can_view(Some(user_id)) {
+            return Err(DbError::Unauthorized);
+        }
+        let chapters = self.chapters(conn).await?;
+        let new_study = NewStudy {
+            nanoid: nanoid!(10),
+            owner_id: user_id,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            visibility: StudyVisibility::Private.to_string(),
+            forked_from: Some(self.id),
+        };
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            let study: Study = diesel::insert_into(studies::table)
+                .values(&new_study)
+                .get_result(tc)
+                .await?;
+            let copies = chapters
+                .into_iter()
+                .map(|chapter| NewStudyChapter {
+                    study_id: study.id,
+                    position: chapter.position,
+                    title: chapter.title,
+                    game_type: chapter.game_type,
+                    tree: chapter.tree,
+                })
+                .collect::<Vec<_>>();
+            diesel::insert_into(study_chapters::table)
+                .values(&copies)
+                .execute(tc)
+                .await?;
+            Ok(study)
+        })
+        .await
+    }
+
+    pub async fn delete(&self, user_id: Uuid, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        self.ensure_owner(user_id)?;
+        diesel::delete(dsl::studies.find(self.id))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// The study this one was forked from, if it still exists.
+    pub async fn source(&self, conn: &mut DbConn<'_>) -> Result<Option<Self>, DbError> {
+        let Some(source_id) = self.forked_from else {
+            return Ok(None);
+        };
+        Ok(dsl::studies
+            .find(source_id)
+            .first(conn)
+            .await
+            .optional()?)
+    }
+}
+
+fn title_text(title: &str, field: &str) -> Result<String, DbError> {
+    let title = title.trim();
+    if title.is_empty() || title.chars().count() > MAX_STUDY_TITLE_LENGTH {
+        return Err(invalid(
+            &format!("{field} must be 1 to {MAX_STUDY_TITLE_LENGTH} characters"),
+            title.to_string(),
+        ));
+    }
+    Ok(title.to_string())
+}
@@ -1,4 +1,13 @@
-use super::{Game, NewGame, Schedule, TournamentInvitation};
+use super::{
+    Game,
+    NewGame,
+    Rating,
+    Schedule,
+    TournamentBye,
+    TournamentInvitation,
+    TournamentSeries,
+    TournamentWaitlist,
+};
 use crate::{
     db_error::DbError,
     models::{
@@ -12,6 +21,7 @@ use crate::{
             self,
             ends_at,
             nanoid as nanoid_field,
+            paused_at,
             series as series_column,
             started_at,
             starts_at,
@@ -27,29 +37,41 @@ use crate::{
 use chrono::{prelude::*, TimeDelta};
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
-use hive_lib::{Color, GameStatus};
+use hive_lib::{Color, GameControl, GameStatus, GameType};
 use itertools::Itertools;
 use nanoid::nanoid;
 use rand::{rng, seq::SliceRandom};
 use serde::{Deserialize, Serialize};
 use shared_types::{
     Conclusion,
+    GameSpeed,
+    GameStart,
     Standings,
     Tiebreaker,
     TimeMode,
+    TournamentApplicant,
     TournamentDetails,
     TournamentGameResult,
     TournamentId,
+    TournamentJoinError,
     TournamentMode,
+    TournamentRequirements,
     TournamentSortOrder,
     TournamentStatus,
+    VariantClass,
 };
 use std::{
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     str::FromStr,
 };
 use uuid::Uuid;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Registration {
+    Joined,
+    Waitlisted,
+}
+
 #[derive(Insertable, Debug)]
 #[diesel(table_name = tournaments)]
 pub struct NewTournament {
@@ -77,6 +99,10 @@ pub struct NewTournament {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub series: Option<Uuid>,
+    pub min_rated_games: i32,
+    pub min_account_age_days: i32,
+    pub allow_bots: bool,
+    pub clock_kind: String,
 }
 
 impl NewTournament {
@@ -101,6 +127,15 @@ impl NewTournament {
             });
         }
 
+        if !details
+            .clock_kind
+            .supports(details.time_mode, details.time_increment)
+        {
+            return Err(DbError::InvalidTournamentDetails {
+                info: format!("{} needs a real-time clock", details.clock_kind.label()),
+            });
+        }
+
         if details.seats < details.min_seats {
             return Err(DbError::InvalidTournamentDetails {
                 info: String::from("Seats is less than minimum number of seats"),
@@ -119,6 +154,20 @@ impl NewTournament {
             });
         }
 
+        if details.min_rated_games < 0 || details.min_account_age_days < 0 {
+            return Err(DbError::InvalidTournamentDetails {
+                info: String::from("Entry requirements cannot be negative"),
+            });
+        }
+
+        if let (Some(lower), Some(upper)) = (details.band_lower, details.band_upper) {
+            if lower > upper {
+                return Err(DbError::InvalidTournamentDetails {
+                    info: String::from("Minimum rating is above the maximum rating"),
+                });
+            }
+        }
+
         Ok(Self {
             nanoid: nanoid!(11),
             name: details.name,
@@ -149,6 +198,10 @@ impl NewTournament {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             series: details.series,
+            min_rated_games: details.min_rated_games,
+            min_account_age_days: details.min_account_age_days,
+            allow_bots: details.allow_bots,
+            clock_kind: details.clock_kind.to_string(),
         })
     }
 }
@@ -184,6 +237,11 @@ pub struct Tournament {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub series: Option<Uuid>,
+    pub min_rated_games: i32,
+    pub min_account_age_days: i32,
+    pub allow_bots: bool,
+    pub paused_at: Option<DateTime<Utc>>,
+    pub clock_kind: String,
 }
 
 impl Tournament {
@@ -254,7 +312,7 @@ impl Tournament {
             {
                 return Ok(());
             }
-            return Err(DbError::TournamentInviteOnly);
+            return Err(DbError::TournamentJoin(TournamentJoinError::InviteOnly));
         }
         Ok(())
     }
@@ -286,6 +344,24 @@ impl Tournament {
         Ok(())
     }
 
+    fn ensure_not_paused(&self) -> Result<(), DbError> {
+        if self.paused_at.is_some() {
+            return Err(DbError::InvalidAction {
+                info: String::from("The tournament is paused"),
+            });
+        }
+        Ok(())
+    }
+
+    fn ensure_swiss(&self) -> Result<(), DbError> {
+        if TournamentMode::from_str(&self.mode).ok() != Some(TournamentMode::DoubleSwiss) {
+            return Err(DbError::InvalidAction {
+                info: String::from("Byes are only available in Swiss tournaments"),
+            });
+        }
+        Ok(())
+    }
+
     pub async fn ensure_games_finished(&self, conn: &mut DbConn<'_>) -> Result<(), DbError> {
         if self.number_of_games(conn).await? != self.number_of_finished_games(conn).await? {
             return Err(DbError::InvalidAction {
@@ -490,51 +566,161 @@ impl Tournament {
 
     pub async fn add_to_series(
         &self,
-        series_id: Uuid,
+        user_id: &Uuid,
+        series: &TournamentSeries,
         conn: &mut DbConn<'_>,
     ) -> Result<Tournament, DbError> {
+        self.ensure_user_is_organizer_or_admin(user_id, conn)
+            .await?;
+        series
+            .ensure_user_is_organizer_or_admin(user_id, conn)
+            .await?;
         Ok(diesel::update(tournaments::table.find(self.id))
-            .set(series_column.eq(Some(series_id)))
+            .set((series_column.eq(Some(series.id)), updated_at.eq(Utc::now())))
             .get_result(conn)
             .await?)
     }
 
-    pub async fn remove_from_series(&self, conn: &mut DbConn<'_>) -> Result<Tournament, DbError> {
+    pub async fn remove_from_series(
+        &self,
+        user_id: &Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Tournament, DbError> {
+        let Some(series_id) = self.series else {
+            return Ok(self.clone());
+        };
+        // Either side of the membership may undo it.
+        match self.ensure_user_is_organizer_or_admin(user_id, conn).await {
+            Err(DbError::Unauthorized) => {
+                TournamentSeries::find(series_id, conn)
+                    .await?
+                    .ensure_user_is_organizer_or_admin(user_id, conn)
+                    .await?;
+            }
+            result => result?,
+        }
         Ok(diesel::update(tournaments::table.find(self.id))
-            .set(series_column.eq(None::<Uuid>))
+            .set((series_column.eq(None::<Uuid>), updated_at.eq(Utc::now())))
             .get_result(conn)
             .await?)
     }
 
-    pub async fn join(&self, user_id: &Uuid, conn: &mut DbConn<'_>) -> Result<Tournament, DbError> {
-        self.ensure_not_started()?;
-        self.ensure_not_full(conn).await?;
+    pub fn requirements(&self) -> TournamentRequirements {
+        TournamentRequirements {
+            min_rated_games: self.min_rated_games,
+            min_account_age_days: self.min_account_age_days,
+            allow_bots: self.allow_bots,
+            band_lower: self.band_lower,
+            band_upper: self.band_upper,
+        }
+    }
+
+    /// Checks the entry requirements. Invited players were picked by an
+    /// organizer and skip them.
+    async fn ensure_eligible(&self, user: &User, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        if TournamentInvitation::exists(&self.id, &user.id, conn).await? {
+            return Ok(());
+        }
+        let requirements = self.requirements();
+        let (rating, rated_games) = if requirements.needs_rating() {
+            let speed = GameSpeed::from_base_increment(self.time_base, self.time_increment);
+            // Tournament games are always played with all expansions.
+            let variant_class = VariantClass::from(GameType::MLP);
+            let rating = match Rating::for_uuid(&user.id, &speed, variant_class, conn).await {
+                Ok(rating) => rating.rating as i32,
+                Err(DbError::NotFound { .. }) => 1500,
+                Err(err) => return Err(err),
+            };
+            let rated_games =
+                Game::count_rated_for_player(user.id, &speed, variant_class, conn).await?;
+            (rating, rated_games)
+        } else {
+            (0, 0)
+        };
+        let applicant = TournamentApplicant {
+            rating,
+            rated_games,
+            account_age_days: (Utc::now() - user.created_at).num_days(),
+            bot: user.bot,
+        };
+        requirements
+            .check(&applicant)
+            .map_err(DbError::TournamentJoin)
+    }
+
+    /// Registers the user, or puts them on the waitlist when all seats are taken.
+    pub async fn join(
+        &self,
+        user_id: &Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Registration, DbError> {
+        if self.ensure_not_started().is_err() {
+            return Err(DbError::TournamentJoin(TournamentJoinError::AlreadyStarted));
+        }
         self.ensure_not_invite_only(user_id, conn).await?;
         let players = self.players(conn).await?;
-        if players.len() == self.seats as usize {
-            return Ok(self.clone());
-        }
         if players.iter().any(|player| player.id == *user_id) {
-            return Ok(self.clone());
+            return Ok(Registration::Joined);
         }
+        let user = User::find_by_uuid(user_id, conn).await?;
+        self.ensure_eligible(&user, conn).await?;
+        if players.len() >= self.seats as usize {
+            TournamentWaitlist::new(self.id, *user_id)
+                .insert(conn)
+                .await?;
+            self.touch(conn).await?;
+            return Ok(Registration::Waitlisted);
+        }
+        self.add_player(user_id, conn).await?;
+        Ok(Registration::Joined)
+    }
+
+    async fn add_player(&self, user_id: &Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
         if let Ok(invitation) = TournamentInvitation::find_by_ids(&self.id, user_id, conn).await {
             invitation.delete(conn).await?;
         }
+        TournamentWaitlist::delete(self.id, *user_id, conn).await?;
         let tournament_user = TournamentUser::new(self.id, *user_id);
         tournament_user.insert(conn).await?;
+        self.touch(conn).await
+    }
+
+    async fn touch(&self, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
         Ok(diesel::update(tournaments::table.find(self.id))
             .set(updated_at.eq(Utc::now()))
             .get_result(conn)
             .await?)
     }
 
+    /// Fills free seats from the waitlist in the order people signed up.
+    /// Entries that no longer meet the requirements are dropped. Returns the
+    /// promoted users.
+    pub async fn promote_from_waitlist(&self, conn: &mut DbConn<'_>) -> Result<Vec<Uuid>, DbError> {
+        let mut promoted = Vec::new();
+        if self.ensure_not_started().is_err() {
+            return Ok(promoted);
+        }
+        let mut free_seats = self.seats as i64 - self.number_of_players(conn).await?;
+        for user in self.waitlist(conn).await? {
+            if free_seats <= 0 {
+                break;
+            }
+            if self.ensure_eligible(&user, conn).await.is_err() {
+                TournamentWaitlist::delete(self.id, user.id, conn).await?;
+                continue;
+            }
+            self.add_player(&user.id, conn).await?;
+            promoted.push(user.id);
+            free_seats -= 1;
+        }
+        Ok(promoted)
+    }
+
     pub async fn leave(&self, user_id: &Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
         self.ensure_not_started()?;
         TournamentUser::delete(self.id, *user_id, conn).await?;
-        Ok(diesel::update(tournaments::table.find(self.id))
-            .set(updated_at.eq(Utc::now()))
-            .get_result(conn)
-            .await?)
+        TournamentWaitlist::delete(self.id, *user_id, conn).await?;
+        self.touch(conn).await
     }
 
     pub async fn update_description(
@@ -565,12 +751,244 @@ impl Tournament {
         self.ensure_user_is_organizer_or_admin(organizer, conn)
             .await?;
         TournamentUser::delete(self.id, *player, conn).await?;
+        TournamentWaitlist::delete(self.id, *player, conn).await?;
+        self.touch(conn).await
+    }
+
+    /// Takes the player out of future pairings and forfeits their remaining
+    /// games. Games that already have moves are resigned so ratings apply.
+    pub async fn withdraw(
+        &self,
+        user_id: &Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Game>, DbError> {
+        self.ensure_inprogress()?;
+        let withdrawn = diesel::update(
+            tournaments_users::table
+                .find((self.id, *user_id))
+                .filter(tournaments_users::withdrawn_at.is_null()),
+        )
+        .set(tournaments_users::withdrawn_at.eq(Some(Utc::now())))
+        .execute(conn)
+        .await?;
+        if withdrawn == 0 {
+            return Err(DbError::InvalidAction {
+                info: String::from("You are not playing in this tournament"),
+            });
+        }
+        if self.ensure_swiss().is_ok() {
+            let next_round = self.current_round(conn).await? + 1;
+            TournamentBye::delete_from_round(self.id, *user_id, next_round, conn).await?;
+        }
+
+        let mut forfeited = Vec::new();
+        // Lock all target games up front, same as abandoning.
+        for game in self
+            .unfinished_games_for_user_locked(*user_id, conn)
+            .await?
+        {
+            let Some(color) = game.user_color(*user_id) else {
+                continue;
+            };
+            let result = if game.turn == 0 {
+                game.forfeit(color, conn).await
+            } else {
+                game.resign(&GameControl::Resign(color), conn).await
+            };
+            match result {
+                Ok(game) => forfeited.push(game),
+                Err(DbError::GameIsOver) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        self.touch(conn).await?;
+        Ok(forfeited)
+    }
+
+    pub async fn withdrawn_players(&self, conn: &mut DbConn<'_>) -> Result<Vec<Uuid>, DbError> {
+        Ok(tournaments_users::table
+            .filter(tournaments_users::tournament_id.eq(self.id))
+            .filter(tournaments_users::withdrawn_at.is_not_null())
+            .select(tournaments_users::user_id)
+            .get_results(conn)
+            .await?)
+    }
+
+    /// Stops the clocks of games nobody has moved in yet and holds new
+    /// starts and rounds until the tournament is resumed.
+    pub async fn pause(&self, organizer: &Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        self.ensure_inprogress()?;
+        self.ensure_not_paused()?;
+        self.ensure_user_is_organizer_or_admin(organizer, conn)
+            .await?;
+        let untouched: Vec<Game> = games::table
+            .filter(tournament_id_column.eq(Some(self.id)))
+            .filter(games::finished.eq(false))
+            .filter(games::turn.eq(0))
+            .filter(games::game_status.eq(GameStatus::InProgress.to_string()))
+            .for_update()
+            .get_results(conn)
+            .await?;
+        for game in untouched {
+            game.stop_clock(conn).await?;
+        }
         Ok(diesel::update(tournaments::table.find(self.id))
-            .set(updated_at.eq(Utc::now()))
+            .set((paused_at.eq(Some(Utc::now())), updated_at.eq(Utc::now())))
+            .get_result(conn)
+            .await?)
+    }
+
+    /// Restarts the clocks of correspondence games stopped by the pause, real
+    /// time games wait for their players to start them again. The round
+    /// deadline moves by the time spent paused.
+    pub async fn resume(&self, organizer: &Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        self.ensure_inprogress()?;
+        self.ensure_user_is_organizer_or_admin(organizer, conn)
+            .await?;
+        let Some(paused) = self.paused_at else {
+            return Err(DbError::InvalidAction {
+                info: String::from("The tournament is not paused"),
+            });
+        };
+        let stopped: Vec<Game> = games::table
+            .filter(tournament_id_column.eq(Some(self.id)))
+            .filter(games::finished.eq(false))
+            .filter(games::turn.eq(0))
+            .filter(games::game_status.eq(GameStatus::NotStarted.to_string()))
+            .filter(games::game_start.eq(GameStart::Immediate.to_string()))
+            .for_update()
+            .get_results(conn)
+            .await?;
+        for game in stopped {
+            game.start(conn).await?;
+        }
+        let new_ends_at = self.ends_at.map(|ends| ends + (Utc::now() - paused));
+        Ok(diesel::update(tournaments::table.find(self.id))
+            .set((
+                paused_at.eq(None::<DateTime<Utc>>),
+                ends_at.eq(new_ends_at),
+                updated_at.eq(Utc::now()),
+            ))
             .get_result(conn)
             .await?)
     }
 
+    /// Swiss rounds paired so far. Every active player gets two games a round,
+    /// including byes, so the busiest player tells the round count.
+    fn rounds_played(games: &[Game], bye_player: Uuid) -> i32 {
+        let mut played = HashMap::new();
+        for game in games {
+            for player in [game.white_id, game.black_id] {
+                if player != bye_player {
+                    *played.entry(player).or_insert(0) += 1;
+                }
+            }
+        }
+        played.into_values().max().unwrap_or(0) / 2
+    }
+
+    pub async fn current_round(&self, conn: &mut DbConn<'_>) -> Result<i32, DbError> {
+        let bye_player = User::bye_player(conn).await?;
+        Ok(Self::rounds_played(&self.games(conn).await?, bye_player.id))
+    }
+
+    /// Half-point byes are played out as two drawn games against the bye
+    /// player, half of what a round is worth.
+    async fn half_point_byes(
+        &self,
+        user_ids: &[Uuid],
+        bye_player: &User,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Game>, DbError> {
+        let mut games = Vec::new();
+        if user_ids.is_empty() {
+            return Ok(games);
+        }
+        if !self
+            .players(conn)
+            .await?
+            .iter()
+            .any(|player| player.id == bye_player.id)
+        {
+            TournamentUser::new(self.id, bye_player.id)
+                .insert(conn)
+                .await?;
+        }
+        for user_id in user_ids {
+            for (white, black) in [(*user_id, bye_player.id), (bye_player.id, *user_id)] {
+                let game = Game::create(NewGame::new_from_tournament(white, black, self), conn)
+                    .await?
+                    .assign_tournament_result(&TournamentGameResult::Draw, conn)
+                    .await?;
+                games.push(game);
+            }
+        }
+        Ok(games)
+    }
+
+    pub async fn request_bye(
+        &self,
+        user_id: &Uuid,
+        round: i32,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        self.ensure_bye_allowed(user_id, conn).await?;
+        if round <= self.current_round(conn).await? || round > self.rounds {
+            return Err(DbError::InvalidAction {
+                info: format!("Round {round} cannot be skipped anymore"),
+            });
+        }
+        TournamentBye::new(self.id, *user_id, round)
+            .insert(conn)
+            .await?;
+        self.touch(conn).await
+    }
+
+    pub async fn cancel_bye(
+        &self,
+        user_id: &Uuid,
+        round: i32,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        self.ensure_bye_allowed(user_id, conn).await?;
+        if round <= self.current_round(conn).await? {
+            return Err(DbError::InvalidAction {
+                info: format!("Round {round} has already been paired"),
+            });
+        }
+        TournamentBye::delete(self.id, *user_id, round, conn).await?;
+        self.touch(conn).await
+    }
+
+    /// Byes can only be changed by players still seated in a Swiss tournament that is not over.
+    async fn ensure_bye_allowed(
+        &self,
+        user_id: &Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        self.ensure_swiss()?;
+        if self.status == TournamentStatus::Finished.to_string() {
+            return Err(DbError::InvalidAction {
+                info: String::from("The tournament is over"),
+            });
+        }
+        let seat: Option<TournamentUser> = tournaments_users::table
+            .find((self.id, *user_id))
+            .first(conn)
+            .await
+            .optional()?;
+        match seat {
+            Some(seat) if seat.withdrawn_at.is_none() => Ok(()),
+            _ => Err(DbError::InvalidAction {
+                info: String::from("You are not playing in this tournament"),
+            }),
+        }
+    }
+
+    pub async fn byes(&self, conn: &mut DbConn<'_>) -> Result<Vec<TournamentBye>, DbError> {
+        TournamentBye::for_tournament(self, conn).await
+    }
+
     pub async fn from_uuid(uuid: &Uuid, conn: &mut DbConn<'_>) -> Result<Tournament, DbError> {
         Ok(tournaments::table.find(uuid).first(conn).await?)
     }
@@ -604,6 +1022,10 @@ impl Tournament {
             .await?)
     }
 
+    pub async fn waitlist(&self, conn: &mut DbConn<'_>) -> Result<Vec<User>, DbError> {
+        TournamentWaitlist::users(self, conn).await
+    }
+
     pub async fn players(&self, conn: &mut DbConn<'_>) -> Result<Vec<User>, DbError> {
         Ok(TournamentUser::belonging_to(self)
             .inner_join(users::table)
@@ -655,6 +1077,31 @@ impl Tournament {
             .await?)
     }
 
+    pub async fn standings(&self, conn: &mut DbConn<'_>) -> Result<Standings, DbError> {
+        Ok(self.standings_from(&self.games(conn).await?))
+    }
+
+    fn standings_from(&self, games: &[Game]) -> Standings {
+        let mut standings = Standings::new();
+        for tiebreaker in self.tiebreaker.iter().flatten() {
+            standings.add_tiebreaker(
+                Tiebreaker::from_str(tiebreaker).unwrap_or(Tiebreaker::SonnebornBerger),
+            );
+        }
+        for game in games {
+            standings.add_result(
+                game.white_id,
+                game.black_id,
+                game.white_rating.unwrap_or(0.0),
+                game.black_rating.unwrap_or(0.0),
+                TournamentGameResult::from_str(&game.tournament_game_result)
+                    .unwrap_or(TournamentGameResult::Unknown),
+            );
+        }
+        standings.enforce_tiebreakers();
+        standings
+    }
+
     pub async fn unfinished_games_for_user_locked(
         &self,
         user_id: Uuid,
@@ -798,12 +1245,18 @@ impl Tournament {
 
     async fn swiss_create_first_round(&self, conn: &mut DbConn<'_>) -> Result<Vec<Game>, DbError> {
         let mut players = self.players(conn).await?;
-        let mut games = Vec::new();
+        let bye_player = User::bye_player(conn).await?;
+        let half_byes = TournamentBye::users_for_round(self.id, 1, conn).await?;
+        let mut games = self.half_point_byes(&half_byes, &bye_player, conn).await?;
+        let bye_player_joined =
+            !half_byes.is_empty() || players.iter().any(|p| p.id == bye_player.id);
+        players.retain(|p| p.id != bye_player.id && !half_byes.contains(&p.id));
 
         // if odd number of players, add a bye player
-        let bye_player = User::find_by_username("SwissByePlayer", conn).await?;
-        if !players.len().is_multiple_of(2) && !players.iter().any(|p| p.id == bye_player.id) {
-            self.join(&bye_player.id, conn).await?;
+        if !players.len().is_multiple_of(2) {
+            if !bye_player_joined {
+                self.add_player(&bye_player.id, conn).await?;
+            }
             players.push(bye_player.clone());
         };
         // shuffle players to create random pairings
@@ -951,6 +1404,7 @@ impl Tournament {
         conn: &mut DbConn<'_>,
     ) -> Result<Vec<Game>, DbError> {
         self.ensure_inprogress()?;
+        self.ensure_not_paused()?;
         self.ensure_user_is_organizer_or_admin(organizer, conn)
             .await?;
         self.ensure_games_finished(conn).await?;
@@ -965,34 +1419,13 @@ impl Tournament {
 
         let played_games = self.games(conn).await?;
         let mut games = Vec::new();
-        let mut standings = Standings::new();
-
-        for tiebreaker in self.tiebreaker.iter().flatten() {
-            standings.add_tiebreaker(
-                Tiebreaker::from_str(tiebreaker).unwrap_or(Tiebreaker::SonnebornBerger),
-            );
-        }
-        for played_game in played_games {
-            let white_uuid = played_game.white_id;
-            let black_uuid = played_game.black_id;
-            let white_elo = played_game.white_rating;
-            let black_elo = played_game.black_rating;
-            let result = TournamentGameResult::from_str(&played_game.tournament_game_result)
-                .unwrap_or(TournamentGameResult::Unknown);
-            standings.add_result(
-                white_uuid,
-                black_uuid,
-                white_elo.unwrap_or(0.0),
-                black_elo.unwrap_or(0.0),
-                result,
-            );
-        }
-        standings.enforce_tiebreakers();
+        let standings = self.standings_from(&played_games);
 
-        let bye_player = User::find_by_username("SwissByePlayer", conn).await?;
+        let bye_player = User::bye_player(conn).await?;
         let mut active_player_ids: HashSet<Uuid> = tournaments_users::table
             .inner_join(users::table)
             .filter(tournaments_users::tournament_id.eq(self.id))
+            .filter(tournaments_users::withdrawn_at.is_null())
             .filter(users::deleted.eq(false))
             .select(tournaments_users::user_id)
             .load::<Uuid>(conn)
@@ -1000,6 +1433,14 @@ impl Tournament {
             .into_iter()
             .collect();
         let bye_player_joined = active_player_ids.remove(&bye_player.id);
+        let round = Self::rounds_played(&played_games, bye_player.id) + 1;
+        let half_byes: Vec<Uuid> = TournamentBye::users_for_round(self.id, round, conn)
+            .await?
+            .into_iter()
+            .filter(|id| active_player_ids.remove(id))
+            .collect();
+        games.extend(self.half_point_byes(&half_byes, &bye_player, conn).await?);
+        let bye_player_joined = bye_player_joined || !half_byes.is_empty();
 
         let mut flattened_players_standing: Vec<Uuid> = standings
             .players_standings
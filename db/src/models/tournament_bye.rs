@@ -0,0 +1,104 @@
+use crate::{
+    db_error::DbError,
+    models::{tournament::Tournament, user::User},
+    schema::tournaments_byes,
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{prelude::*, Identifiable, Insertable, Queryable, Selectable};
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+/// A half-point bye a player asked for in a Swiss round that has not been paired yet.
+#[derive(Insertable, Identifiable, Selectable, Queryable, Associations, Debug, Clone)]
+#[diesel(belongs_to(User, foreign_key = user_id))]
+#[diesel(belongs_to(Tournament))]
+#[diesel(table_name = tournaments_byes)]
+#[diesel(primary_key(tournament_id, user_id, round))]
+pub struct TournamentBye {
+    pub tournament_id: Uuid,
+    pub user_id: Uuid,
+    pub round: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TournamentBye {
+    pub fn new(tournament_id: Uuid, user_id: Uuid, round: i32) -> Self {
+        Self {
+            tournament_id,
+            user_id,
+            round,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub async fn insert(&self, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        diesel::insert_into(tournaments_byes::table)
+            .values(self)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete(
+        tournament_id: Uuid,
+        user_id: Uuid,
+        round: i32,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        let deleted = diesel::delete(tournaments_byes::table.find((tournament_id, user_id, round)))
+            .execute(conn)
+            .await?;
+        if deleted == 0 {
+            return Err(DbError::NotFound {
+                reason: format!("No bye requested for round {round}"),
+            });
+        }
+        Ok(())
+    }
+
+    /// Drops the user's byes for rounds that have not been paired yet.
+    pub async fn delete_from_round(
+        tournament_id: Uuid,
+        user_id: Uuid,
+        round: i32,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        diesel::delete(
+            tournaments_byes::table
+                .filter(tournaments_byes::tournament_id.eq(tournament_id))
+                .filter(tournaments_byes::user_id.eq(user_id))
+                .filter(tournaments_byes::round.ge(round)),
+        )
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn for_tournament(
+        tournament: &Tournament,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Self>, DbError> {
+        Ok(Self::belonging_to(tournament)
+            .order((
+                tournaments_byes::round.asc(),
+                tournaments_byes::created_at.asc(),
+            ))
+            .get_results(conn)
+            .await?)
+    }
+
+    pub async fn users_for_round(
+        tournament_id: Uuid,
+        round: i32,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<Uuid>, DbError> {
+        Ok(tournaments_byes::table
+            .filter(tournaments_byes::tournament_id.eq(tournament_id))
+            .filter(tournaments_byes::round.eq(round))
+            .select(tournaments_byes::user_id)
+            .get_results(conn)
+            .await?)
+    }
+}
@@ -1,16 +1,21 @@
 use crate::{
     db_error::DbError,
-    schema::{tournament_series, users},
+    schema::{
+        tournament_series::{self, nanoid as nanoid_field},
+        tournaments,
+        users,
+    },
     DbConn,
 };
 use chrono::prelude::*;
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel_async::{AsyncConnection, RunQueryDsl};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
+use shared_types::{SeriesScoring, TournamentSeriesId};
 use uuid::Uuid;
 
-use super::{tournament_series_organizer::TournamentSeriesOrganizer, user::User};
+use super::{tournament_series_organizer::TournamentSeriesOrganizer, user::User, Tournament};
 
 #[derive(Insertable, Debug)]
 #[diesel(table_name = tournament_series)]
@@ -46,19 +51,95 @@ pub struct TournamentSeries {
     pub description: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub placement_points: Vec<Option<i32>>,
+    pub best_of: Option<i32>,
 }
 
 impl TournamentSeries {
     pub async fn create(
+        user_id: Uuid,
         new_tournament_series: &NewTournamentSeries,
         conn: &mut DbConn<'_>,
     ) -> Result<TournamentSeries, DbError> {
-        Ok(diesel::insert_into(tournament_series::table)
-            .values(new_tournament_series)
+        conn.transaction::<_, DbError, _>(async move |tc| {
+            let series: TournamentSeries = diesel::insert_into(tournament_series::table)
+                .values(new_tournament_series)
+                .get_result(tc)
+                .await?;
+            TournamentSeriesOrganizer::new(series.id, user_id)
+                .insert(tc)
+                .await?;
+            Ok(series)
+        })
+        .await
+    }
+
+    pub async fn find(id: Uuid, conn: &mut DbConn<'_>) -> Result<Self, DbError> {
+        Ok(tournament_series::table.find(id).first(conn).await?)
+    }
+
+    pub async fn find_by_series_id(
+        series_id: &TournamentSeriesId,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        let TournamentSeriesId(id) = series_id;
+        Ok(tournament_series::table
+            .filter(nanoid_field.eq(id))
+            .first(conn)
+            .await?)
+    }
+
+    pub async fn get_all(conn: &mut DbConn<'_>) -> Result<Vec<Self>, DbError> {
+        Ok(tournament_series::table
+            .order(tournament_series::created_at.desc())
+            .get_results(conn)
+            .await?)
+    }
+
+    pub fn scoring(&self) -> SeriesScoring {
+        SeriesScoring {
+            placement_points: self.placement_points.iter().flatten().copied().collect(),
+            best_of: self.best_of,
+        }
+    }
+
+    pub async fn update_scoring(
+        &self,
+        user_id: &Uuid,
+        scoring: &SeriesScoring,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Self, DbError> {
+        self.ensure_user_is_organizer_or_admin(user_id, conn)
+            .await?;
+        scoring
+            .validate()
+            .map_err(|info| DbError::InvalidTournamentDetails { info })?;
+        Ok(diesel::update(tournament_series::table.find(self.id))
+            .set((
+                tournament_series::placement_points.eq(scoring
+                    .placement_points
+                    .iter()
+                    .map(|points| Some(*points))
+                    .collect::<Vec<_>>()),
+                tournament_series::best_of.eq(scoring.best_of),
+                tournament_series::updated_at.eq(Utc::now()),
+            ))
             .get_result(conn)
             .await?)
     }
 
+    pub async fn ensure_user_is_organizer_or_admin(
+        &self,
+        user_id: &Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        let organizers = self.organizers(conn).await?;
+        if organizers.iter().any(|o| o.id == *user_id) || User::is_admin(user_id, conn).await? {
+            return Ok(());
+        }
+        Err(DbError::Unauthorized)
+    }
+
     pub async fn organizers(&self, conn: &mut DbConn<'_>) -> Result<Vec<User>, DbError> {
         Ok(TournamentSeriesOrganizer::belonging_to(self)
             .inner_join(users::table)
@@ -66,4 +147,15 @@ impl TournamentSeries {
             .get_results(conn)
             .await?)
     }
+
+    pub async fn tournaments(&self, conn: &mut DbConn<'_>) -> Result<Vec<Tournament>, DbError> {
+        Ok(tournaments::table
+            .filter(tournaments::series.eq(self.id))
+            .order((
+                tournaments::started_at.asc().nulls_last(),
+                tournaments::created_at.asc(),
+            ))
+            .get_results(conn)
+            .await?)
+    }
 }
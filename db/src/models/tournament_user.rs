@@ -4,6 +4,7 @@ use crate::{
     schema::tournaments_users::{self, dsl::tournaments_users as tournament_user_table},
     DbConn,
 };
+use chrono::{DateTime, Utc};
 use diesel::{prelude::*, Identifiable, Insertable, Queryable, Selectable};
 use diesel_async::RunQueryDsl;
 use uuid::Uuid;
@@ -16,6 +17,7 @@ use uuid::Uuid;
 pub struct TournamentUser {
     pub tournament_id: Uuid,
     pub user_id: Uuid,
+    pub withdrawn_at: Option<DateTime<Utc>>,
 }
 
 impl TournamentUser {
@@ -23,6 +25,7 @@ impl TournamentUser {
         Self {
             tournament_id,
             user_id,
+            withdrawn_at: None,
         }
     }
 
@@ -0,0 +1,64 @@
+use crate::{
+    db_error::DbError,
+    models::{tournament::Tournament, user::User},
+    schema::{tournaments_waitlist, users},
+    DbConn,
+};
+use chrono::{DateTime, Utc};
+use diesel::{prelude::*, Identifiable, Insertable, Queryable, Selectable};
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+#[derive(Insertable, Identifiable, Selectable, Queryable, Associations, Debug, Clone)]
+#[diesel(belongs_to(User, foreign_key = user_id))]
+#[diesel(belongs_to(Tournament))]
+#[diesel(table_name = tournaments_waitlist)]
+#[diesel(primary_key(tournament_id, user_id))]
+pub struct TournamentWaitlist {
+    pub tournament_id: Uuid,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TournamentWaitlist {
+    pub fn new(tournament_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            tournament_id,
+            user_id,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub async fn insert(&self, conn: &mut DbConn<'_>) -> Result<(), DbError> {
+        diesel::insert_into(tournaments_waitlist::table)
+            .values(self)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete(
+        tournament_id: Uuid,
+        user_id: Uuid,
+        conn: &mut DbConn<'_>,
+    ) -> Result<(), DbError> {
+        diesel::delete(tournaments_waitlist::table.find((tournament_id, user_id)))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Users waiting for a seat, first come first served.
+    pub async fn users(
+        tournament: &Tournament,
+        conn: &mut DbConn<'_>,
+    ) -> Result<Vec<User>, DbError> {
+        Ok(Self::belonging_to(tournament)
+            .inner_join(users::table)
+            .order(tournaments_waitlist::created_at.asc())
+            .select(User::as_select())
+            .get_results(conn)
+            .await?)
+    }
+}
@@ -3,13 +3,16 @@ use crate::{
     db_error::DbError,
     models::{Challenge, Game, GameUser, NewRating, NotificationPreferences, Schedule},
     schema::{
+        availability_windows,
         challenges,
         games::{self, current_player_id, finished, game_status, tournament_id},
         ratings::{self, rating},
         tournaments,
+        tournaments_byes,
         tournaments_invitations,
         tournaments_organizers,
         tournaments_users,
+        tournaments_waitlist,
         users::{
             self,
             dsl::{
@@ -45,16 +48,18 @@ use diesel::{
 };
 use diesel_async::{AsyncConnection, RunQueryDsl};
 use hive_lib::GameControl;
+use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use shared_types::{GameId, GameSpeed, Takeback, TournamentId, TournamentStatus};
+use shared_types::{GameId, GameSpeed, Takeback, TournamentId, TournamentStatus, VariantClass};
 use uuid::Uuid;
 
 const MAX_USERNAME_LENGTH: usize = 20;
 const MIN_USERNAME_LENGTH: usize = 2;
 const VALID_USERNAME_CHARS: &str = "-_";
 const DELETED_USERNAME_PREFIX: &str = "deleted_user_";
+const SWISS_BYE_USERNAME: &str = "SwissByePlayer";
 
 lazy_static! {
     static ref EMAIL_RE: Regex = Regex::new(r"^[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}$").unwrap();
@@ -206,12 +211,17 @@ impl User {
             .values(new_user)
             .get_result(conn)
             .await?;
-        for game_speed in GameSpeed::all_rated().into_iter() {
-            diesel::insert_into(ratings::table)
-                .values(NewRating::for_uuid(&user.id, game_speed))
-                .execute(conn)
-                .await?;
-        }
+        let new_ratings: Vec<NewRating> = GameSpeed::all_rated()
+            .into_iter()
+            .cartesian_product(VariantClass::all())
+            .map(|(game_speed, variant_class)| {
+                NewRating::for_uuid(&user.id, game_speed, variant_class)
+            })
+            .collect();
+        diesel::insert_into(ratings::table)
+            .values(&new_ratings)
+            .execute(conn)
+            .await?;
         NotificationPreferences::create_for_user(user.id, conn).await?;
         Ok(user)
     }
@@ -296,6 +306,20 @@ impl User {
             .await?)
     }
 
+    /// The placeholder account Swiss tournaments pair against for byes.
+    pub async fn bye_player(conn: &mut DbConn<'_>) -> Result<User, DbError> {
+        Self::find_by_username(SWISS_BYE_USERNAME, conn).await
+    }
+
+    /// The bye player's id, `None` on installs that don't have one.
+    pub async fn bye_player_id(conn: &mut DbConn<'_>) -> Result<Option<Uuid>, DbError> {
+        match Self::bye_player(conn).await {
+            Ok(user) => Ok(Some(user.id)),
+            Err(DbError::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Resolves a direct-message route, including soft-deleted accounts whose
     /// tombstone username is still present in the messages catalog.
     pub async fn find_dm_route_user_by_username(
@@ -427,6 +451,22 @@ impl User {
             .execute(tc)
             .await?;
 
+            diesel::delete(
+                tournaments_waitlist::table.filter(tournaments_waitlist::user_id.eq(user_id)),
+            )
+            .execute(tc)
+            .await?;
+
+            diesel::delete(tournaments_byes::table.filter(tournaments_byes::user_id.eq(user_id)))
+                .execute(tc)
+                .await?;
+
+            diesel::delete(
+                availability_windows::table.filter(availability_windows::user_id.eq(user_id)),
+            )
+            .execute(tc)
+            .await?;
+
             let not_started_organized_tournaments: Vec<(Uuid, String)> =
                 tournaments_organizers::table
                     .inner_join(tournaments::table)
@@ -556,17 +596,20 @@ impl User {
 
     pub async fn get_top_users(
         game_speed: &GameSpeed,
+        variant_class: VariantClass,
         maybe_user: Option<Uuid>,
         limit: i64,
         conn: &mut DbConn<'_>,
     ) -> Result<Vec<(User, Rating, i64)>, DbError> {
         let speed = game_speed.to_string();
+        let variant = variant_class.to_string();
         let mut top = Self::assign_ranks(
             users::table
                 .inner_join(ratings::table)
                 .filter(users::deleted.eq(false))
-                .filter(ratings::deviation.le(shared_types::RANKABLE_DEVIATION))
+                .filter(ratings::deviation.lt(shared_types::RANKABLE_DEVIATION))
                 .filter(ratings::speed.eq(speed.clone()))
+                .filter(ratings::variant.eq(variant.clone()))
                 .select((User::as_select(), Rating::as_select()))
                 .order_by(rating.desc())
                 .limit(limit)
@@ -585,8 +628,9 @@ impl User {
         let viewer_row = match users::table
             .inner_join(ratings::table)
             .filter(users::deleted.eq(false))
-            .filter(ratings::deviation.le(shared_types::RANKABLE_DEVIATION))
+            .filter(ratings::deviation.lt(shared_types::RANKABLE_DEVIATION))
             .filter(ratings::speed.eq(speed))
+            .filter(ratings::variant.eq(variant))
             .select((
                 User::as_select(),
                 Rating::as_select(),
@@ -1,5 +1,16 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    availability_windows (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        weekday -> Int4,
+        start_minute -> Int4,
+        end_minute -> Int4,
+        time_zone -> Text,
+    }
+}
+
 diesel::table! {
     challenges (id) {
         id -> Uuid,
@@ -17,6 +28,7 @@ diesel::table! {
         time_increment -> Nullable<Int4>,
         band_upper -> Nullable<Int4>,
         band_lower -> Nullable<Int4>,
+        clock_kind -> Text,
     }
 }
 
@@ -51,6 +63,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    conditional_moves (id) {
+        id -> Uuid,
+        game_id -> Uuid,
+        user_id -> Uuid,
+        history -> Text,
+        moves -> Text,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     email_queue (id) {
         id -> Uuid,
@@ -95,6 +118,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    game_analyses (game_id) {
+        game_id -> Uuid,
+        requested_by -> Uuid,
+        status -> Text,
+        engine -> Text,
+        error -> Nullable<Text>,
+        requested_at -> Timestamptz,
+        finished_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    game_analysis_plies (game_id, ply) {
+        game_id -> Uuid,
+        ply -> Int4,
+        eval -> Int4,
+        best_move -> Text,
+        best_eval -> Int4,
+        judgement -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    game_annotations (game_id, user_id, ply) {
+        game_id -> Uuid,
+        user_id -> Uuid,
+        ply -> Int4,
+        nag -> Nullable<Text>,
+        comment -> Text,
+    }
+}
+
+diesel::table! {
+    game_annotators (game_id, user_id) {
+        game_id -> Uuid,
+        user_id -> Uuid,
+        public -> Bool,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     game_hashes (game_id, turn) {
         hash -> Int8,
@@ -109,6 +174,7 @@ diesel::table! {
         move_piece -> Text,
         move_position -> Text,
         game_length -> Int4,
+        game_rating -> Nullable<Float8>,
     }
 }
 
@@ -147,6 +213,7 @@ diesel::table! {
         game_start -> Text,
         move_times -> Array<Nullable<Int8>>,
         timeout_at -> Nullable<Timestamptz>,
+        clock_kind -> Text,
     }
 }
 
@@ -176,6 +243,67 @@ diesel::table! {
         schedules -> Array<Nullable<Text>>,
         general_chat -> Array<Nullable<Text>>,
         dms -> Array<Nullable<Text>>,
+        following -> Array<Nullable<Text>>,
+    }
+}
+
+diesel::table! {
+    position_stats (hash, next_hash, game_type, speed, rated, rating_band, played_month, long_game) {
+        hash -> Int8,
+        next_hash -> Int8,
+        game_type -> Text,
+        speed -> Text,
+        rated -> Bool,
+        rating_band -> Int4,
+        played_month -> Date,
+        long_game -> Bool,
+        piece -> Text,
+        position -> Text,
+        total -> Int8,
+        white_wins -> Int8,
+        black_wins -> Int8,
+        draws -> Int8,
+        rating_sum -> Float8,
+        rating_count -> Int8,
+    }
+}
+
+diesel::table! {
+    puzzle_attempts (id) {
+        id -> Uuid,
+        puzzle_id -> Uuid,
+        user_id -> Uuid,
+        solved -> Bool,
+        rated -> Bool,
+        rating_change -> Nullable<Float8>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    puzzle_mined_games (game_id) {
+        game_id -> Uuid,
+        mined_at -> Timestamptz,
+        failure -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    puzzles (id) {
+        id -> Uuid,
+        nanoid -> Text,
+        game_type -> Text,
+        history -> Text,
+        solution -> Text,
+        themes -> Array<Nullable<Text>>,
+        rating -> Float8,
+        deviation -> Float8,
+        volatility -> Float8,
+        attempts -> Int8,
+        solves -> Int8,
+        source_game_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        status -> Text,
     }
 }
 
@@ -209,6 +337,23 @@ diesel::table! {
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
         speed -> Text,
+        variant -> Text,
+    }
+}
+
+diesel::table! {
+    rematches (game_id) {
+        game_id -> Uuid,
+        offered_by -> Uuid,
+        new_game_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    schedule_assistant_proposals (game_id) {
+        game_id -> Uuid,
+        proposed_at -> Timestamptz,
     }
 }
 
@@ -222,6 +367,33 @@ diesel::table! {
         start_t -> Timestamptz,
         agreed -> Bool,
         notified -> Bool,
+        reminded -> Bool,
+    }
+}
+
+diesel::table! {
+    studies (id) {
+        id -> Uuid,
+        nanoid -> Text,
+        owner_id -> Uuid,
+        title -> Text,
+        description -> Text,
+        visibility -> Text,
+        forked_from -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    study_chapters (id) {
+        id -> Uuid,
+        study_id -> Uuid,
+        position -> Int4,
+        title -> Text,
+        game_type -> Text,
+        tree -> Text,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -233,6 +405,8 @@ diesel::table! {
         description -> Text,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        placement_points -> Array<Nullable<Int4>>,
+        best_of -> Nullable<Int4>,
     }
 }
 
@@ -270,6 +444,20 @@ diesel::table! {
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
         series -> Nullable<Uuid>,
+        min_rated_games -> Int4,
+        min_account_age_days -> Int4,
+        allow_bots -> Bool,
+        paused_at -> Nullable<Timestamptz>,
+        clock_kind -> Text,
+    }
+}
+
+diesel::table! {
+    tournaments_byes (tournament_id, user_id, round) {
+        tournament_id -> Uuid,
+        user_id -> Uuid,
+        round -> Int4,
+        created_at -> Timestamptz,
     }
 }
 
@@ -292,6 +480,15 @@ diesel::table! {
     tournaments_users (tournament_id, user_id) {
         tournament_id -> Uuid,
         user_id -> Uuid,
+        withdrawn_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    tournaments_waitlist (tournament_id, user_id) {
+        tournament_id -> Uuid,
+        user_id -> Uuid,
+        created_at -> Timestamptz,
     }
 }
 
@@ -302,6 +499,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_follows (follower_id, followed_id) {
+        follower_id -> Uuid,
+        followed_id -> Uuid,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     user_tournament_chat_mutes (user_id, tournament_id) {
         user_id -> Uuid,
@@ -329,22 +534,38 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(availability_windows -> users (user_id));
 diesel::joinable!(chat_channels -> games (game_id));
 diesel::joinable!(chat_channels -> tournaments (tournament_id));
 diesel::joinable!(chat_messages -> chat_channels (channel_id));
 diesel::joinable!(chat_messages -> users (sender_id));
 diesel::joinable!(chat_read_receipts -> chat_channels (channel_id));
 diesel::joinable!(chat_read_receipts -> users (user_id));
+diesel::joinable!(conditional_moves -> games (game_id));
+diesel::joinable!(conditional_moves -> users (user_id));
 diesel::joinable!(email_queue -> users (user_id));
 diesel::joinable!(email_tokens -> users (user_id));
+diesel::joinable!(game_analyses -> games (game_id));
+diesel::joinable!(game_analyses -> users (requested_by));
+diesel::joinable!(game_analysis_plies -> game_analyses (game_id));
+diesel::joinable!(game_annotators -> games (game_id));
+diesel::joinable!(game_annotators -> users (user_id));
 diesel::joinable!(game_hashes -> games (game_id));
 diesel::joinable!(games_users -> games (game_id));
 diesel::joinable!(games_users -> users (user_id));
 diesel::joinable!(notification_preferences -> users (user_id));
 diesel::joinable!(push_devices -> users (user_id));
+diesel::joinable!(puzzle_attempts -> puzzles (puzzle_id));
+diesel::joinable!(puzzle_attempts -> users (user_id));
+diesel::joinable!(puzzle_mined_games -> games (game_id));
+diesel::joinable!(puzzles -> games (source_game_id));
 diesel::joinable!(ratings -> users (user_uid));
+diesel::joinable!(rematches -> users (offered_by));
+diesel::joinable!(schedule_assistant_proposals -> games (game_id));
 diesel::joinable!(schedules -> games (game_id));
 diesel::joinable!(schedules -> tournaments (tournament_id));
+diesel::joinable!(studies -> users (owner_id));
+diesel::joinable!(study_chapters -> studies (study_id));
 diesel::joinable!(tournament_series_organizers -> tournament_series (tournament_series_id));
 diesel::joinable!(tournament_series_organizers -> users (organizer_id));
 diesel::joinable!(tournaments -> tournament_series (series));
@@ -352,35 +573,56 @@ diesel::joinable!(tournaments_invitations -> tournaments (tournament_id));
 diesel::joinable!(tournaments_invitations -> users (invitee_id));
 diesel::joinable!(tournaments_organizers -> tournaments (tournament_id));
 diesel::joinable!(tournaments_organizers -> users (organizer_id));
+diesel::joinable!(tournaments_byes -> tournaments (tournament_id));
+diesel::joinable!(tournaments_byes -> users (user_id));
 diesel::joinable!(tournaments_users -> tournaments (tournament_id));
 diesel::joinable!(tournaments_users -> users (user_id));
+diesel::joinable!(tournaments_waitlist -> tournaments (tournament_id));
+diesel::joinable!(tournaments_waitlist -> users (user_id));
 diesel::joinable!(user_tournament_chat_mutes -> tournaments (tournament_id));
 diesel::joinable!(user_tournament_chat_mutes -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    availability_windows,
     challenges,
     chat_channels,
     chat_messages,
     chat_read_receipts,
+    conditional_moves,
     email_queue,
     email_request_log,
     email_state,
     email_tokens,
+    game_analyses,
+    game_analysis_plies,
+    game_annotations,
+    game_annotators,
     game_hashes,
     games,
     games_users,
     home_banner,
     notification_preferences,
+    position_stats,
     push_devices,
+    puzzle_attempts,
+    puzzle_mined_games,
+    puzzles,
     ratings,
+    rematches,
+    schedule_assistant_proposals,
     schedules,
+    studies,
+    study_chapters,
     tournament_series,
     tournament_series_organizers,
     tournaments,
+    tournaments_byes,
     tournaments_invitations,
     tournaments_organizers,
     tournaments_users,
+    tournaments_waitlist,
     user_blocks,
+    user_follows,
     user_tournament_chat_mutes,
     users,
 );
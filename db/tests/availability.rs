@@ -0,0 +1,115 @@
+mod common;
+
+use common::fixtures::{self, create_user};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{Availability, NewGame, Schedule},
+    schema::tournaments,
+};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use hive_lib::GameStatus;
+use shared_types::{AvailabilityWindow, TournamentStatus};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replacing_windows_overwrites_previous_ones() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let user = create_user("availability_user", &mut conn).await;
+
+    let evening = window(1, 18 * 60, 22 * 60);
+    let morning = window(5, 8 * 60, 12 * 60);
+    let stored =
+        Availability::replace_for_user(user.id, vec![morning.clone(), evening.clone()], &mut conn)
+            .await
+            .expect("store windows");
+    assert_eq!(stored, vec![evening, morning.clone()]);
+
+    let stored = Availability::replace_for_user(user.id, vec![morning.clone()], &mut conn)
+        .await
+        .expect("replace windows");
+    assert_eq!(stored, vec![morning.clone()]);
+
+    assert!(matches!(
+        Availability::replace_for_user(user.id, vec![window(1, 22 * 60, 18 * 60)], &mut conn).await,
+        Err(DbError::InvalidInput { .. })
+    ));
+    let offset = AvailabilityWindow {
+        time_zone: String::from("UTC+2"),
+        ..window(1, 18 * 60, 22 * 60)
+    };
+    assert!(matches!(
+        Availability::replace_for_user(user.id, vec![offset], &mut conn).await,
+        Err(DbError::InvalidInput { .. })
+    ));
+    assert_eq!(
+        Availability::for_user(user.id, &mut conn)
+            .await
+            .expect("load windows"),
+        vec![morning]
+    );
+
+    Availability::replace_for_user(user.id, Vec::new(), &mut conn)
+        .await
+        .expect("clear windows");
+    assert!(Availability::for_user(user.id, &mut conn)
+        .await
+        .expect("load windows")
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn assistant_proposals_are_remembered() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let organizer = create_user("assistant_organizer", &mut conn).await;
+    let white = create_user("assistant_white", &mut conn).await;
+    let black = create_user("assistant_black", &mut conn).await;
+    for user in [&white, &black] {
+        Availability::replace_for_user(user.id, vec![window(1, 18 * 60, 22 * 60)], &mut conn)
+            .await
+            .expect("store windows");
+    }
+    let tournament = fixtures::insert_tournament(
+        organizer.id,
+        fixtures::new_tournament("Assistant", 2),
+        &mut conn,
+    )
+    .await;
+    diesel::update(tournaments::table.find(tournament.id))
+        .set(tournaments::status.eq(TournamentStatus::InProgress.to_string()))
+        .execute(&mut conn)
+        .await
+        .expect("start tournament");
+    let new_game = NewGame {
+        tournament_id: Some(tournament.id),
+        game_status: GameStatus::NotStarted.to_string(),
+        ..fixtures::new_game(white.id, black.id)
+    };
+    let game = fixtures::insert_game(new_game, &mut conn).await;
+
+    let awaiting = Schedule::games_awaiting_proposal(&mut conn)
+        .await
+        .expect("load games");
+    assert_eq!(
+        awaiting.iter().map(|game| game.id).collect::<Vec<_>>(),
+        vec![game.id]
+    );
+    Schedule::mark_auto_proposed(game.id, &mut conn)
+        .await
+        .expect("record proposal");
+    assert!(Schedule::games_awaiting_proposal(&mut conn)
+        .await
+        .expect("load games")
+        .is_empty());
+}
+
+fn window(weekday: i32, start_minute: i32, end_minute: i32) -> AvailabilityWindow {
+    AvailabilityWindow {
+        weekday,
+        start_minute,
+        end_minute,
+        time_zone: String::from("Europe/Berlin"),
+    }
+}
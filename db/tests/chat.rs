@@ -27,6 +27,7 @@ use diesel::prelude::*;
 use diesel_async::{AsyncConnection, RunQueryDsl};
 use hive_lib::{GameStatus, GameType};
 use shared_types::{
+    ClockKind,
     Conclusion,
     ConversationKey,
     GameId,
@@ -859,6 +860,10 @@ async fn create_tournament(organizer_id: Uuid, name: &str, conn: &mut DbConn<'_>
             created_at: Utc::now(),
             updated_at: Utc::now(),
             series: None,
+            min_rated_games: 0,
+            min_account_age_days: 0,
+            allow_bots: true,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
@@ -903,6 +908,7 @@ async fn create_game(white_id: Uuid, black_id: Uuid, conn: &mut DbConn<'_>) -> G
             game_start: GameStart::Moves.to_string(),
             move_times: Vec::new(),
             timeout_at: None,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
@@ -0,0 +1,165 @@
+//! Users, games and tournaments for the integration tests. Each test binary only uses some
+//! of them, and overrides fields of the `New*` rows with struct update syntax.
+#![allow(dead_code)]
+
+use chrono::{Duration, Utc};
+use db_lib::{
+    models::{Game, NewGame, NewTournament, NewUser, Tournament, User},
+    DbConn,
+};
+use hive_lib::{GameResult, GameStatus, GameType};
+use shared_types::{
+    ClockKind,
+    Conclusion,
+    GameSpeed,
+    GameStart,
+    ScoringMode,
+    StartMode,
+    Tiebreaker,
+    TimeMode,
+    TournamentGameResult,
+    TournamentMode,
+    TournamentStatus,
+};
+use uuid::Uuid;
+
+pub async fn create_user(username: &str, conn: &mut DbConn<'_>) -> User {
+    let new_user = NewUser::new(username, "password", &format!("{username}@example.com"))
+        .expect("create new user fixture");
+    User::create(new_user, conn).await.expect("insert user")
+}
+
+/// An untimed, casual Base game two moves in, white to play.
+pub fn new_game(white_id: Uuid, black_id: Uuid) -> NewGame {
+    let now = Utc::now();
+    NewGame {
+        nanoid: nanoid::nanoid!(12),
+        current_player_id: white_id,
+        black_id,
+        finished: false,
+        game_status: GameStatus::InProgress.to_string(),
+        game_type: GameType::Base.to_string(),
+        history: String::from("wS1 ;bA1 wS1-;"),
+        game_control_history: String::new(),
+        rated: false,
+        tournament_queen_rule: true,
+        turn: 2,
+        white_id,
+        white_rating: None,
+        black_rating: None,
+        white_rating_change: None,
+        black_rating_change: None,
+        created_at: now,
+        updated_at: now,
+        time_mode: TimeMode::Untimed.to_string(),
+        time_base: None,
+        time_increment: None,
+        last_interaction: Some(now),
+        black_time_left: None,
+        white_time_left: None,
+        speed: GameSpeed::Untimed.to_string(),
+        hashes: Vec::new(),
+        conclusion: Conclusion::Unknown.to_string(),
+        tournament_id: None,
+        tournament_game_result: TournamentGameResult::Unknown.to_string(),
+        game_start: GameStart::Moves.to_string(),
+        move_times: Vec::new(),
+        timeout_at: None,
+        clock_kind: ClockKind::Fischer.to_string(),
+    }
+}
+
+/// `new_game`, over as a draw.
+pub fn drawn_game(white_id: Uuid, black_id: Uuid) -> NewGame {
+    NewGame {
+        finished: true,
+        game_status: GameStatus::Finished(GameResult::Draw).to_string(),
+        conclusion: Conclusion::Draw.to_string(),
+        ..new_game(white_id, black_id)
+    }
+}
+
+/// A rated one minute MLP game with both queens out.
+pub fn bullet_game(white_id: Uuid, black_id: Uuid) -> NewGame {
+    let now = Utc::now();
+    let time_left = Some(60 * 1_000_000_000_i64);
+    NewGame {
+        game_type: GameType::MLP.to_string(),
+        history: String::from("wQ -;bQ /wQ;"),
+        rated: true,
+        tournament_queen_rule: false,
+        time_mode: TimeMode::RealTime.to_string(),
+        time_base: Some(60),
+        time_increment: Some(0),
+        black_time_left: time_left,
+        white_time_left: time_left,
+        speed: GameSpeed::Bullet.to_string(),
+        timeout_at: Some(now + Duration::seconds(60)),
+        ..new_game(white_id, black_id)
+    }
+}
+
+/// `new_game` as a casual correspondence game with two days per move, after
+/// both players placed an ant.
+pub fn correspondence_game(white_id: Uuid, black_id: Uuid) -> NewGame {
+    let per_move = 2 * 86400;
+    let time_left = Some(per_move as i64 * 1_000_000_000);
+    NewGame {
+        history: String::from("wA1 ;bA1 wA1-;"),
+        time_mode: TimeMode::Correspondence.to_string(),
+        time_increment: Some(per_move),
+        black_time_left: time_left,
+        white_time_left: time_left,
+        speed: GameSpeed::Correspondence.to_string(),
+        timeout_at: Some(Utc::now() + Duration::seconds(per_move as i64)),
+        ..new_game(white_id, black_id)
+    }
+}
+
+pub async fn insert_game(new_game: NewGame, conn: &mut DbConn<'_>) -> Game {
+    Game::create(new_game, conn).await.expect("insert game")
+}
+
+/// A ten minute double round robin that an organizer starts by hand.
+pub fn new_tournament(name: &str, seats: i32) -> NewTournament {
+    NewTournament {
+        nanoid: nanoid::nanoid!(11),
+        name: name.to_string(),
+        description: String::new(),
+        scoring: ScoringMode::Game.to_string(),
+        tiebreaker: vec![Some(Tiebreaker::RawPoints.to_string())],
+        seats,
+        min_seats: 1,
+        rounds: 1,
+        invite_only: false,
+        mode: TournamentMode::DoubleRoundRobin.to_string(),
+        time_mode: TimeMode::RealTime.to_string(),
+        time_base: Some(600),
+        time_increment: Some(5),
+        band_upper: None,
+        band_lower: None,
+        start_mode: StartMode::Manual.to_string(),
+        starts_at: None,
+        ends_at: None,
+        started_at: None,
+        round_duration: None,
+        status: TournamentStatus::NotStarted.to_string(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        series: None,
+        min_rated_games: 0,
+        min_account_age_days: 0,
+        allow_bots: true,
+        clock_kind: ClockKind::Fischer.to_string(),
+    }
+}
+
+pub async fn insert_tournament(
+    organizer_id: Uuid,
+    new_tournament: NewTournament,
+    conn: &mut DbConn<'_>,
+) -> Tournament {
+    Tournament::create(organizer_id, &new_tournament, conn)
+        .await
+        .expect("insert tournament")
+}
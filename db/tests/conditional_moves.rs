@@ -0,0 +1,110 @@
+mod common;
+
+use common::fixtures::{correspondence_game, create_user, insert_game};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{ConditionalMove, Game, TimeSpent},
+    DbConn,
+};
+use diesel_async::AsyncConnection;
+use hive_lib::State;
+
+fn line(moves: &[(&str, &str)]) -> Vec<(String, String)> {
+    moves
+        .iter()
+        .map(|(piece, position)| (piece.to_string(), position.to_string()))
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn conditional_reply_follows_the_matching_line() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("conditional_white", &mut conn).await;
+    let black = create_user("conditional_black", &mut conn).await;
+    let game = insert_game(correspondence_game(white.id, black.id), &mut conn).await;
+
+    let queen = line(&[("wQ", "-wA1"), ("bQ", "bA1-")]);
+    let grasshopper = line(&[("wG1", "-wA1"), ("bG1", "bA1-")]);
+    let to_move = ConditionalMove::replace(&game, white.id, vec![queen.clone()], &mut conn).await;
+    assert!(matches!(to_move, Err(DbError::InvalidAction { .. })));
+    let conflicting = line(&[("wQ", "-wA1"), ("bG1", "bA1-")]);
+    let conflict =
+        ConditionalMove::replace(&game, black.id, vec![queen.clone(), conflicting], &mut conn)
+            .await;
+    assert!(matches!(conflict, Err(DbError::InvalidInput { .. })));
+    let unanswered =
+        ConditionalMove::replace(&game, black.id, vec![line(&[("wQ", "-wA1")])], &mut conn).await;
+    assert!(matches!(unanswered, Err(DbError::InvalidInput { .. })));
+
+    let stored = ConditionalMove::replace(&game, black.id, vec![queen, grasshopper], &mut conn)
+        .await
+        .expect("store conditional moves");
+    assert_eq!(stored.len(), 2);
+
+    let (_, reply) = play(&game, "wQ", "-wA1", &mut conn).await;
+    assert_eq!(reply.map(|(piece, _)| piece), Some(String::from("bQ")));
+    assert!(ConditionalMove::for_player(game.id, black.id, &mut conn)
+        .await
+        .expect("load lines")
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn other_moves_invalidate_conditional_lines() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("unconditional_white", &mut conn).await;
+    let black = create_user("unconditional_black", &mut conn).await;
+    let game = insert_game(correspondence_game(white.id, black.id), &mut conn).await;
+
+    let long = line(&[
+        ("wQ", "-wA1"),
+        ("bQ", "bA1-"),
+        ("wG1", "-wQ"),
+        ("bG1", "bQ-"),
+    ]);
+    ConditionalMove::replace(&game, black.id, vec![long], &mut conn)
+        .await
+        .expect("store conditional moves");
+
+    let (game, reply) = play(&game, "wQ", "-wA1", &mut conn).await;
+    assert_eq!(reply.map(|(piece, _)| piece), Some(String::from("bQ")));
+    let remaining = ConditionalMove::for_player(game.id, black.id, &mut conn)
+        .await
+        .expect("load lines");
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].lines().len(), 2);
+
+    // Black answers by hand instead, so the rest of the line no longer applies
+    let (game, _) = play(&game, "bG1", "bA1-", &mut conn).await;
+    let (_, reply) = play(&game, "wG1", "-wQ", &mut conn).await;
+    assert_eq!(reply, None);
+    assert!(ConditionalMove::for_player(game.id, black.id, &mut conn)
+        .await
+        .expect("load lines")
+        .is_empty());
+}
+
+async fn play(
+    game: &Game,
+    piece: &str,
+    position: &str,
+    conn: &mut DbConn<'_>,
+) -> (Game, Option<(String, String)>) {
+    let mut state = State::new_from_str(&game.history, &game.game_type).expect("load state");
+    state
+        .play_turn_from_history(piece, position)
+        .expect("legal move");
+    let game = game.clone();
+    conn.transaction::<_, DbError, _>(async move |tc| {
+        let played = game
+            .update_gamestate(&state, TimeSpent::Measured(0.0), tc)
+            .await?;
+        let reply = ConditionalMove::take_reply(&played, tc).await?;
+        Ok((played, reply))
+    })
+    .await
+    .expect("play move")
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::fixtures::create_user;
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    helpers::{block_user, follow_user, followed_users, followers_of, is_following, unfollow_user},
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn follows_are_one_way_and_listed_by_name() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let fan = create_user("follow_fan", &mut conn).await;
+    let alice = create_user("follow_alice", &mut conn).await;
+    let bob = create_user("follow_bob", &mut conn).await;
+
+    let own = follow_user(&mut conn, fan.id, fan.id).await;
+    assert!(matches!(own, Err(DbError::InvalidInput { .. })));
+
+    follow_user(&mut conn, fan.id, bob.id)
+        .await
+        .expect("follow");
+    follow_user(&mut conn, fan.id, alice.id)
+        .await
+        .expect("follow");
+    // Following twice is a no-op
+    follow_user(&mut conn, fan.id, alice.id)
+        .await
+        .expect("follow");
+    let followed = followed_users(&mut conn, fan.id).await.expect("list");
+    let names = followed
+        .iter()
+        .map(|u| u.username.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["follow_alice", "follow_bob"]);
+    assert!(!is_following(&mut conn, alice.id, fan.id)
+        .await
+        .expect("check"));
+
+    let mut followers = followers_of(&mut conn, &[alice.id, bob.id])
+        .await
+        .expect("followers");
+    followers.sort_by_key(|(_, followed)| *followed == bob.id);
+    assert_eq!(followers, [(fan.id, alice.id), (fan.id, bob.id)]);
+
+    unfollow_user(&mut conn, fan.id, bob.id)
+        .await
+        .expect("unfollow");
+    assert!(!is_following(&mut conn, fan.id, bob.id)
+        .await
+        .expect("check"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_ends_follows_both_ways() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let a = create_user("block_follow_a", &mut conn).await;
+    let b = create_user("block_follow_b", &mut conn).await;
+
+    follow_user(&mut conn, a.id, b.id).await.expect("follow");
+    follow_user(&mut conn, b.id, a.id).await.expect("follow");
+    block_user(&mut conn, b.id, a.id).await.expect("block");
+
+    assert!(!is_following(&mut conn, a.id, b.id).await.expect("check"));
+    assert!(!is_following(&mut conn, b.id, a.id).await.expect("check"));
+    let again = follow_user(&mut conn, a.id, b.id).await;
+    assert!(matches!(again, Err(DbError::InvalidInput { .. })));
+}
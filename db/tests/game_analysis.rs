@@ -0,0 +1,124 @@
+mod common;
+
+use common::fixtures::{create_user, drawn_game, insert_game};
+use db_lib::{
+    get_conn,
+    models::{AnalysisPly, GameAnalysis},
+};
+use shared_types::{AnalysisStatus, MoveJudgement, PlyAnalysis};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn requests_are_worked_off_in_order() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("analysis_white", &mut conn).await;
+    let black = create_user("analysis_black", &mut conn).await;
+    let first = insert_game(drawn_game(white.id, black.id), &mut conn).await;
+    let second = insert_game(drawn_game(white.id, black.id), &mut conn).await;
+
+    let queued = GameAnalysis::request(first.id, white.id, &mut conn)
+        .await
+        .expect("request analysis");
+    assert_eq!(queued.status(), AnalysisStatus::Pending);
+    GameAnalysis::request(second.id, black.id, &mut conn)
+        .await
+        .expect("request analysis");
+    let again = GameAnalysis::request(first.id, black.id, &mut conn)
+        .await
+        .expect("request again");
+    assert_eq!(again.requested_by, white.id);
+
+    let claimed = GameAnalysis::claim_next(&mut conn)
+        .await
+        .expect("claim")
+        .expect("a pending request");
+    assert_eq!(claimed.game_id, first.id);
+    assert_eq!(claimed.status(), AnalysisStatus::Running);
+
+    let plies = vec![
+        AnalysisPly {
+            game_id: first.id,
+            ply: 0,
+            eval: 0,
+            best_move: String::from("wG1"),
+            best_eval: 0,
+            judgement: None,
+        },
+        AnalysisPly {
+            game_id: first.id,
+            ply: 1,
+            eval: 400,
+            best_move: String::from("bA1 wQ-"),
+            best_eval: 0,
+            judgement: Some(MoveJudgement::Blunder.to_string()),
+        },
+    ];
+    let done = GameAnalysis::finish(first.id, "id test 1.0", plies, &mut conn)
+        .await
+        .expect("finish");
+    assert_eq!(done.status(), AnalysisStatus::Done);
+    assert!(done.finished_at.is_some());
+    let stored: Vec<PlyAnalysis> = done
+        .plies(&mut conn)
+        .await
+        .expect("load plies")
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored[1].judgement, Some(MoveJudgement::Blunder));
+
+    let claimed = GameAnalysis::claim_next(&mut conn)
+        .await
+        .expect("claim")
+        .expect("a pending request");
+    assert_eq!(claimed.game_id, second.id);
+    assert!(GameAnalysis::claim_next(&mut conn)
+        .await
+        .expect("claim")
+        .is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failed_and_interrupted_requests_go_back_in_the_queue() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("requeue_white", &mut conn).await;
+    let black = create_user("requeue_black", &mut conn).await;
+    let game = insert_game(drawn_game(white.id, black.id), &mut conn).await;
+
+    GameAnalysis::request(game.id, white.id, &mut conn)
+        .await
+        .expect("request analysis");
+    GameAnalysis::claim_next(&mut conn)
+        .await
+        .expect("claim")
+        .expect("a pending request");
+    assert_eq!(
+        GameAnalysis::requeue_running(&mut conn)
+            .await
+            .expect("requeue"),
+        1
+    );
+    GameAnalysis::claim_next(&mut conn)
+        .await
+        .expect("claim")
+        .expect("requeued request");
+
+    GameAnalysis::fail(game.id, "engine crashed", &mut conn)
+        .await
+        .expect("fail");
+    let failed = GameAnalysis::find(game.id, &mut conn)
+        .await
+        .expect("find")
+        .expect("analysis exists");
+    assert_eq!(failed.status(), AnalysisStatus::Failed);
+    assert_eq!(failed.error.as_deref(), Some("engine crashed"));
+
+    let retried = GameAnalysis::request(game.id, black.id, &mut conn)
+        .await
+        .expect("request again");
+    assert_eq!(retried.status(), AnalysisStatus::Pending);
+    assert_eq!(retried.requested_by, black.id);
+    assert_eq!(retried.error, None);
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::fixtures::{create_user, drawn_game, insert_game, new_game};
+use db_lib::{db_error::DbError, get_conn, models::GameAnnotation};
+use shared_types::{MoveAnnotation, MoveNag};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn players_annotate_and_choose_who_reads_it() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("annotate_white", &mut conn).await;
+    let black = create_user("annotate_black", &mut conn).await;
+    let game = insert_game(drawn_game(white.id, black.id), &mut conn).await;
+
+    GameAnnotation::save(
+        &game,
+        white.id,
+        annotation(1, Some(MoveNag::Blunder), "Lost the queen"),
+        &mut conn,
+    )
+    .await
+    .expect("annotate");
+    GameAnnotation::save(
+        &game,
+        white.id,
+        annotation(0, None, "Standard start"),
+        &mut conn,
+    )
+    .await
+    .expect("annotate");
+    GameAnnotation::save(
+        &game,
+        white.id,
+        annotation(1, Some(MoveNag::Mistake), ""),
+        &mut conn,
+    )
+    .await
+    .expect("overwrite");
+
+    let own = GameAnnotation::for_game(game.id, Some(white.id), &mut conn)
+        .await
+        .expect("load own");
+    assert_eq!(own.len(), 1);
+    assert!(!own[0].public);
+    assert_eq!(own[0].moves.len(), 2);
+    assert_eq!(own[0].at(1).and_then(|mv| mv.nag), Some(MoveNag::Mistake));
+    assert!(GameAnnotation::for_game(game.id, Some(black.id), &mut conn)
+        .await
+        .expect("load as opponent")
+        .is_empty());
+
+    GameAnnotation::set_public(&game, white.id, true, &mut conn)
+        .await
+        .expect("share");
+    let shared = GameAnnotation::for_game(game.id, None, &mut conn)
+        .await
+        .expect("load as guest");
+    assert_eq!(shared.len(), 1);
+    assert_eq!(shared[0].author, "annotate_white");
+
+    GameAnnotation::save(&game, white.id, annotation(0, None, " "), &mut conn)
+        .await
+        .expect("clear");
+    let cleared = GameAnnotation::for_game(game.id, None, &mut conn)
+        .await
+        .expect("load after clearing");
+    assert_eq!(cleared[0].moves.len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_players_annotate_finished_games() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("annotate_host", &mut conn).await;
+    let black = create_user("annotate_guest", &mut conn).await;
+    let outsider = create_user("annotate_kibitz", &mut conn).await;
+    let finished = insert_game(drawn_game(white.id, black.id), &mut conn).await;
+    let ongoing = insert_game(new_game(white.id, black.id), &mut conn).await;
+
+    assert!(matches!(
+        GameAnnotation::save(
+            &finished,
+            outsider.id,
+            annotation(0, Some(MoveNag::Good), ""),
+            &mut conn
+        )
+        .await,
+        Err(DbError::Unauthorized)
+    ));
+    assert!(matches!(
+        GameAnnotation::save(
+            &ongoing,
+            black.id,
+            annotation(0, Some(MoveNag::Good), ""),
+            &mut conn
+        )
+        .await,
+        Err(DbError::InvalidAction { .. })
+    ));
+    assert!(matches!(
+        GameAnnotation::save(
+            &finished,
+            black.id,
+            annotation(2, Some(MoveNag::Good), ""),
+            &mut conn
+        )
+        .await,
+        Err(DbError::InvalidInput { .. })
+    ));
+}
+
+fn annotation(ply: i32, nag: Option<MoveNag>, comment: &str) -> MoveAnnotation {
+    MoveAnnotation {
+        ply,
+        nag,
+        comment: comment.to_string(),
+    }
+}
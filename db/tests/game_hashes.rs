@@ -1,6 +1,6 @@
 mod common;
 
-use chrono::Utc;
+use chrono::{TimeZone, Utc};
 use db_lib::{
     get_conn,
     models::{Game, GameFinishContext, GameHash, NewGame, NewUser, User},
@@ -9,7 +9,7 @@ use db_lib::{
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
 use hive_lib::{GameStatus, GameType, State};
-use shared_types::{Conclusion, GameSpeed, GameStart, TimeMode, TournamentGameResult};
+use shared_types::{ClockKind, Conclusion, GameSpeed, GameStart, TimeMode, TournamentGameResult};
 
 fn test_ctx(white_rating: Option<f64>, black_rating: Option<f64>) -> GameFinishContext {
     GameFinishContext {
@@ -501,6 +501,43 @@ async fn backfill_replays_history_and_populates_both_tables() {
     assert_eq!(count, state.hashes.len() as i64);
 }
 
+// ---------------------------------------------------------------------------
+// Integration test — opening statistics
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread")]
+async fn opening_stats_group_games_by_book_opening_and_colour() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.unwrap();
+
+    let history = "wS1 ;bG1 wS1-;wQ -wS1;bQ bG1-;";
+    let (game, white, black) = setup_game_with_history("os1", "os2", history, &mut conn).await;
+    let state = State::new_from_str(history, &GameType::MLP.to_string()).unwrap();
+    diesel::update(games::table.find(game.id))
+        .set((
+            games::finished.eq(true),
+            games::game_status.eq("Finished(1-0)"),
+            games::hashes.eq(state
+                .hashes
+                .iter()
+                .map(|h| Some(*h as i64))
+                .collect::<Vec<_>>()),
+        ))
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let stats = Game::opening_stats(white.id, &mut conn).await.unwrap();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].code, "SG1");
+    assert_eq!(stats[0].color, hive_lib::Color::White);
+    assert_eq!((stats[0].games, stats[0].wins, stats[0].losses), (1, 1, 0));
+
+    let stats = Game::opening_stats(black.id, &mut conn).await.unwrap();
+    assert_eq!(stats[0].color, hive_lib::Color::Black);
+    assert_eq!((stats[0].wins, stats[0].losses), (0, 1));
+}
+
 // ---------------------------------------------------------------------------
 // Integration tests — next_moves / aggregate_one (opening explorer)
 // ---------------------------------------------------------------------------
@@ -511,6 +548,11 @@ fn nm_filters() -> shared_types::ExplorerFilters {
         speeds: Vec::new(),
         rated: None,
         min_game_length: None,
+        player: None,
+        rating_min: None,
+        rating_max: None,
+        date_start: None,
+        date_end: None,
     }
 }
 
@@ -523,6 +565,17 @@ async fn seed_game(
     conn: &mut db_lib::DbConn<'_>,
 ) -> Game {
     let (game, _, _) = setup_game_named(w, b, conn).await;
+    seed_hashes(&game, hashes, moves, result, conn).await;
+    game
+}
+
+async fn seed_hashes(
+    game: &Game,
+    hashes: &[u64],
+    moves: &[(&str, &str)],
+    result: &str,
+    conn: &mut db_lib::DbConn<'_>,
+) {
     let owned: Vec<(String, String)> = moves
         .iter()
         .map(|(p, q)| (p.to_string(), q.to_string()))
@@ -531,7 +584,20 @@ async fn seed_game(
     ctx.result = result.to_string();
     let entries = GameHash::from_engine_hashes(game.id, hashes, &owned, &ctx);
     GameHash::insert_batch(&entries, conn).await.unwrap();
-    game
+}
+
+async fn seed_rated_game(
+    w: &str,
+    b: &str,
+    hashes: &[u64],
+    ctx: &GameFinishContext,
+    conn: &mut db_lib::DbConn<'_>,
+) {
+    let (game, _, _) = setup_game_named(w, b, conn).await;
+    let entries = GameHash::from_engine_hashes(game.id, hashes, &[], ctx);
+    GameHash::insert_batch(&entries, conn).await.unwrap();
+    // A second insert must not count the game again.
+    GameHash::insert_batch(&entries, conn).await.unwrap();
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -656,6 +722,40 @@ async fn aggregate_one_counts_results_for_a_position() {
     assert_eq!(stats.draws, 1);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn aggregate_many_keeps_the_order_of_the_hashes() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.unwrap();
+
+    seed_game(
+        "am1",
+        "am2",
+        &[100, 555],
+        &[("wA1", ""), ("bA1", "wA1-")],
+        "Finished(1-0)",
+        &mut conn,
+    )
+    .await;
+    seed_game(
+        "am3",
+        "am4",
+        &[100, 556],
+        &[("wA1", ""), ("bA1", "-wA1")],
+        "Finished(0-1)",
+        &mut conn,
+    )
+    .await;
+
+    let stats = GameHash::aggregate_many(&[556, 999, 100], &nm_filters(), &mut conn)
+        .await
+        .unwrap();
+    let summary = stats
+        .iter()
+        .map(|stats| (stats.next_hash, stats.total, stats.black_wins))
+        .collect::<Vec<_>>();
+    assert_eq!(summary, vec![(556, 1, 1), (999, 0, 0), (100, 2, 1)]);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn next_moves_filters_out_short_games() {
     let db = common::db::test_db().await;
@@ -680,6 +780,160 @@ async fn next_moves_filters_out_short_games() {
     assert!(moves.is_empty());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn next_moves_counts_only_the_players_games() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.unwrap();
+
+    // "prep" wins as white in the first game and loses as black in the second.
+    let (first, prep, _) = setup_game_named("prep", "pf1", &mut conn).await;
+    let opponent = create_test_user("pf2", &mut conn).await;
+    let second = create_game_between(&opponent, &prep, "", &mut conn).await;
+    for game in [&first, &second] {
+        seed_hashes(
+            game,
+            &[100, 200],
+            &[("wA1", ""), ("bA1", "wA1-")],
+            "Finished(1-0)",
+            &mut conn,
+        )
+        .await;
+    }
+    seed_game(
+        "pf3",
+        "pf4",
+        &[100, 200],
+        &[("wA1", ""), ("bA1", "wA1-")],
+        "Finished(0-1)",
+        &mut conn,
+    )
+    .await;
+
+    let mut filters = nm_filters();
+    filters.player = Some(shared_types::ExplorerPlayer {
+        username: "Prep".to_string(),
+        color: None,
+    });
+    let moves = GameHash::next_moves(100, &filters, None, &mut conn)
+        .await
+        .unwrap();
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].total, 2);
+    assert_eq!((moves[0].player_wins, moves[0].player_losses), (1, 1));
+
+    filters.player = Some(shared_types::ExplorerPlayer {
+        username: "prep".to_string(),
+        color: Some(hive_lib::Color::Black),
+    });
+    let stats = GameHash::aggregate_one(100, &filters, &mut conn)
+        .await
+        .unwrap();
+    assert_eq!(stats.total, 1);
+    assert_eq!((stats.player_wins, stats.player_losses), (0, 1));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rating_and_date_filters_agree_with_and_without_aggregates() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.unwrap();
+
+    let mut march = test_ctx(Some(1500.0), Some(1600.0));
+    march.result = "Finished(1-0)".to_string();
+    march.played_at = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+    seed_rated_game("rd1", "rd2", &[100, 200], &march, &mut conn).await;
+    let mut may = test_ctx(Some(1800.0), Some(1800.0));
+    may.result = "Finished(0-1)".to_string();
+    may.played_at = Utc.with_ymd_and_hms(2026, 5, 2, 12, 0, 0).unwrap();
+    seed_rated_game("rd3", "rd4", &[100, 300], &may, &mut conn).await;
+
+    // min_game_length = 1 excludes nothing here but forces the game_hashes queries.
+    let raw = |mut filters: shared_types::ExplorerFilters| {
+        filters.min_game_length = Some(1);
+        filters
+    };
+    let next_hashes = |moves: Vec<shared_types::ExplorerMove>| {
+        moves
+            .iter()
+            .map(|m| (m.next_hash, m.total))
+            .collect::<Vec<_>>()
+    };
+
+    let all = nm_filters();
+    assert!(all.aggregated());
+    let stats = GameHash::aggregate_one(100, &all, &mut conn).await.unwrap();
+    assert_eq!((stats.total, stats.white_wins, stats.black_wins), (2, 1, 1));
+
+    let mut band = nm_filters();
+    band.rating_min = Some(1500);
+    band.rating_max = Some(1600);
+    for filters in [band.clone(), raw(band)] {
+        let moves = GameHash::next_moves(100, &filters, None, &mut conn)
+            .await
+            .unwrap();
+        assert_eq!(next_hashes(moves), vec![(200, 1)]);
+    }
+
+    let mut since = nm_filters();
+    since.date_start = Some(Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap());
+    for filters in [since.clone(), raw(since)] {
+        let moves = GameHash::next_moves(100, &filters, None, &mut conn)
+            .await
+            .unwrap();
+        assert_eq!(next_hashes(moves), vec![(300, 1)]);
+        let stats = GameHash::aggregate_one(100, &filters, &mut conn)
+            .await
+            .unwrap();
+        assert_eq!((stats.total, stats.black_wins), (1, 1));
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn counts_game_follows_the_explorer_filters() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.unwrap();
+
+    let game = seed_game(
+        "cg1",
+        "cg2",
+        &[100, 200],
+        &[("wA1", ""), ("bA1", "wA1-")],
+        "Finished(1-0)",
+        &mut conn,
+    )
+    .await;
+    let (unhashed, _, _) = setup_game_named("cg3", "cg4", &mut conn).await;
+
+    let filters = nm_filters();
+    assert!(GameHash::counts_game(game.id, &filters, &mut conn)
+        .await
+        .unwrap());
+    assert!(!GameHash::counts_game(unhashed.id, &filters, &mut conn)
+        .await
+        .unwrap());
+
+    let mut long_games = nm_filters();
+    long_games.min_game_length = Some(8);
+    assert!(!GameHash::counts_game(game.id, &long_games, &mut conn)
+        .await
+        .unwrap());
+
+    let mut players = nm_filters();
+    players.player = Some(shared_types::ExplorerPlayer {
+        username: "cg2".to_string(),
+        color: Some(hive_lib::Color::Black),
+    });
+    assert!(GameHash::counts_game(game.id, &players, &mut conn)
+        .await
+        .unwrap());
+    players.player = Some(shared_types::ExplorerPlayer {
+        username: "cg2".to_string(),
+        color: Some(hive_lib::Color::White),
+    });
+    assert!(!GameHash::counts_game(game.id, &players, &mut conn)
+        .await
+        .unwrap());
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -698,19 +952,27 @@ async fn setup_game_with_history(
     history: &str,
     conn: &mut db_lib::DbConn<'_>,
 ) -> (Game, User, User) {
-    let white = User::create(
-        NewUser::new(white_name, "password", &format!("{white_name}@test.com")).unwrap(),
-        conn,
-    )
-    .await
-    .unwrap();
-    let black = User::create(
-        NewUser::new(black_name, "password", &format!("{black_name}@test.com")).unwrap(),
+    let white = create_test_user(white_name, conn).await;
+    let black = create_test_user(black_name, conn).await;
+    let game = create_game_between(&white, &black, history, conn).await;
+    (game, white, black)
+}
+
+async fn create_test_user(username: &str, conn: &mut db_lib::DbConn<'_>) -> User {
+    User::create(
+        NewUser::new(username, "password", &format!("{username}@test.com")).unwrap(),
         conn,
     )
     .await
-    .unwrap();
+    .unwrap()
+}
 
+async fn create_game_between(
+    white: &User,
+    black: &User,
+    history: &str,
+    conn: &mut db_lib::DbConn<'_>,
+) -> Game {
     let now = Utc::now();
     let time_left = Some(60_000_000_000_i64);
     let turn = history.split_terminator(';').count() as i32;
@@ -720,7 +982,7 @@ async fn setup_game_with_history(
         None
     };
 
-    let game = Game::create(
+    Game::create(
         NewGame {
             nanoid: nanoid::nanoid!(12),
             current_player_id: if turn % 2 == 0 { white.id } else { black.id },
@@ -758,11 +1020,10 @@ async fn setup_game_with_history(
             game_start: GameStart::Moves.to_string(),
             move_times: Vec::new(),
             timeout_at,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
     .await
-    .unwrap();
-
-    (game, white, black)
+    .unwrap()
 }
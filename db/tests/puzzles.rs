@@ -0,0 +1,194 @@
+mod common;
+
+use common::fixtures::{bullet_game, create_user, insert_game};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{Game, NewGame, NewPuzzle, Puzzle, PuzzleStep},
+};
+use hive_lib::GameType;
+use shared_types::{Conclusion, PuzzleStatus, VariantClass};
+
+fn line(moves: &[(&str, &str)]) -> Vec<(String, String)> {
+    moves
+        .iter()
+        .map(|(piece, position)| (piece.to_string(), position.to_string()))
+        .collect()
+}
+
+fn new_puzzle(rating: f64) -> NewPuzzle {
+    NewPuzzle::new(
+        GameType::Base,
+        "wA1 ;bA1 wA1-;",
+        &line(&[("wQ", "-wA1"), ("bQ", "bA1-"), ("wG1", "-wQ")]),
+        vec![String::from("opening")],
+        rating,
+        None,
+    )
+    .expect("valid puzzle")
+}
+
+fn later_puzzle(rating: f64) -> NewPuzzle {
+    NewPuzzle::new(
+        GameType::Base,
+        "wA1 ;bA1 wA1-;wQ -wA1;bQ bA1-;",
+        &line(&[("wG1", "-wQ")]),
+        vec![String::from("opening")],
+        rating,
+        None,
+    )
+    .expect("valid puzzle")
+}
+
+#[test]
+fn solutions_end_on_the_solvers_move() {
+    let even = NewPuzzle::new(
+        GameType::Base,
+        "wA1 ;bA1 wA1-;",
+        &line(&[("wQ", "-wA1"), ("bQ", "bA1-")]),
+        Vec::new(),
+        1500.0,
+        None,
+    );
+    assert!(matches!(even, Err(DbError::InvalidInput { .. })));
+    let illegal = NewPuzzle::new(
+        GameType::Base,
+        "wA1 ;bA1 wA1-;",
+        &line(&[("bQ", "bA1-")]),
+        Vec::new(),
+        1500.0,
+        None,
+    );
+    assert!(matches!(illegal, Err(DbError::InvalidInput { .. })));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn solver_moves_are_checked_against_the_solution() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let puzzle = Puzzle::create(new_puzzle(1500.0), &mut conn)
+        .await
+        .expect("insert puzzle");
+
+    let step = puzzle.check_line(&line(&[("wQ", "-wA1")])).expect("check");
+    assert_eq!(
+        step,
+        PuzzleStep::Continue {
+            reply: (String::from("bQ"), String::from("bA1-"))
+        }
+    );
+    let wrong = puzzle.check_line(&line(&[("wG1", "-wA1")])).expect("check");
+    assert_eq!(wrong, PuzzleStep::Failed);
+    let solved = puzzle
+        .check_line(&line(&[("wQ", "-wA1"), ("bQ", "bA1-"), ("wG1", "-wQ")]))
+        .expect("check");
+    assert_eq!(solved, PuzzleStep::Solved);
+
+    let other_reply = puzzle.check_line(&line(&[("wQ", "-wA1"), ("bG1", "bA1-"), ("wG1", "-wQ")]));
+    assert!(matches!(other_reply, Err(DbError::InvalidInput { .. })));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_the_first_attempt_is_rated() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let solver = create_user("puzzle_solver", &mut conn).await;
+    let easy = Puzzle::create(new_puzzle(1400.0), &mut conn)
+        .await
+        .expect("insert puzzle");
+    let hard = Puzzle::create(later_puzzle(1700.0), &mut conn)
+        .await
+        .expect("insert puzzle");
+
+    let next = Puzzle::next_for(1500.0, VariantClass::Base, Some(solver.id), &[], &mut conn)
+        .await
+        .expect("next puzzle");
+    assert_eq!(next.map(|puzzle| puzzle.id), Some(easy.id));
+
+    let first = easy
+        .record_attempt(solver.id, true, &mut conn)
+        .await
+        .expect("record attempt");
+    assert!(first.rated);
+    assert!(first.rating_change.is_some_and(|change| change > 0.0));
+    let again = easy
+        .record_attempt(solver.id, false, &mut conn)
+        .await
+        .expect("record attempt");
+    assert!(!again.rated);
+    assert_eq!(again.rating_change, None);
+
+    let next = Puzzle::next_for(1500.0, VariantClass::Base, Some(solver.id), &[], &mut conn)
+        .await
+        .expect("next puzzle");
+    assert_eq!(next.map(|puzzle| puzzle.id), Some(hard.id));
+    let easy = Puzzle::find_by_nanoid(&easy.nanoid, &mut conn)
+        .await
+        .expect("reload puzzle");
+    assert_eq!((easy.attempts, easy.solves), (1, 1));
+    assert!(easy.rating < 1400.0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn candidates_wait_for_approval() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let candidate = Puzzle::create_if_new(new_puzzle(1500.0).candidate(), &mut conn)
+        .await
+        .expect("insert candidate")
+        .expect("new position");
+    assert_eq!(candidate.status(), PuzzleStatus::Candidate);
+    let duplicate = Puzzle::create_if_new(new_puzzle(1500.0).candidate(), &mut conn)
+        .await
+        .expect("insert duplicate");
+    assert!(duplicate.is_none());
+
+    let next = Puzzle::next_for(1500.0, VariantClass::Base, None, &[], &mut conn)
+        .await
+        .expect("next puzzle");
+    assert!(next.is_none());
+    let candidates = Puzzle::candidates(10, &mut conn).await.expect("candidates");
+    assert_eq!(candidates.len(), 1);
+
+    Puzzle::set_status(&candidate.nanoid, PuzzleStatus::Approved, &mut conn)
+        .await
+        .expect("approve");
+    let next = Puzzle::next_for(1500.0, VariantClass::Base, None, &[], &mut conn)
+        .await
+        .expect("next puzzle");
+    assert_eq!(next.map(|puzzle| puzzle.id), Some(candidate.id));
+    assert!(Puzzle::candidates(10, &mut conn)
+        .await
+        .expect("candidates")
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn games_the_miner_gave_up_on_are_not_mined_again() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("miner_white", &mut conn).await;
+    let black = create_user("miner_black", &mut conn).await;
+    let resigned = || NewGame {
+        finished: true,
+        conclusion: Conclusion::Resigned.to_string(),
+        ..bullet_game(white.id, black.id)
+    };
+    let failed = insert_game(resigned(), &mut conn).await;
+    let mined = insert_game(resigned(), &mut conn).await;
+    let pending = insert_game(resigned(), &mut conn).await;
+
+    Puzzle::mark_game_mined(failed.id, Some(String::from("search failed")), &mut conn)
+        .await
+        .expect("mark failed");
+    Puzzle::mark_game_mined(mined.id, None, &mut conn)
+        .await
+        .expect("mark mined");
+    let unmined = Game::find_unmined_for_puzzles(None, 10, &mut conn)
+        .await
+        .expect("unmined games");
+    assert_eq!(
+        unmined.iter().map(|game| game.id).collect::<Vec<_>>(),
+        vec![pending.id]
+    );
+}
@@ -14,7 +14,15 @@ use diesel::{
 };
 use diesel_async::{AsyncConnection, RunQueryDsl};
 use hive_lib::{Color, GameControl, GameStatus, GameType};
-use shared_types::{Conclusion, GameSpeed, GameStart, TimeMode, TournamentGameResult};
+use shared_types::{
+    ClockKind,
+    Conclusion,
+    GameSpeed,
+    GameStart,
+    TimeMode,
+    TournamentGameResult,
+    VariantClass,
+};
 use std::time::Duration;
 use tokio::{sync::oneshot, task::JoinHandle};
 
@@ -34,7 +42,7 @@ async fn game_finalization_reads_rating_rows_after_waiting_for_locks() {
     let mut setup_conn = get_conn(&db.pool).await.expect("get setup connection");
     let white = create_user("alice", &mut setup_conn).await;
     let black = create_user("bob", &mut setup_conn).await;
-    let game = create_bullet_game(white.id, black.id, &mut setup_conn).await;
+    let game = create_bullet_game(white.id, black.id, GameType::MLP, &mut setup_conn).await;
 
     let (release_rating_lock_tx, rating_lock_task) =
         hold_bullet_rating_lock(db.pool.clone(), white.id).await;
@@ -63,7 +71,7 @@ async fn stale_game_finalization_does_not_apply_ratings_twice() {
     let mut setup_conn = get_conn(&db.pool).await.expect("get setup connection");
     let white = create_user("charlie", &mut setup_conn).await;
     let black = create_user("diana", &mut setup_conn).await;
-    let stale_game = create_bullet_game(white.id, black.id, &mut setup_conn).await;
+    let stale_game = create_bullet_game(white.id, black.id, GameType::MLP, &mut setup_conn).await;
 
     let (release_rating_lock_tx, rating_lock_task) =
         hold_bullet_rating_lock(db.pool.clone(), white.id).await;
@@ -105,12 +113,22 @@ async fn stale_game_finalization_does_not_apply_ratings_twice() {
         DbError::GameIsOver
     ));
 
-    let white_rating = Rating::for_uuid(&white.id, &GameSpeed::Bullet, &mut setup_conn)
-        .await
-        .expect("load white rating");
-    let black_rating = Rating::for_uuid(&black.id, &GameSpeed::Bullet, &mut setup_conn)
-        .await
-        .expect("load black rating");
+    let white_rating = Rating::for_uuid(
+        &white.id,
+        &GameSpeed::Bullet,
+        VariantClass::Expansions,
+        &mut setup_conn,
+    )
+    .await
+    .expect("load white rating");
+    let black_rating = Rating::for_uuid(
+        &black.id,
+        &GameSpeed::Bullet,
+        VariantClass::Expansions,
+        &mut setup_conn,
+    )
+    .await
+    .expect("load black rating");
 
     assert_eq!(white_rating.played, 1);
     assert_eq!(white_rating.won, 1);
@@ -120,6 +138,36 @@ async fn stale_game_finalization_does_not_apply_ratings_twice() {
     assert_eq!(black_rating.lost, 1);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn base_games_only_move_base_ratings() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("base_white", &mut conn).await;
+    let black = create_user("base_black", &mut conn).await;
+    let game = create_bullet_game(white.id, black.id, GameType::Base, &mut conn).await;
+
+    spawn_resign_finalizer(db.pool.clone(), "base_variant_finalizer", game)
+        .await
+        .expect("join finalizer task")
+        .expect("finalize game");
+
+    let base = Rating::for_uuid(&white.id, &GameSpeed::Bullet, VariantClass::Base, &mut conn)
+        .await
+        .expect("load base rating");
+    let expansions = Rating::for_uuid(
+        &white.id,
+        &GameSpeed::Bullet,
+        VariantClass::Expansions,
+        &mut conn,
+    )
+    .await
+    .expect("load expansions rating");
+    assert_eq!((base.played, base.won), (1, 1));
+    assert!(base.rating > 1500.0);
+    assert_eq!(expansions.played, 0);
+    assert_eq!(expansions.rating, 1500.0);
+}
+
 async fn hold_bullet_rating_lock(
     pool: db_lib::DbPool,
     user_id: uuid::Uuid,
@@ -182,6 +230,7 @@ async fn create_user(username: &str, conn: &mut db_lib::DbConn<'_>) -> User {
 async fn create_bullet_game(
     white_id: uuid::Uuid,
     black_id: uuid::Uuid,
+    game_type: GameType,
     conn: &mut db_lib::DbConn<'_>,
 ) -> Game {
     let now = Utc::now();
@@ -194,7 +243,7 @@ async fn create_bullet_game(
             black_id,
             finished: false,
             game_status: GameStatus::InProgress.to_string(),
-            game_type: GameType::MLP.to_string(),
+            game_type: game_type.to_string(),
             history: String::from("wQ -;bQ /wQ;"),
             game_control_history: String::new(),
             rated: true,
@@ -221,6 +270,7 @@ async fn create_bullet_game(
             game_start: GameStart::Moves.to_string(),
             move_times: Vec::new(),
             timeout_at,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
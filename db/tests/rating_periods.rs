@@ -0,0 +1,87 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::fixtures::create_user;
+use db_lib::{
+    get_conn,
+    models::{Rating, User},
+    schema::ratings,
+};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use shared_types::{GameSpeed, VariantClass};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inactive_ratings_lose_certainty_and_leave_the_leaderboard() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let user = create_user("inactive_player", &mut conn).await;
+    let now = Utc::now();
+    diesel::update(
+        ratings::table
+            .filter(ratings::user_uid.eq(user.id))
+            .filter(ratings::speed.eq(GameSpeed::Blitz.to_string()))
+            .filter(ratings::variant.eq(VariantClass::Expansions.to_string())),
+    )
+    .set((
+        ratings::played.eq(20),
+        ratings::deviation.eq(94.0),
+        ratings::volatility.eq(0.09),
+        ratings::updated_at.eq(now - Duration::days(15)),
+    ))
+    .execute(&mut conn)
+    .await
+    .expect("age rating");
+    let top = User::get_top_users(
+        &GameSpeed::Blitz,
+        VariantClass::Expansions,
+        None,
+        10,
+        &mut conn,
+    )
+    .await
+    .expect("load top users");
+    assert!(top.iter().any(|(top_user, _, _)| top_user.id == user.id));
+
+    let inflated = Rating::inflate_inactive(Duration::weeks(1), now, &mut conn)
+        .await
+        .expect("inflate");
+    assert_eq!(inflated, 1);
+    let rating = Rating::for_uuid(
+        &user.id,
+        &GameSpeed::Blitz,
+        VariantClass::Expansions,
+        &mut conn,
+    )
+    .await
+    .expect("load rating");
+    // Two whole weeks: sqrt((94 / 173.7178)^2 + 2 * 0.09^2) * 173.7178
+    assert!((rating.deviation - 96.565).abs() < 0.01);
+    assert!(
+        (rating.updated_at - (now - Duration::days(1)))
+            .num_milliseconds()
+            .abs()
+            < 1
+    );
+    let untouched = Rating::for_uuid(&user.id, &GameSpeed::Blitz, VariantClass::Base, &mut conn)
+        .await
+        .expect("load unplayed rating");
+    assert_eq!(untouched.deviation, 500.0);
+
+    assert_eq!(
+        Rating::inflate_inactive(Duration::weeks(1), now, &mut conn)
+            .await
+            .expect("inflate again"),
+        0
+    );
+    let top = User::get_top_users(
+        &GameSpeed::Blitz,
+        VariantClass::Expansions,
+        None,
+        10,
+        &mut conn,
+    )
+    .await
+    .expect("load top users");
+    assert!(top.iter().all(|(top_user, _, _)| top_user.id != user.id));
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::fixtures::{bullet_game, create_user, insert_game};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{NewGame, Rating, RatingReplay, RATING_PERIOD},
+    schema::{games, ratings},
+    DbConn,
+};
+use diesel::prelude::*;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use hive_lib::{Color, GameControl};
+use shared_types::{GameSpeed, VariantClass};
+use uuid::Uuid;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replay_restores_tampered_ratings_only_when_applied() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("replay_white", &mut conn).await;
+    let black = create_user("replay_black", &mut conn).await;
+    let game = insert_game(bullet_game(white.id, black.id), &mut conn).await;
+    let game = conn
+        .transaction::<_, DbError, _>(async move |tc| {
+            game.resign(&GameControl::Resign(Color::Black), tc).await
+        })
+        .await
+        .expect("finish game");
+    let expected = white_rating(white.id, &mut conn).await;
+
+    let untouched = RatingReplay::compute(Utc::now(), &mut conn)
+        .await
+        .expect("compute replay");
+    assert_eq!(untouched.games_replayed, 1);
+    assert!(untouched.ratings.is_empty());
+    assert!(untouched.games.is_empty());
+
+    diesel::update(ratings::table.find(expected.id))
+        .set(ratings::rating.eq(2000.0))
+        .execute(&mut conn)
+        .await
+        .expect("tamper rating");
+    diesel::update(games::table.find(game.id))
+        .set(games::white_rating_change.eq(Some(99.0)))
+        .execute(&mut conn)
+        .await
+        .expect("tamper game");
+
+    let dry_run = RatingReplay::compute(Utc::now(), &mut conn)
+        .await
+        .expect("compute replay");
+    assert_eq!(dry_run.ratings.len(), 1);
+    assert_eq!(dry_run.ratings[0].before.rating, 2000.0);
+    assert!((dry_run.ratings[0].after.rating - expected.rating).abs() < 1e-6);
+    assert_eq!(dry_run.games.len(), 1);
+    assert_eq!(
+        dry_run.games[0].after.white_rating_change,
+        game.white_rating_change
+    );
+    assert_eq!(white_rating(white.id, &mut conn).await.rating, 2000.0);
+
+    let applied = RatingReplay::apply(Utc::now(), &mut conn)
+        .await
+        .expect("apply replay");
+    assert_eq!(applied.ratings.len(), 1);
+    assert!((white_rating(white.id, &mut conn).await.rating - expected.rating).abs() < 1e-6);
+    let again = RatingReplay::compute(Utc::now(), &mut conn)
+        .await
+        .expect("compute replay");
+    assert!(again.ratings.is_empty());
+    assert!(again.games.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replay_counts_rating_periods_from_unrated_draws() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("period_white", &mut conn).await;
+    let black = create_user("period_black", &mut conn).await;
+    let rated = insert_game(bullet_game(white.id, black.id), &mut conn).await;
+    conn.transaction::<_, DbError, _>(async move |tc| {
+        rated.resign(&GameControl::Resign(Color::Black), tc).await
+    })
+    .await
+    .expect("finish rated game");
+    // The rated game ended a period and a half ago.
+    let ended = Utc::now() - RATING_PERIOD - RATING_PERIOD / 2;
+    diesel::update(games::table.filter(games::rated.eq(true)))
+        .set(games::updated_at.eq(ended))
+        .execute(&mut conn)
+        .await
+        .expect("backdate game");
+    diesel::update(ratings::table.filter(ratings::user_uid.eq_any([white.id, black.id])))
+        .set(ratings::updated_at.eq(ended))
+        .execute(&mut conn)
+        .await
+        .expect("backdate ratings");
+    Rating::inflate_inactive(RATING_PERIOD, Utc::now(), &mut conn)
+        .await
+        .expect("inflate inactive ratings");
+
+    let casual = insert_game(
+        NewGame {
+            rated: false,
+            ..bullet_game(white.id, black.id)
+        },
+        &mut conn,
+    )
+    .await;
+    conn.transaction::<_, DbError, _>(async move |tc| {
+        let offered = casual
+            .write_game_control(&GameControl::DrawOffer(Color::White), tc)
+            .await?;
+        offered
+            .accept_draw(&GameControl::DrawAccept(Color::Black), tc)
+            .await
+    })
+    .await
+    .expect("draw casual game");
+
+    // Less than a period after the draw, but more than one after the last
+    // rated game: nothing is due yet.
+    let replay = RatingReplay::compute(Utc::now() + Duration::days(5), &mut conn)
+        .await
+        .expect("compute replay");
+    assert_eq!(replay.games_replayed, 2);
+    assert!(replay.ratings.is_empty());
+    assert!(replay.games.is_empty());
+}
+
+async fn white_rating(user_id: Uuid, conn: &mut DbConn<'_>) -> Rating {
+    Rating::for_uuid(&user_id, &GameSpeed::Bullet, VariantClass::Expansions, conn)
+        .await
+        .expect("load rating")
+}
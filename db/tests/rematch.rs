@@ -0,0 +1,70 @@
+mod common;
+
+use common::fixtures::{bullet_game, create_user, insert_game};
+use db_lib::{db_error::DbError, get_conn, models::Rematch};
+use diesel_async::AsyncConnection;
+use hive_lib::{Color, GameControl};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accepted_rematch_swaps_colours_once() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let white = create_user("rematch_white", &mut conn).await;
+    let black = create_user("rematch_black", &mut conn).await;
+    let game = insert_game(bullet_game(white.id, black.id), &mut conn).await;
+
+    let unfinished = Rematch::offer(&game, white.id, &mut conn).await;
+    assert!(matches!(unfinished, Err(DbError::InvalidAction { .. })));
+
+    let game = conn
+        .transaction::<_, DbError, _>(async move |tc| {
+            game.resign(&GameControl::Resign(Color::Black), tc).await
+        })
+        .await
+        .expect("finish game");
+    Rematch::offer(&game, white.id, &mut conn)
+        .await
+        .expect("offer rematch");
+    assert_eq!(
+        Rematch::offered_to(black.id, &mut conn)
+            .await
+            .expect("load offers")
+            .len(),
+        1
+    );
+    assert!(Rematch::offered_to(white.id, &mut conn)
+        .await
+        .expect("load offers")
+        .is_empty());
+    let own_offer = Rematch::accept(&game, white.id, &mut conn).await;
+    assert!(matches!(own_offer, Err(DbError::NotFound { .. })));
+
+    let accepting = game.clone();
+    let (rematch, _) = conn
+        .transaction::<_, DbError, _>(async move |tc| {
+            Rematch::accept(&accepting, black.id, tc).await
+        })
+        .await
+        .expect("accept rematch");
+    assert_eq!(rematch.white_id, black.id);
+    assert_eq!(rematch.black_id, white.id);
+    assert_eq!(rematch.current_player_id, black.id);
+    assert_eq!(rematch.game_type, game.game_type);
+    assert_eq!(
+        (rematch.time_base, rematch.time_increment),
+        (game.time_base, game.time_increment)
+    );
+    assert_eq!(rematch.rated, game.rated);
+    assert_eq!(rematch.turn, 0);
+    assert!(!rematch.finished);
+
+    let stored = Rematch::for_game(game.id, &mut conn)
+        .await
+        .expect("load rematch")
+        .expect("rematch exists");
+    assert_eq!(stored.new_game_id, Some(rematch.id));
+    let twice = Rematch::accept(&game, black.id, &mut conn).await;
+    assert!(matches!(twice, Err(DbError::NotFound { .. })));
+    let declined = Rematch::decline(&game, black.id, &mut conn).await;
+    assert!(matches!(declined, Err(DbError::NotFound { .. })));
+}
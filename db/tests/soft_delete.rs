@@ -11,6 +11,7 @@ use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
 use hive_lib::{Color, GameResult, GameStatus, GameType};
 use shared_types::{
+    ClockKind,
     Conclusion,
     GameId,
     GameSpeed,
@@ -340,6 +341,7 @@ async fn create_abortable_game(
             game_start: GameStart::Moves.to_string(),
             move_times: Vec::new(),
             timeout_at: None,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
@@ -378,6 +380,10 @@ async fn create_double_swiss_tournament(
             created_at: Utc::now(),
             updated_at: Utc::now(),
             series: None,
+            min_rated_games: 0,
+            min_account_age_days: 0,
+            allow_bots: true,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
@@ -416,6 +422,10 @@ async fn create_realtime_tournament(
             created_at: Utc::now(),
             updated_at: Utc::now(),
             series: None,
+            min_rated_games: 0,
+            min_account_age_days: 0,
+            allow_bots: true,
+            clock_kind: ClockKind::Fischer.to_string(),
         },
         conn,
     )
@@ -0,0 +1,149 @@
+mod common;
+
+use common::fixtures::create_user;
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{NewStudyChapter, Study},
+};
+use hive_lib::GameType;
+use shared_types::StudyVisibility;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn chapters_keep_contiguous_positions() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let owner = create_user("study_owner", &mut conn).await;
+
+    let study = Study::create(
+        owner.id,
+        "Beetle endgames",
+        StudyVisibility::Unlisted,
+        chapter("Intro", "{}"),
+        &mut conn,
+    )
+    .await
+    .expect("create study");
+    let second = study
+        .save_chapter(owner.id, None, chapter("Second", "{}"), &mut conn)
+        .await
+        .expect("append chapter");
+    let third = study
+        .save_chapter(owner.id, None, chapter("Third", "{}"), &mut conn)
+        .await
+        .expect("append chapter");
+    assert_eq!((second, third), (1, 2));
+
+    let overwritten = study
+        .save_chapter(
+            owner.id,
+            Some(1),
+            chapter("Second, revised", "{\"a\":1}"),
+            &mut conn,
+        )
+        .await
+        .expect("overwrite chapter");
+    assert_eq!(overwritten, 1);
+
+    study
+        .delete_chapter(owner.id, 0, &mut conn)
+        .await
+        .expect("delete first chapter");
+    let chapters = study.chapters(&mut conn).await.expect("load chapters");
+    let titles: Vec<_> = chapters
+        .iter()
+        .map(|chapter| (chapter.position, chapter.title.as_str()))
+        .collect();
+    assert_eq!(titles, [(0, "Second, revised"), (1, "Third")]);
+    assert_eq!(chapters[0].tree, "{\"a\":1}");
+
+    study
+        .delete_chapter(owner.id, 1, &mut conn)
+        .await
+        .expect("delete last chapter");
+    assert!(matches!(
+        study.delete_chapter(owner.id, 0, &mut conn).await,
+        Err(DbError::InvalidAction { .. })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_the_owner_edits_and_forks_are_private_copies() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let owner = create_user("study_author", &mut conn).await;
+    let reader = create_user("study_reader", &mut conn).await;
+
+    let study = Study::create(
+        owner.id,
+        "Openings",
+        StudyVisibility::Public,
+        chapter("Pillbug lines", "{}"),
+        &mut conn,
+    )
+    .await
+    .expect("create study");
+    Study::create(
+        owner.id,
+        "Drafts",
+        StudyVisibility::Private,
+        chapter("Scratch", "{}"),
+        &mut conn,
+    )
+    .await
+    .expect("create private study");
+
+    assert!(matches!(
+        study
+            .save_chapter(reader.id, None, chapter("Mine", "{}"), &mut conn)
+            .await,
+        Err(DbError::Unauthorized)
+    ));
+    assert!(matches!(
+        study
+            .update_details(reader.id, "Taken", "", StudyVisibility::Private, &mut conn)
+            .await,
+        Err(DbError::Unauthorized)
+    ));
+    assert!(matches!(
+        study.delete(reader.id, &mut conn).await,
+        Err(DbError::Unauthorized)
+    ));
+
+    let fork = study.fork(reader.id, &mut conn).await.expect("fork study");
+    assert_eq!(fork.owner_id, reader.id);
+    assert_eq!(fork.forked_from, Some(study.id));
+    assert_eq!(fork.visibility(), StudyVisibility::Private);
+    assert!(!fork.can_view(Some(owner.id)));
+    let copied = fork.chapters(&mut conn).await.expect("load fork chapters");
+    assert_eq!(copied.len(), 1);
+    assert_eq!(copied[0].title, "Pillbug lines");
+    let source = fork
+        .source(&mut conn)
+        .await
+        .expect("load source")
+        .expect("fork has a source");
+    assert_eq!(source.id, study.id);
+
+    let public = Study::for_owner(owner.id, Some(reader.id), &mut conn)
+        .await
+        .expect("list public studies");
+    assert_eq!(public.len(), 1);
+    let all = Study::for_owner(owner.id, Some(owner.id), &mut conn)
+        .await
+        .expect("list own studies");
+    assert_eq!(all.len(), 2);
+
+    study
+        .delete(owner.id, &mut conn)
+        .await
+        .expect("delete study");
+    let orphan = Study::find_by_nanoid(&fork.nanoid, &mut conn)
+        .await
+        .expect("fork survives its source");
+    assert_eq!(orphan.forked_from, None);
+}
+
+fn chapter(title: &str, tree: &str) -> NewStudyChapter {
+    NewStudyChapter::new(title, GameType::MLP, tree.to_string()).expect("valid chapter")
+}
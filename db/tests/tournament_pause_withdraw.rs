@@ -0,0 +1,165 @@
+mod common;
+
+use common::fixtures::{self, create_user};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{NewTournament, Tournament, User},
+    DbConn,
+};
+use hive_lib::GameStatus;
+use shared_types::{Conclusion, TimeMode, TournamentGameResult, TournamentMode};
+use uuid::Uuid;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn withdrawal_forfeits_remaining_games() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let organizer = create_user("withdraw_organizer", &mut conn).await;
+    let leaving = create_user("withdraw_leaving", &mut conn).await;
+    let staying = create_user("withdraw_staying", &mut conn).await;
+    let tournament = start_tournament(
+        organizer.id,
+        &[&leaving, &staying],
+        TimeMode::RealTime,
+        &mut conn,
+    )
+    .await;
+
+    let forfeited = tournament
+        .withdraw(&leaving.id, &mut conn)
+        .await
+        .expect("withdraw");
+    assert_eq!(forfeited.len(), 2);
+    for game in &forfeited {
+        assert!(game.finished);
+        assert_eq!(game.conclusion, Conclusion::Forfeit.to_string());
+        let winner = game
+            .user_color(staying.id)
+            .expect("staying player is in the game");
+        assert_eq!(
+            game.tournament_game_result,
+            TournamentGameResult::Winner(winner).to_string()
+        );
+    }
+    assert_eq!(
+        tournament
+            .withdrawn_players(&mut conn)
+            .await
+            .expect("load withdrawn players"),
+        vec![leaving.id]
+    );
+    assert!(matches!(
+        tournament.withdraw(&leaving.id, &mut conn).await,
+        Err(DbError::InvalidAction { .. })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pause_holds_untouched_games_until_resumed() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let organizer = create_user("pause_organizer", &mut conn).await;
+    let first = create_user("pause_first", &mut conn).await;
+    let second = create_user("pause_second", &mut conn).await;
+    let tournament = start_tournament(
+        organizer.id,
+        &[&first, &second],
+        TimeMode::Correspondence,
+        &mut conn,
+    )
+    .await;
+
+    assert!(matches!(
+        tournament.pause(&first.id, &mut conn).await,
+        Err(DbError::Unauthorized)
+    ));
+    let paused = tournament
+        .pause(&organizer.id, &mut conn)
+        .await
+        .expect("pause");
+    assert!(paused.paused_at.is_some());
+    for game in paused.games(&mut conn).await.expect("load games") {
+        assert_eq!(game.game_status, GameStatus::NotStarted.to_string());
+        assert!(game.timeout_at.is_none());
+        assert!(game.ensure_tournament_not_paused(&mut conn).await.is_err());
+    }
+
+    let resumed = paused
+        .resume(&organizer.id, &mut conn)
+        .await
+        .expect("resume");
+    assert!(resumed.paused_at.is_none());
+    for game in resumed.games(&mut conn).await.expect("load games") {
+        assert_eq!(game.game_status, GameStatus::InProgress.to_string());
+        assert!(game.timeout_at.is_some());
+        assert!(game.ensure_tournament_not_paused(&mut conn).await.is_ok());
+        assert_eq!(
+            game.user_color(first.id)
+                .map(|color| color.opposite_color()),
+            game.user_color(second.id)
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn byes_are_cancelled_only_by_seated_players() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    create_user("SwissByePlayer", &mut conn).await;
+    let organizer = create_user("bye_organizer", &mut conn).await;
+    let player = create_user("bye_player", &mut conn).await;
+    let outsider = create_user("bye_outsider", &mut conn).await;
+    let new_tournament = NewTournament {
+        mode: TournamentMode::DoubleSwiss.to_string(),
+        rounds: 3,
+        ..fixtures::new_tournament("Byes", 4)
+    };
+    let tournament = fixtures::insert_tournament(organizer.id, new_tournament, &mut conn).await;
+    tournament.join(&player.id, &mut conn).await.expect("join");
+
+    let unseated = tournament.cancel_bye(&outsider.id, 2, &mut conn).await;
+    assert!(matches!(unseated, Err(DbError::InvalidAction { .. })));
+    let unrequested = tournament.cancel_bye(&player.id, 2, &mut conn).await;
+    assert!(matches!(unrequested, Err(DbError::NotFound { .. })));
+
+    let tournament = tournament
+        .request_bye(&player.id, 2, &mut conn)
+        .await
+        .expect("request bye");
+    let tournament = tournament
+        .cancel_bye(&player.id, 2, &mut conn)
+        .await
+        .expect("cancel bye");
+    assert!(tournament
+        .byes(&mut conn)
+        .await
+        .expect("load byes")
+        .is_empty());
+}
+
+async fn start_tournament(
+    organizer_id: Uuid,
+    players: &[&User],
+    time_mode: TimeMode,
+    conn: &mut DbConn<'_>,
+) -> Tournament {
+    let (time_base, time_increment) = match time_mode {
+        TimeMode::Correspondence => (Some(3 * 86400), None),
+        _ => (Some(600), Some(5)),
+    };
+    let new_tournament = NewTournament {
+        min_seats: players.len() as i32,
+        time_mode: time_mode.to_string(),
+        time_base,
+        time_increment,
+        ..fixtures::new_tournament("Pause and withdraw", players.len() as i32)
+    };
+    let tournament = fixtures::insert_tournament(organizer_id, new_tournament, conn).await;
+    for player in players {
+        tournament.join(&player.id, conn).await.expect("join");
+    }
+    let (tournament, games, _) = tournament.start(conn).await.expect("start tournament");
+    assert_eq!(games.len(), 2);
+    tournament
+}
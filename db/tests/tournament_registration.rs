@@ -0,0 +1,168 @@
+mod common;
+
+use common::fixtures::{bullet_game, create_user, insert_game, insert_tournament, new_tournament};
+use db_lib::{
+    db_error::DbError,
+    get_conn,
+    models::{NewGame, Registration, Tournament},
+    schema::{ratings, tournaments, users},
+};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use hive_lib::{GameResult, GameStatus};
+use shared_types::{Conclusion, GameSpeed, TournamentJoinError};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn full_tournament_waitlists_and_promotes_in_signup_order() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let organizer = create_user("waitlist_organizer", &mut conn).await;
+    let first = create_user("waitlist_first", &mut conn).await;
+    let second = create_user("waitlist_second", &mut conn).await;
+    let third = create_user("waitlist_third", &mut conn).await;
+    let tournament =
+        insert_tournament(organizer.id, new_tournament("Registration", 1), &mut conn).await;
+
+    assert_eq!(
+        tournament.join(&first.id, &mut conn).await.expect("join"),
+        Registration::Joined
+    );
+    for user in [&second, &third] {
+        assert_eq!(
+            tournament
+                .join(&user.id, &mut conn)
+                .await
+                .expect("join waitlist"),
+            Registration::Waitlisted
+        );
+    }
+    let waitlist = tournament.waitlist(&mut conn).await.expect("load waitlist");
+    assert_eq!(
+        waitlist.iter().map(|u| u.id).collect::<Vec<_>>(),
+        vec![second.id, third.id]
+    );
+
+    tournament
+        .leave(&first.id, &mut conn)
+        .await
+        .expect("leave tournament");
+    let promoted = tournament
+        .promote_from_waitlist(&mut conn)
+        .await
+        .expect("promote from waitlist");
+    assert_eq!(promoted, vec![second.id]);
+    let players = tournament.players(&mut conn).await.expect("load players");
+    assert_eq!(
+        players.iter().map(|u| u.id).collect::<Vec<_>>(),
+        vec![second.id]
+    );
+    let waitlist = tournament.waitlist(&mut conn).await.expect("load waitlist");
+    assert_eq!(
+        waitlist.iter().map(|u| u.id).collect::<Vec<_>>(),
+        vec![third.id]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn join_reports_unmet_requirements() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let organizer = create_user("requirements_organizer", &mut conn).await;
+    let newcomer = create_user("requirements_newcomer", &mut conn).await;
+    let bot = create_user("requirements_bot", &mut conn).await;
+    diesel::update(users::table.find(bot.id))
+        .set(users::bot.eq(true))
+        .execute(&mut conn)
+        .await
+        .expect("mark user as bot");
+    let tournament =
+        insert_tournament(organizer.id, new_tournament("Registration", 4), &mut conn).await;
+    let tournament: Tournament = diesel::update(tournaments::table.find(tournament.id))
+        .set((
+            tournaments::min_rated_games.eq(10),
+            tournaments::allow_bots.eq(false),
+        ))
+        .get_result(&mut conn)
+        .await
+        .expect("set requirements");
+
+    assert!(matches!(
+        tournament.join(&bot.id, &mut conn).await,
+        Err(DbError::TournamentJoin(TournamentJoinError::BotsNotAllowed))
+    ));
+    assert!(matches!(
+        tournament.join(&newcomer.id, &mut conn).await,
+        Err(DbError::TournamentJoin(
+            TournamentJoinError::NotEnoughRatedGames {
+                required: 10,
+                played: 0
+            }
+        ))
+    ));
+
+    tournament
+        .create_invitation(&organizer.id, &newcomer.id, &mut conn)
+        .await
+        .expect("invite newcomer");
+    assert_eq!(
+        tournament
+            .join(&newcomer.id, &mut conn)
+            .await
+            .expect("invited players skip requirements"),
+        Registration::Joined
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn only_rated_games_count_towards_the_requirement() {
+    let db = common::db::test_db().await;
+    let mut conn = get_conn(&db.pool).await.expect("get connection");
+    let organizer = create_user("rated_games_organizer", &mut conn).await;
+    let casual = create_user("rated_games_casual", &mut conn).await;
+    let opponent = create_user("rated_games_opponent", &mut conn).await;
+    let tournament =
+        insert_tournament(organizer.id, new_tournament("Registration", 4), &mut conn).await;
+    let tournament: Tournament = diesel::update(tournaments::table.find(tournament.id))
+        .set(tournaments::min_rated_games.eq(1))
+        .get_result(&mut conn)
+        .await
+        .expect("set requirements");
+    // Casual games bump the rating's played counter too.
+    diesel::update(ratings::table.filter(ratings::user_uid.eq(casual.id)))
+        .set(ratings::played.eq(10))
+        .execute(&mut conn)
+        .await
+        .expect("play casual games");
+
+    assert!(matches!(
+        tournament.join(&casual.id, &mut conn).await,
+        Err(DbError::TournamentJoin(
+            TournamentJoinError::NotEnoughRatedGames {
+                required: 1,
+                played: 0
+            }
+        ))
+    ));
+
+    insert_game(
+        NewGame {
+            finished: true,
+            game_status: GameStatus::Finished(GameResult::Draw).to_string(),
+            conclusion: Conclusion::Draw.to_string(),
+            white_rating: Some(1500.0),
+            black_rating: Some(1500.0),
+            white_rating_change: Some(0.0),
+            black_rating_change: Some(0.0),
+            time_base: Some(600),
+            time_increment: Some(5),
+            speed: GameSpeed::Rapid.to_string(),
+            ..bullet_game(casual.id, opponent.id)
+        },
+        &mut conn,
+    )
+    .await;
+    assert_eq!(
+        tournament.join(&casual.id, &mut conn).await.expect("join"),
+        Registration::Joined
+    );
+}
@@ -0,0 +1,51 @@
+use crate::{bug::Bug, color::Color, piece::Piece, state::State};
+
+/// Worth of one piece next to a queen; six of them end the game.
+const SURROUND: i32 = 100;
+/// Worth of one piece that is free to move.
+const MOBILITY: i32 = 10;
+
+impl State {
+    /// A static evaluation in hundredths of a neighbour, from White's point of view:
+    /// pieces around the opposing queen count for you, and so does every piece that can
+    /// still move. Finished games aren't scored here; callers know what a win is worth.
+    pub fn evaluate(&self) -> i32 {
+        self.side_score(Color::White) - self.side_score(Color::Black)
+    }
+
+    fn side_score(&self, color: Color) -> i32 {
+        let pressure = self
+            .board
+            .position_of_piece(Piece::new_from(Bug::Queen, color.opposite_color(), 0))
+            .map_or(0, |position| {
+                self.board.positions_taken_around(position).count() as i32
+            });
+        let mobile = self
+            .board
+            .moves(color)
+            .values()
+            .filter(|targets| !targets.is_empty())
+            .count() as i32;
+        SURROUND * pressure + MOBILITY * mobile
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameType, State};
+
+    #[test]
+    fn the_start_is_level() {
+        let state = State::new(GameType::Base, true);
+        assert_eq!(state.evaluate(), 0);
+    }
+
+    #[test]
+    fn crowding_the_black_queen_favours_white() {
+        let before = r"wS1 ;bG1 /wS1;wS2 wS1-;bS1 -bG1;wQ wS2/;bQ -bS1;wA1 \wS1;bG2 bG1\;";
+        let before = State::new_from_str(before, "Base").expect("valid");
+        let mut after = before.clone();
+        after.play_turn_from_history("wA1", r"\bQ").expect("valid");
+        assert!(after.evaluate() > before.evaluate());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    bug::Bug,
+    color::Color,
+    game_result::GameResult,
+    game_status::GameStatus,
+    piece::Piece,
+    position::Position,
+    state::State,
+};
+use std::{collections::HashSet, str::FromStr};
+
+/// A queen surround the side to move can force, found by [`State::forced_win`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForcedWin {
+    /// The winner's moves alternating with the strongest replies, in history
+    /// notation. Passes forced on the loser show up as their reply.
+    pub line: Vec<(String, String)>,
+    /// The winner's moves in the line, i.e. the length of the shortest win.
+    pub moves: usize,
+    /// Whether every winner's move in `line` is the only one that wins as
+    /// fast. Moves reaching the same position count as one.
+    pub unique: bool,
+}
+
+impl State {
+    /// Searches for a win of the side to move within `max_moves` of its own
+    /// moves against any defence, shortest first.
+    pub fn forced_win(&self, max_moves: usize) -> Option<ForcedWin> {
+        if self.game_status != GameStatus::InProgress {
+            return None;
+        }
+        let winner = self.turn_color;
+        (1..=max_moves).find_map(|moves| principal_line(self, winner, moves))
+    }
+}
+
+fn principal_line(state: &State, winner: Color, moves: usize) -> Option<ForcedWin> {
+    let winning = children(state, moves == 1)
+        .into_iter()
+        .filter(|child| wins_after(child, winner, moves))
+        .collect::<Vec<_>>();
+    let child = winning.first()?;
+    let mut line = child.history.moves[state.history.moves.len()..].to_vec();
+    let mut unique = winning.len() == 1;
+    if child.game_status == GameStatus::Finished(GameResult::Winner(winner)) {
+        return Some(ForcedWin {
+            line,
+            moves: 1,
+            unique,
+        });
+    }
+    // The loser already passed, so the winner simply moves again
+    let (next, rest) = if child.turn_color == winner {
+        (child.clone(), moves - 1)
+    } else {
+        // Prefer the reply that holds out the longest
+        let (reply, rest) = children(child, false)
+            .into_iter()
+            .filter_map(|reply| {
+                let rest = (1..moves).find(|rest| wins_from(&reply, winner, *rest))?;
+                Some((reply, rest))
+            })
+            .max_by_key(|(_, rest)| *rest)?;
+        line.extend_from_slice(&reply.history.moves[child.history.moves.len()..]);
+        (reply, rest)
+    };
+    let tail = principal_line(&next, winner, rest)?;
+    line.extend(tail.line);
+    unique &= tail.unique;
+    Some(ForcedWin {
+        line,
+        moves: tail.moves + 1,
+        unique,
+    })
+}
+
+/// Whether `winner`, to move in `state`, surrounds the queen within `moves`.
+fn wins_from(state: &State, winner: Color, moves: usize) -> bool {
+    moves > 0
+        && state.game_status == GameStatus::InProgress
+        && state.turn_color == winner
+        && children(state, moves == 1)
+            .iter()
+            .any(|child| wins_after(child, winner, moves))
+}
+
+/// Whether the position right after a winner's move is won within the
+/// remaining `moves - 1` whatever the loser replies.
+fn wins_after(child: &State, winner: Color, moves: usize) -> bool {
+    match &child.game_status {
+        GameStatus::Finished(GameResult::Winner(color)) => *color == winner,
+        GameStatus::Finished(_) => false,
+        _ if moves <= 1 => false,
+        _ if child.turn_color == winner => wins_from(child, winner, moves - 1),
+        _ => children(child, false)
+            .iter()
+            .all(|reply| wins_from(reply, winner, moves - 1)),
+    }
+}
+
+/// The positions reachable with one legal turn, one per distinct position.
+/// With `surrounding_only` set, only turns that can end the game right away
+/// are tried: filling the last gap around the opponent's queen or moving
+/// that queen itself.
+fn children(state: &State, surrounding_only: bool) -> Vec<State> {
+    let color = state.turn_color;
+    let board = &state.board;
+    let queen = Piece::new_from(Bug::Queen, color.opposite_color(), 0);
+    let gaps = board
+        .position_of_piece(queen)
+        .map(|position| {
+            board
+                .positions_available_around(position)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if surrounding_only && gaps.len() != 1 {
+        return Vec::new();
+    }
+    let worth_trying = |piece: Piece, target: Position| {
+        !surrounding_only || piece == queen || gaps.contains(&target)
+    };
+
+    let mut turns = Vec::new();
+    for ((piece, _), targets) in board.moves(color) {
+        turns.extend(
+            targets
+                .into_iter()
+                .filter(|target| worth_trying(piece, *target))
+                .map(|target| (piece, target)),
+        );
+    }
+    let spawns = board.spawnable_positions(color).collect::<Vec<_>>();
+    for (bug, pieces) in board.reserve(color, state.game_type) {
+        if board.queen_required(state.turn, color) && bug != Bug::Queen {
+            continue;
+        }
+        if state.tournament && bug == Bug::Queen && state.turn < 2 {
+            continue;
+        }
+        let Some(piece) = pieces.first().and_then(|piece| Piece::from_str(piece).ok()) else {
+            continue;
+        };
+        turns.extend(
+            spawns
+                .iter()
+                .filter(|target| worth_trying(piece, **target))
+                .map(|target| (piece, *target)),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    turns
+        .into_iter()
+        .filter_map(|(piece, target)| {
+            let mut child = state.clone();
+            child.play_turn_from_position(piece, target).ok()?;
+            let hash = *child.hashes.last()?;
+            seen.insert(hash).then_some(child)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_type::GameType;
+
+    fn state(history: &str, game_type: GameType) -> State {
+        State::new_from_str(history, &game_type.to_string()).expect("valid history")
+    }
+
+    #[test]
+    fn finds_the_only_surrounding_move() {
+        let state = state(
+            concat!(
+                r"wA1 ;bA1 wA1-;wA2 /wA1;bQ bA1\;wQ \wA2;bG1 bQ/;wM wQ/;bG1 -wQ;wM -bQ;bM bQ/;",
+                r"wG1 /wM;bM wQ/;wL -wG1;bA1 /wQ;wL /bQ;bL bM/;wG1 bQ\;",
+            ),
+            GameType::ML,
+        );
+        let win = state.forced_win(1).expect("black surrounds the queen");
+        assert_eq!(win.line, [("bL".to_string(), "\\wQ".to_string())]);
+        assert_eq!(win.moves, 1);
+        assert!(win.unique);
+    }
+
+    #[test]
+    fn forced_passes_are_the_losers_reply() {
+        let state = state(
+            concat!(
+                r"wS1 ;bG1 /wS1;wS2 wS1-;bS1 -bG1;wQ wS2/;bQ -bS1;wA1 \wS1;bG2 bG1\;wA1 \bQ;",
+                r"bB1 -bG2;wB1 -wQ;bB2 bG2-;wS2 bB2-;bA1 /bB2;wG1 \wA1;bS2 -bA1;wG1 bQ\;bS1 \wB1;",
+                r"wB2 wA1/;bA1 \wB2;wG2 -wA1;bS2 wS2\;wG2 bQ/;bA2 -bS1;wA2 -wA1;bA2 wQ\;wA2 -bQ;",
+                r"bS1 wQ-;wG3 /wG1;bA3 /bB2;wA3 -wA2;bA3 \wA3;wG2 /bQ;bQ wB2\;wG3 bQ\;bQ wB2-;",
+                r"wG3 /bG2;bA1 wQ/;wG3 \bB1;bB1 bG1;pass ;bG3 bA1-;pass ;bG3 \wQ;pass ;",
+            ),
+            GameType::Base,
+        );
+        assert_eq!(state.forced_win(1), None);
+        let win = state.forced_win(2).expect("black wins in two");
+        assert_eq!(win.moves, 2);
+        assert_eq!(win.line.len(), 3);
+        assert_eq!(win.line[1], ("pass".to_string(), String::new()));
+    }
+
+    #[test]
+    fn open_positions_have_no_forced_win() {
+        let state = state(r"wA1 ;bA1 wA1-;wQ -wA1;bQ bA1-;", GameType::Base);
+        assert_eq!(state.forced_win(1), None);
+    }
+}
@@ -2,7 +2,9 @@ use crate::game_error::GameError;
 use serde::Serialize;
 use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Copy, Default, serde_with::DeserializeFromStr)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, Copy, Default, serde_with::DeserializeFromStr,
+)]
 pub enum GameType {
     #[default]
     Base,
@@ -5,6 +5,8 @@ mod color;
 mod dfs_info;
 mod direction;
 mod dsl;
+mod evaluation;
+mod forced_win;
 mod game_control;
 mod game_error;
 mod game_result;
@@ -13,6 +15,8 @@ mod game_type;
 mod hasher;
 mod history;
 mod mid_move_board;
+mod opening_book;
+mod opening_names;
 mod piece;
 mod player;
 mod position;
@@ -27,12 +31,14 @@ pub use bug_stack::BugStack;
 pub use color::{Color, ColorChoice};
 pub use direction::Direction;
 pub use dsl::*;
+pub use forced_win::ForcedWin;
 pub use game_control::GameControl;
 pub use game_error::GameError;
 pub use game_result::GameResult;
 pub use game_status::GameStatus;
 pub use game_type::GameType;
 pub use history::History;
+pub use opening_book::{Opening, OpeningBook};
 pub use piece::Piece;
 pub use player::Player;
 pub use position::Position;
@@ -0,0 +1,239 @@
+use crate::{
+    bug::Bug,
+    color::Color,
+    game_type::GameType,
+    opening_names::OPENING_NAMES,
+    state::State,
+};
+use lazy_static::lazy_static;
+use std::{collections::HashMap, str::FromStr};
+
+/// The last ply the book names: White's queen on its second move.
+const QUEEN_PLY: usize = 2;
+/// Where White's queen goes on its second move by code digit: in line with the first two
+/// pieces or at an angle. The two angled placements are mirror images and hash the same.
+const QUEEN_PLACEMENTS: [(char, &str); 2] = [('1', "-"), ('2', "/")];
+
+/// A named opening, identified by the canonical hash of the position after `moves`.
+///
+/// Codes work like ECO codes: the first letter is White's opening bug, the second Black's
+/// reply and the digit where White's queen follows up, so every code extends its parent's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opening {
+    pub code: String,
+    pub name: String,
+    pub moves: Vec<(String, String)>,
+    pub hash: u64,
+}
+
+impl Opening {
+    /// The ply at which the opening's position is reached, counting from 0 like
+    /// `State::hashes`.
+    pub fn ply(&self) -> usize {
+        self.moves.len() - 1
+    }
+}
+
+/// Every named opening of one game type.
+#[derive(Debug, Default)]
+pub struct OpeningBook {
+    openings: Vec<Opening>,
+    by_position: HashMap<(usize, u64), usize>,
+}
+
+lazy_static! {
+    static ref BOOKS: HashMap<GameType, OpeningBook> = [
+        GameType::Base,
+        GameType::M,
+        GameType::L,
+        GameType::P,
+        GameType::ML,
+        GameType::LP,
+        GameType::MP,
+        GameType::MLP,
+    ]
+    .into_iter()
+    .map(|game_type| (game_type, OpeningBook::build(game_type)))
+    .collect();
+}
+
+impl OpeningBook {
+    /// How many plies of a game the book looks at, from the first move on.
+    pub const DEPTH: usize = QUEEN_PLY + 1;
+
+    pub fn for_game_type(game_type: GameType) -> &'static OpeningBook {
+        &BOOKS[&game_type]
+    }
+
+    /// `code` in every game type whose book has it, e.g. to search games of any variant.
+    pub fn find_in_all(code: &str) -> Vec<(GameType, &'static Opening)> {
+        BOOKS
+            .iter()
+            .filter_map(|(game_type, book)| Some((*game_type, book.find(code)?)))
+            .collect()
+    }
+
+    pub fn openings(&self) -> &[Opening] {
+        &self.openings
+    }
+
+    pub fn find(&self, code: &str) -> Option<&Opening> {
+        self.openings.iter().find(|opening| opening.code == code)
+    }
+
+    /// The opening whose position is reached at `ply` with canonical `hash`, if it has a name.
+    pub fn lookup(&self, ply: usize, hash: u64) -> Option<&Opening> {
+        self.by_position
+            .get(&(ply, hash))
+            .map(|&index| &self.openings[index])
+    }
+
+    /// The most specific opening a game went through, given its position hashes.
+    pub fn classify(&self, hashes: &[u64]) -> Option<&Opening> {
+        hashes
+            .iter()
+            .enumerate()
+            .take(Self::DEPTH)
+            .filter_map(|(ply, &hash)| self.lookup(ply, hash))
+            .next_back()
+    }
+
+    fn build(game_type: GameType) -> Self {
+        let mut book = Self::default();
+        for (code, name) in OPENING_NAMES {
+            if let Some(moves) = moves_of(code, game_type) {
+                book.add(game_type, code.to_string(), name.to_string(), moves);
+            }
+        }
+        book
+    }
+
+    fn add(
+        &mut self,
+        game_type: GameType,
+        code: String,
+        name: String,
+        moves: Vec<(String, String)>,
+    ) {
+        let mut state = State::new(game_type, false);
+        for (piece, position) in &moves {
+            if state.play_turn_from_history(piece, position).is_err() {
+                return;
+            }
+        }
+        let Some(&hash) = state.hashes.last() else {
+            return;
+        };
+        let opening = Opening {
+            code,
+            name,
+            moves,
+            hash,
+        };
+        self.by_position
+            .entry((opening.ply(), hash))
+            .or_insert(self.openings.len());
+        self.openings.push(opening);
+    }
+}
+
+/// The moves a code stands for: White's bug, Black's reply next to it and where White's
+/// queen follows up. None if the game type lacks one of the bugs.
+fn moves_of(code: &str, game_type: GameType) -> Option<Vec<(String, String)>> {
+    let mut chars = code.chars();
+    let white = piece_of(Color::White, chars.next()?, game_type)?;
+    let mut moves = vec![(white.clone(), String::new())];
+    if let Some(reply) = chars.next() {
+        moves.push((
+            piece_of(Color::Black, reply, game_type)?,
+            format!("{white}-"),
+        ));
+    }
+    if let Some(digit) = chars.next() {
+        let (_, side) = QUEEN_PLACEMENTS.iter().find(|(d, _)| *d == digit)?;
+        moves.push((String::from("wQ"), format!("{side}{white}")));
+    }
+    chars.next().is_none().then_some(moves)
+}
+
+/// The first piece of a bug, as game histories write it.
+fn piece_of(color: Color, bug: char, game_type: GameType) -> Option<String> {
+    let bug = Bug::from_str(&bug.to_string())
+        .ok()
+        .filter(|bug| bug.count(game_type) > 0)?;
+    let order = if bug.has_order() { "1" } else { "" };
+    Some(format!("{color}{}{order}", bug.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(history: &str, game_type: GameType) -> Vec<u64> {
+        State::new_from_str(history, &game_type.to_string())
+            .expect("valid history")
+            .hashes
+    }
+
+    #[test]
+    fn names_the_deepest_known_position() {
+        let book = OpeningBook::for_game_type(GameType::Base);
+        let game = hashes("wS1 ;bG1 wS1-;wQ -wS1;bQ bG1-;", GameType::Base);
+        let opening = book.classify(&game).expect("a book opening");
+        assert_eq!(opening.code, "SG1");
+        assert_eq!(
+            opening.name,
+            "Spider opening, Grasshopper defence, straight Queen"
+        );
+    }
+
+    #[test]
+    fn mirrored_queens_are_the_same_opening() {
+        let book = OpeningBook::for_game_type(GameType::Base);
+        let below = hashes(r"wA1 ;bA1 wA1-;wQ /wA1;", GameType::Base);
+        let above = hashes(r"wA1 ;bA1 wA1-;wQ \wA1;", GameType::Base);
+        assert_eq!(
+            book.classify(&below).map(|o| &o.code),
+            Some(&"AA2".to_string())
+        );
+        assert_eq!(
+            book.classify(&above).map(|o| &o.code),
+            Some(&"AA2".to_string())
+        );
+    }
+
+    #[test]
+    fn games_leaving_the_book_keep_their_last_name() {
+        let book = OpeningBook::for_game_type(GameType::MLP);
+        let game = hashes("wG1 ;bM wG1-;wP -wG1;", GameType::MLP);
+        let opening = book.classify(&game).expect("a book opening");
+        assert_eq!(opening.code, "GM");
+        assert!(book.find("GM1").is_some());
+    }
+
+    #[test]
+    fn expansion_openings_only_exist_with_the_expansion() {
+        let game_types = OpeningBook::find_in_all("MS")
+            .into_iter()
+            .map(|(game_type, _)| game_type)
+            .collect::<Vec<_>>();
+        assert_eq!(game_types.len(), 4);
+        assert!(!game_types.contains(&GameType::Base));
+        assert_eq!(OpeningBook::find_in_all("SG1").len(), 8);
+    }
+
+    #[test]
+    fn every_named_code_is_in_the_full_book() {
+        let book = OpeningBook::for_game_type(GameType::MLP);
+        assert_eq!(book.openings().len(), OPENING_NAMES.len());
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        let book = OpeningBook::for_game_type(GameType::MLP);
+        let mut codes = book.openings().iter().map(|o| &o.code).collect::<Vec<_>>();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), book.openings().len());
+    }
+}
@@ -0,0 +1,206 @@
+/// Names for the openings in the book, by code. Edit names here; the codes decide the
+/// moves (see `OpeningBook`), and codes with a bug a variant lacks are left out of its book.
+pub(crate) const OPENING_NAMES: &[(&str, &str)] = &[
+    ("A", "Ant opening"),
+    ("AA", "Symmetrical Ant"),
+    ("AA1", "Symmetrical Ant, straight Queen"),
+    ("AA2", "Symmetrical Ant, angled Queen"),
+    ("AB", "Ant opening, Beetle defence"),
+    ("AB1", "Ant opening, Beetle defence, straight Queen"),
+    ("AB2", "Ant opening, Beetle defence, angled Queen"),
+    ("AG", "Ant opening, Grasshopper defence"),
+    ("AG1", "Ant opening, Grasshopper defence, straight Queen"),
+    ("AG2", "Ant opening, Grasshopper defence, angled Queen"),
+    ("AL", "Ant opening, Ladybug defence"),
+    ("AL1", "Ant opening, Ladybug defence, straight Queen"),
+    ("AL2", "Ant opening, Ladybug defence, angled Queen"),
+    ("AM", "Ant opening, Mosquito defence"),
+    ("AM1", "Ant opening, Mosquito defence, straight Queen"),
+    ("AM2", "Ant opening, Mosquito defence, angled Queen"),
+    ("AP", "Ant opening, Pillbug defence"),
+    ("AP1", "Ant opening, Pillbug defence, straight Queen"),
+    ("AP2", "Ant opening, Pillbug defence, angled Queen"),
+    ("AQ", "Ant opening, early Queen"),
+    ("AQ1", "Ant opening, early Queens, straight"),
+    ("AQ2", "Ant opening, early Queens, angled"),
+    ("AS", "Ant opening, Spider defence"),
+    ("AS1", "Ant opening, Spider defence, straight Queen"),
+    ("AS2", "Ant opening, Spider defence, angled Queen"),
+    ("B", "Beetle opening"),
+    ("BA", "Beetle opening, Ant defence"),
+    ("BA1", "Beetle opening, Ant defence, straight Queen"),
+    ("BA2", "Beetle opening, Ant defence, angled Queen"),
+    ("BB", "Symmetrical Beetle"),
+    ("BB1", "Symmetrical Beetle, straight Queen"),
+    ("BB2", "Symmetrical Beetle, angled Queen"),
+    ("BG", "Beetle opening, Grasshopper defence"),
+    ("BG1", "Beetle opening, Grasshopper defence, straight Queen"),
+    ("BG2", "Beetle opening, Grasshopper defence, angled Queen"),
+    ("BL", "Beetle opening, Ladybug defence"),
+    ("BL1", "Beetle opening, Ladybug defence, straight Queen"),
+    ("BL2", "Beetle opening, Ladybug defence, angled Queen"),
+    ("BM", "Beetle opening, Mosquito defence"),
+    ("BM1", "Beetle opening, Mosquito defence, straight Queen"),
+    ("BM2", "Beetle opening, Mosquito defence, angled Queen"),
+    ("BP", "Beetle opening, Pillbug defence"),
+    ("BP1", "Beetle opening, Pillbug defence, straight Queen"),
+    ("BP2", "Beetle opening, Pillbug defence, angled Queen"),
+    ("BQ", "Beetle opening, early Queen"),
+    ("BQ1", "Beetle opening, early Queens, straight"),
+    ("BQ2", "Beetle opening, early Queens, angled"),
+    ("BS", "Beetle opening, Spider defence"),
+    ("BS1", "Beetle opening, Spider defence, straight Queen"),
+    ("BS2", "Beetle opening, Spider defence, angled Queen"),
+    ("G", "Grasshopper opening"),
+    ("GA", "Grasshopper opening, Ant defence"),
+    ("GA1", "Grasshopper opening, Ant defence, straight Queen"),
+    ("GA2", "Grasshopper opening, Ant defence, angled Queen"),
+    ("GB", "Grasshopper opening, Beetle defence"),
+    ("GB1", "Grasshopper opening, Beetle defence, straight Queen"),
+    ("GB2", "Grasshopper opening, Beetle defence, angled Queen"),
+    ("GG", "Symmetrical Grasshopper"),
+    ("GG1", "Symmetrical Grasshopper, straight Queen"),
+    ("GG2", "Symmetrical Grasshopper, angled Queen"),
+    ("GL", "Grasshopper opening, Ladybug defence"),
+    (
+        "GL1",
+        "Grasshopper opening, Ladybug defence, straight Queen",
+    ),
+    ("GL2", "Grasshopper opening, Ladybug defence, angled Queen"),
+    ("GM", "Grasshopper opening, Mosquito defence"),
+    (
+        "GM1",
+        "Grasshopper opening, Mosquito defence, straight Queen",
+    ),
+    ("GM2", "Grasshopper opening, Mosquito defence, angled Queen"),
+    ("GP", "Grasshopper opening, Pillbug defence"),
+    (
+        "GP1",
+        "Grasshopper opening, Pillbug defence, straight Queen",
+    ),
+    ("GP2", "Grasshopper opening, Pillbug defence, angled Queen"),
+    ("GQ", "Grasshopper opening, early Queen"),
+    ("GQ1", "Grasshopper opening, early Queens, straight"),
+    ("GQ2", "Grasshopper opening, early Queens, angled"),
+    ("GS", "Grasshopper opening, Spider defence"),
+    ("GS1", "Grasshopper opening, Spider defence, straight Queen"),
+    ("GS2", "Grasshopper opening, Spider defence, angled Queen"),
+    ("L", "Ladybug opening"),
+    ("LA", "Ladybug opening, Ant defence"),
+    ("LA1", "Ladybug opening, Ant defence, straight Queen"),
+    ("LA2", "Ladybug opening, Ant defence, angled Queen"),
+    ("LB", "Ladybug opening, Beetle defence"),
+    ("LB1", "Ladybug opening, Beetle defence, straight Queen"),
+    ("LB2", "Ladybug opening, Beetle defence, angled Queen"),
+    ("LG", "Ladybug opening, Grasshopper defence"),
+    (
+        "LG1",
+        "Ladybug opening, Grasshopper defence, straight Queen",
+    ),
+    ("LG2", "Ladybug opening, Grasshopper defence, angled Queen"),
+    ("LL", "Symmetrical Ladybug"),
+    ("LL1", "Symmetrical Ladybug, straight Queen"),
+    ("LL2", "Symmetrical Ladybug, angled Queen"),
+    ("LM", "Ladybug opening, Mosquito defence"),
+    ("LM1", "Ladybug opening, Mosquito defence, straight Queen"),
+    ("LM2", "Ladybug opening, Mosquito defence, angled Queen"),
+    ("LP", "Ladybug opening, Pillbug defence"),
+    ("LP1", "Ladybug opening, Pillbug defence, straight Queen"),
+    ("LP2", "Ladybug opening, Pillbug defence, angled Queen"),
+    ("LQ", "Ladybug opening, early Queen"),
+    ("LQ1", "Ladybug opening, early Queens, straight"),
+    ("LQ2", "Ladybug opening, early Queens, angled"),
+    ("LS", "Ladybug opening, Spider defence"),
+    ("LS1", "Ladybug opening, Spider defence, straight Queen"),
+    ("LS2", "Ladybug opening, Spider defence, angled Queen"),
+    ("M", "Mosquito opening"),
+    ("MA", "Mosquito opening, Ant defence"),
+    ("MA1", "Mosquito opening, Ant defence, straight Queen"),
+    ("MA2", "Mosquito opening, Ant defence, angled Queen"),
+    ("MB", "Mosquito opening, Beetle defence"),
+    ("MB1", "Mosquito opening, Beetle defence, straight Queen"),
+    ("MB2", "Mosquito opening, Beetle defence, angled Queen"),
+    ("MG", "Mosquito opening, Grasshopper defence"),
+    (
+        "MG1",
+        "Mosquito opening, Grasshopper defence, straight Queen",
+    ),
+    ("MG2", "Mosquito opening, Grasshopper defence, angled Queen"),
+    ("ML", "Mosquito opening, Ladybug defence"),
+    ("ML1", "Mosquito opening, Ladybug defence, straight Queen"),
+    ("ML2", "Mosquito opening, Ladybug defence, angled Queen"),
+    ("MM", "Symmetrical Mosquito"),
+    ("MM1", "Symmetrical Mosquito, straight Queen"),
+    ("MM2", "Symmetrical Mosquito, angled Queen"),
+    ("MP", "Mosquito opening, Pillbug defence"),
+    ("MP1", "Mosquito opening, Pillbug defence, straight Queen"),
+    ("MP2", "Mosquito opening, Pillbug defence, angled Queen"),
+    ("MQ", "Mosquito opening, early Queen"),
+    ("MQ1", "Mosquito opening, early Queens, straight"),
+    ("MQ2", "Mosquito opening, early Queens, angled"),
+    ("MS", "Mosquito opening, Spider defence"),
+    ("MS1", "Mosquito opening, Spider defence, straight Queen"),
+    ("MS2", "Mosquito opening, Spider defence, angled Queen"),
+    ("P", "Pillbug opening"),
+    ("PA", "Pillbug opening, Ant defence"),
+    ("PA1", "Pillbug opening, Ant defence, straight Queen"),
+    ("PA2", "Pillbug opening, Ant defence, angled Queen"),
+    ("PB", "Pillbug opening, Beetle defence"),
+    ("PB1", "Pillbug opening, Beetle defence, straight Queen"),
+    ("PB2", "Pillbug opening, Beetle defence, angled Queen"),
+    ("PG", "Pillbug opening, Grasshopper defence"),
+    (
+        "PG1",
+        "Pillbug opening, Grasshopper defence, straight Queen",
+    ),
+    ("PG2", "Pillbug opening, Grasshopper defence, angled Queen"),
+    ("PL", "Pillbug opening, Ladybug defence"),
+    ("PL1", "Pillbug opening, Ladybug defence, straight Queen"),
+    ("PL2", "Pillbug opening, Ladybug defence, angled Queen"),
+    ("PM", "Pillbug opening, Mosquito defence"),
+    ("PM1", "Pillbug opening, Mosquito defence, straight Queen"),
+    ("PM2", "Pillbug opening, Mosquito defence, angled Queen"),
+    ("PP", "Symmetrical Pillbug"),
+    ("PP1", "Symmetrical Pillbug, straight Queen"),
+    ("PP2", "Symmetrical Pillbug, angled Queen"),
+    ("PQ", "Pillbug opening, early Queen"),
+    ("PQ1", "Pillbug opening, early Queens, straight"),
+    ("PQ2", "Pillbug opening, early Queens, angled"),
+    ("PS", "Pillbug opening, Spider defence"),
+    ("PS1", "Pillbug opening, Spider defence, straight Queen"),
+    ("PS2", "Pillbug opening, Spider defence, angled Queen"),
+    ("Q", "Queen opening"),
+    ("QA", "Queen opening, Ant defence"),
+    ("QB", "Queen opening, Beetle defence"),
+    ("QG", "Queen opening, Grasshopper defence"),
+    ("QL", "Queen opening, Ladybug defence"),
+    ("QM", "Queen opening, Mosquito defence"),
+    ("QP", "Queen opening, Pillbug defence"),
+    ("QQ", "Symmetrical Queen"),
+    ("QS", "Queen opening, Spider defence"),
+    ("S", "Spider opening"),
+    ("SA", "Spider opening, Ant defence"),
+    ("SA1", "Spider opening, Ant defence, straight Queen"),
+    ("SA2", "Spider opening, Ant defence, angled Queen"),
+    ("SB", "Spider opening, Beetle defence"),
+    ("SB1", "Spider opening, Beetle defence, straight Queen"),
+    ("SB2", "Spider opening, Beetle defence, angled Queen"),
+    ("SG", "Spider opening, Grasshopper defence"),
+    ("SG1", "Spider opening, Grasshopper defence, straight Queen"),
+    ("SG2", "Spider opening, Grasshopper defence, angled Queen"),
+    ("SL", "Spider opening, Ladybug defence"),
+    ("SL1", "Spider opening, Ladybug defence, straight Queen"),
+    ("SL2", "Spider opening, Ladybug defence, angled Queen"),
+    ("SM", "Spider opening, Mosquito defence"),
+    ("SM1", "Spider opening, Mosquito defence, straight Queen"),
+    ("SM2", "Spider opening, Mosquito defence, angled Queen"),
+    ("SP", "Spider opening, Pillbug defence"),
+    ("SP1", "Spider opening, Pillbug defence, straight Queen"),
+    ("SP2", "Spider opening, Pillbug defence, angled Queen"),
+    ("SQ", "Spider opening, early Queen"),
+    ("SQ1", "Spider opening, early Queens, straight"),
+    ("SQ2", "Spider opening, early Queens, angled"),
+    ("SS", "Symmetrical Spider"),
+    ("SS1", "Symmetrical Spider, straight Queen"),
+    ("SS2", "Symmetrical Spider, angled Queen"),
+];
@@ -0,0 +1,242 @@
+use chrono::{
+    DateTime,
+    Datelike,
+    Duration,
+    DurationRound,
+    NaiveDate,
+    NaiveDateTime,
+    TimeZone,
+    Utc,
+};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+pub const MINUTES_PER_DAY: i32 = 24 * 60;
+/// Suggested start times are rounded up to this many minutes.
+const SLOT_GRANULARITY_MINUTES: i64 = 15;
+
+/// A weekly stretch of time in which a player is happy to play, in the local
+/// time of their time zone, so it follows daylight saving changes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AvailabilityWindow {
+    /// Days from Monday, 0 to 6.
+    pub weekday: i32,
+    pub start_minute: i32,
+    pub end_minute: i32,
+    /// IANA time zone name, e.g. "Europe/Berlin".
+    pub time_zone: String,
+}
+
+impl AvailabilityWindow {
+    pub fn is_valid(&self) -> bool {
+        (0..7).contains(&self.weekday)
+            && 0 <= self.start_minute
+            && self.start_minute < self.end_minute
+            && self.end_minute <= MINUTES_PER_DAY
+            && self.time_zone.parse::<Tz>().is_ok()
+    }
+
+    /// The window's occurrence on `date` in UTC, if it falls on that weekday.
+    fn on(&self, date: NaiveDate, time_zone: Tz) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        if date.weekday().num_days_from_monday() as i32 != self.weekday {
+            return None;
+        }
+        let midnight = date.and_hms_opt(0, 0, 0)?;
+        let local = |minute: i32| midnight + Duration::minutes(minute as i64);
+        let start = to_utc(time_zone, local(self.start_minute));
+        let end = to_utc(time_zone, local(self.end_minute));
+        (start < end).then_some((start, end))
+    }
+}
+
+/// Local times repeated by a daylight saving change count from their first
+/// occurrence, skipped ones from the end of the gap.
+fn to_utc(time_zone: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    time_zone
+        .from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            time_zone
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map_or_else(|| local.and_utc(), |time| time.with_timezone(&Utc))
+}
+
+/// Every stretch of `windows` between `from` and `until`, merged where the
+/// windows touch, e.g. across midnight.
+fn occurrences(
+    windows: &[AvailabilityWindow],
+    from: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut spans = Vec::new();
+    for window in windows {
+        let Ok(time_zone) = window.time_zone.parse::<Tz>() else {
+            continue;
+        };
+        // Local dates run up to a day ahead of or behind UTC.
+        let mut date = from.date_naive() - Duration::days(1);
+        while date <= until.date_naive() + Duration::days(1) {
+            if let Some((start, end)) = window.on(date, time_zone) {
+                let (start, end) = (start.max(from), end.min(until));
+                if start < end {
+                    spans.push((start, end));
+                }
+            }
+            date += Duration::days(1);
+        }
+    }
+    spans.sort();
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Start times between `from` and `until` that fall inside both players'
+/// windows with room for a game of `length`, earliest first. Each shared
+/// stretch yields one slot.
+pub fn overlapping_slots(
+    ours: &[AvailabilityWindow],
+    theirs: &[AvailabilityWindow],
+    from: DateTime<Utc>,
+    until: DateTime<Utc>,
+    length: Duration,
+) -> Vec<DateTime<Utc>> {
+    let ours = occurrences(ours, from, until);
+    let theirs = occurrences(theirs, from, until);
+    let granularity = Duration::minutes(SLOT_GRANULARITY_MINUTES);
+    let (mut i, mut j) = (0, 0);
+    let mut slots = Vec::new();
+    while i < ours.len() && j < theirs.len() {
+        let start = ours[i].0.max(theirs[j].0);
+        let end = ours[i].1.min(theirs[j].1);
+        if start < end {
+            let rounded = start.duration_trunc(granularity).unwrap_or(start);
+            let start = if rounded < start {
+                rounded + granularity
+            } else {
+                rounded
+            };
+            if start + length <= end {
+                slots.push(start);
+            }
+        }
+        if ours[i].1 < theirs[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(weekday: i32, start_hour: i32, end_hour: i32, time_zone: &str) -> AvailabilityWindow {
+        AvailabilityWindow {
+            weekday,
+            start_minute: start_hour * 60,
+            end_minute: end_hour * 60,
+            time_zone: time_zone.to_string(),
+        }
+    }
+
+    // 2026-07-20 is a Monday.
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn validates_windows() {
+        assert!(window(0, 18, 22, "Europe/Berlin").is_valid());
+        assert!(window(6, 0, 24, "Pacific/Kiritimati").is_valid());
+        assert!(!window(7, 18, 22, "UTC").is_valid());
+        assert!(!window(0, 22, 18, "UTC").is_valid());
+        assert!(!window(0, 18, 25, "UTC").is_valid());
+        assert!(!window(0, 18, 22, "UTC+2").is_valid());
+    }
+
+    #[test]
+    fn overlaps_across_time_zones() {
+        // Tuesday 19:00-23:00 in Berlin is 17:00-21:00 UTC in summer, Tuesday
+        // 15:00-18:00 in New York is 19:00-22:00 UTC.
+        let berlin = [window(1, 19, 23, "Europe/Berlin")];
+        let new_york = [window(1, 15, 18, "America/New_York")];
+        let slots = overlapping_slots(
+            &berlin,
+            &new_york,
+            at(20, 0, 0),
+            at(20, 0, 0) + Duration::weeks(2),
+            Duration::hours(1),
+        );
+        assert_eq!(slots, vec![at(21, 19, 0), at(28, 19, 0)]);
+        assert!(overlapping_slots(
+            &berlin,
+            &new_york,
+            at(20, 0, 0),
+            at(27, 0, 0),
+            Duration::hours(3),
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn merges_windows_across_midnight_and_rounds_up() {
+        let ours = [window(0, 23, 24, "UTC"), window(1, 0, 1, "UTC")];
+        let theirs = [window(0, 22, 24, "UTC"), window(1, 0, 2, "UTC")];
+        let slots = overlapping_slots(
+            &ours,
+            &theirs,
+            at(20, 23, 7),
+            at(21, 12, 0),
+            Duration::minutes(90),
+        );
+        assert_eq!(slots, vec![at(20, 23, 15)]);
+    }
+
+    #[test]
+    fn eastern_zones_reach_into_the_previous_day() {
+        // Monday 02:00-04:00 in Brisbane is Sunday 16:00-18:00 UTC.
+        let brisbane = [window(0, 2, 4, "Australia/Brisbane")];
+        let london = [window(6, 16, 20, "Europe/London")];
+        let slots = overlapping_slots(
+            &brisbane,
+            &london,
+            at(20, 0, 0),
+            at(27, 0, 0),
+            Duration::hours(2),
+        );
+        assert_eq!(slots, vec![at(26, 16, 0)]);
+    }
+
+    #[test]
+    fn windows_follow_daylight_saving_changes() {
+        // Berlin moves to summer time on 2026-03-29, so a Monday evening
+        // window is an hour earlier in UTC the week after.
+        let berlin = [window(0, 19, 21, "Europe/Berlin")];
+        let utc = [window(0, 17, 21, "UTC")];
+        let from = Utc.with_ymd_and_hms(2026, 3, 23, 0, 0, 0).unwrap();
+        let slots = overlapping_slots(
+            &berlin,
+            &utc,
+            from,
+            from + Duration::weeks(2),
+            Duration::hours(1),
+        );
+        assert_eq!(
+            slots,
+            vec![
+                Utc.with_ymd_and_hms(2026, 3, 23, 18, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 30, 17, 0, 0).unwrap(),
+            ]
+        );
+    }
+}
@@ -20,4 +20,22 @@ impl Certainty {
         }
         Certainty::Clueless
     }
+
+    /// Ratings that are not yet rankable are shown as provisional and kept off
+    /// the leaderboards.
+    pub fn is_provisional(&self) -> bool {
+        *self != Certainty::Rankable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provisional_until_rankable() {
+        assert!(Certainty::from_deviation(500.0).is_provisional());
+        assert!(Certainty::from_deviation(RANKABLE_DEVIATION).is_provisional());
+        assert!(!Certainty::from_deviation(RANKABLE_DEVIATION - 0.1).is_provisional());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::TimeMode;
+use crate::{ClockKind, TimeMode};
 use hive_lib::{ColorChoice, GameType};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
@@ -21,6 +21,8 @@ pub enum ChallengeError {
     NotUserChallenge,
     #[error("{found} is not a valid TimeMode")]
     NotValidTimeMode { found: String },
+    #[error("{found} is not a valid ClockKind")]
+    NotValidClockKind { found: String },
     #[error("Your rating {rating} is outside the rating band {band_lower}-{band_upper}")]
     OutsideBand {
         rating: u64,
@@ -38,6 +40,8 @@ pub struct ChallengeDetails {
     pub time_mode: TimeMode,
     pub time_base: Option<i32>,
     pub time_increment: Option<i32>,
+    #[serde(default)]
+    pub clock_kind: ClockKind,
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
 }
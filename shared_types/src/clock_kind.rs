@@ -0,0 +1,255 @@
+use crate::{challenge::ChallengeError, TimeMode};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr, time::Duration};
+
+/// How a real-time clock treats each move. Every kind reads its per-move
+/// seconds from `time_increment`: the Fischer increment, the delay, or the
+/// length of one byo-yomi period.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ClockKind {
+    /// The increment is added after every move
+    #[default]
+    Fischer,
+    /// Time spent on a move is given back, up to the delay
+    Bronstein,
+    /// The clock only starts running once the delay has passed
+    SimpleDelay,
+    /// Once the base time is gone every move must fit in one period, and
+    /// each overrun period is lost for good
+    ByoYomi { periods: u8 },
+}
+
+impl ClockKind {
+    pub const MAX_PERIODS: u8 = 10;
+
+    pub fn is_fischer(&self) -> bool {
+        matches!(self, ClockKind::Fischer)
+    }
+
+    /// Anything but Fischer is for real-time games with a delay or period to run.
+    pub fn supports(&self, time_mode: TimeMode, per_move: Option<i32>) -> bool {
+        self.is_fischer() || (time_mode == TimeMode::RealTime && per_move.is_some_and(|s| s > 0))
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            ClockKind::Fischer => String::from("Increment"),
+            ClockKind::Bronstein => String::from("Bronstein delay"),
+            ClockKind::SimpleDelay => String::from("Simple delay"),
+            ClockKind::ByoYomi { periods } => format!("Byo-yomi ×{periods}"),
+        }
+    }
+
+    /// Short suffix for time controls, e.g. "3+2" becomes "3 d2" for delays.
+    pub fn time_control(&self, base: impl fmt::Display, per_move: impl fmt::Display) -> String {
+        match self {
+            ClockKind::Fischer => format!("{base}+{per_move}"),
+            ClockKind::Bronstein => format!("{base} b{per_move}"),
+            ClockKind::SimpleDelay => format!("{base} d{per_move}"),
+            ClockKind::ByoYomi { periods } => format!("{base}+{periods}×{per_move}"),
+        }
+    }
+
+    /// What a player starts with. Byo-yomi periods are banked on top of the
+    /// base time, so the stored clock is a single budget for every kind.
+    pub fn initial_time(&self, base: Duration, per_move: Duration) -> Duration {
+        match self {
+            ClockKind::ByoYomi { periods } => base + per_move * u32::from(*periods),
+            _ => base,
+        }
+    }
+
+    /// The clock `elapsed` into a move that started with `time_left`.
+    pub fn running(&self, time_left: Duration, elapsed: Duration, per_move: Duration) -> Duration {
+        let charged = match self {
+            ClockKind::SimpleDelay => elapsed.saturating_sub(per_move),
+            _ => elapsed,
+        };
+        time_left.saturating_sub(charged)
+    }
+
+    /// How long a move starting with `time_left` can take before the flag falls.
+    pub fn time_to_flag(&self, time_left: Duration, per_move: Duration) -> Duration {
+        match self {
+            ClockKind::SimpleDelay => time_left + per_move,
+            _ => time_left,
+        }
+    }
+
+    /// The clock once a move took `elapsed`, or `None` when the flag fell first.
+    pub fn after_move(
+        &self,
+        time_left: Duration,
+        elapsed: Duration,
+        per_move: Duration,
+    ) -> Option<Duration> {
+        let running = self.running(time_left, elapsed, per_move);
+        if running.is_zero() {
+            return None;
+        }
+        Some(match self {
+            ClockKind::Fischer => running + per_move,
+            ClockKind::Bronstein => running + elapsed.min(per_move),
+            ClockKind::SimpleDelay => running,
+            ClockKind::ByoYomi { periods } => {
+                let banked = per_move * u32::from(*periods);
+                if running >= banked || per_move.is_zero() {
+                    running
+                } else {
+                    // The period the move was made in starts over
+                    let started = running.as_nanos().div_ceil(per_move.as_nanos());
+                    per_move * started as u32
+                }
+            }
+        })
+    }
+
+    /// What every move is guaranteed to add, which a takeback has to remove again.
+    pub fn fixed_bonus(&self, per_move: Duration) -> Duration {
+        match self {
+            ClockKind::Fischer => per_move,
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Byo-yomi splits the clock into the time to show and the periods left,
+    /// counting the one in progress. `None` for every other kind.
+    pub fn periods_left(&self, time_left: Duration, per_move: Duration) -> Option<(Duration, u8)> {
+        let ClockKind::ByoYomi { periods } = self else {
+            return None;
+        };
+        let banked = per_move * u32::from(*periods);
+        if time_left > banked || per_move.is_zero() {
+            return Some((time_left.saturating_sub(banked), *periods));
+        }
+        let left = time_left.as_nanos().div_ceil(per_move.as_nanos()) as u32;
+        let in_period = time_left.saturating_sub(per_move * left.saturating_sub(1));
+        Some((in_period, left as u8))
+    }
+}
+
+impl fmt::Display for ClockKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClockKind::Fischer => write!(f, "Fischer"),
+            ClockKind::Bronstein => write!(f, "Bronstein"),
+            ClockKind::SimpleDelay => write!(f, "SimpleDelay"),
+            ClockKind::ByoYomi { periods } => write!(f, "ByoYomi({periods})"),
+        }
+    }
+}
+
+impl FromStr for ClockKind {
+    type Err = ChallengeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ChallengeError::NotValidClockKind {
+            found: s.to_string(),
+        };
+        match s {
+            "Fischer" => Ok(ClockKind::Fischer),
+            "Bronstein" => Ok(ClockKind::Bronstein),
+            "SimpleDelay" => Ok(ClockKind::SimpleDelay),
+            s => {
+                let periods = s
+                    .strip_prefix("ByoYomi(")
+                    .and_then(|rest| rest.strip_suffix(')'))
+                    .and_then(|periods| periods.parse::<u8>().ok())
+                    .filter(|periods| (1..=Self::MAX_PERIODS).contains(periods))
+                    .ok_or_else(invalid)?;
+                Ok(ClockKind::ByoYomi { periods })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn fischer_adds_the_increment() {
+        let clock = ClockKind::Fischer;
+        assert_eq!(
+            clock.after_move(secs(60), secs(10), secs(2)),
+            Some(secs(52))
+        );
+        assert_eq!(clock.after_move(secs(60), secs(60), secs(2)), None);
+    }
+
+    #[test]
+    fn bronstein_refunds_at_most_the_delay() {
+        let clock = ClockKind::Bronstein;
+        assert_eq!(clock.after_move(secs(60), secs(2), secs(5)), Some(secs(60)));
+        assert_eq!(
+            clock.after_move(secs(60), secs(10), secs(5)),
+            Some(secs(55))
+        );
+        assert_eq!(clock.after_move(secs(3), secs(4), secs(5)), None);
+    }
+
+    #[test]
+    fn simple_delay_holds_the_clock() {
+        let clock = ClockKind::SimpleDelay;
+        assert_eq!(clock.running(secs(60), secs(4), secs(5)), secs(60));
+        assert_eq!(clock.after_move(secs(60), secs(8), secs(5)), Some(secs(57)));
+        assert_eq!(clock.time_to_flag(secs(3), secs(5)), secs(8));
+        assert_eq!(clock.after_move(secs(3), secs(8), secs(5)), None);
+    }
+
+    #[test]
+    fn byo_yomi_resets_the_period_in_progress() {
+        let clock = ClockKind::ByoYomi { periods: 3 };
+        let start = clock.initial_time(secs(60), secs(10));
+        assert_eq!(start, secs(90));
+        // Still in main time
+        assert_eq!(clock.after_move(start, secs(20), secs(10)), Some(secs(70)));
+        assert_eq!(clock.periods_left(secs(70), secs(10)), Some((secs(40), 3)));
+        // Into the first period: it starts over
+        assert_eq!(
+            clock.after_move(secs(35), secs(8), secs(10)),
+            Some(secs(30))
+        );
+        // Overrunning one period loses it
+        assert_eq!(
+            clock.after_move(secs(30), secs(15), secs(10)),
+            Some(secs(20))
+        );
+        assert_eq!(clock.periods_left(secs(20), secs(10)), Some((secs(10), 2)));
+        assert_eq!(clock.periods_left(secs(14), secs(10)), Some((secs(4), 2)));
+        assert_eq!(clock.after_move(secs(10), secs(10), secs(10)), None);
+    }
+
+    #[test]
+    fn takebacks_only_undo_fixed_increments() {
+        assert_eq!(ClockKind::Fischer.fixed_bonus(secs(3)), secs(3));
+        assert_eq!(ClockKind::Bronstein.fixed_bonus(secs(3)), Duration::ZERO);
+    }
+
+    #[test]
+    fn delays_need_a_real_time_clock() {
+        let delay = ClockKind::SimpleDelay;
+        assert!(delay.supports(TimeMode::RealTime, Some(5)));
+        assert!(!delay.supports(TimeMode::RealTime, Some(0)));
+        assert!(!delay.supports(TimeMode::Correspondence, Some(5)));
+        assert!(ClockKind::Fischer.supports(TimeMode::Untimed, None));
+    }
+
+    #[test]
+    fn round_trips_through_strings() {
+        for clock in [
+            ClockKind::Fischer,
+            ClockKind::Bronstein,
+            ClockKind::SimpleDelay,
+            ClockKind::ByoYomi { periods: 5 },
+        ] {
+            assert_eq!(ClockKind::from_str(&clock.to_string()).ok(), Some(clock));
+        }
+        assert!(ClockKind::from_str("ByoYomi(0)").is_err());
+        assert!(ClockKind::from_str("ByoYomi(x)").is_err());
+    }
+}
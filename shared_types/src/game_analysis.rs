@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Where a game's computer analysis is in the queue.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum AnalysisStatus {
+    #[default]
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+impl fmt::Display for AnalysisStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            Self::Pending => "Pending",
+            Self::Running => "Running",
+            Self::Done => "Done",
+            Self::Failed => "Failed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+impl FromStr for AnalysisStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Pending" => Ok(AnalysisStatus::Pending),
+            "Running" => Ok(AnalysisStatus::Running),
+            "Done" => Ok(AnalysisStatus::Done),
+            "Failed" => Ok(AnalysisStatus::Failed),
+            _ => Err(anyhow::anyhow!("Invalid AnalysisStatus string")),
+        }
+    }
+}
+
+/// Score of a finished game. A win the engine reached `n` plies into its line scores
+/// `MATE_SCORE - n`, far beyond any evaluation of a running game.
+pub const MATE_SCORE: i32 = 10_000;
+
+/// How much a move gave away compared to the engine's choice.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MoveJudgement {
+    Inaccuracy,
+    Mistake,
+    Blunder,
+}
+
+impl MoveJudgement {
+    /// Thresholds on the evaluation lost, in `State::evaluate` units.
+    const INACCURACY: i32 = 60;
+    const MISTAKE: i32 = 150;
+    const BLUNDER: i32 = 300;
+    /// Scores are capped here before comparing, so a won position that stays won, or a
+    /// lost one that stays lost, isn't flagged for the margin alone.
+    const DECIDED: i32 = 600;
+
+    /// Judges the played move by the scores after it and after the engine's move, both
+    /// from the point of view of the side that moved.
+    pub fn judge(best: i32, played: i32) -> Option<Self> {
+        let capped = |score: i32| score.clamp(-Self::DECIDED, Self::DECIDED);
+        match capped(best) - capped(played) {
+            loss if loss >= Self::BLUNDER => Some(Self::Blunder),
+            loss if loss >= Self::MISTAKE => Some(Self::Mistake),
+            loss if loss >= Self::INACCURACY => Some(Self::Inaccuracy),
+            _ => None,
+        }
+    }
+
+    /// The usual annotation glyph.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Inaccuracy => "?!",
+            Self::Mistake => "?",
+            Self::Blunder => "??",
+        }
+    }
+}
+
+impl fmt::Display for MoveJudgement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let judgement = match self {
+            Self::Inaccuracy => "Inaccuracy",
+            Self::Mistake => "Mistake",
+            Self::Blunder => "Blunder",
+        };
+        write!(f, "{judgement}")
+    }
+}
+
+impl FromStr for MoveJudgement {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Inaccuracy" => Ok(MoveJudgement::Inaccuracy),
+            "Mistake" => Ok(MoveJudgement::Mistake),
+            "Blunder" => Ok(MoveJudgement::Blunder),
+            _ => Err(anyhow::anyhow!("Invalid MoveJudgement string")),
+        }
+    }
+}
+
+/// The engine's verdict on one move of the game. Scores are `State::evaluate` at the end
+/// of a short line the engine plays from the position, or a `MATE_SCORE` if that line
+/// ends the game, and are from White's point of view.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PlyAnalysis {
+    /// Index into the game's history, like `State::hashes`.
+    pub ply: i32,
+    /// Score after the move that was played.
+    pub eval: i32,
+    /// The engine's move in the position before, in history notation.
+    pub best_move: String,
+    /// Score after the engine's move instead.
+    pub best_eval: i32,
+    pub judgement: Option<MoveJudgement>,
+}
+
+/// A game's computer analysis: its place in the queue and, once done, every move.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct AnalysisReport {
+    pub status: AnalysisStatus,
+    /// The engine's UHP `id` line.
+    pub engine: String,
+    pub error: Option<String>,
+    pub plies: Vec<PlyAnalysis>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_strings() {
+        for status in [
+            AnalysisStatus::Pending,
+            AnalysisStatus::Running,
+            AnalysisStatus::Done,
+            AnalysisStatus::Failed,
+        ] {
+            assert_eq!(
+                status,
+                AnalysisStatus::from_str(&status.to_string()).unwrap()
+            );
+        }
+        for judgement in [
+            MoveJudgement::Inaccuracy,
+            MoveJudgement::Mistake,
+            MoveJudgement::Blunder,
+        ] {
+            assert_eq!(
+                judgement,
+                MoveJudgement::from_str(&judgement.to_string()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn judges_by_the_evaluation_lost() {
+        assert_eq!(MoveJudgement::judge(0, 40), None);
+        assert_eq!(MoveJudgement::judge(100, 41), None);
+        assert_eq!(
+            MoveJudgement::judge(100, 40),
+            Some(MoveJudgement::Inaccuracy)
+        );
+        assert_eq!(MoveJudgement::judge(50, -150), Some(MoveJudgement::Mistake));
+        assert_eq!(
+            MoveJudgement::judge(0, -MATE_SCORE + 3),
+            Some(MoveJudgement::Blunder)
+        );
+    }
+
+    #[test]
+    fn decided_games_are_not_judged_by_margin() {
+        assert_eq!(MoveJudgement::judge(MATE_SCORE - 2, 900), None);
+        assert_eq!(MoveJudgement::judge(-700, -MATE_SCORE + 1), None);
+        assert_eq!(
+            MoveJudgement::judge(MATE_SCORE - 2, 0),
+            Some(MoveJudgement::Blunder)
+        );
+    }
+}
@@ -102,7 +102,7 @@ impl std::str::FromStr for GameSpeed {
             "Classic" => Ok(GameSpeed::Classic),
             "Correspondence" => Ok(GameSpeed::Correspondence),
             "Untimed" => Ok(GameSpeed::Untimed),
-            "Puzzle" => Ok(GameSpeed::Untimed),
+            "Puzzle" => Ok(GameSpeed::Puzzle),
             s => Err(GameSpeedError::InvalidGameSpeed {
                 found: s.to_string(),
             }),
@@ -1,6 +1,6 @@
 use crate::game_speed::GameSpeed;
 use chrono::{DateTime, Utc};
-use hive_lib::Color;
+use hive_lib::{Color, OpeningBook};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use thiserror::Error;
@@ -181,6 +181,9 @@ pub struct GamesQueryOptions {
     /// Canonical position hash (`game_hashes.hash`) to restrict results to games that passed
     /// through that position. Set by the opening explorer's "Search this position" link.
     pub position_hash: Option<i64>,
+    /// Opening book code (see `hive_lib::OpeningBook`) the games must have passed through,
+    /// including the more specific lines under it.
+    pub opening: Option<String>,
 }
 
 impl Default for GamesQueryOptions {
@@ -208,6 +211,7 @@ impl Default for GamesQueryOptions {
             game_progress: GameProgress::All,
             include_total: true,
             position_hash: None,
+            opening: None,
         }
     }
 }
@@ -236,6 +240,8 @@ pub enum GameQueryValidationError {
     PageOutOfRange,
     #[error("sort key is only valid for finished games")]
     SortKeyRequiresFinished,
+    #[error("unknown opening {0}")]
+    UnknownOpening(String),
 }
 
 #[derive(Error, Debug, PartialEq, Eq, Clone)]
@@ -350,6 +356,12 @@ impl GamesQueryOptions {
             }
         }
 
+        if let Some(code) = &self.opening {
+            if OpeningBook::find_in_all(code).is_empty() {
+                errors.push(GameQueryValidationError::UnknownOpening(code.clone()));
+            }
+        }
+
         if let Some(token) = &self.batch_token {
             if token.sort != self.sort {
                 errors.push(GameQueryValidationError::BatchTokenSortMismatch);
@@ -452,6 +464,9 @@ impl std::fmt::Display for GamesQueryOptions {
         if let Some(hash) = self.position_hash {
             push("position_hash", hash.to_string());
         }
+        if let Some(code) = &self.opening {
+            push("opening", code.clone());
+        }
 
         if self.page > 1 {
             push("page", self.page.to_string());
@@ -696,6 +711,10 @@ impl GamesQueryOptions {
                     }
                     _ => Some(GamesQueryParseError::InvalidBatchSize),
                 },
+                "opening" => {
+                    opts.opening = Some(value).filter(|code| !code.is_empty());
+                    None
+                }
                 "position_hash" => match value.trim().parse::<i64>() {
                     Ok(h) => {
                         opts.position_hash = Some(h);
@@ -1078,6 +1097,30 @@ mod tests {
         assert!(!base_options().to_string().contains("position_hash"));
     }
 
+    #[test]
+    fn opening_round_trips_in_query_string() {
+        let opts = GamesQueryOptions {
+            opening: Some("SG1".to_string()),
+            ..base_options()
+        };
+
+        let query = opts.to_string();
+        assert!(query.contains("opening=SG1"));
+
+        let parsed = GamesQueryOptions::from_str(&query).unwrap();
+        assert_eq!(parsed.opening.as_deref(), Some("SG1"));
+    }
+
+    #[test]
+    fn rejects_unknown_opening() {
+        let opts = GamesQueryOptions {
+            opening: Some("ZZ9".to_string()),
+            ..base_options()
+        };
+        let errs = opts.validate_all().unwrap_err();
+        assert!(errs.contains(&GameQueryValidationError::UnknownOpening("ZZ9".to_string())));
+    }
+
     #[test]
     fn only_tournament_round_trips_in_query_string() {
         let opts = GamesQueryOptions {
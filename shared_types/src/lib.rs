@@ -1,23 +1,32 @@
+mod availability;
 mod certainty;
 mod challenge;
 mod chat_capabilities;
 mod chat_message;
+mod clock_kind;
 mod conclusion;
+mod game_analysis;
 mod game_speed;
 mod game_start;
 mod games_query_options;
 mod messages_hub;
+mod move_annotation;
 mod newtypes;
 mod notification_category;
 mod notification_channel;
 mod opening_explorer;
+mod opening_stats;
 mod pretty_string;
+mod puzzle_status;
 mod ready_user;
 mod reserved_username;
 mod scoring_mode;
+mod seek;
+mod series_standings;
 mod simple_user;
 mod standings;
 mod start_mode;
+mod study_visibility;
 mod takeback_conf;
 mod telemetry;
 mod tiebreaker;
@@ -26,8 +35,11 @@ mod time_mode;
 mod tournament_details;
 mod tournament_game_result;
 mod tournament_mode;
+mod tournament_requirements;
 mod tournament_sort_order;
 mod tournament_status;
+mod variant_class;
+pub use availability::{overlapping_slots, AvailabilityWindow, MINUTES_PER_DAY};
 pub use certainty::{Certainty, RANKABLE_DEVIATION};
 pub use challenge::{ChallengeDetails, ChallengeError, ChallengeVisibility};
 pub use chat_capabilities::GameChatCapabilities;
@@ -42,7 +54,9 @@ pub use chat_message::{
     GameThread,
     MAX_CHAT_MESSAGE_LENGTH,
 };
+pub use clock_kind::ClockKind;
 pub use conclusion::Conclusion;
+pub use game_analysis::{AnalysisReport, AnalysisStatus, MoveJudgement, PlyAnalysis, MATE_SCORE};
 pub use game_speed::GameSpeed;
 pub use game_start::GameStart;
 pub use games_query_options::{
@@ -65,17 +79,38 @@ pub use messages_hub::{
     TournamentChannel,
     MESSAGES_HUB_SECTION_LIMIT,
 };
-pub use newtypes::{ApisId, ChallengeId, GameId, Password, TournamentId};
+pub use move_annotation::{GameAnnotations, MoveAnnotation, MoveNag, MAX_MOVE_COMMENT_LENGTH};
+pub use newtypes::{ApisId, ChallengeId, GameId, Password, TournamentId, TournamentSeriesId};
 pub use notification_category::NotificationCategory;
 pub use notification_channel::{CHANNEL_DISCORD, CHANNEL_EMAIL, CHANNEL_PUSH};
-pub use opening_explorer::{ExplorerFilters, ExplorerMove, MIN_PLIES};
+pub use opening_explorer::{
+    ExplorerFilters,
+    ExplorerMove,
+    ExplorerPlayer,
+    GameReview,
+    ReviewedPly,
+    MIN_PLIES,
+    RATING_BAND,
+};
+pub use opening_stats::OpeningStats;
 pub use pretty_string::PrettyString;
+pub use puzzle_status::PuzzleStatus;
 pub use ready_user::ReadyUser;
 pub use reserved_username::RESERVED_USERNAMES;
 pub use scoring_mode::ScoringMode;
+pub use seek::{SeekDetails, SeekError};
+pub use series_standings::{
+    SeriesResult,
+    SeriesScoring,
+    SeriesStanding,
+    SeriesStandings,
+    DEFAULT_PLACEMENT_POINTS,
+    MAX_PLACEMENT_POINTS,
+};
 pub use simple_user::SimpleUser;
 pub use standings::{PlayerScores, Standings};
 pub use start_mode::StartMode;
+pub use study_visibility::StudyVisibility;
 pub use takeback_conf::Takeback;
 pub use telemetry::{PushMetrics, TelemetryRange, TelemetryRow, TELEMETRY_COLUMN_COUNT};
 pub use tiebreaker::Tiebreaker;
@@ -84,5 +119,11 @@ pub use time_mode::{CorrespondenceMode, TimeMode};
 pub use tournament_details::TournamentDetails;
 pub use tournament_game_result::TournamentGameResult;
 pub use tournament_mode::TournamentMode;
+pub use tournament_requirements::{
+    TournamentApplicant,
+    TournamentJoinError,
+    TournamentRequirements,
+};
 pub use tournament_sort_order::TournamentSortOrder;
 pub use tournament_status::TournamentStatus;
+pub use variant_class::{VariantClass, VariantClassError};
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use uuid::Uuid;
+
+pub const MAX_MOVE_COMMENT_LENGTH: usize = 500;
+
+/// A move quality symbol, numbered like the standard PGN NAGs `$1`-`$6`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MoveNag {
+    Good,
+    Mistake,
+    Brilliant,
+    Blunder,
+    Interesting,
+    Dubious,
+}
+
+impl MoveNag {
+    pub fn all() -> [MoveNag; 6] {
+        [
+            Self::Brilliant,
+            Self::Good,
+            Self::Interesting,
+            Self::Dubious,
+            Self::Mistake,
+            Self::Blunder,
+        ]
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Good => "!",
+            Self::Mistake => "?",
+            Self::Brilliant => "!!",
+            Self::Blunder => "??",
+            Self::Interesting => "!?",
+            Self::Dubious => "?!",
+        }
+    }
+
+    /// The number PGN writes after `$`.
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Good => 1,
+            Self::Mistake => 2,
+            Self::Brilliant => 3,
+            Self::Blunder => 4,
+            Self::Interesting => 5,
+            Self::Dubious => 6,
+        }
+    }
+}
+
+impl fmt::Display for MoveNag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nag = match self {
+            Self::Good => "Good",
+            Self::Mistake => "Mistake",
+            Self::Brilliant => "Brilliant",
+            Self::Blunder => "Blunder",
+            Self::Interesting => "Interesting",
+            Self::Dubious => "Dubious",
+        };
+        write!(f, "{nag}")
+    }
+}
+
+impl FromStr for MoveNag {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Good" => Ok(MoveNag::Good),
+            "Mistake" => Ok(MoveNag::Mistake),
+            "Brilliant" => Ok(MoveNag::Brilliant),
+            "Blunder" => Ok(MoveNag::Blunder),
+            "Interesting" => Ok(MoveNag::Interesting),
+            "Dubious" => Ok(MoveNag::Dubious),
+            _ => Err(anyhow::anyhow!("Invalid MoveNag string")),
+        }
+    }
+}
+
+/// A player's note on one move of their game.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MoveAnnotation {
+    /// Index into the game's history.
+    pub ply: i32,
+    pub nag: Option<MoveNag>,
+    pub comment: String,
+}
+
+impl MoveAnnotation {
+    /// What follows the move in PGN movetext: the NAG and a `{}` comment. Braces can't be
+    /// escaped inside a comment, so they become parentheses.
+    pub fn pgn_suffix(&self) -> String {
+        let mut suffix = String::new();
+        if let Some(nag) = self.nag {
+            suffix.push_str(&format!(" ${}", nag.code()));
+        }
+        let comment = self.comment.trim();
+        if !comment.is_empty() {
+            let comment = comment.replace('{', "(").replace('}', ")");
+            suffix.push_str(&format!(" {{{comment}}}"));
+        }
+        suffix
+    }
+}
+
+/// Everything one player wrote on a game.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GameAnnotations {
+    pub author_id: Uuid,
+    pub author: String,
+    /// Whether other people see these, the author always does.
+    pub public: bool,
+    pub moves: Vec<MoveAnnotation>,
+}
+
+impl GameAnnotations {
+    pub fn at(&self, ply: i32) -> Option<&MoveAnnotation> {
+        self.moves.iter().find(|annotation| annotation.ply == ply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_strings() {
+        for nag in MoveNag::all() {
+            assert_eq!(nag, MoveNag::from_str(&nag.to_string()).unwrap());
+        }
+    }
+
+    #[test]
+    fn writes_pgn_nags_and_comments() {
+        let annotation = MoveAnnotation {
+            ply: 3,
+            nag: Some(MoveNag::Dubious),
+            comment: String::from(" Too slow {again} "),
+        };
+        assert_eq!(annotation.pgn_suffix(), " $6 {Too slow (again)}");
+        let bare = MoveAnnotation {
+            ply: 0,
+            nag: None,
+            comment: String::new(),
+        };
+        assert_eq!(bare.pgn_suffix(), "");
+    }
+}
@@ -44,3 +44,12 @@ impl Display for TournamentId {
         write!(f, "{}", self.0)
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct TournamentSeriesId(pub String);
+
+impl Display for TournamentSeriesId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
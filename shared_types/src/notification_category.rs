@@ -8,16 +8,18 @@ pub enum NotificationCategory {
     Tournament,
     Schedules,
     Dms,
+    Following,
 }
 
 impl NotificationCategory {
-    pub const ALL: [NotificationCategory; 6] = [
+    pub const ALL: [NotificationCategory; 7] = [
         Self::YourTurn,
         Self::Challenges,
         Self::GameEnded,
         Self::Tournament,
         Self::Schedules,
         Self::Dms,
+        Self::Following,
     ];
 
     pub fn column(&self) -> &'static str {
@@ -28,6 +30,7 @@ impl NotificationCategory {
             Self::Tournament => "tournament",
             Self::Schedules => "schedules",
             Self::Dms => "dms",
+            Self::Following => "following",
         }
     }
 }
@@ -1,13 +1,18 @@
-use crate::GameSpeed;
-use hive_lib::GameType;
+use crate::{GameSpeed, PlyAnalysis};
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use hive_lib::{Color, GameResult, GameType};
 use serde::{Deserialize, Serialize};
 
 /// Default minimum game length (in plies/turns) for the opening explorer. Games shorter than
 /// this are usually early resigns/timeouts that would skew opening statistics.
 pub const MIN_PLIES: i32 = 8;
 
-/// Filters applied when aggregating games for the opening explorer. All fields map directly to
-/// columns denormalized onto every `game_hashes` row.
+/// Width of the rating bands the explorer aggregates are kept in. Rating filters on band
+/// boundaries are answered from the aggregates; anything else falls back to `game_hashes`.
+pub const RATING_BAND: i32 = 100;
+
+/// Filters applied when aggregating games for the opening explorer. All fields but `player` map
+/// directly to columns denormalized onto every `game_hashes` row.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ExplorerFilters {
     /// Variant the explorer is browsing. Locked to one variant so suggested moves are
@@ -19,6 +24,23 @@ pub struct ExplorerFilters {
     pub rated: Option<bool>,
     /// Exclude games shorter than this many plies. Defaults to [`MIN_PLIES`].
     pub min_game_length: Option<i32>,
+    /// Restrict to one player's games, for preparation. `None` => everybody's games.
+    pub player: Option<ExplorerPlayer>,
+    /// Average rating of the two players, from `rating_min` (inclusive) to `rating_max`
+    /// (exclusive). Games without ratings only show up when both are `None`.
+    pub rating_min: Option<i32>,
+    pub rating_max: Option<i32>,
+    /// When the game was played, from `date_start` (inclusive) to `date_end` (exclusive).
+    pub date_start: Option<DateTime<Utc>>,
+    pub date_end: Option<DateTime<Utc>>,
+}
+
+/// A player whose games the explorer is limited to, matched on `games.white_id/black_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExplorerPlayer {
+    pub username: String,
+    /// Only games where the player had this colour. `None` => either colour.
+    pub color: Option<Color>,
 }
 
 impl ExplorerFilters {
@@ -28,8 +50,30 @@ impl ExplorerFilters {
             speeds: GameSpeed::all_rated_games(),
             rated: Some(true),
             min_game_length: Some(MIN_PLIES),
+            player: None,
+            rating_min: None,
+            rating_max: None,
+            date_start: None,
+            date_end: None,
         }
     }
+
+    /// Whether the pre-aggregated `position_stats` can answer these filters: no player, the
+    /// default length cut-off or none, and ratings and dates on band and month boundaries.
+    pub fn aggregated(&self) -> bool {
+        let on_band = |rating: Option<i32>| rating.is_none_or(|r| r % RATING_BAND == 0);
+        let on_month = |date: Option<DateTime<Utc>>| {
+            date.is_none_or(|d| {
+                d.day() == 1 && d.num_seconds_from_midnight() == 0 && d.nanosecond() == 0
+            })
+        };
+        self.player.is_none()
+            && matches!(self.min_game_length, None | Some(MIN_PLIES))
+            && on_band(self.rating_min)
+            && on_band(self.rating_max)
+            && on_month(self.date_start)
+            && on_month(self.date_end)
+    }
 }
 
 /// One aggregated suggested move (or the current position itself, as a header). Keyed by the
@@ -46,4 +90,158 @@ pub struct ExplorerMove {
     pub black_wins: i64,
     pub draws: i64,
     pub avg_rating: Option<f64>,
+    /// Games won and lost by the filtered player. Both zero without a player filter.
+    pub player_wins: i64,
+    pub player_losses: i64,
+}
+
+impl ExplorerMove {
+    /// Takes one game that ended in `result` back out of the counts. `player` is the colour
+    /// the filtered player had in it, if there is a player filter. The average rating stays.
+    pub fn discount(&mut self, result: &GameResult, player: Option<Color>) {
+        self.total = (self.total - 1).max(0);
+        match result {
+            GameResult::Winner(Color::White) => self.white_wins = (self.white_wins - 1).max(0),
+            GameResult::Winner(Color::Black) => self.black_wins = (self.black_wins - 1).max(0),
+            GameResult::Draw => self.draws = (self.draws - 1).max(0),
+            GameResult::Unknown => {}
+        }
+        if let (Some(player), GameResult::Winner(winner)) = (player, result) {
+            if player == *winner {
+                self.player_wins = (self.player_wins - 1).max(0);
+            } else {
+                self.player_losses = (self.player_losses - 1).max(0);
+            }
+        }
+    }
+}
+
+/// One move of a finished game next to the explorer's games. The game itself is left out.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReviewedPly {
+    /// Index into the game's history.
+    pub ply: i32,
+    /// Games that reached the position the move was played in.
+    pub before: i64,
+    /// Games that reached the position after it, transpositions included.
+    pub after: ExplorerMove,
+    /// The engine's verdict on the move, if the game was analysed.
+    pub analysis: Option<PlyAnalysis>,
+}
+
+impl ReviewedPly {
+    /// How many of the games before went on to the position after. Transpositions can lead
+    /// there from elsewhere, hence the cap.
+    pub fn popularity(&self) -> Option<f64> {
+        (self.before > 0).then(|| (self.after.total as f64 / self.before as f64).min(1.0))
+    }
+
+    /// How the side that made the move scored from there, draws counting half.
+    pub fn score(&self) -> Option<f64> {
+        if self.after.total == 0 {
+            return None;
+        }
+        let wins = if self.ply % 2 == 0 {
+            self.after.white_wins
+        } else {
+            self.after.black_wins
+        };
+        Some((wins as f64 + self.after.draws as f64 / 2.0) / self.after.total as f64)
+    }
+}
+
+/// A finished game replayed against the explorer, up to and including the move where it
+/// left known territory.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct GameReview {
+    pub plies: Vec<ReviewedPly>,
+    /// The first move no other game played here.
+    pub novelty: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn aggregates_answer_band_and_month_aligned_filters() {
+        let mut filters = ExplorerFilters::new(GameType::MLP);
+        assert!(filters.aggregated());
+        filters.rating_min = Some(1600);
+        filters.date_start = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).single();
+        assert!(filters.aggregated());
+        filters.rating_max = Some(1750);
+        assert!(!filters.aggregated());
+        filters.rating_max = None;
+        filters.date_end = Utc.with_ymd_and_hms(2026, 3, 15, 0, 0, 0).single();
+        assert!(!filters.aggregated());
+    }
+
+    #[test]
+    fn player_and_custom_lengths_need_the_raw_hashes() {
+        let mut filters = ExplorerFilters::new(GameType::Base);
+        filters.min_game_length = Some(20);
+        assert!(!filters.aggregated());
+        filters.min_game_length = None;
+        assert!(filters.aggregated());
+        filters.player = Some(ExplorerPlayer {
+            username: String::from("someone"),
+            color: None,
+        });
+        assert!(!filters.aggregated());
+    }
+
+    fn stats(total: i64, white_wins: i64, black_wins: i64, draws: i64) -> ExplorerMove {
+        ExplorerMove {
+            next_hash: 1,
+            piece: String::new(),
+            position: String::new(),
+            total,
+            white_wins,
+            black_wins,
+            draws,
+            avg_rating: None,
+            player_wins: 0,
+            player_losses: 0,
+        }
+    }
+
+    #[test]
+    fn discount_removes_one_game_and_its_result() {
+        let mut after = stats(3, 2, 0, 1);
+        after.player_wins = 2;
+        after.discount(&GameResult::Winner(Color::White), Some(Color::White));
+        assert_eq!((after.total, after.white_wins, after.draws), (2, 1, 1));
+        assert_eq!(after.player_wins, 1);
+        after.discount(&GameResult::Winner(Color::Black), None);
+        assert_eq!((after.total, after.black_wins), (1, 0));
+    }
+
+    #[test]
+    fn reviewed_plies_score_for_the_side_that_moved() {
+        let white = ReviewedPly {
+            ply: 2,
+            before: 10,
+            after: stats(4, 3, 0, 1),
+            analysis: None,
+        };
+        assert_eq!(white.popularity(), Some(0.4));
+        assert_eq!(white.score(), Some(0.875));
+        let black = ReviewedPly {
+            ply: 3,
+            before: 2,
+            after: stats(4, 3, 0, 1),
+            analysis: None,
+        };
+        assert_eq!(black.popularity(), Some(1.0));
+        assert_eq!(black.score(), Some(0.125));
+        let novelty = ReviewedPly {
+            ply: 5,
+            before: 0,
+            after: stats(0, 0, 0, 0),
+            analysis: None,
+        };
+        assert_eq!((novelty.popularity(), novelty.score()), (None, None));
+    }
 }
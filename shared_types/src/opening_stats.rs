@@ -0,0 +1,15 @@
+use hive_lib::Color;
+use serde::{Deserialize, Serialize};
+
+/// A player's results in one book opening with one colour, for their profile.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OpeningStats {
+    /// Opening book code, see `hive_lib::OpeningBook`.
+    pub code: String,
+    pub name: String,
+    pub color: Color,
+    pub games: i64,
+    pub wins: i64,
+    pub draws: i64,
+    pub losses: i64,
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Where a puzzle is in review. Mined puzzles start out as candidates and
+/// are only served to solvers once an admin approves them.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum PuzzleStatus {
+    Candidate,
+    #[default]
+    Approved,
+    Rejected,
+}
+
+impl fmt::Display for PuzzleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = match self {
+            Self::Candidate => "Candidate",
+            Self::Approved => "Approved",
+            Self::Rejected => "Rejected",
+        };
+        write!(f, "{status}")
+    }
+}
+
+impl FromStr for PuzzleStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Candidate" => Ok(PuzzleStatus::Candidate),
+            "Approved" => Ok(PuzzleStatus::Approved),
+            "Rejected" => Ok(PuzzleStatus::Rejected),
+            _ => Err(anyhow::anyhow!("Invalid PuzzleStatus string")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_strings() {
+        for status in [
+            PuzzleStatus::Candidate,
+            PuzzleStatus::Approved,
+            PuzzleStatus::Rejected,
+        ] {
+            assert_eq!(status, PuzzleStatus::from_str(&status.to_string()).unwrap());
+        }
+    }
+}
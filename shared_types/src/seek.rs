@@ -0,0 +1,69 @@
+use crate::{GameSpeed, VariantClass};
+use hive_lib::GameType;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A quick-pairing request: play anyone close in rating at this time control.
+/// Seeks are always real-time with random colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeekDetails {
+    pub game_type: GameType,
+    pub rated: bool,
+    /// Seconds
+    pub time_base: i32,
+    /// Seconds
+    pub time_increment: i32,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeekError {
+    #[error("Quick pairing is only available for real-time games")]
+    NotRealTime,
+}
+
+impl SeekDetails {
+    pub fn time_control(&self) -> (i32, i32) {
+        (self.time_base, self.time_increment)
+    }
+
+    pub fn speed(&self) -> GameSpeed {
+        GameSpeed::from_base_increment(Some(self.time_base), Some(self.time_increment))
+    }
+
+    pub fn variant_class(&self) -> VariantClass {
+        VariantClass::from(self.game_type)
+    }
+
+    pub fn validate(&self) -> Result<(), SeekError> {
+        if self.time_base <= 0 || self.time_increment < 0 || !self.speed().is_real_time() {
+            return Err(SeekError::NotRealTime);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seek(time_base: i32, time_increment: i32) -> SeekDetails {
+        SeekDetails {
+            game_type: GameType::MLP,
+            rated: true,
+            time_base,
+            time_increment,
+        }
+    }
+
+    #[test]
+    fn only_real_time_controls_can_be_sought() {
+        assert_eq!(seek(60, 2).speed(), GameSpeed::Bullet);
+        assert!(seek(60, 2).validate().is_ok());
+        assert!(seek(30 * 60, 30).validate().is_ok());
+        assert_eq!(seek(0, 10).validate(), Err(SeekError::NotRealTime));
+        assert_eq!(
+            seek(24 * 60 * 60, 0).validate(),
+            Err(SeekError::NotRealTime)
+        );
+    }
+}
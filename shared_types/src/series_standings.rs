@@ -0,0 +1,259 @@
+use crate::TournamentId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub const DEFAULT_PLACEMENT_POINTS: [i32; 6] = [10, 7, 5, 3, 2, 1];
+pub const MAX_PLACEMENT_POINTS: usize = 64;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SeriesScoring {
+    /// Points awarded for 1st, 2nd, 3rd... place. Placements past the end of
+    /// the list score zero.
+    pub placement_points: Vec<i32>,
+    /// Only the best N tournament results of each player count towards the
+    /// series total. `None` counts every result.
+    pub best_of: Option<i32>,
+}
+
+impl Default for SeriesScoring {
+    fn default() -> Self {
+        Self {
+            placement_points: DEFAULT_PLACEMENT_POINTS.to_vec(),
+            best_of: None,
+        }
+    }
+}
+
+impl SeriesScoring {
+    pub fn points_for_placement(&self, placement: usize) -> i32 {
+        placement
+            .checked_sub(1)
+            .and_then(|index| self.placement_points.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.placement_points.is_empty() {
+            return Err(String::from("At least one placement needs to award points"));
+        }
+        if self.placement_points.len() > MAX_PLACEMENT_POINTS {
+            return Err(format!(
+                "At most {MAX_PLACEMENT_POINTS} placements can award points"
+            ));
+        }
+        if self.placement_points.iter().any(|points| *points < 0) {
+            return Err(String::from("Placement points cannot be negative"));
+        }
+        if self.best_of.is_some_and(|best_of| best_of < 1) {
+            return Err(String::from("Best of needs to be >= 1"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SeriesResult {
+    pub tournament_id: TournamentId,
+    pub placement: usize,
+    pub points: i32,
+    pub counted: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SeriesStanding {
+    pub player: Uuid,
+    pub position: usize,
+    pub total: i32,
+    pub results: Vec<SeriesResult>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SeriesStandings {
+    pub scoring: SeriesScoring,
+    results: HashMap<Uuid, Vec<SeriesResult>>,
+}
+
+impl SeriesStandings {
+    pub fn new(scoring: SeriesScoring) -> Self {
+        Self {
+            scoring,
+            results: HashMap::new(),
+        }
+    }
+
+    /// Adds the final standings of a tournament. `groups` is ordered from first
+    /// to last place and players within one group are tied; tied players share
+    /// the best placement of their group, like `Standings::results` shows it.
+    pub fn add_tournament(&mut self, tournament_id: TournamentId, groups: &[Vec<Uuid>]) {
+        let mut placement = 1;
+        for group in groups {
+            let points = self.scoring.points_for_placement(placement);
+            for player in group {
+                self.results.entry(*player).or_default().push(SeriesResult {
+                    tournament_id: tournament_id.clone(),
+                    placement,
+                    points,
+                    counted: false,
+                });
+            }
+            placement += group.len();
+        }
+    }
+
+    pub fn leaderboard(&self) -> Vec<SeriesStanding> {
+        let best_of = self
+            .scoring
+            .best_of
+            .map(|n| n.max(0) as usize)
+            .unwrap_or(usize::MAX);
+        let mut rows = self
+            .results
+            .iter()
+            .map(|(player, results)| {
+                let mut results = results.clone();
+                let mut by_points: Vec<usize> = (0..results.len()).collect();
+                by_points.sort_by(|a, b| {
+                    results[*b]
+                        .points
+                        .cmp(&results[*a].points)
+                        .then(results[*a].placement.cmp(&results[*b].placement))
+                });
+                for index in by_points.into_iter().take(best_of) {
+                    results[index].counted = true;
+                }
+                let total = results.iter().filter(|r| r.counted).map(|r| r.points).sum();
+                SeriesStanding {
+                    player: *player,
+                    position: 0,
+                    total,
+                    results,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Ties on total are broken by the better single placement, then by the
+        // number of tournaments played. Anything still tied shares a position.
+        let best_placement = |row: &SeriesStanding| {
+            row.results
+                .iter()
+                .map(|r| r.placement)
+                .min()
+                .unwrap_or(usize::MAX)
+        };
+        rows.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then(best_placement(a).cmp(&best_placement(b)))
+                .then(b.results.len().cmp(&a.results.len()))
+                .then(a.player.cmp(&b.player))
+        });
+        let mut previous: Option<((i32, usize, usize), usize)> = None;
+        for (index, row) in rows.iter_mut().enumerate() {
+            let key = (row.total, best_placement(row), row.results.len());
+            row.position = match previous {
+                Some((previous_key, position)) if previous_key == key => position,
+                _ => index + 1,
+            };
+            previous = Some((key, row.position));
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tournament(id: &str) -> TournamentId {
+        TournamentId(id.to_string())
+    }
+
+    #[test]
+    fn tied_players_share_placement_points() {
+        let mut series = SeriesStandings::new(SeriesScoring::default());
+        let (one, two, three) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        series.add_tournament(tournament("a"), &[vec![one, two], vec![three]]);
+        let leaderboard = series.leaderboard();
+        let totals: HashMap<Uuid, (i32, usize)> = leaderboard
+            .iter()
+            .map(|row| (row.player, (row.total, row.results[0].placement)))
+            .collect();
+        assert_eq!(totals[&one], (10, 1));
+        assert_eq!(totals[&two], (10, 1));
+        assert_eq!(totals[&three], (5, 3));
+    }
+
+    #[test]
+    fn placements_past_the_table_score_zero() {
+        let scoring = SeriesScoring {
+            placement_points: vec![3, 1],
+            best_of: None,
+        };
+        assert_eq!(scoring.points_for_placement(1), 3);
+        assert_eq!(scoring.points_for_placement(2), 1);
+        assert_eq!(scoring.points_for_placement(3), 0);
+        assert_eq!(scoring.points_for_placement(0), 0);
+    }
+
+    #[test]
+    fn only_best_n_results_count() {
+        let scoring = SeriesScoring {
+            placement_points: vec![10, 5, 1],
+            best_of: Some(2),
+        };
+        let mut series = SeriesStandings::new(scoring);
+        let (one, two, three) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        series.add_tournament(tournament("a"), &[vec![one], vec![two], vec![three]]);
+        series.add_tournament(tournament("b"), &[vec![two], vec![three], vec![one]]);
+        series.add_tournament(tournament("c"), &[vec![three], vec![one], vec![two]]);
+        let leaderboard = series.leaderboard();
+        for row in &leaderboard {
+            assert_eq!(row.total, 15);
+            assert_eq!(row.results.iter().filter(|r| r.counted).count(), 2);
+            assert_eq!(row.position, 1);
+        }
+    }
+
+    #[test]
+    fn leaderboard_orders_by_total_then_best_placement() {
+        let mut series = SeriesStandings::new(SeriesScoring {
+            placement_points: vec![4, 3, 2, 1],
+            best_of: None,
+        });
+        let (one, two, three) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        series.add_tournament(tournament("a"), &[vec![one], vec![three], vec![two]]);
+        series.add_tournament(tournament("b"), &[vec![two], vec![three], vec![one]]);
+        let leaderboard = series.leaderboard();
+        // one: 4 + 2, two: 2 + 4, three: 3 + 3
+        assert_eq!(leaderboard[0].total, 6);
+        assert_eq!(leaderboard[0].position, 1);
+        assert_eq!(leaderboard[1].position, 1);
+        assert_eq!(leaderboard[2].player, three);
+        assert_eq!(leaderboard[2].position, 3);
+    }
+
+    #[test]
+    fn validation_rejects_bad_scoring() {
+        assert!(SeriesScoring::default().validate().is_ok());
+        assert!(SeriesScoring {
+            placement_points: vec![],
+            best_of: None
+        }
+        .validate()
+        .is_err());
+        assert!(SeriesScoring {
+            placement_points: vec![1, -1],
+            best_of: None
+        }
+        .validate()
+        .is_err());
+        assert!(SeriesScoring {
+            placement_points: vec![1],
+            best_of: Some(0)
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Who can open a study. Unlisted studies work for anyone with the link but
+/// don't show up on the owner's profile.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub enum StudyVisibility {
+    Public,
+    #[default]
+    Unlisted,
+    Private,
+}
+
+impl StudyVisibility {
+    pub fn all() -> [StudyVisibility; 3] {
+        [Self::Public, Self::Unlisted, Self::Private]
+    }
+}
+
+impl fmt::Display for StudyVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let visibility = match self {
+            Self::Public => "Public",
+            Self::Unlisted => "Unlisted",
+            Self::Private => "Private",
+        };
+        write!(f, "{visibility}")
+    }
+}
+
+impl FromStr for StudyVisibility {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Public" => Ok(StudyVisibility::Public),
+            "Unlisted" => Ok(StudyVisibility::Unlisted),
+            "Private" => Ok(StudyVisibility::Private),
+            _ => Err(anyhow::anyhow!("Invalid StudyVisibility string")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_strings() {
+        for visibility in StudyVisibility::all() {
+            assert_eq!(
+                visibility,
+                StudyVisibility::from_str(&visibility.to_string()).unwrap()
+            );
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use crate::TimeMode;
+use crate::{ClockKind, TimeMode};
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct TimeInfo {
     pub mode: TimeMode,
     pub base: Option<i32>,
     pub increment: Option<i32>,
+    pub clock: ClockKind,
 }
@@ -1,4 +1,4 @@
-use crate::{ScoringMode, StartMode, Tiebreaker, TimeMode};
+use crate::{ClockKind, ScoringMode, StartMode, Tiebreaker, TimeMode};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -18,8 +18,13 @@ pub struct TournamentDetails {
     pub time_mode: TimeMode,
     pub time_base: Option<i32>,
     pub time_increment: Option<i32>,
+    #[serde(default)]
+    pub clock_kind: ClockKind,
     pub band_upper: Option<i32>,
     pub band_lower: Option<i32>,
+    pub min_rated_games: i32,
+    pub min_account_age_days: i32,
+    pub allow_bots: bool,
     pub start_mode: StartMode,
     pub starts_at: Option<DateTime<Utc>>,
     pub round_duration: Option<i32>,
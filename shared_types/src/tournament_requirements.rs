@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Entry requirements a player has to meet to join a tournament.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TournamentRequirements {
+    /// Rated games the player needs at the tournament's speed.
+    pub min_rated_games: i32,
+    pub min_account_age_days: i32,
+    pub allow_bots: bool,
+    pub band_lower: Option<i32>,
+    pub band_upper: Option<i32>,
+}
+
+impl Default for TournamentRequirements {
+    fn default() -> Self {
+        Self {
+            min_rated_games: 0,
+            min_account_age_days: 0,
+            allow_bots: true,
+            band_lower: None,
+            band_upper: None,
+        }
+    }
+}
+
+/// What we know about a player who wants to join, taken at the tournament's speed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TournamentApplicant {
+    pub rating: i32,
+    pub rated_games: i64,
+    pub account_age_days: i64,
+    pub bot: bool,
+}
+
+#[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TournamentJoinError {
+    #[error("The tournament has already started")]
+    AlreadyStarted,
+    #[error("The tournament is invite only")]
+    InviteOnly,
+    #[error("Bots cannot join this tournament")]
+    BotsNotAllowed,
+    #[error("Your account needs to be at least {required} days old, it is {age} days old")]
+    AccountTooNew { required: i32, age: i64 },
+    #[error("You need at least {required} rated games at this speed, you have {played}")]
+    NotEnoughRatedGames { required: i32, played: i64 },
+    #[error("Your rating of {rating} is below the minimum of {minimum}")]
+    RatingTooLow { rating: i32, minimum: i32 },
+    #[error("Your rating of {rating} is above the maximum of {maximum}")]
+    RatingTooHigh { rating: i32, maximum: i32 },
+}
+
+impl TournamentRequirements {
+    /// Whether checking requires the applicant's rating row for the tournament speed.
+    pub fn needs_rating(&self) -> bool {
+        self.min_rated_games > 0 || self.band_lower.is_some() || self.band_upper.is_some()
+    }
+
+    pub fn check(&self, applicant: &TournamentApplicant) -> Result<(), TournamentJoinError> {
+        if applicant.bot && !self.allow_bots {
+            return Err(TournamentJoinError::BotsNotAllowed);
+        }
+        if applicant.account_age_days < self.min_account_age_days as i64 {
+            return Err(TournamentJoinError::AccountTooNew {
+                required: self.min_account_age_days,
+                age: applicant.account_age_days,
+            });
+        }
+        if applicant.rated_games < self.min_rated_games as i64 {
+            return Err(TournamentJoinError::NotEnoughRatedGames {
+                required: self.min_rated_games,
+                played: applicant.rated_games,
+            });
+        }
+        if let Some(minimum) = self.band_lower {
+            if applicant.rating < minimum {
+                return Err(TournamentJoinError::RatingTooLow {
+                    rating: applicant.rating,
+                    minimum,
+                });
+            }
+        }
+        if let Some(maximum) = self.band_upper {
+            if applicant.rating > maximum {
+                return Err(TournamentJoinError::RatingTooHigh {
+                    rating: applicant.rating,
+                    maximum,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn applicant() -> TournamentApplicant {
+        TournamentApplicant {
+            rating: 1500,
+            rated_games: 20,
+            account_age_days: 30,
+            bot: false,
+        }
+    }
+
+    #[test]
+    fn default_requirements_accept_everyone() {
+        let requirements = TournamentRequirements::default();
+        assert!(!requirements.needs_rating());
+        assert!(requirements
+            .check(&TournamentApplicant {
+                rating: 0,
+                rated_games: 0,
+                account_age_days: 0,
+                bot: true,
+            })
+            .is_ok());
+    }
+
+    #[test]
+    fn rating_band_is_inclusive() {
+        let requirements = TournamentRequirements {
+            band_lower: Some(1500),
+            band_upper: Some(1500),
+            ..Default::default()
+        };
+        assert!(requirements.check(&applicant()).is_ok());
+        assert_eq!(
+            requirements.check(&TournamentApplicant {
+                rating: 1499,
+                ..applicant()
+            }),
+            Err(TournamentJoinError::RatingTooLow {
+                rating: 1499,
+                minimum: 1500
+            })
+        );
+        assert_eq!(
+            requirements.check(&TournamentApplicant {
+                rating: 1501,
+                ..applicant()
+            }),
+            Err(TournamentJoinError::RatingTooHigh {
+                rating: 1501,
+                maximum: 1500
+            })
+        );
+    }
+
+    #[test]
+    fn reports_the_first_unmet_requirement() {
+        let requirements = TournamentRequirements {
+            min_rated_games: 25,
+            min_account_age_days: 60,
+            allow_bots: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            requirements.check(&TournamentApplicant {
+                bot: true,
+                ..applicant()
+            }),
+            Err(TournamentJoinError::BotsNotAllowed)
+        );
+        assert_eq!(
+            requirements.check(&applicant()),
+            Err(TournamentJoinError::AccountTooNew {
+                required: 60,
+                age: 30
+            })
+        );
+        assert_eq!(
+            requirements.check(&TournamentApplicant {
+                account_age_days: 60,
+                ..applicant()
+            }),
+            Err(TournamentJoinError::NotEnoughRatedGames {
+                required: 25,
+                played: 20
+            })
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use hive_lib::GameType;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Base Hive and the expansion variants play differently enough to be rated
+/// separately. All expansion combinations share one rating.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum VariantClass {
+    Base,
+    Expansions,
+}
+
+impl VariantClass {
+    pub fn all() -> Vec<VariantClass> {
+        vec![VariantClass::Base, VariantClass::Expansions]
+    }
+}
+
+impl From<GameType> for VariantClass {
+    fn from(game_type: GameType) -> Self {
+        match game_type {
+            GameType::Base => VariantClass::Base,
+            _ => VariantClass::Expansions,
+        }
+    }
+}
+
+impl fmt::Display for VariantClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let variant = match self {
+            VariantClass::Base => "Base",
+            VariantClass::Expansions => "Expansions",
+        };
+        write!(f, "{variant}")
+    }
+}
+
+#[derive(Error, Debug, Clone, Serialize, Deserialize)]
+pub enum VariantClassError {
+    #[error("{found} is not a valid VariantClass")]
+    InvalidVariantClass { found: String },
+}
+
+impl FromStr for VariantClass {
+    type Err = VariantClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Base" => Ok(VariantClass::Base),
+            "Expansions" => Ok(VariantClass::Expansions),
+            s => Err(VariantClassError::InvalidVariantClass {
+                found: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_base_is_base() {
+        assert_eq!(VariantClass::from(GameType::Base), VariantClass::Base);
+        for game_type in [GameType::M, GameType::L, GameType::P, GameType::MLP] {
+            assert_eq!(VariantClass::from(game_type), VariantClass::Expansions);
+        }
+    }
+
+    #[test]
+    fn round_trips_through_strings() {
+        for variant in VariantClass::all() {
+            assert_eq!(
+                VariantClass::from_str(&variant.to_string()).unwrap(),
+                variant
+            );
+        }
+    }
+}